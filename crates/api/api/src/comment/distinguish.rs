@@ -2,6 +2,7 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_utils::{
   context::LemmyContext,
+  notify::notify_comment_distinguished,
   send_activity::{ActivityChannel, SendActivityData},
   utils::{check_community_mod_action, check_community_user_action},
 };
@@ -57,6 +58,9 @@ pub async fn distinguish_comment(
   };
 
   let comment = Comment::update(&mut context.pool(), data.comment_id, &form).await?;
+  if data.distinguished && !orig_comment.comment.distinguished {
+    notify_comment_distinguished(&comment, &context);
+  }
   ActivityChannel::submit_activity(SendActivityData::UpdateComment(comment), &context)?;
 
   let comment_view = CommentView::read(
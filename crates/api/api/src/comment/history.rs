@@ -0,0 +1,143 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_comment_deleted_or_removed, check_private_instance, is_mod_or_admin_opt},
+};
+use lemmy_db_schema::source::{comment::Comment, comment_revision::CommentRevision};
+use lemmy_db_schema_file::enums::EditHistoryVisibility;
+use lemmy_db_views_comment::{
+  CommentView,
+  api::{GetCommentHistory, GetCommentHistoryResponse},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Lists previous versions of an edited comment. Depending on the site settings this is only
+/// available to mods and admins.
+pub async fn get_comment_history(
+  Query(data): Query<GetCommentHistory>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<GetCommentHistoryResponse>> {
+  let SiteView {
+    site, local_site, ..
+  } = SiteView::read_local(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let comment = Comment::read(&mut context.pool(), data.comment_id).await?;
+  let is_mod_or_admin = is_mod_or_admin_opt(
+    &mut context.pool(),
+    local_user_view.as_ref(),
+    Some(comment.community_id),
+  )
+  .await
+  .is_ok();
+
+  if !is_mod_or_admin {
+    if local_site.edit_history_visibility == EditHistoryVisibility::ModsOnly {
+      return Err(LemmyErrorType::NotAModOrAdmin.into());
+    }
+    // Mods need the previous versions of removed comments to handle reports, but for others they
+    // shouldn't be readable either
+    check_comment_deleted_or_removed(&comment)?;
+    // Make sure the user can see the comment itself
+    CommentView::read(
+      &mut context.pool(),
+      comment.id,
+      local_user_view.as_ref().map(|l| &l.local_user),
+      site.instance_id,
+    )
+    .await?;
+  }
+
+  let revisions = CommentRevision::list_for_comment(&mut context.pool(), comment.id).await?;
+  Ok(Json(GetCommentHistoryResponse { revisions }))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_db_schema::{
+    source::{
+      comment::{CommentInsertForm, CommentUpdateForm},
+      community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
+      local_site::{LocalSite, LocalSiteUpdateForm},
+      post::{Post, PostInsertForm},
+    },
+    test_data::TestData,
+  };
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_history_of_removed_comment() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+    let data = TestData::create(pool).await?;
+    let form = LocalSiteUpdateForm {
+      edit_history_visibility: Some(EditHistoryVisibility::Everyone),
+      ..Default::default()
+    };
+    LocalSite::update(pool, &form).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(
+        data.instance.id,
+        "comment_history".to_string(),
+        "pubkey".to_string(),
+      ),
+    )
+    .await?;
+    let moderator = LocalUserView::create_test_user(pool, "comment_history_mod", "", false).await?;
+    let form = CommunityModeratorForm::new(community.id, moderator.person.id);
+    CommunityActions::join(pool, &form).await?;
+    let user = LocalUserView::create_test_user(pool, "comment_history_user", "", false).await?;
+
+    let post = Post::create(
+      pool,
+      &PostInsertForm::new("A post".into(), data.person.id, community.id),
+    )
+    .await?;
+    let original = Comment::create(
+      pool,
+      &CommentInsertForm::new(data.person.id, post.id, community.id, "First".into()),
+      None,
+    )
+    .await?;
+    let form = CommentUpdateForm {
+      content: Some("Second".into()),
+      removed: Some(true),
+      ..Default::default()
+    };
+    let updated = Comment::update(pool, original.id, &form).await?;
+    CommentRevision::create_if_changed(pool, &original, &updated).await?;
+
+    // Mods can read the original version of the removed comment
+    let query = || {
+      Query(GetCommentHistory {
+        comment_id: original.id,
+      })
+    };
+    let Json(history) = get_comment_history(query(), context.clone(), Some(moderator)).await?;
+    let contents = history
+      .revisions
+      .iter()
+      .map(|r| r.content.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(vec!["First"], contents);
+
+    // Even if everyone can see the edit history, the removed comment stays hidden for others
+    let res = get_comment_history(query(), context.clone(), Some(user)).await;
+    assert_eq!(
+      Some(LemmyErrorType::Deleted),
+      res.err().map(|e| e.error_type)
+    );
+
+    data.delete(pool).await?;
+    Ok(())
+  }
+}
@@ -1,6 +1,8 @@
 pub mod distinguish;
+pub mod history;
 pub mod like;
 pub mod list_comment_likes;
 pub mod lock;
+pub mod react;
 pub mod save;
 pub mod warning;
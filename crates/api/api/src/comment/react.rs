@@ -0,0 +1,79 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  build_response::build_comment_response,
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{build_reaction_form, check_bot_account, check_community_user_action},
+};
+use lemmy_db_schema::{newtypes::PostOrCommentId, source::reaction::Reaction};
+use lemmy_db_views_comment::{
+  CommentView,
+  api::{CommentResponse, CreateCommentReaction},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+use std::ops::Deref;
+
+pub async fn react_comment(
+  Json(data): Json<CreateCommentReaction>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommentResponse>> {
+  let local_instance_id = local_user_view.person.instance_id;
+  let comment_id = data.comment_id;
+  let my_person_id = local_user_view.person.id;
+
+  check_bot_account(&local_user_view.person)?;
+
+  let orig_comment = CommentView::read(
+    &mut context.pool(),
+    comment_id,
+    Some(&local_user_view.local_user),
+    local_instance_id,
+  )
+  .await?;
+
+  check_community_user_action(
+    &local_user_view,
+    &orig_comment.community,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let mut form = build_reaction_form(&data.emoji, my_person_id, &mut context.pool()).await?;
+  if data.add {
+    form.comment_id = Some(comment_id);
+    Reaction::add(&mut context.pool(), &form).await?;
+  } else {
+    Reaction::remove(
+      &mut context.pool(),
+      my_person_id,
+      PostOrCommentId::Comment(comment_id),
+      &form.emoji,
+    )
+    .await?;
+  }
+
+  ActivityChannel::submit_activity(
+    SendActivityData::ReactPostOrComment {
+      object_id: orig_comment.comment.ap_id,
+      actor: local_user_view.person.clone(),
+      community: orig_comment.community,
+      emoji: form.emoji,
+      emoji_url: form.emoji_url,
+      add: data.add,
+    },
+    &context,
+  )?;
+
+  Ok(Json(
+    build_comment_response(
+      context.deref(),
+      comment_id,
+      Some(local_user_view),
+      local_instance_id,
+    )
+    .await?,
+  ))
+}
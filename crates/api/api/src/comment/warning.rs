@@ -5,7 +5,8 @@ use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_comment_deleted_or_removed, check_community_mod_action},
+  utils::{check_comment_deleted_or_removed, check_community_mod_action, check_expire_time},
+  warning_policy::apply_warning_policy,
 };
 use lemmy_db_schema::source::modlog::{Modlog, ModlogInsertForm};
 use lemmy_db_views_comment::{
@@ -23,6 +24,7 @@ pub async fn create_comment_warning(
 ) -> LemmyResult<Json<CommentResponse>> {
   let local_instance_id = local_user_view.person.instance_id;
   let comment_id = data.comment_id;
+  let expires_at = check_expire_time(data.expires_at)?;
 
   let orig_comment =
     CommentView::read(&mut context.pool(), comment_id, None, local_instance_id).await?;
@@ -43,7 +45,8 @@ pub async fn create_comment_warning(
     &orig_comment.comment,
     orig_comment.community.id,
     &data.reason,
-  );
+  )
+  .with_expires_at(expires_at);
 
   let action = Modlog::create(&mut context.pool(), &[form]).await?;
 
@@ -53,11 +56,14 @@ pub async fn create_comment_warning(
     SendActivityData::Warning(
       Box::new(Either::Right(orig_comment.clone())),
       data.reason,
+      expires_at,
       local_user_view.person,
     ),
     &context,
   )?;
 
+  apply_warning_policy(&orig_comment.community, orig_comment.creator.id, &context).await;
+
   Ok(Json(CommentResponse {
     comment_view: orig_comment,
   }))
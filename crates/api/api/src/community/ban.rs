@@ -1,40 +1,26 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use diesel_async::scoped_futures::ScopedFutureExt;
 use lemmy_api_utils::{
   context::LemmyContext,
-  notify::notify_mod_action,
-  send_activity::{ActivityChannel, SendActivityData},
   utils::{
+    ban_person_from_community,
     check_community_mod_action,
     check_expire_time,
-    remove_or_restore_user_data_in_community,
+    read_cited_rule,
   },
 };
-use lemmy_db_schema::{
-  source::{
-    community::{Community, CommunityActions, CommunityPersonBanForm},
-    local_user::LocalUser,
-    modlog::{Modlog, ModlogInsertForm},
-  },
-  traits::{Bannable, Followable},
-};
+use lemmy_db_schema::source::{community::Community, local_user::LocalUser};
 use lemmy_db_views_community::api::BanFromCommunity;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_person::{PersonView, api::PersonResponse};
-use lemmy_diesel_utils::{connection::get_conn, traits::Crud};
-use lemmy_utils::{
-  error::{LemmyErrorType, LemmyResult},
-  spawn_try_task,
-  utils::validation::is_valid_body_field,
-};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_body_field};
 
 pub async fn ban_from_community(
   Json(data): Json<BanFromCommunity>,
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<PersonResponse>> {
-  let banned_person_id = data.person_id;
   let my_person_id = local_user_view.person.id;
   let expires_at = check_expire_time(data.expires_at)?;
   let local_instance_id = local_user_view.person.instance_id;
@@ -52,67 +38,18 @@ pub async fn ban_from_community(
   .await?;
 
   is_valid_body_field(&data.reason, false)?;
+  let rule = read_cited_rule(data.rule_id, Some(community.id), &mut context.pool()).await?;
+  let rule_id = rule.map(|r| r.id);
 
-  let community_user_ban_form = CommunityPersonBanForm {
-    ban_expires_at: Some(expires_at),
-    ..CommunityPersonBanForm::new(data.community_id, data.person_id)
-  };
-
-  let pool = &mut context.pool();
-  let conn = &mut get_conn(pool).await?;
-  let tx_data = data.clone();
-  let action = conn
-    .run_transaction(|conn| {
-      async move {
-        if tx_data.ban {
-          CommunityActions::ban(&mut conn.into(), &community_user_ban_form).await?;
-
-          // Also unsubscribe them from the community, if they are subscribed
-          CommunityActions::unfollow(&mut conn.into(), banned_person_id, tx_data.community_id)
-            .await
-            .ok();
-        } else {
-          CommunityActions::unban(&mut conn.into(), &community_user_ban_form).await?;
-        }
-
-        // Mod tables - create ban entry first so bulk actions can reference it as parent
-        let form = ModlogInsertForm::mod_ban_from_community(
-          my_person_id,
-          tx_data.community_id,
-          tx_data.person_id,
-          tx_data.ban,
-          expires_at,
-          &tx_data.reason,
-        );
-        let action = Modlog::create(&mut conn.into(), &[form]).await?;
-
-        Ok(action)
-      }
-      .scope_boxed()
-    })
-    .await?;
-  notify_mod_action(action.clone(), &context);
-
-  // Remove/Restore their data in background if that's desired
-  // Cant do this inside transaction because `conn` cannot be passed into spawn_try_task.
-  if data.remove_or_restore_data.unwrap_or(false) {
-    let remove_data = data.ban;
-    let reason = data.reason.clone();
-    let ban_id = action.first().ok_or(LemmyErrorType::NotFound)?.id;
-    let context = context.clone();
-    spawn_try_task(async move {
-      remove_or_restore_user_data_in_community(
-        data.community_id,
-        my_person_id,
-        banned_person_id,
-        remove_data,
-        &reason,
-        ban_id,
-        &mut context.pool(),
-      )
-      .await
-    });
-  }
+  ban_person_from_community(
+    &data,
+    &local_user_view.person,
+    expires_at,
+    rule_id,
+    None,
+    &context,
+  )
+  .await?;
 
   let person_view = PersonView::read(
     &mut context.pool(),
@@ -123,15 +60,5 @@ pub async fn ban_from_community(
   )
   .await?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::BanFromCommunity {
-      moderator: local_user_view.person,
-      community_id: data.community_id,
-      target: person_view.person.clone(),
-      data: data.clone(),
-    },
-    &context,
-  )?;
-
   Ok(Json(PersonResponse { person_view }))
 }
@@ -0,0 +1,89 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  utils::{
+    ban_person_from_community,
+    check_community_mod_action,
+    check_expire_time,
+    read_cited_rule,
+  },
+};
+use lemmy_db_schema::source::{
+  community::Community,
+  local_user::LocalUser,
+  modlog::{Modlog, ModlogInsertForm},
+  person::Person,
+};
+use lemmy_db_views_community::api::{BanFromCommunity, BulkBanFromCommunity};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modlog::api::BulkActionResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::{check_api_elements_count, is_valid_body_field},
+};
+
+pub async fn bulk_ban_from_community(
+  Json(data): Json<BulkBanFromCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BulkActionResponse>> {
+  check_api_elements_count(data.person_ids.len())?;
+  let my_person_id = local_user_view.person.id;
+  let expires_at = check_expire_time(data.expires_at)?;
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+
+  // Verify that only mods or admins can ban
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+
+  LocalUser::is_higher_mod_or_admin_check(
+    &mut context.pool(),
+    community.id,
+    my_person_id,
+    data.person_ids.clone(),
+  )
+  .await?;
+
+  is_valid_body_field(&data.reason, false)?;
+  let rule = read_cited_rule(data.rule_id, Some(community.id), &mut context.pool()).await?;
+  let rule_id = rule.map(|r| r.id);
+
+  let targets = Person::read_many(&mut context.pool(), &data.person_ids).await?;
+  if targets.is_empty() {
+    return Err(LemmyErrorType::NotFound.into());
+  }
+
+  // Create the parent first so that the bans can reference it
+  let parent_form =
+    ModlogInsertForm::mod_bulk_action(my_person_id, community.id, &data.reason).with_rule(rule_id);
+  let parent = Modlog::create(&mut context.pool(), &[parent_form]).await?;
+  let parent_id = parent.first().ok_or(LemmyErrorType::NotFound)?.id;
+
+  for target in &targets {
+    let ban_data = BanFromCommunity {
+      community_id: community.id,
+      person_id: target.id,
+      ban: data.ban,
+      remove_or_restore_data: data.remove_or_restore_data,
+      reason: data.reason.clone(),
+      expires_at: data.expires_at,
+      rule_id: data.rule_id,
+    };
+    ban_person_from_community(
+      &ban_data,
+      &local_user_view.person,
+      expires_at,
+      rule_id,
+      Some(parent_id),
+      &context,
+    )
+    .await?;
+  }
+  notify_mod_action(parent, &context);
+
+  // Read the parent again, to include the child count
+  let bulk_action = Modlog::read(&mut context.pool(), parent_id).await?;
+  Ok(Json(BulkActionResponse { bulk_action }))
+}
@@ -0,0 +1,93 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::source::{
+  community::Community,
+  modlog::{Modlog, ModlogInsertForm},
+  post::{Post, PostUpdateForm},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modlog::api::{BulkActionResponse, BulkLockPosts};
+use lemmy_diesel_utils::{connection::get_conn, traits::Crud};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::check_api_elements_count,
+};
+
+pub async fn bulk_lock_posts(
+  Json(data): Json<BulkLockPosts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BulkActionResponse>> {
+  check_api_elements_count(data.post_ids.len())?;
+  let my_person_id = local_user_view.person.id;
+
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+
+  // Posts of other communities are skipped
+  let posts =
+    Post::read_many_in_community(&mut context.pool(), &data.post_ids, community.id).await?;
+  if posts.is_empty() {
+    return Err(LemmyErrorType::NotFound.into());
+  }
+  let post_ids: Vec<_> = posts.iter().map(|p| p.id).collect();
+  let community_id = community.id;
+  let locked = data.locked;
+  let reason = data.reason.clone();
+
+  let pool = &mut context.pool();
+  let conn = &mut get_conn(pool).await?;
+  let (posts, actions) = conn
+    .run_transaction(|conn| {
+      async move {
+        let form = PostUpdateForm {
+          locked: Some(locked),
+          ..Default::default()
+        };
+        let posts = Post::update_many(&mut conn.into(), &post_ids, &form).await?;
+
+        // Create the parent first so that the locks can reference it
+        let parent_form = ModlogInsertForm::mod_bulk_action(my_person_id, community_id, &reason);
+        let mut actions = Modlog::create(&mut conn.into(), &[parent_form]).await?;
+        let parent_id = actions.first().ok_or(LemmyErrorType::NotFound)?.id;
+
+        let forms: Vec<_> = posts
+          .iter()
+          .map(|post| {
+            ModlogInsertForm::mod_lock_post(my_person_id, post, locked, &reason)
+              .with_bulk_action_parent(parent_id)
+          })
+          .collect();
+        actions.extend(Modlog::create(&mut conn.into(), &forms).await?);
+
+        Ok((posts, actions))
+      }
+      .scope_boxed()
+    })
+    .await?;
+
+  let parent_id = actions.first().ok_or(LemmyErrorType::NotFound)?.id;
+  notify_mod_action(actions, &context);
+
+  ActivityChannel::submit_activity(
+    SendActivityData::BulkLockPosts {
+      posts,
+      moderator: local_user_view.person,
+      community,
+      locked: data.locked,
+      reason: data.reason,
+    },
+    &context,
+  )?;
+
+  // Read the parent again, to include the child count
+  let bulk_action = Modlog::read(&mut context.pool(), parent_id).await?;
+  Ok(Json(BulkActionResponse { bulk_action }))
+}
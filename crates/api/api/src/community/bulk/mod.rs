@@ -0,0 +1,4 @@
+pub mod ban;
+pub mod lock;
+pub mod remove;
+pub mod resolve_reports;
@@ -0,0 +1,142 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, read_cited_rule},
+};
+use lemmy_db_schema::source::{
+  comment::{Comment, CommentUpdateForm},
+  comment_report::CommentReport,
+  community::Community,
+  local_user::LocalUser,
+  modlog::{Modlog, ModlogInsertForm},
+  post::{Post, PostUpdateForm},
+  post_report::PostReport,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modlog::api::{BulkActionResponse, BulkRemove};
+use lemmy_diesel_utils::{connection::get_conn, traits::Crud};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::check_api_elements_count,
+};
+
+pub async fn bulk_remove(
+  Json(data): Json<BulkRemove>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BulkActionResponse>> {
+  let post_ids = data.post_ids.clone().unwrap_or_default();
+  let comment_ids = data.comment_ids.clone().unwrap_or_default();
+  check_api_elements_count(post_ids.len() + comment_ids.len())?;
+  let my_person_id = local_user_view.person.id;
+
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  let rule = read_cited_rule(data.rule_id, Some(community.id), &mut context.pool()).await?;
+  let rule_id = rule.map(|r| r.id);
+
+  // Items of other communities are skipped. Comments which were deleted by their creator are
+  // skipped too, as removing them would reveal the comment text in the modlog.
+  let posts = Post::read_many_in_community(&mut context.pool(), &post_ids, community.id).await?;
+  let comments: Vec<_> =
+    Comment::read_many_in_community(&mut context.pool(), &comment_ids, community.id)
+      .await?
+      .into_iter()
+      .filter(|c| !c.deleted)
+      .collect();
+  if posts.is_empty() && comments.is_empty() {
+    return Err(LemmyErrorType::NotFound.into());
+  }
+
+  let creator_ids = posts
+    .iter()
+    .map(|p| p.creator_id)
+    .chain(comments.iter().map(|c| c.creator_id))
+    .collect();
+  LocalUser::is_higher_mod_or_admin_check(
+    &mut context.pool(),
+    community.id,
+    my_person_id,
+    creator_ids,
+  )
+  .await?;
+
+  let post_ids: Vec<_> = posts.iter().map(|p| p.id).collect();
+  let comment_ids: Vec<_> = comments.iter().map(|c| c.id).collect();
+  let community_id = community.id;
+  let removed = data.removed;
+  let reason = data.reason.clone();
+
+  let pool = &mut context.pool();
+  let conn = &mut get_conn(pool).await?;
+  let (posts, comments, actions) = conn
+    .run_transaction(|conn| {
+      async move {
+        let post_form = PostUpdateForm {
+          removed: Some(removed),
+          ..Default::default()
+        };
+        let posts = Post::update_many(&mut conn.into(), &post_ids, &post_form).await?;
+        let comment_form = CommentUpdateForm {
+          removed: Some(removed),
+          ..Default::default()
+        };
+        let comments = Comment::update_many(&mut conn.into(), &comment_ids, &comment_form).await?;
+
+        PostReport::resolve_all_for_posts(&mut conn.into(), &post_ids, my_person_id).await?;
+        CommentReport::resolve_all_for_comments(&mut conn.into(), &comment_ids, my_person_id)
+          .await?;
+
+        // Create the parent first so that the removals can reference it
+        let parent_form =
+          ModlogInsertForm::mod_bulk_action(my_person_id, community_id, &reason).with_rule(rule_id);
+        let mut actions = Modlog::create(&mut conn.into(), &[parent_form]).await?;
+        let parent_id = actions.first().ok_or(LemmyErrorType::NotFound)?.id;
+
+        let post_forms = posts.iter().map(|post| {
+          ModlogInsertForm::mod_remove_post(my_person_id, post, removed, &reason, Some(parent_id))
+            .with_rule(rule_id)
+        });
+        let comment_forms = comments.iter().map(|comment| {
+          ModlogInsertForm::mod_remove_comment(
+            my_person_id,
+            comment,
+            community_id,
+            removed,
+            &reason,
+            Some(parent_id),
+          )
+          .with_rule(rule_id)
+        });
+        let forms: Vec<_> = post_forms.chain(comment_forms).collect();
+        actions.extend(Modlog::create(&mut conn.into(), &forms).await?);
+
+        Ok((posts, comments, actions))
+      }
+      .scope_boxed()
+    })
+    .await?;
+
+  let parent_id = actions.first().ok_or(LemmyErrorType::NotFound)?.id;
+  notify_mod_action(actions, &context);
+
+  ActivityChannel::submit_activity(
+    SendActivityData::BulkRemove {
+      posts,
+      comments,
+      moderator: local_user_view.person,
+      community,
+      reason: data.reason,
+      removed: data.removed,
+    },
+    &context,
+  )?;
+
+  // Read the parent again, to include the child count
+  let bulk_action = Modlog::read(&mut context.pool(), parent_id).await?;
+  Ok(Json(BulkActionResponse { bulk_action }))
+}
@@ -0,0 +1,145 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::source::{
+  comment::Comment,
+  comment_report::{CommentReport, UpdateCommentReportForm},
+  community::Community,
+  modlog::{Modlog, ModlogInsertForm},
+  post::Post,
+  post_report::{PostReport, UpdatePostReportForm},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modlog::api::BulkActionResponse;
+use lemmy_db_views_report_combined::api::BulkResolveReports;
+use lemmy_diesel_utils::{connection::get_conn, traits::Crud, utils::diesel_string_update};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::check_api_elements_count,
+};
+
+/// Resolves or unresolves many reports at once. The modlog only lists the reported posts and
+/// comments, not the users who created them.
+pub async fn bulk_resolve_reports(
+  Json(data): Json<BulkResolveReports>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BulkActionResponse>> {
+  let post_report_ids = data.post_report_ids.unwrap_or_default();
+  let comment_report_ids = data.comment_report_ids.unwrap_or_default();
+  check_api_elements_count(post_report_ids.len() + comment_report_ids.len())?;
+  let person_id = local_user_view.person.id;
+
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(&local_user_view, &community, true, &mut context.pool()).await?;
+
+  let community_id = community.id;
+  let resolved = data.resolved;
+  let reason = data.conclusion.clone().unwrap_or_default();
+  let conclusion = diesel_string_update(data.conclusion.as_deref());
+
+  let pool = &mut context.pool();
+  let conn = &mut get_conn(pool).await?;
+  let (post_reports, comment_reports, actions) = conn
+    .run_transaction(|conn| {
+      async move {
+        // Reports about items of other communities are left unchanged
+        let post_form = UpdatePostReportForm {
+          resolver_id: Some(person_id),
+          resolved: Some(resolved),
+          conclusion: conclusion.clone(),
+          updated_at: Some(Utc::now()),
+        };
+        let post_reports = PostReport::update_resolved_in_community(
+          &mut conn.into(),
+          &post_report_ids,
+          community_id,
+          &post_form,
+        )
+        .await?;
+
+        let comment_form = UpdateCommentReportForm {
+          resolver_id: Some(person_id),
+          resolved: Some(resolved),
+          conclusion,
+          updated_at: Some(Utc::now()),
+        };
+        let comment_reports = CommentReport::update_resolved_in_community(
+          &mut conn.into(),
+          &comment_report_ids,
+          community_id,
+          &comment_form,
+        )
+        .await?;
+        if post_reports.is_empty() && comment_reports.is_empty() {
+          return Err(LemmyErrorType::NotFound.into());
+        }
+
+        let comment_ids: Vec<_> = comment_reports.iter().map(|r| r.comment_id).collect();
+        let comments =
+          Comment::read_many_in_community(&mut conn.into(), &comment_ids, community_id).await?;
+        let post_ids: Vec<_> = post_reports
+          .iter()
+          .map(|r| r.post_id)
+          .chain(comments.iter().map(|c| c.post_id))
+          .collect();
+        let posts = Post::read_many_in_community(&mut conn.into(), &post_ids, community_id).await?;
+
+        // Create the parent first so that the resolutions can reference it
+        let parent_form = ModlogInsertForm::mod_bulk_action(person_id, community_id, &reason);
+        let mut actions = Modlog::create(&mut conn.into(), &[parent_form]).await?;
+        let parent_id = actions.first().ok_or(LemmyErrorType::NotFound)?.id;
+
+        let post_forms = post_reports.iter().filter_map(|report| {
+          let post = posts.iter().find(|p| p.id == report.post_id)?;
+          Some(ModlogInsertForm::mod_resolve_report(
+            person_id, post, None, resolved, &reason,
+          ))
+        });
+        let comment_forms = comment_reports.iter().filter_map(|report| {
+          let comment = comments.iter().find(|c| c.id == report.comment_id)?;
+          let post = posts.iter().find(|p| p.id == comment.post_id)?;
+          Some(ModlogInsertForm::mod_resolve_report(
+            person_id,
+            post,
+            Some(comment),
+            resolved,
+            &reason,
+          ))
+        });
+        let forms: Vec<_> = post_forms
+          .chain(comment_forms)
+          .map(|form| form.with_bulk_action_parent(parent_id))
+          .collect();
+        actions.extend(Modlog::create(&mut conn.into(), &forms).await?);
+
+        Ok((post_reports, comment_reports, actions))
+      }
+      .scope_boxed()
+    })
+    .await?;
+
+  let parent_id = actions.first().ok_or(LemmyErrorType::NotFound)?.id;
+  notify_mod_action(actions, &context);
+
+  ActivityChannel::submit_activity(
+    SendActivityData::BulkResolveReports {
+      actor: local_user_view.person,
+      community,
+      post_reports,
+      comment_reports,
+    },
+    &context,
+  )?;
+
+  // Read the parent again, to include the child count
+  let bulk_action = Modlog::read(&mut context.pool(), parent_id).await?;
+  Ok(Json(BulkActionResponse { bulk_action }))
+}
@@ -0,0 +1,194 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, check_community_user_action},
+};
+use lemmy_db_schema::source::{
+  community::{Community, CommunityActions, CommunityPersonFlairForm},
+  community_flair::{CommunityFlair, CommunityFlairInsertForm, CommunityFlairUpdateForm},
+  person::Person,
+};
+use lemmy_db_views_community::{
+  CommunityView,
+  api::{AssignCommunityFlair, CreateCommunityFlair, DeleteCommunityFlair, EditCommunityFlair},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::{SiteView, api::SuccessResponse};
+use lemmy_diesel_utils::{traits::Crud, utils::diesel_string_update};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::{check_api_elements_count, is_valid_actor_name},
+};
+use url::Url;
+
+pub async fn create_community_flair(
+  Json(data): Json<CreateCommunityFlair>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityFlair>> {
+  is_valid_actor_name(&data.name)?;
+
+  let community_view =
+    CommunityView::read(&mut context.pool(), data.community_id, None, false).await?;
+  let community = community_view.community;
+
+  // Verify that only mods can create flairs
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+
+  check_api_elements_count(community_view.flairs.0.len())?;
+
+  let ap_id = Url::parse(&format!("{}/flair/{}", community.ap_id, &data.name))?;
+
+  let flair_form = CommunityFlairInsertForm {
+    ap_id: ap_id.into(),
+    name: data.name.clone(),
+    display_name: data.display_name.clone(),
+    community_id: data.community_id,
+    color: data.color,
+    self_assignable: data.self_assignable,
+    deleted: Some(false),
+  };
+
+  let flair = CommunityFlair::create(&mut context.pool(), &flair_form).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), community),
+    &context,
+  )?;
+
+  Ok(Json(flair))
+}
+
+pub async fn edit_community_flair(
+  Json(data): Json<EditCommunityFlair>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityFlair>> {
+  let flair = CommunityFlair::read(&mut context.pool(), data.flair_id).await?;
+  let community = Community::read(&mut context.pool(), flair.community_id).await?;
+
+  // Verify that only mods can update flairs
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+
+  let flair_form = CommunityFlairUpdateForm {
+    display_name: diesel_string_update(data.display_name.as_deref()),
+    color: data.color,
+    self_assignable: data.self_assignable,
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+
+  let flair = CommunityFlair::update(&mut context.pool(), data.flair_id, &flair_form).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), community),
+    &context,
+  )?;
+
+  Ok(Json(flair))
+}
+
+pub async fn delete_community_flair(
+  Json(data): Json<DeleteCommunityFlair>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityFlair>> {
+  let flair = CommunityFlair::read(&mut context.pool(), data.flair_id).await?;
+  let community = Community::read(&mut context.pool(), flair.community_id).await?;
+
+  // Verify that only mods can delete flairs
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+
+  // Soft delete the flair, so that it can be restored together with its assignments
+  let flair_form = CommunityFlairUpdateForm {
+    updated_at: Some(Some(Utc::now())),
+    deleted: Some(data.delete),
+    ..Default::default()
+  };
+
+  let flair = CommunityFlair::update(&mut context.pool(), data.flair_id, &flair_form).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), community),
+    &context,
+  )?;
+
+  Ok(Json(flair))
+}
+
+pub async fn assign_community_flair(
+  Json(data): Json<AssignCommunityFlair>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  let person = Person::read(&mut context.pool(), data.person_id).await?;
+
+  let flair = if let Some(flair_id) = data.flair_id {
+    let flair = CommunityFlair::read(&mut context.pool(), flair_id).await?;
+    if flair.community_id != community.id || flair.deleted {
+      return Err(LemmyErrorType::NotFound.into());
+    }
+    Some(flair)
+  } else {
+    None
+  };
+
+  let is_mod = check_community_mod_action(&local_user_view, &community, false, &mut context.pool())
+    .await
+    .is_ok();
+  if !is_mod {
+    // Users can only change their own flair, and only in local communities as the remote
+    // community would overwrite it.
+    if person.id != local_user_view.person.id || !community.local {
+      return Err(LemmyErrorType::NotAModerator.into());
+    }
+    check_community_user_action(&local_user_view, &community, &mut context.pool()).await?;
+    if flair.as_ref().is_some_and(|f| !f.self_assignable) {
+      return Err(LemmyErrorType::FlairNotSelfAssignable.into());
+    }
+  }
+
+  // Other instances only accept flair assignments from moderators, so a self-assigned flair is
+  // federated by the system account.
+  let actor = if is_mod {
+    local_user_view.person.clone()
+  } else {
+    SiteView::read_system_account(&mut context.pool()).await?
+  };
+
+  let (flair, added) = if let Some(flair) = flair {
+    let form = CommunityPersonFlairForm::new(community.id, person.id, flair.id);
+    CommunityFlair::assign(&mut context.pool(), &form).await?;
+    (flair, true)
+  } else {
+    let previous_flair_id = CommunityActions::read(&mut context.pool(), community.id, person.id)
+      .await
+      .ok()
+      .and_then(|a| a.flair_id);
+    let Some(previous_flair_id) = previous_flair_id else {
+      return Ok(Json(SuccessResponse::default()));
+    };
+    CommunityFlair::unassign(&mut context.pool(), community.id, person.id).await?;
+    (
+      CommunityFlair::read(&mut context.pool(), previous_flair_id).await?,
+      false,
+    )
+  };
+
+  ActivityChannel::submit_activity(
+    SendActivityData::AssignCommunityFlair {
+      actor,
+      community,
+      target: person,
+      flair,
+      added,
+    },
+    &context,
+  )?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -16,7 +16,12 @@ use lemmy_utils::error::LemmyResult;
 pub mod add_mod;
 pub mod ban;
 pub mod block;
+pub mod bulk;
+pub mod flair;
 pub mod follow;
+pub mod mod_case;
+pub mod mod_queue;
+pub mod modmail;
 pub mod multi_community_follow;
 pub mod pending_follows;
 pub mod random;
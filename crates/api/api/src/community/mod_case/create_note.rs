@@ -0,0 +1,34 @@
+use crate::community::mod_case::process_note_content;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_mod_action};
+use lemmy_db_schema::source::{
+  community::Community,
+  mod_note::{ModNote, ModNoteInsertForm},
+  person::Person,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_mod_case::{
+  ModNoteView,
+  api::{CreateModNote, ModNoteResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn create_mod_note(
+  Json(data): Json<CreateModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModNoteResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  // Make sure the user exists
+  let person = Person::read(&mut context.pool(), data.person_id).await?;
+
+  let content = process_note_content(&data.content, &context).await?;
+  let form = ModNoteInsertForm::new(community.id, person.id, local_user_view.person.id, content);
+  let note = ModNote::create(&mut context.pool(), &form).await?;
+
+  let mod_note_view = ModNoteView::read(&mut context.pool(), note.id).await?;
+  Ok(Json(ModNoteResponse { mod_note_view }))
+}
@@ -0,0 +1,23 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_mod_action};
+use lemmy_db_schema::source::{community::Community, mod_note::ModNote};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_mod_case::api::DeleteModNote;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_mod_note(
+  Json(data): Json<DeleteModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let note = ModNote::read(&mut context.pool(), data.mod_note_id).await?;
+  let community = Community::read(&mut context.pool(), note.community_id).await?;
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+
+  ModNote::delete(&mut context.pool(), note.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,41 @@
+use crate::community::mod_case::process_note_content;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_mod_action};
+use lemmy_db_schema::source::{
+  community::Community,
+  mod_note::{ModNote, ModNoteUpdateForm},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_mod_case::{
+  ModNoteView,
+  api::{EditModNote, ModNoteResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn edit_mod_note(
+  Json(data): Json<EditModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModNoteResponse>> {
+  let note = ModNote::read(&mut context.pool(), data.mod_note_id).await?;
+  let community = Community::read(&mut context.pool(), note.community_id).await?;
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+
+  // Only the author can edit a note, other moderators can only delete it
+  if note.creator_id != local_user_view.person.id {
+    return Err(LemmyErrorType::EditModNoteNotAllowed.into());
+  }
+
+  let content = process_note_content(&data.content, &context).await?;
+  let form = ModNoteUpdateForm {
+    content: Some(content),
+    updated_at: Some(Some(Utc::now())),
+  };
+  ModNote::update(&mut context.pool(), note.id, &form).await?;
+
+  let mod_note_view = ModNoteView::read(&mut context.pool(), note.id).await?;
+  Ok(Json(ModNoteResponse { mod_note_view }))
+}
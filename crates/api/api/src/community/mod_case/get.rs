@@ -0,0 +1,30 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::is_mod_or_admin};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_mod_case::{
+  ModCaseView,
+  api::{GetModCase, ModCaseResponse},
+};
+use lemmy_db_views_site::SiteView;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn get_mod_case(
+  Query(data): Query<GetModCase>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModCaseResponse>> {
+  is_mod_or_admin(&mut context.pool(), &local_user_view, data.community_id).await?;
+
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  let mod_case_view = ModCaseView::read(
+    &mut context.pool(),
+    data.community_id,
+    data.person_id,
+    &local_user_view,
+    &local_site,
+  )
+  .await?;
+
+  Ok(Json(ModCaseResponse { mod_case_view }))
+}
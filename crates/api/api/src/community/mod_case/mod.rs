@@ -0,0 +1,25 @@
+use activitypub_federation::config::Data;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_views_site::SiteView;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+
+pub mod create_note;
+pub mod delete_note;
+pub mod edit_note;
+pub mod get;
+
+async fn process_note_content(content: &str, context: &Data<LemmyContext>) -> LemmyResult<String> {
+  let slur_regex = slur_regex(context).await?;
+  check_slurs(content, &slur_regex)?;
+  is_valid_body_field(content, false)?;
+
+  let url_blocklist = get_url_blocklist(context).await?;
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  process_markdown(content, &slur_regex, &url_blocklist, &local_site, context).await
+}
@@ -0,0 +1,170 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::{NotifyData, notify_mod_action},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, send_webmention},
+  webhooks::send_webhook,
+};
+use lemmy_db_schema::{
+  source::{
+    comment::{Comment, CommentUpdateForm},
+    comment_report::CommentReport,
+    community::Community,
+    modlog::{Modlog, ModlogInsertForm},
+    person::Person,
+    post::{Post, PostUpdateForm},
+    post_report::PostReport,
+  },
+  traits::Reportable,
+};
+use lemmy_db_schema_file::enums::WebhookEventType;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::{
+  SiteView,
+  api::{ApproveModQueueItem, SuccessResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn post_mod_queue_approve(
+  Json(data): Json<ApproveModQueueItem>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  match (data.post_id, data.comment_id) {
+    (Some(post_id), None) => {
+      let post = Post::read(&mut context.pool(), post_id).await?;
+      approve_post(post, &data, &context, &local_user_view).await?
+    }
+    (None, Some(comment_id)) => {
+      let comment = Comment::read(&mut context.pool(), comment_id).await?;
+      approve_comment(comment, &data, &context, &local_user_view).await?
+    }
+    _ => return Err(LemmyErrorType::NoIdGiven.into()),
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+async fn approve_post(
+  post: Post,
+  data: &ApproveModQueueItem,
+  context: &Data<LemmyContext>,
+  local_user_view: &LocalUserView,
+) -> LemmyResult<()> {
+  let community = Community::read(&mut context.pool(), post.community_id).await?;
+  check_community_mod_action(local_user_view, &community, false, &mut context.pool()).await?;
+  if !post.approval_pending {
+    return Err(LemmyErrorType::CouldntUpdate.into());
+  }
+
+  let form = PostUpdateForm {
+    approval_pending: Some(false),
+    removed: (!data.approve).then_some(true),
+    ..Default::default()
+  };
+  let post = Post::update(&mut context.pool(), post.id, &form).await?;
+
+  if data.approve {
+    // Scheduled posts are federated once they get published
+    if post.scheduled_publish_time_at.is_none() {
+      ActivityChannel::submit_activity(SendActivityData::CreatePost(post.clone()), context)?;
+      send_webhook(
+        WebhookEventType::NewPost,
+        Some(community.id),
+        &post,
+        context,
+      );
+      if post.local {
+        send_webmention(post.clone(), &community, context.clone());
+      }
+    }
+    let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+    let creator = Person::read(&mut context.pool(), post.creator_id).await?;
+    NotifyData {
+      do_send_email: !local_site.email_notifications_disabled,
+      ..NotifyData::new(post, creator, community)
+    }
+    .send(context);
+  } else {
+    let mod_id = local_user_view.person.id;
+    let reason = data.reason.clone().unwrap_or_default();
+    PostReport::resolve_all_for_object(&mut context.pool(), post.id, mod_id).await?;
+    let form = ModlogInsertForm::mod_remove_post(mod_id, &post, true, &reason, None);
+    let action = Modlog::create(&mut context.pool(), &[form]).await?;
+    notify_mod_action(action, context.app_data());
+
+    // Local posts were never federated, remote ones only exist on the creator's instance
+    if !post.local {
+      ActivityChannel::submit_activity(
+        SendActivityData::RemovePost {
+          post,
+          moderator: local_user_view.person.clone(),
+          reason,
+          removed: true,
+          with_replies: false,
+        },
+        context,
+      )?;
+    }
+  }
+  Ok(())
+}
+
+async fn approve_comment(
+  comment: Comment,
+  data: &ApproveModQueueItem,
+  context: &Data<LemmyContext>,
+  local_user_view: &LocalUserView,
+) -> LemmyResult<()> {
+  let community = Community::read(&mut context.pool(), comment.community_id).await?;
+  check_community_mod_action(local_user_view, &community, false, &mut context.pool()).await?;
+  if !comment.approval_pending {
+    return Err(LemmyErrorType::CouldntUpdate.into());
+  }
+
+  let form = CommentUpdateForm {
+    approval_pending: Some(false),
+    removed: (!data.approve).then_some(true),
+    ..Default::default()
+  };
+  let comment = Comment::update(&mut context.pool(), comment.id, &form).await?;
+
+  if data.approve {
+    ActivityChannel::submit_activity(SendActivityData::CreateComment(comment.clone()), context)?;
+    let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+    let post = Post::read(&mut context.pool(), comment.post_id).await?;
+    let creator = Person::read(&mut context.pool(), comment.creator_id).await?;
+    NotifyData {
+      comment: Some(comment),
+      do_send_email: !local_site.email_notifications_disabled,
+      ..NotifyData::new(post, creator, community)
+    }
+    .send(context);
+  } else {
+    let mod_id = local_user_view.person.id;
+    let reason = data.reason.clone().unwrap_or_default();
+    CommentReport::resolve_all_for_object(&mut context.pool(), comment.id, mod_id).await?;
+    let form =
+      ModlogInsertForm::mod_remove_comment(mod_id, &comment, community.id, true, &reason, None);
+    let action = Modlog::create(&mut context.pool(), &[form]).await?;
+    notify_mod_action(action, context.app_data());
+
+    // Local comments were never federated, remote ones only exist on the creator's instance
+    if !comment.local {
+      ActivityChannel::submit_activity(
+        SendActivityData::RemoveComment {
+          comment,
+          moderator: local_user_view.person.clone(),
+          community,
+          reason,
+          with_replies: false,
+        },
+        context,
+      )?;
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,47 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_of_any_or_admin_action, is_mod_or_admin},
+};
+use lemmy_db_views_comment::CommentView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::PostView;
+use lemmy_db_views_site::api::{ListModQueue, ListModQueueResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn get_mod_queue_list(
+  Query(data): Query<ListModQueue>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListModQueueResponse>> {
+  if let Some(community_id) = data.community_id {
+    is_mod_or_admin(&mut context.pool(), &local_user_view, community_id).await?;
+  } else {
+    check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+  }
+  // The permission for a single community was checked above
+  let all_communities = data.community_id.is_some()
+    || (data.all_communities.unwrap_or_default() && local_user_view.local_user.admin);
+  let local_instance_id = local_user_view.person.instance_id;
+
+  let posts = PostView::list_approval_pending(
+    &mut context.pool(),
+    &local_user_view.local_user,
+    local_instance_id,
+    data.community_id,
+    all_communities,
+    data.limit,
+  )
+  .await?;
+  let comments = CommentView::list_approval_pending(
+    &mut context.pool(),
+    &local_user_view.local_user,
+    local_instance_id,
+    data.community_id,
+    all_communities,
+    data.limit,
+  )
+  .await?;
+
+  Ok(Json(ListModQueueResponse { posts, comments }))
+}
@@ -0,0 +1,48 @@
+use crate::community::modmail::read_thread_as_moderator;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::modmail::{ModmailThread, ModmailThreadUpdateForm};
+use lemmy_db_views_community_moderator::CommunityModeratorView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailThreadView,
+  api::{AssignModmail, ModmailThreadResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn assign_modmail(
+  Json(data): Json<AssignModmail>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModmailThreadResponse>> {
+  let (thread, community) =
+    read_thread_as_moderator(data.modmail_thread_id, &local_user_view, &context).await?;
+  if let Some(assignee_id) = data.assignee_id {
+    let is_moderator = CommunityModeratorView::check_is_community_moderator(
+      &mut context.pool(),
+      community.id,
+      assignee_id,
+    )
+    .await
+    .is_ok();
+    if !is_moderator {
+      return Err(LemmyErrorType::ModmailAssigneeNotAModerator.into());
+    }
+  }
+
+  let form = ModmailThreadUpdateForm {
+    assignee_id: Some(data.assignee_id),
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  ModmailThread::update(&mut context.pool(), thread.id, &form).await?;
+
+  let modmail_thread_view =
+    ModmailThreadView::read(&mut context.pool(), thread.id, local_user_view.person.id).await?;
+  Ok(Json(ModmailThreadResponse {
+    modmail_thread_view,
+  }))
+}
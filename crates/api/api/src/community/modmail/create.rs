@@ -0,0 +1,48 @@
+use crate::community::modmail::process_modmail_content;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_deleted_removed, check_local_user_banned_or_deleted},
+};
+use lemmy_db_schema::source::{
+  community::Community,
+  modmail::{ModmailMessage, ModmailMessageInsertForm, ModmailThread},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailMessageView,
+  api::{CreateModmail, ModmailMessageResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn create_modmail(
+  Json(data): Json<CreateModmail>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModmailMessageResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_deleted_removed(&community)?;
+  let content = process_modmail_content(&data.content, &context).await?;
+
+  let person_id = local_user_view.person.id;
+  let thread = ModmailThread::read_or_create(&mut context.pool(), community.id, person_id).await?;
+  if thread.resolved {
+    ModmailThread::reopen(&mut context.pool(), thread.id).await?;
+  }
+  let form = ModmailMessageInsertForm::new(thread.id, Some(person_id), content);
+  let message = ModmailMessage::create(&mut context.pool(), &form).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::CreateModmailMessage(message.clone()),
+    &context,
+  )?;
+
+  let modmail_message_view = ModmailMessageView::read(&mut context.pool(), message.id).await?;
+  Ok(Json(ModmailMessageResponse {
+    modmail_message_view,
+  }))
+}
@@ -0,0 +1,45 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_of_any_or_admin_action, is_mod_or_admin},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailThreadView,
+  api::ListModmailThreads,
+  impls::ModmailThreadQuery,
+};
+use lemmy_diesel_utils::pagination::PagedResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_modmail_threads(
+  Query(data): Query<ListModmailThreads>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<ModmailThreadView>>> {
+  let mine = data.mine.unwrap_or_default();
+  if !mine {
+    if let Some(community_id) = data.community_id {
+      is_mod_or_admin(&mut context.pool(), &local_user_view, community_id).await?;
+    } else {
+      check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+    }
+  }
+
+  let threads = ModmailThreadQuery {
+    community_id: data.community_id,
+    unresolved_only: data.unresolved_only,
+    assigned_to_me: data.assigned_to_me,
+    mine: data.mine,
+    page_cursor: data.page_cursor,
+    limit: data.limit,
+  }
+  .list(
+    &mut context.pool(),
+    local_user_view.person.id,
+    local_user_view.local_user.admin,
+  )
+  .await?;
+  Ok(Json(threads))
+}
@@ -0,0 +1,37 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::is_mod_or_admin};
+use lemmy_db_schema::source::modmail::ModmailThread;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailMessageView,
+  api::ListModmailMessages,
+  impls::ModmailMessageQuery,
+};
+use lemmy_diesel_utils::{pagination::PagedResponse, traits::Crud};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn list_modmail_messages(
+  Query(data): Query<ListModmailMessages>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<ModmailMessageView>>> {
+  let thread = ModmailThread::read(&mut context.pool(), data.modmail_thread_id).await?;
+  let as_moderator = is_mod_or_admin(&mut context.pool(), &local_user_view, thread.community_id)
+    .await
+    .is_ok();
+  // Besides the moderators, only the user who wrote the modmail can read it
+  if !as_moderator && thread.creator_id != local_user_view.person.id {
+    return Err(LemmyErrorType::NotAModOrAdmin.into());
+  }
+
+  let messages = ModmailMessageQuery {
+    modmail_thread_id: thread.id,
+    as_moderator,
+    page_cursor: data.page_cursor,
+    limit: data.limit,
+  }
+  .list(&mut context.pool())
+  .await?;
+  Ok(Json(messages))
+}
@@ -0,0 +1,45 @@
+use activitypub_federation::config::Data;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_action, get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::{
+  newtypes::ModmailThreadId,
+  source::{community::Community, modmail::ModmailThread},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_body_field};
+
+pub mod assign;
+pub mod create;
+pub mod list;
+pub mod list_messages;
+pub mod reply;
+pub mod resolve;
+
+/// Reads the thread, and checks that the user moderates its community.
+async fn read_thread_as_moderator(
+  modmail_thread_id: ModmailThreadId,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<(ModmailThread, Community)> {
+  let thread = ModmailThread::read(&mut context.pool(), modmail_thread_id).await?;
+  let community = Community::read(&mut context.pool(), thread.community_id).await?;
+  check_community_mod_action(local_user_view, &community, false, &mut context.pool()).await?;
+  Ok((thread, community))
+}
+
+async fn process_modmail_content(
+  content: &str,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<String> {
+  let slur_regex = slur_regex(context).await?;
+  let url_blocklist = get_url_blocklist(context).await?;
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  let content =
+    process_markdown(content, &slur_regex, &url_blocklist, &local_site, context).await?;
+  is_valid_body_field(&content, false)?;
+  Ok(content)
+}
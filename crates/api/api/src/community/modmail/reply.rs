@@ -0,0 +1,48 @@
+use crate::community::modmail::{process_modmail_content, read_thread_as_moderator};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+};
+use lemmy_db_schema::source::modmail::{ModmailMessage, ModmailMessageInsertForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailMessageView,
+  api::{ModmailMessageResponse, ReplyToModmail},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn reply_to_modmail(
+  Json(data): Json<ReplyToModmail>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModmailMessageResponse>> {
+  let (thread, community) =
+    read_thread_as_moderator(data.modmail_thread_id, &local_user_view, &context).await?;
+  let internal = data.internal.unwrap_or_default();
+  // Replies are sent as the community, which is only possible on its home instance.
+  if !internal && !community.local {
+    return Err(LemmyErrorType::CantReplyToModmailOfRemoteCommunity.into());
+  }
+  let content = process_modmail_content(&data.content, &context).await?;
+
+  let form = ModmailMessageInsertForm {
+    from_moderator: Some(true),
+    internal: Some(internal),
+    ..ModmailMessageInsertForm::new(thread.id, Some(local_user_view.person.id), content)
+  };
+  let message = ModmailMessage::create(&mut context.pool(), &form).await?;
+
+  // Internal notes are never sent
+  ActivityChannel::submit_activity(
+    SendActivityData::CreateModmailMessage(message.clone()),
+    &context,
+  )?;
+
+  let modmail_message_view = ModmailMessageView::read(&mut context.pool(), message.id).await?;
+  Ok(Json(ModmailMessageResponse {
+    modmail_message_view,
+  }))
+}
@@ -0,0 +1,37 @@
+use crate::community::modmail::read_thread_as_moderator;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::modmail::{ModmailThread, ModmailThreadUpdateForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailThreadView,
+  api::{ModmailThreadResponse, ResolveModmail},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn resolve_modmail(
+  Json(data): Json<ResolveModmail>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModmailThreadResponse>> {
+  let (thread, _) =
+    read_thread_as_moderator(data.modmail_thread_id, &local_user_view, &context).await?;
+  let my_person_id = local_user_view.person.id;
+
+  let form = ModmailThreadUpdateForm {
+    resolved: Some(data.resolved),
+    resolver_id: Some(data.resolved.then_some(my_person_id)),
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  ModmailThread::update(&mut context.pool(), thread.id, &form).await?;
+
+  let modmail_thread_view =
+    ModmailThreadView::read(&mut context.pool(), thread.id, my_person_id).await?;
+  Ok(Json(ModmailThreadResponse {
+    modmail_thread_view,
+  }))
+}
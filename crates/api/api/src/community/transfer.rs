@@ -102,5 +102,6 @@ pub async fn transfer_community(
     site: None,
     moderators,
     discussion_languages: vec![],
+    rules: vec![],
   }))
 }
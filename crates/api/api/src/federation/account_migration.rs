@@ -0,0 +1,107 @@
+use crate::federation::fetcher::resolve_ap_identifier;
+use activitypub_federation::{config::Data, fetch::object_id::ObjectId};
+use actix_web::web::Json;
+use bcrypt::verify;
+use futures::future::try_join_all;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_local_user_banned_or_deleted, move_person_account},
+};
+use lemmy_apub_objects::objects::person::ApubPerson;
+use lemmy_db_schema::source::person::{Person, PersonUpdateForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{MoveAccount, SetAccountAliases, SuccessResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use url::Url;
+
+const MAX_ACCOUNT_ALIASES: usize = 10;
+
+pub async fn set_account_aliases(
+  Json(data): Json<SetAccountAliases>,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<SuccessResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  if data.also_known_as.len() > MAX_ACCOUNT_ALIASES {
+    return Err(LemmyErrorType::TooManyItems.into());
+  }
+
+  let also_known_as = try_join_all(
+    data
+      .also_known_as
+      .iter()
+      .map(|a| resolve_person(a, &local_user_view, &context)),
+  )
+  .await?
+  .into_iter()
+  .map(|p| p.ap_id.clone())
+  .collect();
+
+  let form = PersonUpdateForm {
+    also_known_as: Some(also_known_as),
+    ..Default::default()
+  };
+  Person::update(&mut context.pool(), local_user_view.person.id, &form).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+pub async fn move_account(
+  Json(data): Json<MoveAccount>,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<SuccessResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  if local_user_view.person.moved_to_id.is_some() {
+    return Err(LemmyErrorType::AccountAlreadyMoved.into());
+  }
+
+  // Verify the password
+  let valid: bool = local_user_view
+    .local_user
+    .password_encrypted
+    .as_ref()
+    .and_then(|password_encrypted| verify(&data.password, password_encrypted).ok())
+    .unwrap_or(false);
+  if !valid {
+    return Err(LemmyErrorType::IncorrectLogin.into());
+  }
+
+  let mut target = resolve_person(&data.target, &local_user_view, &context).await?;
+  // Refetch remote accounts, in case the alias was only added recently
+  if !target.local {
+    target = ObjectId::<ApubPerson>::from(target.ap_id.clone())
+      .dereference_forced(&context)
+      .await?;
+  }
+
+  move_person_account(&local_user_view.person, &target, &context).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::MoveUser {
+      person: local_user_view.person,
+      target: target.0,
+    },
+    &context,
+  )?;
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+/// Resolves an account from its identifier like `name@example.com`, or from its ActivityPub id.
+async fn resolve_person(
+  identifier: &str,
+  local_user_view: &LocalUserView,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<ApubPerson> {
+  match Url::parse(identifier) {
+    Ok(url) => ObjectId::<ApubPerson>::from(url).dereference(context).await,
+    Err(_) => {
+      let local_user_view = Some(local_user_view.clone());
+      resolve_ap_identifier::<ApubPerson, Person>(identifier, context, &local_user_view, false)
+        .await
+    }
+  }
+}
@@ -42,6 +42,7 @@ pub async fn list_persons(
     community_id: data.community_id,
     limit: data.limit,
     page_cursor: data.page_cursor,
+    ..Default::default()
   }
   .list(&site, &mut context.pool())
   .await?;
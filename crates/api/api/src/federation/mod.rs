@@ -5,6 +5,7 @@ use lemmy_db_schema::{
 };
 use lemmy_db_schema_file::enums::{CommentSortType, ListingType, PostSortType};
 
+pub mod account_migration;
 mod fetcher;
 pub mod list_comments;
 pub mod list_person_content;
@@ -13,6 +13,7 @@ use lemmy_db_schema::{
   PersonListingType,
   PersonSortType,
   SearchType,
+  source::instance::Instance,
 };
 use lemmy_db_schema_file::enums::{CommentSortType, ListingType};
 use lemmy_db_views_comment::impls::CommentQuery;
@@ -42,8 +43,10 @@ pub async fn search(
     .await
     .ok();
 
-  let search_term = Some(data.search_term);
+  let operators = SearchOperators::parse(&data.search_term);
+  let search_term = Some(operators.search_term).filter(|t| !t.is_empty());
   let search_type = data.type_.unwrap_or_default();
+  let search_sort = data.sort;
   let listing_type = Some(ListingType::All);
   let search_title_only = data.title_only;
   let time_range_seconds = data.time_range_seconds;
@@ -53,7 +56,7 @@ pub async fn search(
   let limit = data.limit;
 
   let community_id = resolve_community_identifier(
-    &data.community_name,
+    &operators.community.or(data.community_name),
     data.community_id,
     &context,
     &local_user_view,
@@ -62,12 +65,31 @@ pub async fn search(
 
   let creator_id = resolve_person_identifier(
     data.creator_id,
-    &data.creator_username,
+    &operators.author.or(data.creator_username),
     &context,
     &local_user_view,
   )
   .await?;
 
+  let instance_id = if let Some(domain) = &operators.instance {
+    let Ok(instance) = Instance::read_by_domain(&mut context.pool(), domain).await else {
+      // Nothing can be found on an unknown instance
+      return Ok(Json(SearchResponse {
+        resolve,
+        comments: vec![],
+        posts: vec![],
+        communities: vec![],
+        multi_communities: vec![],
+        persons: vec![],
+        prev_page: None,
+        next_page: None,
+      }));
+    };
+    Some(instance.id)
+  } else {
+    None
+  };
+
   let local_user = local_user_view.as_ref().map(|u| &u.local_user);
 
   let pool = &mut context.pool();
@@ -80,6 +102,8 @@ pub async fn search(
     creator_id,
     time_range_seconds,
     search_url_only,
+    search_sort,
+    instance_id,
     show_nsfw,
     page_cursor: page_cursors[0].clone(),
     limit,
@@ -96,6 +120,8 @@ pub async fn search(
     creator_id,
     time_range_seconds,
     sort: Some(CommentSortType::New),
+    search_sort,
+    instance_id,
     page_cursor: page_cursors[1].clone(),
     limit,
     ..Default::default()
@@ -110,6 +136,8 @@ pub async fn search(
     listing_type: Some(PersonListingType::All),
     community_id: None,
     sort: Some(PersonSortType::New),
+    search_sort,
+    instance_id,
     page_cursor: page_cursors[2].clone(),
     limit,
   }
@@ -124,6 +152,8 @@ pub async fn search(
     time_range_seconds,
     show_nsfw,
     sort: Some(CommunitySortType::New),
+    search_sort,
+    instance_id,
     page_cursor: page_cursors[3].clone(),
     limit,
     ..Default::default()
@@ -157,8 +187,7 @@ pub async fn search(
   let search_all = search_type == SearchType::All;
 
   // If the community or creator is included and it's All search, only search posts and comments
-  let community_or_creator_included =
-    data.community_id.is_some() || data.community_name.is_some() || data.creator_id.is_some();
+  let community_or_creator_included = community_id.is_some() || creator_id.is_some();
   let search_all_no_community_or_creator = search_all && !community_or_creator_included;
 
   if (search_type == SearchType::Posts || search_all)
@@ -216,6 +245,45 @@ pub async fn search(
   Ok(Json(res))
 }
 
+/// Operators like `author:name@example.com` which are given as part of the search term.
+#[derive(Debug, Default, PartialEq)]
+struct SearchOperators {
+  /// The remaining search term, without operators
+  search_term: String,
+  author: Option<String>,
+  community: Option<String>,
+  instance: Option<String>,
+}
+
+impl SearchOperators {
+  fn parse(search_term: &str) -> Self {
+    let mut res = Self::default();
+    let mut words = Vec::new();
+    let mut in_quotes = false;
+    for word in search_term.split_whitespace() {
+      // Operators inside of quoted phrases are searched as plain text
+      let operator = if in_quotes {
+        None
+      } else {
+        word.split_once(':').filter(|(_, value)| !value.is_empty())
+      };
+      match operator {
+        Some(("author", value)) => res.author = Some(value.to_string()),
+        Some(("community", value)) => res.community = Some(value.to_string()),
+        Some(("instance", value)) => res.instance = Some(value.to_string()),
+        _ => {
+          if word.matches('"').count() % 2 == 1 {
+            in_quotes = !in_quotes;
+          }
+          words.push(word);
+        }
+      }
+    }
+    res.search_term = words.join(" ");
+    res
+  }
+}
+
 fn to_single_cursor(
   cursors: [Option<PaginationCursor>; 5],
   search_type: SearchType,
@@ -276,6 +344,38 @@ fn from_single_cursor(
 #[cfg(test)]
 mod test {
   use super::*;
+
+  #[test]
+  fn test_search_operators() {
+    assert_eq!(
+      SearchOperators {
+        search_term: "rust -java".to_string(),
+        ..Default::default()
+      },
+      SearchOperators::parse("rust  -java")
+    );
+
+    assert_eq!(
+      SearchOperators {
+        search_term: "\"memory safety\" rust".to_string(),
+        author: Some("alice@example.com".to_string()),
+        community: Some("programming".to_string()),
+        instance: Some("lemmy.ml".to_string()),
+      },
+      SearchOperators::parse(
+        "author:alice@example.com \"memory safety\" community:programming rust instance:lemmy.ml"
+      )
+    );
+
+    // Operators in quotes and without value are plain text
+    assert_eq!(
+      SearchOperators {
+        search_term: "\"see author:bob\" author:".to_string(),
+        ..Default::default()
+      },
+      SearchOperators::parse("\"see author:bob\" author:")
+    );
+  }
   #[test]
   fn test_from_single_cursor() {
     let a = None;
@@ -97,6 +97,8 @@ pub async fn import_user_settings(
     show_avatars: data.settings.as_ref().map(|s| s.show_avatars),
     show_media: data.settings.as_ref().map(|s| s.show_media),
     hide_posts_with_media: data.settings.as_ref().map(|s| s.hide_posts_with_media),
+    email_digest_cadence: data.settings.as_ref().map(|s| s.email_digest_cadence),
+    email_digest_top_posts: data.settings.as_ref().map(|s| s.email_digest_top_posts),
     send_notifications_to_email: data
       .settings
       .as_ref()
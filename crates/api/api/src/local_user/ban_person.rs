@@ -4,13 +4,20 @@ use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_expire_time, is_admin, remove_or_restore_user_data},
+  utils::{
+    check_expire_time,
+    is_admin,
+    read_cited_rule,
+    remove_or_restore_user_data,
+    resolve_person_reports_for_site_ban,
+  },
 };
 use lemmy_db_schema::{
   source::{
     instance::{InstanceActions, InstanceBanForm},
     local_user::LocalUser,
     modlog::{Modlog, ModlogInsertForm},
+    person::Person,
   },
   traits::Bannable,
 };
@@ -19,6 +26,7 @@ use lemmy_db_views_person::{
   PersonView,
   api::{BanPerson, PersonResponse},
 };
+use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::{
   error::{LemmyErrorType, LemmyResult},
   spawn_try_task,
@@ -40,6 +48,7 @@ pub async fn ban_from_site(
   LocalUser::is_higher_admin_check(&mut context.pool(), my_person_id, vec![data.person_id]).await?;
 
   is_valid_body_field(&data.reason, false)?;
+  let rule = read_cited_rule(data.rule_id, None, &mut context.pool()).await?;
 
   let expires_at = check_expire_time(data.expires_at)?;
 
@@ -50,6 +59,14 @@ pub async fn ban_from_site(
   );
   if data.ban {
     InstanceActions::ban(&mut context.pool(), &form).await?;
+    let person = Person::read(&mut context.pool(), data.person_id).await?;
+    resolve_person_reports_for_site_ban(
+      &person,
+      local_instance_id,
+      my_person_id,
+      &mut context.pool(),
+    )
+    .await?;
   } else {
     InstanceActions::unban(&mut context.pool(), &form).await?;
   }
@@ -61,7 +78,8 @@ pub async fn ban_from_site(
     data.ban,
     expires_at,
     &data.reason,
-  );
+  )
+  .with_rule(rule.map(|r| r.id));
   let action = Modlog::create(&mut context.pool(), &[form]).await?;
   notify_mod_action(action.clone(), &context);
 
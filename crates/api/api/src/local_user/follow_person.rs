@@ -0,0 +1,65 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_local_user_banned_or_deleted,
+};
+use lemmy_db_schema::{
+  source::person::{Person, PersonActions, PersonFollowerForm},
+  traits::{Blockable, Followable},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::{
+  PersonView,
+  api::{FollowPerson, PersonResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn user_follow_person(
+  Json(data): Json<FollowPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  let target_id = data.person_id;
+  let my_person_id = local_user_view.person.id;
+  let local_instance_id = local_user_view.person.instance_id;
+
+  // Don't let a person follow themselves
+  if target_id == my_person_id {
+    return Err(LemmyErrorType::CantFollowYourself.into());
+  }
+
+  let target = Person::read(&mut context.pool(), target_id).await?;
+
+  if data.follow {
+    // Can't follow someone who blocked you
+    PersonActions::read_block(&mut context.pool(), target_id, my_person_id).await?;
+
+    // Local follow is accepted immediately, remote follow needs to be federated first
+    let form = PersonFollowerForm::new(target_id, my_person_id, !target.local);
+    PersonActions::follow(&mut context.pool(), &form).await?;
+  } else {
+    PersonActions::unfollow(&mut context.pool(), my_person_id, target_id).await?;
+  }
+
+  // Send the federated follow
+  if !target.local {
+    ActivityChannel::submit_activity(
+      SendActivityData::FollowPerson(target, local_user_view.person.clone(), data.follow),
+      &context,
+    )?;
+  }
+
+  let person_view = PersonView::read(
+    &mut context.pool(),
+    target_id,
+    Some(my_person_id),
+    local_instance_id,
+    false,
+  )
+  .await?;
+  Ok(Json(PersonResponse { person_view }))
+}
@@ -0,0 +1,26 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::{
+  post::Post,
+  scheduled::{ScheduledComment, ScheduledPrivateMessage},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::ListScheduledResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_scheduled(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListScheduledResponse>> {
+  let person_id = local_user_view.person.id;
+  let posts = Post::list_user_scheduled_posts(person_id, &mut context.pool()).await?;
+  let comments = ScheduledComment::list_for_creator(&mut context.pool(), person_id).await?;
+  let private_messages =
+    ScheduledPrivateMessage::list_for_creator(&mut context.pool(), person_id).await?;
+
+  Ok(Json(ListScheduledResponse {
+    posts,
+    comments,
+    private_messages,
+  }))
+}
@@ -4,6 +4,7 @@ pub mod block;
 pub mod change_password;
 pub mod change_password_after_reset;
 pub mod export_data;
+pub mod follow_person;
 pub mod generate_totp_secret;
 pub mod get_captcha;
 pub mod list_hidden;
@@ -12,6 +13,7 @@ pub mod list_logins;
 pub mod list_media;
 pub mod list_read;
 pub mod list_saved;
+pub mod list_scheduled;
 pub mod login;
 pub mod logout;
 pub mod mark_donation_dialog_shown;
@@ -19,6 +19,7 @@ pub async fn list_notifications(
     page_cursor: data.page_cursor,
     hide_modlog_names: Some(hide_modlog_names),
     creator_id: data.creator_id,
+    post_id: data.post_id,
     limit: data.limit,
     no_limit: None,
   }
@@ -0,0 +1,31 @@
+use crate::hide_modlog_names;
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_notification::{
+  ListNotificationGroups,
+  NotificationGroupView,
+  impls::NotificationGroupQuery,
+};
+use lemmy_diesel_utils::pagination::PagedResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_notification_groups(
+  Query(data): Query<ListNotificationGroups>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<NotificationGroupView>>> {
+  let hide_modlog_names = hide_modlog_names(Some(&local_user_view), None, &context).await;
+  let groups = NotificationGroupQuery {
+    type_: data.type_,
+    unread_only: data.unread_only,
+    show_bot_accounts: Some(local_user_view.local_user.show_bot_accounts),
+    hide_modlog_names: Some(hide_modlog_names),
+    page_cursor: data.page_cursor,
+    limit: data.limit,
+  }
+  .list(&mut context.pool(), &local_user_view.person)
+  .await?;
+
+  Ok(Json(groups))
+}
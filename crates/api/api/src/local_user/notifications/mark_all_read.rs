@@ -1,5 +1,5 @@
 use actix_web::web::{Data, Json};
-use lemmy_api_utils::context::LemmyContext;
+use lemmy_api_utils::{context::LemmyContext, realtime::RealtimeEvent};
 use lemmy_db_schema::source::notification::Notification;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::api::SuccessResponse;
@@ -9,7 +9,9 @@ pub async fn mark_all_notifications_read(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
-  Notification::mark_all_as_read(&mut context.pool(), local_user_view.person.id).await?;
+  let person_id = local_user_view.person.id;
+  Notification::mark_all_as_read(&mut context.pool(), person_id).await?;
+  RealtimeEvent::NotificationsRead { person_id }.publish(&context);
 
   Ok(Json(SuccessResponse::default()))
 }
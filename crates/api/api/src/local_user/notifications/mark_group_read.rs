@@ -0,0 +1,26 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, realtime::RealtimeEvent};
+use lemmy_db_schema::source::notification::Notification;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_notification::api::MarkNotificationGroupAsRead;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn mark_notification_group_as_read(
+  Json(data): Json<MarkNotificationGroupAsRead>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let person_id = local_user_view.person.id;
+  Notification::mark_read_by_group_and_person(
+    &mut context.pool(),
+    data.kind,
+    data.post_id,
+    person_id,
+    data.read,
+  )
+  .await?;
+  RealtimeEvent::NotificationsRead { person_id }.publish(&context);
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -1,5 +1,5 @@
 use actix_web::web::{Data, Json};
-use lemmy_api_utils::context::LemmyContext;
+use lemmy_api_utils::{context::LemmyContext, realtime::RealtimeEvent};
 use lemmy_db_schema::source::notification::Notification;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_notification::api::MarkNotificationAsRead;
@@ -11,13 +11,15 @@ pub async fn mark_notification_as_read(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
+  let person_id = local_user_view.person.id;
   Notification::mark_read_by_id_and_person(
     &mut context.pool(),
     data.notification_id,
-    local_user_view.person.id,
+    person_id,
     data.read,
   )
   .await?;
+  RealtimeEvent::NotificationsRead { person_id }.publish(&context);
 
   Ok(Json(SuccessResponse::default()))
 }
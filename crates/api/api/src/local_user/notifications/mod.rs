@@ -1,3 +1,6 @@
 pub mod list;
+pub mod list_groups;
 pub mod mark_all_read;
+pub mod mark_group_read;
 pub mod mark_notification_read;
+pub mod push_subscription;
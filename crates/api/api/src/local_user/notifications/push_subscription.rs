@@ -0,0 +1,58 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  push::{MAX_PUSH_SUBSCRIPTIONS, check_push_subscription},
+};
+use lemmy_db_schema::source::push_subscription::{PushSubscription, PushSubscriptionInsertForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_notification::api::{CreatePushSubscription, DeletePushSubscription};
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn create_push_subscription(
+  Json(data): Json<CreatePushSubscription>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  check_push_subscription(&data.endpoint, &data.p256dh, &data.auth).await?;
+
+  // Registering an existing endpoint again replaces it, so it doesn't count towards the limit
+  let count = PushSubscription::count_for_local_user(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    &data.endpoint,
+  )
+  .await?;
+  if count >= MAX_PUSH_SUBSCRIPTIONS {
+    return Err(LemmyErrorType::TooManyPushSubscriptions.into());
+  }
+
+  let form = PushSubscriptionInsertForm {
+    expires_at: data.expires_at,
+    ..PushSubscriptionInsertForm::new(
+      local_user_view.local_user.id,
+      data.endpoint,
+      data.p256dh,
+      data.auth,
+    )
+  };
+  PushSubscription::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+pub async fn delete_push_subscription(
+  Json(data): Json<DeletePushSubscription>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  PushSubscription::delete_by_endpoint(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    &data.endpoint,
+  )
+  .await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
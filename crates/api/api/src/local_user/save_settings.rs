@@ -14,6 +14,7 @@ use lemmy_db_schema::{
     actor_language::LocalUserLanguage,
     keyword_block::LocalUserKeywordBlock,
     local_user::{LocalUser, LocalUserUpdateForm},
+    notification_preference::NotificationPreference,
     person::{Person, PersonUpdateForm},
   },
   utils::limit_fetch_check,
@@ -156,6 +157,11 @@ pub async fn save_user_settings(
     .await?;
   }
 
+  if let Some(notification_preferences) = data.notification_preferences.clone() {
+    NotificationPreference::update(&mut context.pool(), notification_preferences, local_user_id)
+      .await?;
+  }
+
   let local_user_form = LocalUserUpdateForm {
     email,
     show_avatars: data.show_avatars,
@@ -180,6 +186,8 @@ pub async fn save_user_settings(
     collapse_bot_comments: data.collapse_bot_comments,
     auto_mark_fetched_posts_as_read: data.auto_mark_fetched_posts_as_read,
     hide_posts_with_media: data.hide_posts_with_media,
+    email_digest_cadence: data.email_digest_cadence,
+    email_digest_top_posts: data.email_digest_top_posts,
     // Update the vote display modes
     show_score: data.show_score,
     show_upvotes: data.show_upvotes,
@@ -6,6 +6,7 @@ use lemmy_api_utils::{
 };
 use lemmy_db_views_community_follower_approval::PendingFollowerView;
 use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::ModmailThreadView;
 use lemmy_db_views_notification::NotificationView;
 use lemmy_db_views_registration_applications::RegistrationApplicationView;
 use lemmy_db_views_report_combined::ReportCombinedViewInternal;
@@ -21,9 +22,13 @@ pub async fn get_unread_counts(
 
   let notification_count =
     NotificationView::get_unread_count(&mut context.pool(), person, show_bot_accounts).await?;
+  let notification_group_count =
+    NotificationView::get_unread_group_count(&mut context.pool(), person, show_bot_accounts)
+      .await?;
 
-  // Community mods get additional counts for reports and pending follows for private communities.
-  let (report_count, pending_follow_count) =
+  // Community mods get additional counts for reports, unresolved modmail and pending follows for
+  // private communities.
+  let (report_count, modmail_count, pending_follow_count) =
     if check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool())
       .await
       .is_ok()
@@ -33,10 +38,18 @@ pub async fn get_unread_counts(
           ReportCombinedViewInternal::get_report_count(&mut context.pool(), &local_user_view)
             .await?,
         ),
+        Some(
+          ModmailThreadView::get_unresolved_count(
+            &mut context.pool(),
+            person.id,
+            local_user_view.local_user.admin,
+          )
+          .await?,
+        ),
         Some(PendingFollowerView::count_approval_required(&mut context.pool(), person.id).await?),
       )
     } else {
-      (None, None)
+      (None, None, None)
     };
 
   // Admins also get the number of unread registration applications.
@@ -53,7 +66,9 @@ pub async fn get_unread_counts(
 
   Ok(Json(UnreadCountsResponse {
     notification_count,
+    notification_group_count,
     report_count,
+    modmail_count,
     pending_follow_count,
     registration_application_count,
   }))
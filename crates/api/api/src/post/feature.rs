@@ -3,8 +3,10 @@ use actix_web::web::Json;
 use lemmy_api_utils::{
   build_response::build_post_response,
   context::LemmyContext,
+  notify::notify_post_featured,
   send_activity::{ActivityChannel, SendActivityData},
   utils::{check_community_mod_action, is_admin},
+  webhooks::send_modlog_webhooks,
 };
 use lemmy_db_schema::{
   PostFeatureType,
@@ -60,7 +62,11 @@ pub async fn feature_post(
   let post = Post::update(&mut context.pool(), post_id, &post_form).await?;
 
   // Mod tables
-  Modlog::create(&mut context.pool(), &[modlog_form]).await?;
+  let actions = Modlog::create(&mut context.pool(), &[modlog_form]).await?;
+  send_modlog_webhooks(&actions, &context);
+  if data.featured {
+    notify_post_featured(&post, local_user_view.person.id, &context);
+  }
 
   ActivityChannel::submit_activity(
     SendActivityData::FeaturePost(post, local_user_view.person.clone(), data.featured),
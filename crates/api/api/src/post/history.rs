@@ -0,0 +1,130 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_post_deleted_or_removed, check_private_instance, is_mod_or_admin_opt},
+};
+use lemmy_db_schema::source::{post::Post, post_revision::PostRevision};
+use lemmy_db_schema_file::enums::EditHistoryVisibility;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::{
+  PostView,
+  api::{GetPostHistory, GetPostHistoryResponse},
+};
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Lists previous versions of an edited post. Depending on the site settings this is only
+/// available to mods and admins.
+pub async fn get_post_history(
+  Query(data): Query<GetPostHistory>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<GetPostHistoryResponse>> {
+  let SiteView {
+    site, local_site, ..
+  } = SiteView::read_local(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let post = Post::read(&mut context.pool(), data.post_id).await?;
+  let is_mod_or_admin = is_mod_or_admin_opt(
+    &mut context.pool(),
+    local_user_view.as_ref(),
+    Some(post.community_id),
+  )
+  .await
+  .is_ok();
+
+  if !is_mod_or_admin {
+    if local_site.edit_history_visibility == EditHistoryVisibility::ModsOnly {
+      return Err(LemmyErrorType::NotAModOrAdmin.into());
+    }
+    // Mods need the previous versions of removed posts to handle reports, but for others they
+    // shouldn't be readable either
+    check_post_deleted_or_removed(&post)?;
+    // Make sure the user can see the post itself
+    PostView::read(
+      &mut context.pool(),
+      post.id,
+      local_user_view.as_ref().map(|l| &l.local_user),
+      site.instance_id,
+      false,
+    )
+    .await?;
+  }
+
+  let revisions = PostRevision::list_for_post(&mut context.pool(), post.id).await?;
+  Ok(Json(GetPostHistoryResponse { revisions }))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_db_schema::{
+    source::{
+      community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
+      post::{PostInsertForm, PostUpdateForm},
+    },
+    test_data::TestData,
+  };
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_history_of_removed_post() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+    let data = TestData::create(pool).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(
+        data.instance.id,
+        "post_history".to_string(),
+        "pubkey".to_string(),
+      ),
+    )
+    .await?;
+    let moderator = LocalUserView::create_test_user(pool, "post_history_mod", "", false).await?;
+    let form = CommunityModeratorForm::new(community.id, moderator.person.id);
+    CommunityActions::join(pool, &form).await?;
+    let user = LocalUserView::create_test_user(pool, "post_history_user", "", false).await?;
+
+    let original = Post::create(
+      pool,
+      &PostInsertForm::new("First title".into(), data.person.id, community.id),
+    )
+    .await?;
+    let form = PostUpdateForm {
+      name: Some("Second title".into()),
+      removed: Some(true),
+      ..Default::default()
+    };
+    let updated = Post::update(pool, original.id, &form).await?;
+    PostRevision::create_if_changed(pool, &original, &updated).await?;
+
+    // Mods can read the original version of the removed post
+    let query = || {
+      Query(GetPostHistory {
+        post_id: original.id,
+      })
+    };
+    let Json(history) = get_post_history(query(), context.clone(), Some(moderator)).await?;
+    let names = history
+      .revisions
+      .iter()
+      .map(|r| r.name.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(vec!["First title"], names);
+
+    let res = get_post_history(query(), context.clone(), Some(user)).await;
+    assert_eq!(
+      Some(LemmyErrorType::NotAModOrAdmin),
+      res.err().map(|e| e.error_type)
+    );
+
+    data.delete(pool).await?;
+    Ok(())
+  }
+}
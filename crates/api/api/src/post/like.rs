@@ -3,6 +3,7 @@ use actix_web::web::Json;
 use lemmy_api_utils::{
   build_response::build_post_response,
   context::LemmyContext,
+  notify::notify_score_milestone,
   plugins::{plugin_hook_after, plugin_hook_before},
   send_activity::{ActivityChannel, SendActivityData},
   utils::{check_bot_account, check_community_user_action, check_local_vote_mode},
@@ -71,6 +72,7 @@ pub async fn like_post(
   .await?;
 
   plugin_hook_after("post_after_vote", &like);
+  notify_score_milestone(post_id, orig_post.post.score, &context);
 
   // Mark Post Read
   PostActions::mark_as_read(&mut context.pool(), my_person_id, &[post_id]).await?;
@@ -1,12 +1,15 @@
 pub mod feature;
 pub mod get_link_metadata;
 pub mod hide;
+pub mod history;
 pub mod like;
 pub mod list_post_likes;
 pub mod lock;
 pub mod mark_many_read;
 pub mod mark_read;
 pub mod mod_update;
+pub mod react;
 pub mod save;
 pub mod update_notifications;
+pub mod vote_poll;
 pub mod warning;
@@ -0,0 +1,73 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  build_response::build_post_response,
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{build_reaction_form, check_bot_account, check_community_user_action},
+};
+use lemmy_db_schema::{newtypes::PostOrCommentId, source::reaction::Reaction};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::{
+  PostView,
+  api::{CreatePostReaction, PostResponse},
+};
+use lemmy_utils::error::LemmyResult;
+use std::ops::Deref;
+
+pub async fn react_post(
+  Json(data): Json<CreatePostReaction>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  let local_instance_id = local_user_view.person.instance_id;
+  let post_id = data.post_id;
+  let my_person_id = local_user_view.person.id;
+
+  check_bot_account(&local_user_view.person)?;
+
+  let orig_post = PostView::read(
+    &mut context.pool(),
+    post_id,
+    Some(&local_user_view.local_user),
+    local_instance_id,
+    false,
+  )
+  .await?;
+
+  check_community_user_action(&local_user_view, &orig_post.community, &mut context.pool()).await?;
+
+  let mut form = build_reaction_form(&data.emoji, my_person_id, &mut context.pool()).await?;
+  if data.add {
+    form.post_id = Some(post_id);
+    Reaction::add(&mut context.pool(), &form).await?;
+  } else {
+    Reaction::remove(
+      &mut context.pool(),
+      my_person_id,
+      PostOrCommentId::Post(post_id),
+      &form.emoji,
+    )
+    .await?;
+  }
+
+  ActivityChannel::submit_activity(
+    SendActivityData::ReactPostOrComment {
+      object_id: orig_post.post.ap_id,
+      actor: local_user_view.person.clone(),
+      community: orig_post.community.clone(),
+      emoji: form.emoji,
+      emoji_url: form.emoji_url,
+      add: data.add,
+    },
+    &context,
+  )?;
+
+  build_post_response(
+    context.deref(),
+    orig_post.community.id,
+    local_user_view,
+    post_id,
+  )
+  .await
+}
@@ -0,0 +1,94 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  build_response::build_post_response,
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_bot_account, check_community_user_action},
+};
+use lemmy_db_schema::source::{
+  poll::{Poll, PollVote},
+  post::PostActions,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::{
+  PostView,
+  api::{PostResponse, VotePoll},
+};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use std::{collections::HashSet, ops::Deref};
+
+pub async fn vote_poll(
+  Json(data): Json<VotePoll>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  let local_instance_id = local_user_view.person.instance_id;
+  let post_id = data.post_id;
+  let my_person_id = local_user_view.person.id;
+
+  check_bot_account(&local_user_view.person)?;
+
+  let orig_post = PostView::read(
+    &mut context.pool(),
+    post_id,
+    Some(&local_user_view.local_user),
+    local_instance_id,
+    false,
+  )
+  .await?;
+  check_community_user_action(&local_user_view, &orig_post.community, &mut context.pool()).await?;
+
+  let poll = orig_post.poll.ok_or(LemmyErrorType::NotFound)?;
+  if poll.poll.end_time_at.is_some_and(|e| e < Utc::now()) {
+    return Err(LemmyErrorType::PollClosed.into());
+  }
+  let unique = data.option_ids.iter().collect::<HashSet<_>>();
+  if unique.len() != data.option_ids.len() {
+    return Err(LemmyErrorType::InvalidPoll.into());
+  }
+  let options = data
+    .option_ids
+    .iter()
+    .map(|id| {
+      poll
+        .options
+        .iter()
+        .find(|o| &o.id == id)
+        .ok_or(LemmyErrorType::InvalidPoll)
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+  if !poll.poll.multiple_choice && options.len() > 1 {
+    return Err(LemmyErrorType::InvalidPoll.into());
+  }
+  // Remote instances have no way to change or retract a vote.
+  let is_local = orig_post.post.local;
+  if !is_local && !poll.my_votes.is_empty() {
+    return Err(LemmyErrorType::AlreadyExists.into());
+  }
+
+  PollVote::vote(&mut context.pool(), post_id, my_person_id, &data.option_ids).await?;
+  PostActions::mark_as_read(&mut context.pool(), my_person_id, &[post_id]).await?;
+
+  // For local polls the new vote counts are federated by a scheduled task, otherwise send the vote
+  // to the poll creator.
+  if is_local {
+    Poll::mark_votes_changed(&mut context.pool(), post_id).await?;
+  } else {
+    let activity = SendActivityData::VotePoll {
+      post: orig_post.post,
+      actor: local_user_view.person.clone(),
+      options: options.into_iter().map(|o| o.name.clone()).collect(),
+    };
+    ActivityChannel::submit_activity(activity, &context)?;
+  }
+
+  build_post_response(
+    context.deref(),
+    orig_post.community.id,
+    local_user_view,
+    post_id,
+  )
+  .await
+}
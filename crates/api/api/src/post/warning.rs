@@ -6,7 +6,8 @@ use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{check_community_mod_action, check_expire_time},
+  warning_policy::apply_warning_policy,
 };
 use lemmy_db_schema::source::modlog::{Modlog, ModlogInsertForm};
 use lemmy_db_views_local_user::LocalUserView;
@@ -24,6 +25,7 @@ pub async fn create_post_warning(
 ) -> LemmyResult<Json<PostResponse>> {
   let post_id = data.post_id;
   let local_instance_id = local_user_view.person.instance_id;
+  let expires_at = check_expire_time(data.expires_at)?;
 
   let orig_post = PostView::read(
     &mut context.pool(),
@@ -47,7 +49,8 @@ pub async fn create_post_warning(
     local_user_view.person.id,
     &orig_post.post,
     &data.reason,
-  );
+  )
+  .with_expires_at(expires_at);
   let action = Modlog::create(&mut context.pool(), &[form]).await?;
   notify_mod_action(action, &context);
 
@@ -55,10 +58,13 @@ pub async fn create_post_warning(
     SendActivityData::Warning(
       Box::new(Either::Left(orig_post.clone())),
       data.reason,
+      expires_at,
       local_user_view.person.clone(),
     ),
     &context,
   )?;
 
+  apply_warning_policy(&orig_post.community, orig_post.creator.id, &context).await;
+
   build_post_response(&context, orig_post.community.id, local_user_view, post_id).await
 }
@@ -3,6 +3,7 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use either::Either;
 use lemmy_api_utils::{
+  automod::{AutomodEvent, automod_comment},
   context::LemmyContext,
   plugins::plugin_hook_after,
   send_activity::{ActivityChannel, SendActivityData},
@@ -10,13 +11,16 @@ use lemmy_api_utils::{
     check_comment_deleted_or_removed,
     check_community_user_action,
     check_local_user_banned_or_deleted,
+    read_cited_rule,
     slur_regex,
   },
+  webhooks::send_webhook,
 };
 use lemmy_db_schema::{
   source::comment_report::{CommentReport, CommentReportForm},
   traits::Reportable,
 };
+use lemmy_db_schema_file::enums::WebhookEventType;
 use lemmy_db_views_comment::CommentView;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_report_combined::{
@@ -59,12 +63,23 @@ pub async fn create_comment_report(
   // Don't allow creating reports for removed / deleted comments
   check_comment_deleted_or_removed(&comment_view.comment)?;
 
+  let rule = read_cited_rule(
+    data.rule_id,
+    Some(comment_view.community.id),
+    &mut context.pool(),
+  )
+  .await?;
+  // Site rules are enforced by the local admins
+  let violates_instance_rules = data.violates_instance_rules.unwrap_or_default()
+    || rule.as_ref().is_some_and(|r| r.community_id.is_none());
+
   let report_form = CommentReportForm {
     creator_id: person.id,
     comment_id,
     original_comment_text: comment_view.comment.content,
     reason,
-    violates_instance_rules: data.violates_instance_rules.unwrap_or_default(),
+    violates_instance_rules,
+    rule_id: rule.as_ref().map(|r| r.id),
   };
 
   let report = CommentReport::report(&mut context.pool(), &report_form).await?;
@@ -72,6 +87,13 @@ pub async fn create_comment_report(
   let comment_report_view =
     ReportCombinedViewInternal::read_comment_report(&mut context.pool(), report.id, person).await?;
   plugin_hook_after("comment_report_after_create", &comment_report_view);
+  let community_id = (!report.violates_instance_rules).then_some(comment_report_view.community.id);
+  send_webhook(
+    WebhookEventType::NewReport,
+    community_id,
+    &comment_report_view,
+    &context,
+  );
 
   // Email the admins
   let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
@@ -92,11 +114,14 @@ pub async fn create_comment_report(
         actor: local_user_view.person,
         receiver: Either::Right(comment_view.community),
         reason: data.reason.clone(),
+        rule: rule.map(|r| r.ap_id.into()),
       },
       &context,
     )?;
   }
 
+  automod_comment(comment_id, AutomodEvent::Report, &context).await;
+
   Ok(Json(CommentReportResponse {
     comment_report_view,
   }))
@@ -0,0 +1,98 @@
+use crate::check_report_reason;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use either::Either;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  plugins::plugin_hook_after,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_local_user_banned_or_deleted, slur_regex},
+  webhooks::send_webhook,
+};
+use lemmy_db_schema::{
+  source::{
+    person::Person,
+    person_report::{PersonReport, PersonReportForm},
+    site::Site,
+  },
+  traits::Reportable,
+};
+use lemmy_db_schema_file::enums::WebhookEventType;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{
+  ReportCombinedViewInternal,
+  api::{CreatePersonReport, PersonReportResponse},
+};
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_email::admin::send_new_report_email_to_admins;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn create_person_report(
+  Json(data): Json<CreatePersonReport>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonReportResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  let reason = data.reason.trim().to_string();
+  let slur_regex = slur_regex(&context).await?;
+  check_report_reason(&reason, &slur_regex)?;
+
+  let person = &local_user_view.person;
+  let reported = Person::read(&mut context.pool(), data.person_id).await?;
+
+  // Reporting yourself makes no sense
+  if person.id == reported.id {
+    return Err(LemmyErrorType::CantReportYourself.into());
+  }
+
+  let report_form = PersonReportForm {
+    creator_id: person.id,
+    person_id: reported.id,
+    original_person_name: reported.name,
+    original_person_display_name: reported.display_name,
+    original_person_bio: reported.bio,
+    original_person_avatar: reported.avatar,
+    original_person_banner: reported.banner,
+    reason,
+  };
+
+  let report = PersonReport::report(&mut context.pool(), &report_form).await?;
+
+  let person_report_view =
+    ReportCombinedViewInternal::read_person_report(&mut context.pool(), report.id, person).await?;
+  plugin_hook_after("person_report_after_create", &person_report_view);
+  send_webhook(
+    WebhookEventType::NewReport,
+    None,
+    &person_report_view,
+    &context,
+  );
+
+  // Email the admins
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  if local_site.reports_email_admins {
+    send_new_report_email_to_admins(
+      &person_report_view.creator.name,
+      &person_report_view.person.name,
+      &mut context.pool(),
+      context.settings(),
+    )
+    .await?;
+  }
+
+  // Send the report to the admins of the user's home instance
+  let site = Site::read_from_instance_id(&mut context.pool(), reported.instance_id).await?;
+  ActivityChannel::submit_activity(
+    SendActivityData::CreateReport {
+      object_id: reported.ap_id.inner().clone(),
+      actor: local_user_view.person,
+      receiver: Either::Left(site),
+      reason: data.reason.clone(),
+      rule: None,
+    },
+    &context,
+  )?;
+
+  Ok(Json(PersonReportResponse { person_report_view }))
+}
@@ -0,0 +1,62 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use either::Either;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::{
+    person_report::{PersonReport, UpdatePersonReportForm},
+    site::Site,
+  },
+  traits::Reportable,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{
+  ReportCombinedViewInternal,
+  api::{PersonReportResponse, ResolvePersonReport},
+};
+use lemmy_diesel_utils::utils::diesel_string_update;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn resolve_person_report(
+  Json(data): Json<ResolvePersonReport>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonReportResponse>> {
+  is_admin(&local_user_view)?;
+
+  let report_id = data.report_id;
+  let person = &local_user_view.person;
+
+  let conclusion = diesel_string_update(data.conclusion.as_deref());
+
+  let form = UpdatePersonReportForm {
+    resolver_id: Some(person.id),
+    resolved: Some(data.resolved),
+    conclusion,
+    updated_at: Some(Utc::now()),
+  };
+
+  PersonReport::update_resolved(&mut context.pool(), report_id, &form).await?;
+
+  let person_report_view =
+    ReportCombinedViewInternal::read_person_report(&mut context.pool(), report_id, person).await?;
+  let site =
+    Site::read_from_instance_id(&mut context.pool(), person_report_view.person.instance_id).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::SendResolveReport {
+      object_id: person_report_view.person.ap_id.inner().clone(),
+      actor: local_user_view.person,
+      report_creator: person_report_view.creator.clone(),
+      receiver: Either::Left(site),
+    },
+    &context,
+  )?;
+
+  Ok(Json(PersonReportResponse { person_report_view }))
+}
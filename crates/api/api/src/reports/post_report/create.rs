@@ -3,6 +3,7 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use either::Either;
 use lemmy_api_utils::{
+  automod::{AutomodEvent, automod_post},
   context::LemmyContext,
   plugins::plugin_hook_after,
   send_activity::{ActivityChannel, SendActivityData},
@@ -10,13 +11,16 @@ use lemmy_api_utils::{
     check_community_user_action,
     check_local_user_banned_or_deleted,
     check_post_deleted_or_removed,
+    read_cited_rule,
     slur_regex,
   },
+  webhooks::send_webhook,
 };
 use lemmy_db_schema::{
   source::post_report::{PostReport, PostReportForm},
   traits::Reportable,
 };
+use lemmy_db_schema_file::enums::WebhookEventType;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_post::PostView;
 use lemmy_db_views_report_combined::{
@@ -54,6 +58,16 @@ pub async fn create_post_report(
 
   check_post_deleted_or_removed(&orig_post.post)?;
 
+  let rule = read_cited_rule(
+    data.rule_id,
+    Some(orig_post.community.id),
+    &mut context.pool(),
+  )
+  .await?;
+  // Site rules are enforced by the local admins
+  let violates_instance_rules = data.violates_instance_rules.unwrap_or_default()
+    || rule.as_ref().is_some_and(|r| r.community_id.is_none());
+
   let report_form = PostReportForm {
     creator_id: person.id,
     post_id,
@@ -61,7 +75,8 @@ pub async fn create_post_report(
     original_post_url: orig_post.post.url,
     original_post_body: orig_post.post.body,
     reason,
-    violates_instance_rules: data.violates_instance_rules.unwrap_or_default(),
+    violates_instance_rules,
+    rule_id: rule.as_ref().map(|r| r.id),
   };
 
   let report = PostReport::report(&mut context.pool(), &report_form).await?;
@@ -69,6 +84,13 @@ pub async fn create_post_report(
   let post_report_view =
     ReportCombinedViewInternal::read_post_report(&mut context.pool(), report.id, person).await?;
   plugin_hook_after("post_report_after_create", &post_report_view);
+  let community_id = (!report.violates_instance_rules).then_some(post_report_view.community.id);
+  send_webhook(
+    WebhookEventType::NewReport,
+    community_id,
+    &post_report_view,
+    &context,
+  );
 
   // Email the admins
   let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
@@ -89,10 +111,13 @@ pub async fn create_post_report(
         actor: local_user_view.person,
         receiver: Either::Right(orig_post.community),
         reason: data.reason.clone(),
+        rule: rule.map(|r| r.ap_id.into()),
       },
       &context,
     )?;
   }
 
+  automod_post(post_id, AutomodEvent::Report, &context).await;
+
   Ok(Json(PostReportResponse { post_report_view }))
 }
@@ -26,6 +26,8 @@ pub async fn list_reports(
     limit,
     show_community_rule_violations,
     my_reports_only,
+    rule_id,
+    item_creator_id,
   } = data;
 
   // Only check mod or admin status when not viewing my reports
@@ -40,6 +42,8 @@ pub async fn list_reports(
     unresolved_only,
     show_community_rule_violations,
     my_reports_only,
+    rule_id,
+    item_creator_id,
     sort,
     page_cursor,
     limit,
@@ -1,6 +1,6 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin, webhooks::send_modlog_webhooks};
 use lemmy_db_schema::source::{
   federation_allowlist::{FederationAllowList, FederationAllowListForm},
   instance::Instance,
@@ -38,7 +38,8 @@ pub async fn admin_allow_instance(
     data.allow,
     &data.reason,
   );
-  Modlog::create(&mut context.pool(), &[form]).await?;
+  let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+  send_modlog_webhooks(&actions, &context);
 
   Ok(Json(
     FederatedInstanceView::read(&mut context.pool(), instance_id).await?,
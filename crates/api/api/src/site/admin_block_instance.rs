@@ -3,6 +3,7 @@ use actix_web::web::Json;
 use lemmy_api_utils::{
   context::LemmyContext,
   utils::{check_expire_time, is_admin},
+  webhooks::send_modlog_webhooks,
 };
 use lemmy_db_schema::source::{
   federation_blocklist::{FederationBlockList, FederationBlockListForm},
@@ -45,7 +46,8 @@ pub async fn admin_block_instance(
     data.block,
     &data.reason,
   );
-  Modlog::create(&mut context.pool(), &[form]).await?;
+  let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+  send_modlog_webhooks(&actions, &context);
 
   Ok(Json(
     FederatedInstanceView::read(&mut context.pool(), instance_id).await?,
@@ -0,0 +1,20 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::email_outbox::EmailOutbox;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::ListFailedEmails;
+use lemmy_diesel_utils::pagination::PagedResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_failed_emails(
+  Query(data): Query<ListFailedEmails>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<EmailOutbox>>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let emails = EmailOutbox::list_failed(&mut context.pool(), data.page_cursor, data.limit).await?;
+
+  Ok(Json(emails))
+}
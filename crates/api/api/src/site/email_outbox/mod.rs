@@ -0,0 +1,2 @@
+pub mod list_failed;
+pub mod resend;
@@ -0,0 +1,19 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::email_outbox::EmailOutbox;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{EmailOutboxResponse, ResendEmail};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn resend_email(
+  Json(data): Json<ResendEmail>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<EmailOutboxResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let email = EmailOutbox::resend(&mut context.pool(), data.email_outbox_id).await?;
+
+  Ok(Json(EmailOutboxResponse { email }))
+}
@@ -37,6 +37,7 @@ pub async fn get_mod_log(
     hide_modlog_names: Some(hide_modlog_names),
     show_bulk: data.show_bulk,
     bulk_action_parent_id: data.bulk_action_parent_id,
+    rule_id: data.rule_id,
     page_cursor: data.page_cursor,
     limit: data.limit,
   }
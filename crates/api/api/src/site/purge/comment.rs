@@ -4,6 +4,7 @@ use lemmy_api_utils::{
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
   utils::is_admin,
+  webhooks::send_modlog_webhooks,
 };
 use lemmy_db_schema::source::{
   comment::Comment,
@@ -55,7 +56,8 @@ pub async fn purge_comment(
     comment_view.community.id,
     &data.reason,
   );
-  Modlog::create(&mut context.pool(), &[form]).await?;
+  let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+  send_modlog_webhooks(&actions, &context);
 
   ActivityChannel::submit_activity(
     SendActivityData::RemoveComment {
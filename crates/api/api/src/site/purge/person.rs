@@ -4,6 +4,7 @@ use lemmy_api_utils::{
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
   utils::{is_admin, purge_user_account},
+  webhooks::send_modlog_webhooks,
 };
 use lemmy_db_schema::{
   source::{
@@ -67,7 +68,8 @@ pub async fn purge_person(
 
   // Mod tables
   let form = ModlogInsertForm::admin_purge_person(local_user_view.person.id, &data.reason);
-  Modlog::create(&mut context.pool(), &[form]).await?;
+  let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+  send_modlog_webhooks(&actions, &context);
 
   Ok(Json(SuccessResponse::default()))
 }
@@ -4,6 +4,7 @@ use lemmy_api_utils::{
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
   utils::{is_admin, purge_post_images},
+  webhooks::send_modlog_webhooks,
 };
 use lemmy_db_schema::source::{
   local_user::LocalUser,
@@ -42,7 +43,8 @@ pub async fn purge_post(
   // Mod tables
   let form =
     ModlogInsertForm::admin_purge_post(local_user_view.person.id, post.community_id, &data.reason);
-  Modlog::create(&mut context.pool(), &[form]).await?;
+  let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+  send_modlog_webhooks(&actions, &context);
 
   ActivityChannel::submit_activity(
     SendActivityData::RemovePost {
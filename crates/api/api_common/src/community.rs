@@ -44,6 +44,7 @@ pub mod actions {
       AddModToCommunityResponse,
       ApproveCommunityPendingFollower,
       BanFromCommunity,
+      BulkBanFromCommunity,
       CommunityIdQuery,
       CreateCommunityTag,
       DeleteCommunity,
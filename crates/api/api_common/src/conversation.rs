@@ -0,0 +1,27 @@
+pub use lemmy_db_schema::{
+  newtypes::{ConversationId, ConversationMessageId},
+  source::conversation::{Conversation, ConversationMessage, ConversationParticipant},
+};
+pub use lemmy_db_views_conversation::{
+  ConversationMessageView,
+  ConversationView,
+  api::{
+    ConversationMessageResponse,
+    ConversationResponse,
+    GetConversation,
+    ListConversationMessages,
+    ListConversations,
+  },
+};
+
+pub mod actions {
+  pub use lemmy_db_views_conversation::api::{
+    AddConversationParticipant,
+    CreateConversation,
+    CreateConversationMessage,
+    DeleteConversationMessage,
+    EditConversationMessage,
+    MarkConversationAsRead,
+    RemoveConversationParticipant,
+  };
+}
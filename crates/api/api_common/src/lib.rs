@@ -1,12 +1,15 @@
 pub mod account;
 pub mod comment;
 pub mod community;
+pub mod conversation;
 pub mod custom_emoji;
 pub mod error;
 pub mod federation;
 pub mod language;
 pub mod media;
+pub mod mod_case;
 pub mod modlog;
+pub mod modmail;
 pub mod notification;
 pub mod oauth;
 pub mod person;
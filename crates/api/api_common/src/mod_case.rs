@@ -0,0 +1,12 @@
+pub use lemmy_db_schema::{newtypes::ModNoteId, source::mod_note::ModNote};
+pub use lemmy_db_views_mod_case::{
+  ModCaseView,
+  ModNoteView,
+  api::{ModCaseResponse, ModNoteResponse},
+};
+
+pub mod actions {
+  pub mod moderation {
+    pub use lemmy_db_views_mod_case::api::{CreateModNote, DeleteModNote, EditModNote, GetModCase};
+  }
+}
@@ -1 +1,4 @@
-pub use lemmy_db_views_modlog::{ModlogView, api::GetModlog};
+pub use lemmy_db_views_modlog::{
+  ModlogView,
+  api::{BulkActionResponse, BulkLockPosts, BulkRemove, GetModlog},
+};
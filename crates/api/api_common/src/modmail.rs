@@ -0,0 +1,22 @@
+pub use lemmy_db_schema::{
+  newtypes::{ModmailMessageId, ModmailThreadId},
+  source::modmail::{ModmailMessage, ModmailThread},
+};
+pub use lemmy_db_views_modmail::{
+  ModmailMessageView,
+  ModmailThreadView,
+  api::{ListModmailMessages, ModmailMessageResponse, ModmailThreadResponse},
+};
+
+pub mod actions {
+  pub use lemmy_db_views_modmail::api::CreateModmail;
+
+  pub mod moderation {
+    pub use lemmy_db_views_modmail::api::{
+      AssignModmail,
+      ListModmailThreads,
+      ReplyToModmail,
+      ResolveModmail,
+    };
+  }
+}
@@ -4,7 +4,9 @@ pub use lemmy_db_schema::{
   source::notification::Notification,
 };
 pub use lemmy_db_views_notification::{
+  ListNotificationGroups,
   ListNotifications,
+  NotificationGroupView,
   NotificationView,
-  api::MarkNotificationAsRead,
+  api::{MarkNotificationAsRead, MarkNotificationGroupAsRead},
 };
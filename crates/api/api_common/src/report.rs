@@ -1,9 +1,16 @@
 pub use lemmy_db_schema::{
   ReportType,
-  newtypes::{CommentReportId, CommunityReportId, PostReportId, PrivateMessageReportId},
+  newtypes::{
+    CommentReportId,
+    CommunityReportId,
+    PersonReportId,
+    PostReportId,
+    PrivateMessageReportId,
+  },
   source::{
     comment_report::CommentReport,
     community_report::CommunityReport,
+    person_report::PersonReport,
     post_report::PostReport,
     private_message_report::PrivateMessageReport,
   },
@@ -11,21 +18,26 @@ pub use lemmy_db_schema::{
 pub use lemmy_db_views_report_combined::{
   CommentReportView,
   CommunityReportView,
+  PersonReportView,
   PostReportView,
   PrivateMessageReportView,
   ReportCombinedView,
   api::{
+    BulkResolveReports,
     CommentReportResponse,
     CommunityReportResponse,
     CreateCommentReport,
     CreateCommunityReport,
+    CreatePersonReport,
     CreatePostReport,
     CreatePrivateMessageReport,
     ListReports,
+    PersonReportResponse,
     PostReportResponse,
     PrivateMessageReportResponse,
     ResolveCommentReport,
     ResolveCommunityReport,
+    ResolvePersonReport,
     ResolvePostReport,
     ResolvePrivateMessageReport,
   },
@@ -0,0 +1,39 @@
+use crate::automod::check_manage_automod_rules;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{automod::validate_automod_rule, context::LemmyContext};
+use lemmy_db_schema::source::automod_rule::{AutomodRule, AutomodRuleInsertForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{AutomodRuleResponse, CreateAutomodRule};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn create_automod_rule(
+  Json(data): Json<CreateAutomodRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<AutomodRuleResponse>> {
+  check_manage_automod_rules(data.community_id, &local_user_view, &context).await?;
+
+  let form = AutomodRuleInsertForm {
+    reply_text: data.reply_text,
+    check_posts: data.check_posts,
+    check_comments: data.check_comments,
+    content_regex: data.content_regex,
+    link_domain: data.link_domain,
+    max_account_age_days: data.max_account_age_days,
+    max_creator_score: data.max_creator_score,
+    report_count: data.report_count,
+    language_id: data.language_id,
+    ..AutomodRuleInsertForm::new(
+      local_user_view.person.id,
+      data.community_id,
+      data.reason,
+      data.action,
+    )
+  };
+  validate_automod_rule(&form)?;
+  let automod_rule = AutomodRule::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(AutomodRuleResponse { automod_rule }))
+}
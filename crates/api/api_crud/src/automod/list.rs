@@ -0,0 +1,20 @@
+use crate::automod::check_manage_automod_rules;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::automod_rule::AutomodRule;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{ListAutomodRules, ListAutomodRulesResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_automod_rules(
+  Query(data): Query<ListAutomodRules>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListAutomodRulesResponse>> {
+  check_manage_automod_rules(data.community_id, &local_user_view, &context).await?;
+
+  let automod_rules = AutomodRule::list(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListAutomodRulesResponse { automod_rules }))
+}
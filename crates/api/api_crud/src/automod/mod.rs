@@ -0,0 +1,28 @@
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_action, is_admin},
+};
+use lemmy_db_schema::{newtypes::CommunityId, source::community::Community};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// Site rules can only be managed by admins, community rules also by the community mods.
+async fn check_manage_automod_rules(
+  community_id: Option<CommunityId>,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  match community_id {
+    Some(community_id) => {
+      let community = Community::read(&mut context.pool(), community_id).await?;
+      check_community_mod_action(local_user_view, &community, false, &mut context.pool()).await
+    }
+    None => is_admin(local_user_view),
+  }
+}
@@ -0,0 +1,60 @@
+use crate::automod::check_manage_automod_rules;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{automod::validate_automod_rule, context::LemmyContext};
+use lemmy_db_schema::source::automod_rule::{
+  AutomodRule,
+  AutomodRuleInsertForm,
+  AutomodRuleUpdateForm,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{AutomodRuleResponse, EditAutomodRule};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn edit_automod_rule(
+  Json(data): Json<EditAutomodRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<AutomodRuleResponse>> {
+  let rule = AutomodRule::read(&mut context.pool(), data.automod_rule_id).await?;
+  check_manage_automod_rules(rule.community_id, &local_user_view, &context).await?;
+
+  // Validate the rule as it will be after the edit
+  let edited = AutomodRuleInsertForm {
+    reply_text: data.reply_text,
+    content_regex: data.content_regex,
+    link_domain: data.link_domain,
+    max_account_age_days: data.max_account_age_days,
+    max_creator_score: data.max_creator_score,
+    report_count: data.report_count,
+    language_id: data.language_id,
+    ..AutomodRuleInsertForm::new(
+      rule.creator_id,
+      rule.community_id,
+      data.reason.unwrap_or(rule.reason),
+      data.action.unwrap_or(rule.action),
+    )
+  };
+  validate_automod_rule(&edited)?;
+
+  let form = AutomodRuleUpdateForm {
+    reason: Some(edited.reason),
+    action: Some(edited.action),
+    reply_text: Some(edited.reply_text),
+    check_posts: data.check_posts,
+    check_comments: data.check_comments,
+    content_regex: Some(edited.content_regex),
+    link_domain: Some(edited.link_domain),
+    max_account_age_days: Some(edited.max_account_age_days),
+    max_creator_score: Some(edited.max_creator_score),
+    report_count: Some(edited.report_count),
+    language_id: Some(edited.language_id),
+    enabled: data.enabled,
+    updated_at: Some(Some(Utc::now())),
+  };
+  let automod_rule = AutomodRule::update(&mut context.pool(), rule.id, &form).await?;
+
+  Ok(Json(AutomodRuleResponse { automod_rule }))
+}
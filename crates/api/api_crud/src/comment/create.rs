@@ -2,6 +2,7 @@ use crate::community_use_pending;
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_utils::{
+  automod::{AutomodEvent, automod_comment},
   build_response::build_comment_response,
   context::LemmyContext,
   notify::NotifyData,
@@ -15,6 +16,7 @@ use lemmy_api_utils::{
     get_url_blocklist,
     is_mod_or_admin,
     process_markdown,
+    requires_approval,
     slur_regex,
     update_read_comments,
   },
@@ -106,9 +108,17 @@ pub async fn create_comment(
     check_comment_deleted_or_removed(parent)?;
   }
 
+  let approval_pending = requires_approval(
+    &local_user_view.person,
+    &post_view.community,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
   let mut comment_form = CommentInsertForm {
     language_id: data.language_id,
     federation_pending: Some(community_use_pending(&post_view.community, &context).await),
+    approval_pending: Some(approval_pending),
     ..CommentInsertForm::new(my_person_id, post_id, community_id, content.clone())
   };
   comment_form = plugin_hook_before("local_comment_before_create", comment_form).await?;
@@ -120,26 +130,35 @@ pub async fn create_comment(
     Comment::create(&mut context.pool(), &comment_form, parent_path.as_ref()).await?;
   plugin_hook_after("local_comment_after_create", &inserted_comment);
 
-  NotifyData {
-    comment: Some(inserted_comment.clone()),
-    do_send_email: !local_site.email_notifications_disabled,
-    ..NotifyData::new(
-      post.clone(),
-      local_user_view.person.clone(),
-      post_view.community,
-    )
+  // Automod can hold the comment for approval, which needs to happen before it is federated
+  let approval_pending = approval_pending
+    || automod_comment(inserted_comment.id, AutomodEvent::BeforeCreate, &context).await;
+
+  // Comments in the mod queue are only federated and announced once approved
+  if !approval_pending {
+    NotifyData {
+      comment: Some(inserted_comment.clone()),
+      do_send_email: !local_site.email_notifications_disabled,
+      ..NotifyData::new(
+        post.clone(),
+        local_user_view.person.clone(),
+        post_view.community,
+      )
+    }
+    .send(&context);
   }
-  .send(&context);
 
   // You like your own comment by default
   let like_form = CommentLikeForm::new(inserted_comment.id, my_person_id, Some(true));
 
   CommentActions::like(&mut context.pool(), &like_form).await?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::CreateComment(inserted_comment.clone()),
-    &context,
-  )?;
+  if !approval_pending {
+    ActivityChannel::submit_activity(
+      SendActivityData::CreateComment(inserted_comment.clone()),
+      &context,
+    )?;
+  }
 
   // Update the read comments, so your own new comment doesn't appear as a +1 unread
   update_read_comments(
@@ -150,6 +169,10 @@ pub async fn create_comment(
   )
   .await?;
 
+  if !approval_pending {
+    automod_comment(inserted_comment.id, AutomodEvent::Create, &context).await;
+  }
+
   // If we're responding to a comment where we're the recipient,
   // (ie we're the grandparent, or the recipient of the parent comment_reply),
   // then mark the parent as read.
@@ -2,4 +2,5 @@ pub mod create;
 pub mod delete;
 pub mod read;
 pub mod remove;
+pub mod schedule;
 pub mod update;
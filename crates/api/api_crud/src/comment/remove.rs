@@ -5,7 +5,7 @@ use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{check_community_mod_action, read_cited_rule},
 };
 use lemmy_db_schema::{
   source::{
@@ -46,6 +46,13 @@ pub async fn remove_comment(
     &mut context.pool(),
   )
   .await?;
+  let rule = read_cited_rule(
+    data.rule_id,
+    Some(orig_comment.community.id),
+    &mut context.pool(),
+  )
+  .await?;
+  let rule_id = rule.map(|r| r.id);
 
   LocalUser::is_higher_mod_or_admin_check(
     &mut context.pool(),
@@ -88,6 +95,7 @@ pub async fn remove_comment(
           &data.reason,
           None,
         )
+        .with_rule(rule_id)
       })
       .collect();
 
@@ -127,7 +135,8 @@ pub async fn remove_comment(
       removed,
       &data.reason,
       None,
-    );
+    )
+    .with_rule(rule_id);
 
     (updated_comment, vec![form])
   };
@@ -0,0 +1,195 @@
+use crate::{MAX_SCHEDULED_ITEMS, comment::create::create_comment, convert_scheduled_time};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_comment_distinguished,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{
+    check_community_mod_action,
+    check_community_user_action,
+    check_post_deleted_or_removed,
+    slur_regex,
+  },
+};
+use lemmy_db_schema::source::{
+  comment::{Comment, CommentUpdateForm},
+  community::Community,
+  post::Post,
+  scheduled::{ScheduledComment, ScheduledCommentInsertForm, ScheduledCommentUpdateForm},
+};
+use lemmy_db_views_comment::api::{
+  CreateComment,
+  CreateScheduledComment,
+  DeleteScheduledComment,
+  EditScheduledComment,
+  ScheduledCommentResponse,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+use tracing::warn;
+
+pub async fn create_scheduled_comment(
+  Json(data): Json<CreateScheduledComment>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ScheduledCommentResponse>> {
+  let scheduled_publish_time_at = convert_scheduled_time(data.scheduled_publish_time_at)?;
+  let my_person_id = local_user_view.person.id;
+  if !local_user_view.local_user.admin {
+    let count = ScheduledComment::count_for_creator(&mut context.pool(), my_person_id).await?;
+    if count >= MAX_SCHEDULED_ITEMS {
+      return Err(LemmyErrorType::TooManyScheduledComments.into());
+    }
+  }
+
+  // The content is processed when the comment gets created, here it is only validated.
+  let slur_regex = slur_regex(&context).await?;
+  check_slurs(&data.content, &slur_regex)?;
+  is_valid_body_field(&data.content, false)?;
+
+  let post = Post::read(&mut context.pool(), data.post_id).await?;
+  let community = Community::read(&mut context.pool(), post.community_id).await?;
+  check_community_user_action(&local_user_view, &community, &mut context.pool()).await?;
+  check_post_deleted_or_removed(&post)?;
+  if let Some(parent_id) = data.parent_id {
+    let parent = Comment::read(&mut context.pool(), parent_id).await?;
+    if parent.post_id != post.id {
+      return Err(LemmyErrorType::CouldntCreate.into());
+    }
+  }
+  if data.distinguished == Some(true) {
+    check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  }
+
+  let form = ScheduledCommentInsertForm {
+    parent_id: data.parent_id,
+    language_id: data.language_id,
+    distinguished: data.distinguished,
+    ..ScheduledCommentInsertForm::new(
+      my_person_id,
+      post.id,
+      data.content,
+      scheduled_publish_time_at,
+    )
+  };
+  let scheduled_comment = ScheduledComment::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(ScheduledCommentResponse { scheduled_comment }))
+}
+
+pub async fn edit_scheduled_comment(
+  Json(data): Json<EditScheduledComment>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ScheduledCommentResponse>> {
+  let orig = ScheduledComment::read(&mut context.pool(), data.scheduled_comment_id).await?;
+  if orig.creator_id != local_user_view.person.id {
+    return Err(LemmyErrorType::EditScheduledCommentNotAllowed.into());
+  }
+
+  if let Some(content) = &data.content {
+    let slur_regex = slur_regex(&context).await?;
+    check_slurs(content, &slur_regex)?;
+    is_valid_body_field(content, false)?;
+  }
+  if data.distinguished == Some(true) {
+    let post = Post::read(&mut context.pool(), orig.post_id).await?;
+    let community = Community::read(&mut context.pool(), post.community_id).await?;
+    check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  }
+  let scheduled_publish_time_at = data
+    .scheduled_publish_time_at
+    .map(convert_scheduled_time)
+    .transpose()?;
+
+  let form = ScheduledCommentUpdateForm {
+    content: data.content,
+    language_id: data.language_id.map(Some),
+    distinguished: data.distinguished,
+    scheduled_publish_time_at,
+    updated_at: Some(Some(Utc::now())),
+  };
+  let scheduled_comment =
+    ScheduledComment::update(&mut context.pool(), data.scheduled_comment_id, &form).await?;
+
+  Ok(Json(ScheduledCommentResponse { scheduled_comment }))
+}
+
+pub async fn delete_scheduled_comment(
+  Json(data): Json<DeleteScheduledComment>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let orig = ScheduledComment::read(&mut context.pool(), data.scheduled_comment_id).await?;
+  if orig.creator_id != local_user_view.person.id {
+    return Err(LemmyErrorType::EditScheduledCommentNotAllowed.into());
+  }
+  ScheduledComment::delete(&mut context.pool(), data.scheduled_comment_id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+/// Creates the scheduled comments whose time has come. They go through the same checks as
+/// normal comments, so a comment is dropped if the user got banned or the post was locked in the
+/// meantime.
+pub async fn publish_scheduled_comments(context: &Data<LemmyContext>) -> LemmyResult<()> {
+  for scheduled in ScheduledComment::list_due(&mut context.pool()).await? {
+    // Delete it first, so that a failing comment isn't retried forever
+    ScheduledComment::delete(&mut context.pool(), scheduled.id).await?;
+    let Ok(local_user_view) =
+      LocalUserView::read_person(&mut context.pool(), scheduled.creator_id).await
+    else {
+      continue;
+    };
+
+    let form = CreateComment {
+      content: scheduled.content,
+      post_id: scheduled.post_id,
+      parent_id: scheduled.parent_id,
+      language_id: scheduled.language_id,
+    };
+    let comment_view = match Box::pin(create_comment(
+      Json(form),
+      context.reset_request_count(),
+      local_user_view.clone(),
+    ))
+    .await
+    {
+      Ok(res) => res.0.comment_view,
+      Err(e) => {
+        warn!(
+          "Failed to publish scheduled comment {}: {e}",
+          scheduled.id.0
+        );
+        continue;
+      }
+    };
+
+    if scheduled.distinguished
+      && check_community_mod_action(
+        &local_user_view,
+        &comment_view.community,
+        false,
+        &mut context.pool(),
+      )
+      .await
+      .is_ok()
+    {
+      let form = CommentUpdateForm {
+        distinguished: Some(true),
+        ..Default::default()
+      };
+      let comment = Comment::update(&mut context.pool(), comment_view.comment.id, &form).await?;
+      notify_comment_distinguished(&comment, context);
+      ActivityChannel::submit_activity(SendActivityData::UpdateComment(comment), context)?;
+    }
+  }
+  Ok(())
+}
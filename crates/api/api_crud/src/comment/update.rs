@@ -11,7 +11,10 @@ use lemmy_api_utils::{
 };
 use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
-  source::comment::{Comment, CommentUpdateForm},
+  source::{
+    comment::{Comment, CommentUpdateForm},
+    comment_revision::CommentRevision,
+  },
 };
 use lemmy_db_views_comment::{
   CommentView,
@@ -84,24 +87,29 @@ pub async fn edit_comment(
   .await?;
 
   let updated_comment = Comment::update(&mut context.pool(), comment_id, &form).await?;
+  CommentRevision::create_if_changed(&mut context.pool(), &orig_comment.comment, &updated_comment)
+    .await?;
 
   plugin_hook_after("local_comment_after_update", &updated_comment);
 
-  // Do the mentions / recipients
-  NotifyData {
-    comment: Some(updated_comment.clone()),
-    ..NotifyData::new(
-      orig_comment.post,
-      local_user_view.person.clone(),
-      orig_comment.community,
-    )
-  }
-  .send(&context);
+  // Comments in the mod queue are only federated once approved
+  if !updated_comment.approval_pending {
+    // Do the mentions / recipients
+    NotifyData {
+      comment: Some(updated_comment.clone()),
+      ..NotifyData::new(
+        orig_comment.post,
+        local_user_view.person.clone(),
+        orig_comment.community,
+      )
+    }
+    .send(&context);
 
-  ActivityChannel::submit_activity(
-    SendActivityData::UpdateComment(updated_comment.clone()),
-    &context,
-  )?;
+    ActivityChannel::submit_activity(
+      SendActivityData::UpdateComment(updated_comment.clone()),
+      &context,
+    )?;
+  }
 
   Ok(Json(
     build_comment_response(
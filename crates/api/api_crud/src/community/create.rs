@@ -39,6 +39,7 @@ use lemmy_utils::{
   utils::{
     slurs::{check_slurs, check_slurs_opt},
     validation::{
+      check_warning_policy,
       is_valid_actor_name,
       is_valid_body_field,
       is_valid_display_name,
@@ -62,6 +63,11 @@ pub async fn create_community(
   }
 
   check_nsfw_allowed(data.nsfw, Some(&local_site))?;
+  check_warning_policy(
+    data.warning_ban_threshold,
+    data.warning_ban_window_days,
+    data.warning_ban_days,
+  )?;
   let slur_regex = slur_regex(&context).await?;
   let url_blocklist = get_url_blocklist(&context).await?;
   check_slurs(&data.name, &slur_regex)?;
@@ -114,6 +120,13 @@ pub async fn create_community(
     featured_url: Some(generate_featured_url(&ap_id)?),
     posting_restricted_to_mods: data.posting_restricted_to_mods,
     visibility: data.visibility,
+    posts_require_approval: data.posts_require_approval,
+    comments_require_approval: data.comments_require_approval,
+    approval_max_account_age_days: data.approval_max_account_age_days.filter(|d| *d != 0),
+    approval_remote_only: data.approval_remote_only,
+    warning_ban_threshold: data.warning_ban_threshold.filter(|t| *t != 0),
+    warning_ban_window_days: data.warning_ban_window_days.filter(|d| *d != 0),
+    warning_ban_days: data.warning_ban_days.filter(|d| *d != 0),
     ..CommunityInsertForm::new(site.instance_id, data.name.clone(), keypair.public_key)
   };
 
@@ -45,6 +45,7 @@ pub async fn list_communities(
     search_title_only,
     page_cursor,
     limit,
+    ..Default::default()
   }
   .list(&mut context.pool(), &site, &local_site)
   .await?;
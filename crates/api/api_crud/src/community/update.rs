@@ -12,6 +12,7 @@ use lemmy_api_utils::{
     process_markdown_opt,
     slur_regex,
   },
+  webhooks::send_modlog_webhooks,
 };
 use lemmy_db_schema::source::{
   actor_language::{CommunityLanguage, SiteLanguage},
@@ -21,12 +22,15 @@ use lemmy_db_schema::source::{
 use lemmy_db_views_community::api::{CommunityResponse, EditCommunity};
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::SiteView;
-use lemmy_diesel_utils::{traits::Crud, utils::diesel_string_update};
+use lemmy_diesel_utils::{
+  traits::Crud,
+  utils::{diesel_opt_number_update, diesel_string_update},
+};
 use lemmy_utils::{
   error::{LemmyErrorType, LemmyResult},
   utils::{
     slurs::{check_slurs, check_slurs_opt},
-    validation::{is_valid_body_field, is_valid_display_name},
+    validation::{check_warning_policy, is_valid_body_field, is_valid_display_name},
   },
 };
 
@@ -42,6 +46,11 @@ pub async fn edit_community(
   check_slurs_opt(&data.title, &slur_regex)?;
   check_slurs_opt(&data.summary, &slur_regex)?;
   check_nsfw_allowed(data.nsfw, Some(&local_site))?;
+  check_warning_policy(
+    data.warning_ban_threshold,
+    data.warning_ban_window_days,
+    data.warning_ban_days,
+  )?;
 
   let title = data.title.as_ref().map(|x| x.trim().to_string());
   let title = diesel_string_update(title.as_deref());
@@ -91,6 +100,13 @@ pub async fn edit_community(
     nsfw: data.nsfw,
     posting_restricted_to_mods: data.posting_restricted_to_mods,
     visibility: data.visibility,
+    posts_require_approval: data.posts_require_approval,
+    comments_require_approval: data.comments_require_approval,
+    approval_max_account_age_days: diesel_opt_number_update(data.approval_max_account_age_days),
+    approval_remote_only: data.approval_remote_only,
+    warning_ban_threshold: diesel_opt_number_update(data.warning_ban_threshold),
+    warning_ban_window_days: diesel_opt_number_update(data.warning_ban_window_days),
+    warning_ban_days: diesel_opt_number_update(data.warning_ban_days),
     updated_at: Some(Some(Utc::now())),
     ..Default::default()
   };
@@ -104,7 +120,8 @@ pub async fn edit_community(
       local_user_view.person.id,
       data.community_id,
     );
-    Modlog::create(&mut context.pool(), &[form]).await?;
+    let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+    send_modlog_webhooks(&actions, &context);
   }
 
   // If community visibility was changed to local-only, mark it as deleted on other instances. Also
@@ -0,0 +1,74 @@
+use crate::conversation::{
+  MAX_CONVERSATION_PARTICIPANTS,
+  check_can_add_participant,
+  conversation_response,
+  process_message_content,
+};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_local_user_banned_or_deleted, check_private_messages_enabled},
+};
+use lemmy_db_schema::source::conversation::{
+  Conversation,
+  ConversationInsertForm,
+  ConversationMessage,
+  ConversationMessageInsertForm,
+  ConversationParticipant,
+};
+use lemmy_db_views_conversation::{
+  ConversationMessageView,
+  api::{ConversationResponse, CreateConversation},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use std::collections::HashSet;
+
+pub async fn create_conversation(
+  Json(data): Json<CreateConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ConversationResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  check_private_messages_enabled(&local_user_view)?;
+  let my_person_id = local_user_view.person.id;
+
+  // A conversation with a single other person is a normal private message
+  let others = data
+    .participant_ids
+    .into_iter()
+    .filter(|id| *id != my_person_id)
+    .collect::<HashSet<_>>();
+  if others.len() < 2 || others.len() >= MAX_CONVERSATION_PARTICIPANTS {
+    return Err(LemmyErrorType::InvalidConversationParticipants.into());
+  }
+  for person_id in &others {
+    check_can_add_participant(*person_id, &local_user_view, &context).await?;
+  }
+  let content = process_message_content(&data.content, &context).await?;
+
+  let conversation = Conversation::create(
+    &mut context.pool(),
+    &ConversationInsertForm::new(my_person_id),
+  )
+  .await?;
+  let participant_ids = [vec![my_person_id], others.into_iter().collect()].concat();
+  ConversationParticipant::add(&mut context.pool(), conversation.id, &participant_ids).await?;
+
+  let form = ConversationMessageInsertForm::new(conversation.id, my_person_id, content);
+  let message = ConversationMessage::create(&mut context.pool(), &form).await?;
+  ConversationParticipant::mark_as_read(&mut context.pool(), conversation.id, my_person_id).await?;
+
+  let message_view = ConversationMessageView::read(&mut context.pool(), message.id).await?;
+  ActivityChannel::submit_activity(
+    SendActivityData::CreateConversationMessage(message_view),
+    &context,
+  )?;
+
+  Ok(Json(
+    conversation_response(conversation.id, &local_user_view, &context).await?,
+  ))
+}
@@ -0,0 +1,47 @@
+use crate::conversation::process_message_content;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_local_user_banned_or_deleted, check_private_messages_enabled},
+};
+use lemmy_db_schema::source::conversation::{
+  ConversationMessage,
+  ConversationMessageInsertForm,
+  ConversationParticipant,
+};
+use lemmy_db_views_conversation::{
+  ConversationMessageView,
+  api::{ConversationMessageResponse, CreateConversationMessage},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn create_conversation_message(
+  Json(data): Json<CreateConversationMessage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ConversationMessageResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  check_private_messages_enabled(&local_user_view)?;
+  let my_person_id = local_user_view.person.id;
+  ConversationParticipant::read(&mut context.pool(), data.conversation_id, my_person_id).await?;
+  let content = process_message_content(&data.content, &context).await?;
+
+  let form = ConversationMessageInsertForm::new(data.conversation_id, my_person_id, content);
+  let message = ConversationMessage::create(&mut context.pool(), &form).await?;
+  ConversationParticipant::mark_as_read(&mut context.pool(), data.conversation_id, my_person_id)
+    .await?;
+
+  let view = ConversationMessageView::read(&mut context.pool(), message.id).await?;
+  ActivityChannel::submit_activity(
+    SendActivityData::CreateConversationMessage(view.clone()),
+    &context,
+  )?;
+
+  Ok(Json(ConversationMessageResponse {
+    conversation_message_view: view,
+  }))
+}
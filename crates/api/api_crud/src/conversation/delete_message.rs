@@ -0,0 +1,49 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_local_user_banned_or_deleted,
+};
+use lemmy_db_schema::source::conversation::{
+  ConversationMessage,
+  ConversationMessageUpdateForm,
+  ConversationParticipant,
+};
+use lemmy_db_views_conversation::{
+  ConversationMessageView,
+  api::{ConversationMessageResponse, DeleteConversationMessage},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn delete_conversation_message(
+  Json(data): Json<DeleteConversationMessage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ConversationMessageResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  let my_person_id = local_user_view.person.id;
+  let orig = ConversationMessage::read(&mut context.pool(), data.conversation_message_id).await?;
+  if orig.creator_id != my_person_id {
+    return Err(LemmyErrorType::EditConversationMessageNotAllowed.into());
+  }
+  ConversationParticipant::read(&mut context.pool(), orig.conversation_id, my_person_id).await?;
+
+  let form = ConversationMessageUpdateForm {
+    deleted: Some(data.deleted),
+    ..Default::default()
+  };
+  let message = ConversationMessage::update(&mut context.pool(), orig.id, &form).await?;
+
+  let view = ConversationMessageView::read(&mut context.pool(), orig.id).await?;
+  ActivityChannel::submit_activity(
+    SendActivityData::DeleteConversationMessage(local_user_view.person, message, data.deleted),
+    &context,
+  )?;
+
+  Ok(Json(ConversationMessageResponse {
+    conversation_message_view: view,
+  }))
+}
@@ -0,0 +1,25 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_views_conversation::{
+  ConversationView,
+  api::ListConversations,
+  impls::ConversationQuery,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::pagination::PagedResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_conversations(
+  Query(data): Query<ListConversations>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<ConversationView>>> {
+  let conversations = ConversationQuery {
+    page_cursor: data.page_cursor,
+    limit: data.limit,
+  }
+  .list(&mut context.pool(), local_user_view.person.id)
+  .await?;
+  Ok(Json(conversations))
+}
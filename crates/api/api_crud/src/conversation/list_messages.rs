@@ -0,0 +1,34 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::conversation::ConversationParticipant;
+use lemmy_db_views_conversation::{
+  ConversationMessageView,
+  api::ListConversationMessages,
+  impls::ConversationMessageQuery,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::pagination::PagedResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_conversation_messages(
+  Query(data): Query<ListConversationMessages>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<ConversationMessageView>>> {
+  ConversationParticipant::read(
+    &mut context.pool(),
+    data.conversation_id,
+    local_user_view.person.id,
+  )
+  .await?;
+
+  let messages = ConversationMessageQuery {
+    conversation_id: data.conversation_id,
+    page_cursor: data.page_cursor,
+    limit: data.limit,
+  }
+  .list(&mut context.pool())
+  .await?;
+  Ok(Json(messages))
+}
@@ -0,0 +1,22 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::conversation::ConversationParticipant;
+use lemmy_db_views_conversation::api::MarkConversationAsRead;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn mark_conversation_as_read(
+  Json(data): Json<MarkConversationAsRead>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  ConversationParticipant::mark_as_read(
+    &mut context.pool(),
+    data.conversation_id,
+    local_user_view.person.id,
+  )
+  .await?;
+  Ok(Json(SuccessResponse::default()))
+}
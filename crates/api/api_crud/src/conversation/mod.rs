@@ -0,0 +1,73 @@
+use activitypub_federation::config::Data;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_private_messages_enabled, get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::{
+  newtypes::ConversationId,
+  source::{conversation::Conversation, person::PersonActions},
+  traits::Blockable,
+};
+use lemmy_db_schema_file::PersonId;
+use lemmy_db_views_conversation::{ConversationView, api::ConversationResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::SiteView;
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_body_field};
+
+pub mod create;
+pub mod create_message;
+pub mod delete_message;
+pub mod list;
+pub mod list_messages;
+pub mod mark_as_read;
+pub mod participant;
+pub mod read;
+pub mod update_message;
+
+/// Upper limit for the number of people in a conversation, to keep federation fan-out small.
+const MAX_CONVERSATION_PARTICIPANTS: usize = 50;
+
+/// Same checks as for private messages: the person must not block the sender, and local users
+/// must have private messages enabled.
+async fn check_can_add_participant(
+  person_id: PersonId,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  PersonActions::read_block(&mut context.pool(), person_id, local_user_view.person.id).await?;
+  if let Ok(local_user) = LocalUserView::read_person(&mut context.pool(), person_id).await {
+    check_private_messages_enabled(&local_user)?;
+  }
+  Ok(())
+}
+
+async fn process_message_content(
+  content: &str,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<String> {
+  let slur_regex = slur_regex(context).await?;
+  let url_blocklist = get_url_blocklist(context).await?;
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  let content =
+    process_markdown(content, &slur_regex, &url_blocklist, &local_site, context).await?;
+  is_valid_body_field(&content, false)?;
+  Ok(content)
+}
+
+async fn conversation_response(
+  conversation_id: ConversationId,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<ConversationResponse> {
+  let conversation_view = ConversationView::read(
+    &mut context.pool(),
+    conversation_id,
+    local_user_view.person.id,
+  )
+  .await?;
+  let participants = Conversation::list_participants(&mut context.pool(), conversation_id).await?;
+  Ok(ConversationResponse {
+    conversation_view,
+    participants,
+  })
+}
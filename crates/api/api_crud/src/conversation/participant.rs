@@ -0,0 +1,99 @@
+use crate::conversation::{
+  MAX_CONVERSATION_PARTICIPANTS,
+  check_can_add_participant,
+  conversation_response,
+};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_local_user_banned_or_deleted,
+};
+use lemmy_db_schema::source::{
+  conversation::{Conversation, ConversationParticipant},
+  person::Person,
+};
+use lemmy_db_views_conversation::api::{
+  AddConversationParticipant,
+  ConversationResponse,
+  RemoveConversationParticipant,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn add_conversation_participant(
+  Json(data): Json<AddConversationParticipant>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ConversationResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  // Other instances only accept membership changes by the creator, whose instance hosts the
+  // conversation
+  let conversation = Conversation::read(&mut context.pool(), data.conversation_id).await?;
+  if conversation.creator_id != local_user_view.person.id {
+    return Err(LemmyErrorType::OnlyConversationCreatorCanAddParticipants.into());
+  }
+  ConversationParticipant::read(
+    &mut context.pool(),
+    conversation.id,
+    conversation.creator_id,
+  )
+  .await?;
+  let participants =
+    Conversation::list_participants(&mut context.pool(), data.conversation_id).await?;
+  if participants.len() >= MAX_CONVERSATION_PARTICIPANTS {
+    return Err(LemmyErrorType::InvalidConversationParticipants.into());
+  }
+  check_can_add_participant(data.person_id, &local_user_view, &context).await?;
+
+  ConversationParticipant::add(&mut context.pool(), data.conversation_id, &[data.person_id])
+    .await?;
+
+  let participant = Person::read(&mut context.pool(), data.person_id).await?;
+  ActivityChannel::submit_activity(
+    SendActivityData::ConversationParticipant {
+      conversation,
+      actor: local_user_view.person.clone(),
+      participant,
+      added: true,
+    },
+    &context,
+  )?;
+
+  Ok(Json(
+    conversation_response(data.conversation_id, &local_user_view, &context).await?,
+  ))
+}
+
+pub async fn remove_conversation_participant(
+  Json(data): Json<RemoveConversationParticipant>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let my_person_id = local_user_view.person.id;
+  ConversationParticipant::read(&mut context.pool(), data.conversation_id, my_person_id).await?;
+
+  // Everyone can leave, but only the creator can remove others
+  let conversation = Conversation::read(&mut context.pool(), data.conversation_id).await?;
+  if data.person_id != my_person_id && conversation.creator_id != my_person_id {
+    return Err(LemmyErrorType::OnlyConversationCreatorCanRemoveParticipants.into());
+  }
+
+  // Federate before removing, so that the removed person is also informed
+  let participant = Person::read(&mut context.pool(), data.person_id).await?;
+  ActivityChannel::submit_activity(
+    SendActivityData::ConversationParticipant {
+      conversation,
+      actor: local_user_view.person,
+      participant,
+      added: false,
+    },
+    &context,
+  )?;
+  ConversationParticipant::remove(&mut context.pool(), data.conversation_id, data.person_id)
+    .await?;
+  Ok(Json(SuccessResponse::default()))
+}
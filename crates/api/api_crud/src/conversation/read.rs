@@ -0,0 +1,17 @@
+use crate::conversation::conversation_response;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_views_conversation::api::{ConversationResponse, GetConversation};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn get_conversation(
+  Query(data): Query<GetConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ConversationResponse>> {
+  Ok(Json(
+    conversation_response(data.conversation_id, &local_user_view, &context).await?,
+  ))
+}
@@ -0,0 +1,55 @@
+use crate::conversation::process_message_content;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_local_user_banned_or_deleted, check_private_messages_enabled},
+};
+use lemmy_db_schema::source::conversation::{
+  ConversationMessage,
+  ConversationMessageUpdateForm,
+  ConversationParticipant,
+};
+use lemmy_db_views_conversation::{
+  ConversationMessageView,
+  api::{ConversationMessageResponse, EditConversationMessage},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn edit_conversation_message(
+  Json(data): Json<EditConversationMessage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ConversationMessageResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  check_private_messages_enabled(&local_user_view)?;
+  let my_person_id = local_user_view.person.id;
+  let orig = ConversationMessage::read(&mut context.pool(), data.conversation_message_id).await?;
+  if orig.creator_id != my_person_id {
+    return Err(LemmyErrorType::EditConversationMessageNotAllowed.into());
+  }
+  // People who left the conversation can't change what the others see anymore
+  ConversationParticipant::read(&mut context.pool(), orig.conversation_id, my_person_id).await?;
+  let content = process_message_content(&data.content, &context).await?;
+
+  let form = ConversationMessageUpdateForm {
+    content: Some(content),
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  ConversationMessage::update(&mut context.pool(), orig.id, &form).await?;
+
+  let view = ConversationMessageView::read(&mut context.pool(), orig.id).await?;
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateConversationMessage(view.clone()),
+    &context,
+  )?;
+
+  Ok(Json(ConversationMessageResponse {
+    conversation_message_view: view,
+  }))
+}
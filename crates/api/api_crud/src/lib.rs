@@ -1,17 +1,23 @@
+use chrono::{DateTime, TimeZone, Utc};
 use lemmy_api_utils::context::LemmyContext;
 use lemmy_db_schema::source::community::{Community, CommunityActions};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
 
+pub mod automod;
 pub mod comment;
 pub mod community;
+pub mod conversation;
 pub mod custom_emoji;
 pub mod invite;
 pub mod multi_community;
 pub mod oauth_provider;
 pub mod post;
 pub mod private_message;
+pub mod rule;
 pub mod site;
 pub mod tagline;
 pub mod user;
+pub mod webhook;
 
 /// Only mark new posts/comments to remote community as pending if it has any local followers.
 /// Otherwise it could never get updated to be marked as published.
@@ -23,3 +29,18 @@ async fn community_use_pending(community: &Community, context: &LemmyContext) ->
     .await
     .is_ok()
 }
+
+/// How many scheduled posts, comments or private messages a user who isn't admin may have.
+const MAX_SCHEDULED_ITEMS: i64 = 10;
+
+/// Converts the unix timestamp of scheduled content, which needs to be in the future.
+fn convert_scheduled_time(scheduled_publish_time: i64) -> LemmyResult<DateTime<Utc>> {
+  let converted = Utc
+    .timestamp_opt(scheduled_publish_time, 0)
+    .single()
+    .ok_or(LemmyErrorType::InvalidUnixTime)?;
+  if converted < Utc::now() {
+    return Err(LemmyErrorType::PostScheduleTimeMustBeInFuture.into());
+  }
+  Ok(converted)
+}
@@ -1,8 +1,9 @@
-use super::convert_published_time;
+use super::{convert_published_time, upsert_poll};
 use crate::community_use_pending;
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_utils::{
+  automod::{AutomodEvent, automod_post},
   build_response::build_post_response,
   context::LemmyContext,
   notify::NotifyData,
@@ -15,16 +16,19 @@ use lemmy_api_utils::{
     get_url_blocklist,
     honeypot_check,
     process_markdown_opt,
+    requires_approval,
     send_webmention,
     slur_regex,
     update_post_tags,
   },
+  webhooks::send_webhook,
 };
 use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
   source::post::{Post, PostActions, PostInsertForm, PostLikeForm},
   traits::Likeable,
 };
+use lemmy_db_schema_file::enums::WebhookEventType;
 use lemmy_db_views_community::CommunityView;
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use lemmy_db_views_local_user::LocalUserView;
@@ -117,6 +121,13 @@ pub async fn create_post(
 
   let scheduled_publish_time_at =
     convert_published_time(data.scheduled_publish_time_at, &local_user_view, &context).await?;
+  let approval_pending = requires_approval(
+    &local_user_view.person,
+    community,
+    true,
+    &mut context.pool(),
+  )
+  .await?;
   let mut post_form = PostInsertForm {
     url,
     body,
@@ -125,6 +136,8 @@ pub async fn create_post(
     language_id: data.language_id,
     federation_pending: Some(community_use_pending(community, &context).await),
     scheduled_publish_time_at,
+    scheduled_recurrence: scheduled_publish_time_at.and(data.scheduled_recurrence),
+    approval_pending: Some(approval_pending),
     ..PostInsertForm::new(
       data.name.trim().to_string(),
       local_user_view.person.id,
@@ -148,9 +161,27 @@ pub async fn create_post(
     update_post_tags(&inserted_post, tags, &context).await?;
   }
 
+  if let Some(poll) = &data.poll {
+    upsert_poll(poll, inserted_post.id, &slur_regex, &context).await?;
+  }
+
+  // Automod can hold the post for approval, which needs to happen before it is federated
+  let approval_pending = if scheduled_publish_time_at.is_none() && !approval_pending {
+    automod_post(inserted_post.id, AutomodEvent::BeforeCreate, &context).await
+  } else {
+    approval_pending
+  };
+
   let community_id = community.id;
-  let federate_post = if scheduled_publish_time_at.is_none() {
+  // Posts in the mod queue are only federated and announced once approved
+  let federate_post = if scheduled_publish_time_at.is_none() && !approval_pending {
     send_webmention(inserted_post.clone(), community, context.clone());
+    send_webhook(
+      WebhookEventType::NewPost,
+      Some(community.id),
+      &inserted_post,
+      &context,
+    );
     |post| Some(SendActivityData::CreatePost(post))
   } else {
     |_| None
@@ -170,17 +201,23 @@ pub async fn create_post(
 
   PostActions::like(&mut context.pool(), &like_form).await?;
 
-  NotifyData {
-    do_send_email: !local_site.email_notifications_disabled,
-    ..NotifyData::new(
-      inserted_post.clone(),
-      local_user_view.person.clone(),
-      community.clone(),
-    )
+  if !approval_pending {
+    NotifyData {
+      do_send_email: !local_site.email_notifications_disabled,
+      ..NotifyData::new(
+        inserted_post.clone(),
+        local_user_view.person.clone(),
+        community.clone(),
+      )
+    }
+    .send(&context);
   }
-  .send(&context);
 
   PostActions::mark_as_read(&mut context.pool(), person_id, &[post_id]).await?;
 
+  if scheduled_publish_time_at.is_none() && !approval_pending {
+    automod_post(post_id, AutomodEvent::Create, &context).await;
+  }
+
   build_post_response(&context, community_id, local_user_view, post_id).await
 }
@@ -1,8 +1,21 @@
+use crate::{MAX_SCHEDULED_ITEMS, convert_scheduled_time};
 use chrono::{DateTime, TimeZone, Utc};
 use lemmy_api_utils::context::LemmyContext;
-use lemmy_db_schema::source::post::Post;
+use lemmy_db_schema::{
+  newtypes::PostId,
+  source::{
+    poll::{Poll, PollInsertForm, PollOptionInsertForm},
+    post::Post,
+  },
+};
 use lemmy_db_views_local_user::LocalUserView;
-use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use lemmy_db_views_post::api::CreatePoll;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::slurs::check_slurs,
+};
+use regex::Regex;
+use std::collections::HashSet;
 
 pub mod create;
 pub mod delete;
@@ -15,19 +28,12 @@ async fn convert_published_time(
   local_user_view: &LocalUserView,
   context: &LemmyContext,
 ) -> LemmyResult<Option<DateTime<Utc>>> {
-  const MAX_SCHEDULED_POSTS: i64 = 10;
   if let Some(scheduled_publish_time) = scheduled_publish_time {
-    let converted = Utc
-      .timestamp_opt(scheduled_publish_time, 0)
-      .single()
-      .ok_or(LemmyErrorType::InvalidUnixTime)?;
-    if converted < Utc::now() {
-      return Err(LemmyErrorType::PostScheduleTimeMustBeInFuture.into());
-    }
+    let converted = convert_scheduled_time(scheduled_publish_time)?;
     if !local_user_view.local_user.admin {
       let count =
         Post::user_scheduled_post_count(local_user_view.person.id, &mut context.pool()).await?;
-      if count >= MAX_SCHEDULED_POSTS {
+      if count >= MAX_SCHEDULED_ITEMS {
         return Err(LemmyErrorType::TooManyScheduledPosts.into());
       }
     }
@@ -36,3 +42,66 @@ async fn convert_published_time(
     Ok(None)
   }
 }
+
+/// Validates the poll options and end time, then creates or updates the poll of the post.
+async fn upsert_poll(
+  poll: &CreatePoll,
+  post_id: PostId,
+  slur_regex: &Regex,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  const MIN_POLL_OPTIONS: usize = 2;
+  const MAX_POLL_OPTIONS: usize = 20;
+  const MAX_POLL_OPTION_LENGTH: usize = 200;
+
+  let options = poll
+    .options
+    .iter()
+    .map(|o| o.trim().to_string())
+    .collect::<Vec<_>>();
+  let unique = options.iter().collect::<HashSet<_>>();
+  if options.len() < MIN_POLL_OPTIONS
+    || options.len() > MAX_POLL_OPTIONS
+    || unique.len() != options.len()
+    || options
+      .iter()
+      .any(|o| o.is_empty() || o.chars().count() > MAX_POLL_OPTION_LENGTH)
+  {
+    return Err(LemmyErrorType::InvalidPoll.into());
+  }
+  for option in &options {
+    check_slurs(option, slur_regex)?;
+  }
+
+  let end_time_at = poll
+    .end_time_at
+    .map(|t| {
+      Utc
+        .timestamp_opt(t, 0)
+        .single()
+        .ok_or(LemmyErrorType::InvalidUnixTime)
+    })
+    .transpose()?;
+  if end_time_at.is_some_and(|e| e < Utc::now()) {
+    return Err(LemmyErrorType::PollClosed.into());
+  }
+
+  let form = PollInsertForm {
+    post_id,
+    multiple_choice: poll.multiple_choice.unwrap_or_default(),
+    end_time_at,
+    updated_at: Some(Utc::now()),
+  };
+  let options = options
+    .into_iter()
+    .zip(0..)
+    .map(|(name, display_order)| PollOptionInsertForm {
+      post_id,
+      name,
+      display_order,
+      vote_count: None,
+    })
+    .collect::<Vec<_>>();
+  Poll::upsert(&mut context.pool(), &form, &options).await?;
+  Ok(())
+}
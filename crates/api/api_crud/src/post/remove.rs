@@ -5,7 +5,7 @@ use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{check_community_mod_action, read_cited_rule},
 };
 use lemmy_db_schema::{
   source::{
@@ -39,6 +39,8 @@ pub async fn remove_post(
   let community = Community::read(&mut context.pool(), orig_post.community_id).await?;
 
   check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  let rule = read_cited_rule(data.rule_id, Some(community.id), &mut context.pool()).await?;
+  let rule_id = rule.map(|r| r.id);
 
   LocalUser::is_higher_mod_or_admin_check(
     &mut context.pool(),
@@ -69,7 +71,8 @@ pub async fn remove_post(
     remove_post,
     &data.reason,
     None,
-  );
+  )
+  .with_rule(rule_id);
   let action = Modlog::create(&mut context.pool(), &[form]).await?;
   notify_mod_action(action, context.app_data());
 
@@ -90,6 +93,7 @@ pub async fn remove_post(
           &data.reason,
           None,
         )
+        .with_rule(rule_id)
       })
       .collect();
 
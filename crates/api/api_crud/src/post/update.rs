@@ -1,4 +1,4 @@
-use super::convert_published_time;
+use super::{convert_published_time, upsert_poll};
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use chrono::Utc;
@@ -23,7 +23,9 @@ use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
   source::{
     community::Community,
+    poll::Poll,
     post::{Post, PostUpdateForm},
+    post_revision::PostRevision,
   },
 };
 use lemmy_db_views_local_user::LocalUserView;
@@ -141,6 +143,12 @@ pub async fn edit_post(
     // unchanged
     (_, _) => None,
   };
+  // the recurrence can only be changed while the post is scheduled
+  let scheduled_recurrence = match scheduled_publish_time_at {
+    Some(Some(_)) => Some(data.scheduled_recurrence),
+    Some(None) => Some(None),
+    None => None,
+  };
 
   let mut post_form = PostUpdateForm {
     name: data.name.clone(),
@@ -151,6 +159,7 @@ pub async fn edit_post(
     language_id: data.language_id,
     updated_at: Some(Some(Utc::now())),
     scheduled_publish_time_at,
+    scheduled_recurrence,
     ..Default::default()
   };
   post_form = plugin_hook_before("local_post_before_update", post_form).await?;
@@ -164,17 +173,28 @@ pub async fn edit_post(
   let post_id = data.post_id;
   let updated_post = Post::update(&mut context.pool(), post_id, &post_form).await?;
   plugin_hook_after("local_post_after_update", &post_form);
+  PostRevision::create_if_changed(&mut context.pool(), &orig_post.post, &updated_post).await?;
 
   if let Some(tags) = &data.tags {
     update_post_tags(&orig_post.post, tags, &context).await?;
   }
 
-  NotifyData::new(
-    updated_post.clone(),
-    local_user_view.person.clone(),
-    orig_post.community.clone(),
-  )
-  .send(&context);
+  if data.remove_poll.unwrap_or_default() {
+    Poll::delete(&mut context.pool(), post_id).await?;
+  } else if let Some(poll) = &data.poll {
+    upsert_poll(poll, post_id, &slur_regex, &context).await?;
+  }
+
+  // Posts in the mod queue are only federated once approved
+  let approval_pending = updated_post.approval_pending;
+  if !approval_pending {
+    NotifyData::new(
+      updated_post.clone(),
+      local_user_view.person.clone(),
+      orig_post.community.clone(),
+    )
+    .send(&context);
+  }
 
   // send out federation/webmention if necessary
   match (
@@ -182,7 +202,7 @@ pub async fn edit_post(
     data.scheduled_publish_time_at,
   ) {
     // schedule was removed, send create activity and webmention
-    (Some(_), None) => {
+    (Some(_), None) if !approval_pending => {
       let community = Community::read(&mut context.pool(), orig_post.community.id).await?;
       send_webmention(updated_post.clone(), &community, context.clone());
       generate_post_link_metadata(
@@ -194,7 +214,7 @@ pub async fn edit_post(
       .await?;
     }
     // post was already public, send update
-    (None, _) => {
+    (None, _) if !approval_pending => {
       generate_post_link_metadata(
         updated_post.clone(),
         custom_thumbnail.flatten().map(Into::into),
@@ -203,8 +223,18 @@ pub async fn edit_post(
       )
       .await?
     }
+    // still in the mod queue, only refresh the metadata
+    (_, None) => {
+      generate_post_link_metadata(
+        updated_post.clone(),
+        custom_thumbnail.flatten().map(Into::into),
+        |_| None,
+        context.clone(),
+      )
+      .await?
+    }
     // schedule was changed, do nothing
-    (Some(_), Some(_)) => {}
+    (_, Some(_)) => {}
   };
 
   build_post_response(
@@ -0,0 +1,162 @@
+use crate::{
+  MAX_SCHEDULED_ITEMS,
+  convert_scheduled_time,
+  private_message::create::create_private_message,
+};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_local_user_banned_or_deleted, check_private_messages_enabled, slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    person::{Person, PersonActions},
+    scheduled::{
+      ScheduledPrivateMessage,
+      ScheduledPrivateMessageInsertForm,
+      ScheduledPrivateMessageUpdateForm,
+    },
+  },
+  traits::Blockable,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_private_message::api::{
+  CreatePrivateMessage,
+  CreateScheduledPrivateMessage,
+  DeleteScheduledPrivateMessage,
+  EditScheduledPrivateMessage,
+  ScheduledPrivateMessageResponse,
+};
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+use tracing::warn;
+
+pub async fn create_scheduled_private_message(
+  Json(data): Json<CreateScheduledPrivateMessage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ScheduledPrivateMessageResponse>> {
+  check_local_user_banned_or_deleted(&local_user_view)?;
+  check_private_messages_enabled(&local_user_view)?;
+  let scheduled_publish_time_at = convert_scheduled_time(data.scheduled_publish_time_at)?;
+  let my_person_id = local_user_view.person.id;
+  if !local_user_view.local_user.admin {
+    let count =
+      ScheduledPrivateMessage::count_for_creator(&mut context.pool(), my_person_id).await?;
+    if count >= MAX_SCHEDULED_ITEMS {
+      return Err(LemmyErrorType::TooManyScheduledPrivateMessages.into());
+    }
+  }
+
+  // The content is processed when the message gets sent, here it is only validated.
+  let slur_regex = slur_regex(&context).await?;
+  check_slurs(&data.content, &slur_regex)?;
+  is_valid_body_field(&data.content, false)?;
+
+  Person::read(&mut context.pool(), data.recipient_id).await?;
+  PersonActions::read_block(&mut context.pool(), data.recipient_id, my_person_id).await?;
+
+  let form = ScheduledPrivateMessageInsertForm::new(
+    my_person_id,
+    data.recipient_id,
+    data.content,
+    scheduled_publish_time_at,
+  );
+  let scheduled_private_message =
+    ScheduledPrivateMessage::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(ScheduledPrivateMessageResponse {
+    scheduled_private_message,
+  }))
+}
+
+pub async fn edit_scheduled_private_message(
+  Json(data): Json<EditScheduledPrivateMessage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ScheduledPrivateMessageResponse>> {
+  let orig =
+    ScheduledPrivateMessage::read(&mut context.pool(), data.scheduled_private_message_id).await?;
+  if orig.creator_id != local_user_view.person.id {
+    return Err(LemmyErrorType::EditPrivateMessageNotAllowed.into());
+  }
+
+  if let Some(content) = &data.content {
+    let slur_regex = slur_regex(&context).await?;
+    check_slurs(content, &slur_regex)?;
+    is_valid_body_field(content, false)?;
+  }
+  let scheduled_publish_time_at = data
+    .scheduled_publish_time_at
+    .map(convert_scheduled_time)
+    .transpose()?;
+
+  let form = ScheduledPrivateMessageUpdateForm {
+    content: data.content,
+    scheduled_publish_time_at,
+    updated_at: Some(Some(Utc::now())),
+  };
+  let scheduled_private_message = ScheduledPrivateMessage::update(
+    &mut context.pool(),
+    data.scheduled_private_message_id,
+    &form,
+  )
+  .await?;
+
+  Ok(Json(ScheduledPrivateMessageResponse {
+    scheduled_private_message,
+  }))
+}
+
+pub async fn delete_scheduled_private_message(
+  Json(data): Json<DeleteScheduledPrivateMessage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let orig =
+    ScheduledPrivateMessage::read(&mut context.pool(), data.scheduled_private_message_id).await?;
+  if orig.creator_id != local_user_view.person.id {
+    return Err(LemmyErrorType::EditPrivateMessageNotAllowed.into());
+  }
+  ScheduledPrivateMessage::delete(&mut context.pool(), data.scheduled_private_message_id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+/// Sends the scheduled private messages whose time has come, through the same path as normal
+/// private messages.
+pub async fn publish_scheduled_private_messages(context: &Data<LemmyContext>) -> LemmyResult<()> {
+  for scheduled in ScheduledPrivateMessage::list_due(&mut context.pool()).await? {
+    // Delete it first, so that a failing message isn't retried forever
+    ScheduledPrivateMessage::delete(&mut context.pool(), scheduled.id).await?;
+    let Ok(local_user_view) =
+      LocalUserView::read_person(&mut context.pool(), scheduled.creator_id).await
+    else {
+      continue;
+    };
+
+    let form = CreatePrivateMessage {
+      content: scheduled.content,
+      recipient_id: scheduled.recipient_id,
+    };
+    if let Err(e) = Box::pin(create_private_message(
+      Json(form),
+      context.reset_request_count(),
+      local_user_view,
+    ))
+    .await
+    {
+      warn!(
+        "Failed to send scheduled private message {}: {e}",
+        scheduled.id.0
+      );
+    }
+  }
+  Ok(())
+}
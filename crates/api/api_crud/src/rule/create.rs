@@ -0,0 +1,39 @@
+use crate::rule::{check_manage_rules, federate_rules, validate_rule};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::rule::{Rule, RuleInsertForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{CreateRule, RuleResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{error::LemmyResult, utils::validation::check_api_elements_count};
+
+pub async fn create_rule(
+  Json(data): Json<CreateRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RuleResponse>> {
+  let community = check_manage_rules(data.community_id, &local_user_view, &context).await?;
+
+  let title = data.title.trim().to_string();
+  validate_rule(&title, &data.description, &context).await?;
+  check_api_elements_count(
+    Rule::list(&mut context.pool(), data.community_id)
+      .await?
+      .len(),
+  )?;
+
+  let position = match data.position {
+    Some(position) => position,
+    None => Rule::next_position(&mut context.pool(), data.community_id).await?,
+  };
+  let form = RuleInsertForm {
+    description: data.description,
+    ..RuleInsertForm::new(data.community_id, position, title)
+  };
+  let rule = Rule::create(&mut context.pool(), &form).await?;
+
+  federate_rules(community, &local_user_view, &context)?;
+
+  Ok(Json(RuleResponse { rule }))
+}
@@ -0,0 +1,31 @@
+use crate::rule::{check_manage_rules, federate_rules};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::rule::{Rule, RuleUpdateForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{DeleteRule, SuccessResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_rule(
+  Json(data): Json<DeleteRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let rule = Rule::read(&mut context.pool(), data.rule_id).await?;
+  let community = check_manage_rules(rule.community_id, &local_user_view, &context).await?;
+
+  // Only mark as deleted, so that reports and modlog entries can still show the rule
+  let form = RuleUpdateForm {
+    deleted: Some(true),
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  Rule::update(&mut context.pool(), rule.id, &form).await?;
+
+  federate_rules(community, &local_user_view, &context)?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,29 @@
+use crate::rule::check_manage_rules;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::rule::Rule;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{GetRuleStats, GetRuleStatsResponse, ListRules, ListRulesResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_rules(
+  Query(data): Query<ListRules>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<ListRulesResponse>> {
+  let rules = Rule::list(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListRulesResponse { rules }))
+}
+
+pub async fn get_rule_stats(
+  Query(data): Query<GetRuleStats>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetRuleStatsResponse>> {
+  check_manage_rules(data.community_id, &local_user_view, &context).await?;
+
+  let stats = Rule::stats(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(GetRuleStatsResponse { stats }))
+}
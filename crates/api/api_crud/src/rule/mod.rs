@@ -0,0 +1,75 @@
+use activitypub_federation::config::Data;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, is_admin, slur_regex},
+};
+use lemmy_db_schema::{newtypes::CommunityId, source::community::Community};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{
+    slurs::{check_slurs, check_slurs_opt},
+    validation::{is_valid_body_field, is_valid_post_title},
+  },
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// Site rules can only be managed by admins, community rules also by the community mods. Rules of
+/// remote communities are overwritten by the next update from their instance, so they can't be
+/// managed here. Returns the community for community rules.
+async fn check_manage_rules(
+  community_id: Option<CommunityId>,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<Option<Community>> {
+  match community_id {
+    Some(community_id) => {
+      let community = Community::read(&mut context.pool(), community_id).await?;
+      if !community.local {
+        return Err(LemmyErrorType::CantManageRulesOfRemoteCommunity.into());
+      }
+      check_community_mod_action(local_user_view, &community, false, &mut context.pool()).await?;
+      Ok(Some(community))
+    }
+    None => {
+      is_admin(local_user_view)?;
+      Ok(None)
+    }
+  }
+}
+
+async fn validate_rule(
+  title: &str,
+  description: &Option<String>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let slur_regex = slur_regex(context).await?;
+  is_valid_post_title(title)?;
+  check_slurs(title, &slur_regex)?;
+  check_slurs_opt(description, &slur_regex)?;
+  if let Some(description) = description {
+    is_valid_body_field(description, false)?;
+  }
+  Ok(())
+}
+
+/// Community rules are part of the group, so remote instances need to be informed about changes.
+fn federate_rules(
+  community: Option<Community>,
+  local_user_view: &LocalUserView,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  if let Some(community) = community {
+    ActivityChannel::submit_activity(
+      SendActivityData::UpdateCommunity(local_user_view.person.clone(), community),
+      context,
+    )?;
+  }
+  Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::rule::{check_manage_rules, federate_rules, validate_rule};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::rule::{Rule, RuleUpdateForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{EditRule, RuleResponse};
+use lemmy_diesel_utils::{traits::Crud, utils::diesel_string_update};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn edit_rule(
+  Json(data): Json<EditRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RuleResponse>> {
+  let rule = Rule::read(&mut context.pool(), data.rule_id).await?;
+  if rule.deleted {
+    return Err(LemmyErrorType::InvalidRule.into());
+  }
+  let community = check_manage_rules(rule.community_id, &local_user_view, &context).await?;
+
+  let title = data.title.map(|t| t.trim().to_string());
+  let description = diesel_string_update(data.description.as_deref());
+  validate_rule(
+    title.as_deref().unwrap_or(&rule.title),
+    &description.clone().unwrap_or(rule.description),
+    &context,
+  )
+  .await?;
+
+  let form = RuleUpdateForm {
+    position: data.position,
+    title,
+    description,
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  let rule = Rule::update(&mut context.pool(), rule.id, &form).await?;
+
+  federate_rules(community, &local_user_view, &context)?;
+
+  Ok(Json(RuleResponse { rule }))
+}
@@ -38,6 +38,7 @@ use lemmy_utils::{
     slurs::check_slurs,
     validation::{
       build_and_check_regex,
+      check_warning_policy,
       is_valid_body_field,
       site_name_length_check,
       summary_length_check,
@@ -134,6 +135,10 @@ pub async fn create_site(
     image_allow_video_uploads: data.image_allow_video_uploads,
     image_upload_disabled: data.image_upload_disabled,
     max_invites_per_user_allowed: data.max_invites_per_user_allowed,
+    edit_history_visibility: data.edit_history_visibility,
+    warning_ban_threshold: diesel_opt_number_update(data.warning_ban_threshold),
+    warning_ban_window_days: diesel_opt_number_update(data.warning_ban_window_days),
+    warning_ban_days: diesel_opt_number_update(data.warning_ban_days),
   };
 
   LocalSite::update(&mut context.pool(), &local_site_form).await?;
@@ -199,6 +204,12 @@ fn validate_create_payload(local_site: &LocalSite, create_site: &CreateSite) ->
     is_valid_body_field(sidebar, false)?;
   }
 
+  check_warning_policy(
+    create_site.warning_ban_threshold,
+    create_site.warning_ban_window_days,
+    create_site.warning_ban_days,
+  )?;
+
   application_question_check(
     &local_site.application_question,
     &create_site.application_question,
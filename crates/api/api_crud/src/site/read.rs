@@ -1,11 +1,12 @@
 use actix_web::web::{Data, Json};
-use lemmy_api_utils::{context::LemmyContext, plugins::LemmyPlugins};
+use lemmy_api_utils::{context::LemmyContext, plugins::LemmyPlugins, push::vapid_public_key};
 use lemmy_db_schema::source::{
   actor_language::SiteLanguage,
   language::Language,
   local_site_url_blocklist::LocalSiteUrlBlocklist,
   oauth_provider::AdminOAuthProvider,
   registration_application::RegistrationApplication,
+  rule::Rule,
   tagline::Tagline,
 };
 use lemmy_db_views_local_user::LocalUserView;
@@ -42,6 +43,7 @@ async fn read_site(context: &LemmyContext) -> LemmyResult<GetSiteResponse> {
   let discussion_languages = SiteLanguage::read_local_raw(&mut context.pool()).await?;
   let blocked_urls = LocalSiteUrlBlocklist::get_all(&mut context.pool()).await?;
   let tagline = Tagline::get_random(&mut context.pool()).await.ok();
+  let rules = Rule::list(&mut context.pool(), None).await?;
   let admin_oauth_providers = AdminOAuthProvider::get_all(&mut context.pool()).await?;
   let oauth_providers =
     AdminOAuthProvider::convert_providers_to_public(admin_oauth_providers.clone());
@@ -64,5 +66,7 @@ async fn read_site(context: &LemmyContext) -> LemmyResult<GetSiteResponse> {
     active_plugins: LemmyPlugins::metadata(),
     last_application_duration_seconds,
     captcha_enabled: LemmyPlugins::get_or_init().is_captcha_plugin_loaded(),
+    vapid_public_key: vapid_public_key(context).ok(),
+    rules,
   })
 }
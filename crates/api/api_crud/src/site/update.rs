@@ -41,6 +41,7 @@ use lemmy_utils::{
     validation::{
       build_and_check_regex,
       check_urls_are_valid,
+      check_warning_policy,
       is_valid_body_field,
       site_name_length_check,
       summary_length_check,
@@ -141,6 +142,10 @@ pub async fn edit_site(
     image_allow_video_uploads: data.image_allow_video_uploads,
     image_upload_disabled: data.image_upload_disabled,
     max_invites_per_user_allowed: data.max_invites_per_user_allowed,
+    edit_history_visibility: data.edit_history_visibility,
+    warning_ban_threshold: diesel_opt_number_update(data.warning_ban_threshold),
+    warning_ban_window_days: diesel_opt_number_update(data.warning_ban_window_days),
+    warning_ban_days: diesel_opt_number_update(data.warning_ban_days),
   };
 
   let update_local_site = LocalSite::update(&mut context.pool(), &local_site_form)
@@ -239,6 +244,12 @@ fn validate_update_payload(local_site: &LocalSite, edit_site: &EditSite) -> Lemm
     is_valid_body_field(sidebar, false)?;
   }
 
+  check_warning_policy(
+    edit_site.warning_ban_threshold,
+    edit_site.warning_ban_window_days,
+    edit_site.warning_ban_days,
+  )?;
+
   application_question_check(
     &local_site.application_question,
     &edit_site.application_question,
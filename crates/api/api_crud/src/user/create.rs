@@ -21,6 +21,7 @@ use lemmy_api_utils::{
     password_length_check,
     slur_regex,
   },
+  webhooks::send_webhook,
 };
 use lemmy_apub_objects::objects::community::ApubCommunity;
 use lemmy_db_schema::{
@@ -40,11 +41,11 @@ use lemmy_db_schema::{
   },
   traits::{ApubActor, Likeable},
 };
-use lemmy_db_schema_file::enums::RegistrationMode;
+use lemmy_db_schema_file::enums::{RegistrationMode, WebhookEventType};
 use lemmy_db_views_community::CommunityView;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_person::PersonView;
-use lemmy_db_views_registration_applications::api::Register;
+use lemmy_db_views_registration_applications::{RegistrationApplicationView, api::Register};
 use lemmy_db_views_site::{
   SiteView,
   api::{AuthenticateWithOauth, LoginResponse},
@@ -226,6 +227,9 @@ pub async fn register(
   if local_site.application_email_admins && !local_site.email_verification_required {
     send_new_applicant_email_to_admins(&data.username, pool, context.settings()).await?;
   }
+  if require_registration_application {
+    send_application_webhook(&user, &context).await;
+  }
 
   let mut login_response = LoginResponse {
     jwt: None,
@@ -485,6 +489,10 @@ pub async fn authenticate_with_oauth(
         })
         .await?;
 
+      if login_response.registration_created {
+        send_application_webhook(&user, &context).await;
+      }
+
       // Check email is verified when required
       login_response.verify_email_sent = send_verification_email_if_required(
         &local_site,
@@ -505,6 +513,19 @@ pub async fn authenticate_with_oauth(
   Ok(Json(login_response))
 }
 
+async fn send_application_webhook(user: &LocalUserView, context: &LemmyContext) {
+  if let Ok(application) =
+    RegistrationApplicationView::read_by_person(&mut context.pool(), user.person.id).await
+  {
+    send_webhook(
+      WebhookEventType::NewRegistrationApplication,
+      None,
+      &application,
+      context,
+    );
+  }
+}
+
 async fn create_person(
   username: String,
   site_view: &SiteView,
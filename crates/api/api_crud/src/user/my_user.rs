@@ -8,6 +8,7 @@ use lemmy_db_schema::{
     community::CommunityActions,
     instance::InstanceActions,
     keyword_block::LocalUserKeywordBlock,
+    notification_preference::NotificationPreference,
     person::PersonActions,
   },
   traits::Blockable,
@@ -41,6 +42,7 @@ pub async fn get_my_user(
     multi_community_follows,
     keyword_blocks,
     discussion_languages,
+    notification_preferences,
   ) = lemmy_diesel_utils::try_join_with_pool!(pool => (
     |pool| CommunityFollowerView::for_person(pool, person_id),
     |pool| CommunityActions::read_blocks_for_person(pool, person_id),
@@ -57,7 +59,8 @@ pub async fn get_my_user(
     }
     .list(pool),
     |pool| LocalUserKeywordBlock::read(pool, local_user_id),
-    |pool| LocalUserLanguage::read(pool, local_user_id)
+    |pool| LocalUserLanguage::read(pool, local_user_id),
+    |pool| NotificationPreference::read(pool, local_user_id)
   ))?;
 
   Ok(Json(MyUserInfo {
@@ -71,5 +74,6 @@ pub async fn get_my_user(
     person_blocks,
     keyword_blocks,
     discussion_languages,
+    notification_preferences,
   }))
 }
@@ -0,0 +1,37 @@
+use crate::webhook::{check_manage_webhooks, parse_webhook_url};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::webhook::{Webhook, WebhookInsertForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{CreateWebhook, WebhookResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+use rand::{RngExt, distr::Alphanumeric};
+
+pub async fn create_webhook(
+  Json(data): Json<CreateWebhook>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WebhookResponse>> {
+  check_manage_webhooks(data.community_id, &local_user_view, &context).await?;
+
+  let form = WebhookInsertForm::new(
+    local_user_view.person.id,
+    data.community_id,
+    parse_webhook_url(&data.url).await?,
+    generate_webhook_secret(),
+    data.events,
+  );
+  let webhook = Webhook::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(WebhookResponse { webhook }))
+}
+
+fn generate_webhook_secret() -> String {
+  rand::rng()
+    .sample_iter(Alphanumeric)
+    .take(32)
+    .map(char::from)
+    .collect()
+}
@@ -0,0 +1,20 @@
+use crate::webhook::check_manage_webhooks;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::webhook::Webhook;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{ListWebhooks, ListWebhooksResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_webhooks(
+  Query(data): Query<ListWebhooks>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListWebhooksResponse>> {
+  check_manage_webhooks(data.community_id, &local_user_view, &context).await?;
+
+  let webhooks = Webhook::list(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListWebhooksResponse { webhooks }))
+}
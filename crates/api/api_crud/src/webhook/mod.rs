@@ -0,0 +1,42 @@
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_action, check_url_is_public, is_admin},
+};
+use lemmy_db_schema::{newtypes::CommunityId, source::community::Community};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::{dburl::DbUrl, traits::Crud};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_url,
+};
+use url::Url;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// Site webhooks can only be managed by admins, community webhooks also by the community mods.
+async fn check_manage_webhooks(
+  community_id: Option<CommunityId>,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  match community_id {
+    Some(community_id) => {
+      let community = Community::read(&mut context.pool(), community_id).await?;
+      check_community_mod_action(local_user_view, &community, false, &mut context.pool()).await
+    }
+    None => is_admin(local_user_view),
+  }
+}
+
+async fn parse_webhook_url(url: &str) -> LemmyResult<DbUrl> {
+  let url = Url::parse(url)?;
+  is_valid_url(&url)?;
+  if !["http", "https"].contains(&url.scheme()) {
+    return Err(LemmyErrorType::InvalidUrlScheme.into());
+  }
+  check_url_is_public(&url).await?;
+  Ok(url.into())
+}
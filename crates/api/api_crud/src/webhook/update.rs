@@ -0,0 +1,33 @@
+use crate::webhook::{check_manage_webhooks, parse_webhook_url};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::webhook::{Webhook, WebhookUpdateForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{EditWebhook, WebhookResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn edit_webhook(
+  Json(data): Json<EditWebhook>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WebhookResponse>> {
+  let webhook = Webhook::read(&mut context.pool(), data.webhook_id).await?;
+  check_manage_webhooks(webhook.community_id, &local_user_view, &context).await?;
+
+  let url = match &data.url {
+    Some(url) => Some(parse_webhook_url(url).await?),
+    None => None,
+  };
+  let form = WebhookUpdateForm {
+    url,
+    events: data.events,
+    enabled: data.enabled,
+    updated_at: Some(Some(Utc::now())),
+  };
+  let webhook = Webhook::update(&mut context.pool(), webhook.id, &form).await?;
+
+  Ok(Json(WebhookResponse { webhook }))
+}
@@ -0,0 +1,602 @@
+use crate::{
+  context::LemmyContext,
+  notify::{NotifyData, notify_mod_action},
+  send_activity::{ActivityChannel, SendActivityData},
+  webhooks::send_modlog_webhooks,
+};
+use activitypub_federation::config::Data;
+use chrono::{Duration, Utc};
+use lemmy_db_schema::{
+  newtypes::{CommentId, LanguageId, PostId},
+  source::{
+    automod_rule::{AutomodRule, AutomodRuleInsertForm},
+    comment::{Comment, CommentInsertForm, CommentUpdateForm},
+    comment_report::{CommentReport, CommentReportForm},
+    community::Community,
+    modlog::{Modlog, ModlogInsertForm},
+    person::Person,
+    post::{Post, PostUpdateForm},
+    post_report::{PostReport, PostReportForm},
+  },
+  traits::Reportable,
+};
+use lemmy_db_schema_file::enums::AutomodAction;
+use lemmy_db_views_comment::CommentView;
+use lemmy_db_views_post::PostView;
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::build_and_check_regex,
+};
+use moka::future::Cache;
+use regex::Regex;
+use std::sync::LazyLock;
+use tracing::warn;
+use url::Url;
+
+/// Why the automod rules are evaluated. Rules with a report count condition are only evaluated
+/// for new reports, all other rules only for new content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutomodEvent {
+  /// New content which isn't federated yet. Only the rules which hold content for approval are
+  /// applied, so that held content is never federated.
+  BeforeCreate,
+  Create,
+  Report,
+}
+
+/// The parts of a post or comment which automod rules match on.
+struct AutomodTarget<'a> {
+  texts: Vec<&'a str>,
+  creator: &'a Person,
+  language_id: LanguageId,
+  report_count: i16,
+}
+
+static LINK_REGEX: LazyLock<Option<Regex>> =
+  LazyLock::new(|| Regex::new(r"https?://[^\s<>()\[\]]+").ok());
+
+/// Compiled content regexes of the rules, by pattern.
+static CONTENT_REGEX_CACHE: LazyLock<Cache<String, Regex>> =
+  LazyLock::new(|| Cache::builder().max_capacity(1000).build());
+
+/// Runs the automod rules of the post's community and of the site. Failures are only logged, so
+/// that they don't affect the creation of the post or report. Returns whether the post was put
+/// into the mod queue.
+pub async fn automod_post(
+  post_id: PostId,
+  event: AutomodEvent,
+  context: &Data<LemmyContext>,
+) -> bool {
+  automod_post_internal(post_id, event, context)
+    .await
+    .unwrap_or_else(|e| {
+      warn!("Failed to run automod for post {}: {e}", post_id.0);
+      false
+    })
+}
+
+/// Runs the automod rules of the comment's community and of the site. Failures are only logged,
+/// so that they don't affect the creation of the comment or report. Returns whether the comment
+/// was put into the mod queue.
+pub async fn automod_comment(
+  comment_id: CommentId,
+  event: AutomodEvent,
+  context: &Data<LemmyContext>,
+) -> bool {
+  automod_comment_internal(comment_id, event, context)
+    .await
+    .unwrap_or_else(|e| {
+      warn!("Failed to run automod for comment {}: {e}", comment_id.0);
+      false
+    })
+}
+
+/// Checks that a rule has at least one condition, a valid regex and a reply text if needed.
+/// Content can only be held for approval before it is federated, so not by report count.
+pub fn validate_automod_rule(rule: &AutomodRuleInsertForm) -> LemmyResult<()> {
+  let has_condition = rule.content_regex.is_some()
+    || rule.link_domain.is_some()
+    || rule.max_account_age_days.is_some()
+    || rule.max_creator_score.is_some()
+    || rule.report_count.is_some()
+    || rule.language_id.is_some();
+  let has_reply_text = rule
+    .reply_text
+    .as_ref()
+    .is_some_and(|t| !t.trim().is_empty());
+  let holds_reported = rule.action == AutomodAction::HoldForApproval && rule.report_count.is_some();
+  if !has_condition || (rule.action == AutomodAction::Reply) != has_reply_text || holds_reported {
+    return Err(LemmyErrorType::InvalidAutomodRule.into());
+  }
+  if let Some(regex) = &rule.content_regex {
+    build_and_check_regex(Some(regex))?;
+  }
+  Ok(())
+}
+
+async fn automod_post_internal(
+  post_id: PostId,
+  event: AutomodEvent,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<bool> {
+  let system_account = SiteView::read_system_account(&mut context.pool()).await?;
+  let view = PostView::read(
+    &mut context.pool(),
+    post_id,
+    None,
+    system_account.instance_id,
+    true,
+  )
+  .await?;
+  if view.creator_is_admin || view.creator_is_moderator || view.creator.id == system_account.id {
+    return Ok(false);
+  }
+  let rules = AutomodRule::list_enabled(&mut context.pool(), view.community.id).await?;
+  let mut post = view.post.clone();
+  let mut held = false;
+  let target = AutomodTarget {
+    texts: [
+      Some(view.post.name.as_str()),
+      view.post.body.as_deref(),
+      view.post.url.as_ref().map(|u| u.as_str()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect(),
+    creator: &view.creator,
+    language_id: view.post.language_id,
+    report_count: view.post.report_count,
+  };
+  // Only federate actions which the community or the post instance will accept
+  let federate = view.community.local || view.post.local;
+
+  for rule in rules.iter().filter(|r| r.check_posts) {
+    if !rule_matches(rule, event, &target).await
+      || (event == AutomodEvent::Report
+        && !AutomodRule::mark_triggered(&mut context.pool(), rule.id, post.id, None).await?)
+    {
+      continue;
+    }
+    let reason = &rule.reason;
+    if rule.action == AutomodAction::HoldForApproval && !post.approval_pending {
+      let form = PostUpdateForm {
+        approval_pending: Some(true),
+        ..Default::default()
+      };
+      post = Post::update(&mut context.pool(), post.id, &form).await?;
+      let form = ModlogInsertForm::automod_hold(system_account.id, &post, None, reason);
+      let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+      send_modlog_webhooks(&actions, context);
+      held = true;
+    }
+    if rule.action == AutomodAction::Remove && !post.removed {
+      let form = PostUpdateForm {
+        removed: Some(true),
+        ..Default::default()
+      };
+      post = Post::update(&mut context.pool(), post.id, &form).await?;
+      let form = ModlogInsertForm::mod_remove_post(system_account.id, &post, true, reason, None);
+      let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+      notify_mod_action(actions, context);
+      if federate {
+        ActivityChannel::submit_activity(
+          SendActivityData::RemovePost {
+            post: post.clone(),
+            moderator: system_account.clone(),
+            reason: reason.clone(),
+            removed: true,
+            with_replies: false,
+          },
+          context,
+        )?;
+      }
+    }
+    if rule.action == AutomodAction::Report {
+      let form = PostReportForm {
+        creator_id: system_account.id,
+        post_id: post.id,
+        original_post_name: post.name.clone(),
+        original_post_url: post.url.clone(),
+        original_post_body: post.body.clone(),
+        reason: reason.clone(),
+        violates_instance_rules: rule.community_id.is_none(),
+        rule_id: None,
+      };
+      PostReport::report(&mut context.pool(), &form).await?;
+      let form = ModlogInsertForm::automod_report(system_account.id, &post, None, reason);
+      let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+      send_modlog_webhooks(&actions, context);
+    }
+    if rule.action == AutomodAction::Lock && !post.locked {
+      let form = PostUpdateForm {
+        locked: Some(true),
+        ..Default::default()
+      };
+      post = Post::update(&mut context.pool(), post.id, &form).await?;
+      let form = ModlogInsertForm::mod_lock_post(system_account.id, &post, true, reason);
+      let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+      notify_mod_action(actions, context);
+      if federate {
+        ActivityChannel::submit_activity(
+          SendActivityData::LockPost(post.clone(), system_account.clone(), true, reason.clone()),
+          context,
+        )?;
+      }
+    }
+    if let (AutomodAction::Reply, Some(reply_text)) = (rule.action, &rule.reply_text) {
+      let form = CommentInsertForm::new(
+        system_account.id,
+        post.id,
+        post.community_id,
+        reply_text.clone(),
+      );
+      let reply = Comment::create(&mut context.pool(), &form, None).await?;
+      let form = ModlogInsertForm::automod_reply(system_account.id, &post, None, reason);
+      let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+      send_modlog_webhooks(&actions, context);
+      send_automod_reply(
+        reply,
+        &post,
+        &system_account,
+        &view.community,
+        federate,
+        context,
+      )?;
+    }
+  }
+  Ok(held)
+}
+
+async fn automod_comment_internal(
+  comment_id: CommentId,
+  event: AutomodEvent,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<bool> {
+  let system_account = SiteView::read_system_account(&mut context.pool()).await?;
+  let view = CommentView::read(
+    &mut context.pool(),
+    comment_id,
+    None,
+    system_account.instance_id,
+  )
+  .await?;
+  if view.creator_is_admin || view.creator_is_moderator || view.creator.id == system_account.id {
+    return Ok(false);
+  }
+  let rules = AutomodRule::list_enabled(&mut context.pool(), view.community.id).await?;
+  let mut comment = view.comment.clone();
+  let mut held = false;
+  let target = AutomodTarget {
+    texts: vec![view.comment.content.as_str()],
+    creator: &view.creator,
+    language_id: view.comment.language_id,
+    report_count: view.comment.report_count,
+  };
+  let federate = view.community.local || view.comment.local;
+
+  for rule in rules.iter().filter(|r| r.check_comments) {
+    if !rule_matches(rule, event, &target).await
+      || (event == AutomodEvent::Report
+        && !AutomodRule::mark_triggered(
+          &mut context.pool(),
+          rule.id,
+          comment.post_id,
+          Some(comment.id),
+        )
+        .await?)
+    {
+      continue;
+    }
+    let reason = &rule.reason;
+    if rule.action == AutomodAction::HoldForApproval && !comment.approval_pending {
+      let form = CommentUpdateForm {
+        approval_pending: Some(true),
+        ..Default::default()
+      };
+      comment = Comment::update(&mut context.pool(), comment.id, &form).await?;
+      let form =
+        ModlogInsertForm::automod_hold(system_account.id, &view.post, Some(&comment), reason);
+      let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+      send_modlog_webhooks(&actions, context);
+      held = true;
+    }
+    if rule.action == AutomodAction::Remove && !comment.removed {
+      let form = CommentUpdateForm {
+        removed: Some(true),
+        ..Default::default()
+      };
+      comment = Comment::update(&mut context.pool(), comment.id, &form).await?;
+      let form = ModlogInsertForm::mod_remove_comment(
+        system_account.id,
+        &comment,
+        view.community.id,
+        true,
+        reason,
+        None,
+      );
+      let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+      notify_mod_action(actions, context);
+      if federate {
+        ActivityChannel::submit_activity(
+          SendActivityData::RemoveComment {
+            comment: comment.clone(),
+            moderator: system_account.clone(),
+            community: view.community.clone(),
+            reason: reason.clone(),
+            with_replies: false,
+          },
+          context,
+        )?;
+      }
+    }
+    if rule.action == AutomodAction::Report {
+      let form = CommentReportForm {
+        creator_id: system_account.id,
+        comment_id: comment.id,
+        original_comment_text: comment.content.clone(),
+        reason: reason.clone(),
+        violates_instance_rules: rule.community_id.is_none(),
+        rule_id: None,
+      };
+      CommentReport::report(&mut context.pool(), &form).await?;
+      let form =
+        ModlogInsertForm::automod_report(system_account.id, &view.post, Some(&comment), reason);
+      let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+      send_modlog_webhooks(&actions, context);
+    }
+    if rule.action == AutomodAction::Lock && !comment.locked {
+      let comments =
+        Comment::update_locked_for_comment_and_children(&mut context.pool(), &comment.path, true)
+          .await?;
+      comment = comments
+        .into_iter()
+        .find(|c| c.id == comment.id)
+        .ok_or(LemmyErrorType::NotFound)?;
+      let form = ModlogInsertForm::mod_lock_comment(
+        system_account.id,
+        &comment,
+        view.community.id,
+        true,
+        reason,
+      );
+      let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+      notify_mod_action(actions, context);
+      if federate {
+        ActivityChannel::submit_activity(
+          SendActivityData::LockComment(
+            comment.clone(),
+            system_account.clone(),
+            true,
+            reason.clone(),
+          ),
+          context,
+        )?;
+      }
+    }
+    if let (AutomodAction::Reply, Some(reply_text)) = (rule.action, &rule.reply_text) {
+      let form = CommentInsertForm::new(
+        system_account.id,
+        view.post.id,
+        view.community.id,
+        reply_text.clone(),
+      );
+      let reply = Comment::create(&mut context.pool(), &form, Some(&comment.path)).await?;
+      let form =
+        ModlogInsertForm::automod_reply(system_account.id, &view.post, Some(&comment), reason);
+      let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+      send_modlog_webhooks(&actions, context);
+      send_automod_reply(
+        reply,
+        &view.post,
+        &system_account,
+        &view.community,
+        federate,
+        context,
+      )?;
+    }
+  }
+  Ok(held)
+}
+
+/// Notifies the creator of the parent about the reply, and federates it if the other instances
+/// accept actions of the system account for this content.
+fn send_automod_reply(
+  reply: Comment,
+  post: &Post,
+  system_account: &Person,
+  community: &Community,
+  federate: bool,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  NotifyData {
+    comment: Some(reply.clone()),
+    ..NotifyData::new(post.clone(), system_account.clone(), community.clone())
+  }
+  .send(context);
+  if federate {
+    ActivityChannel::submit_activity(SendActivityData::CreateComment(reply), context)?;
+  }
+  Ok(())
+}
+
+async fn rule_matches(rule: &AutomodRule, event: AutomodEvent, target: &AutomodTarget<'_>) -> bool {
+  // Rules with a report count are checked for new reports once the threshold is reached. The
+  // caller makes sure that they only trigger once.
+  let applies = match (event, rule.report_count) {
+    (AutomodEvent::Report, Some(report_count)) => i32::from(target.report_count) >= report_count,
+    (AutomodEvent::BeforeCreate, None) => rule.action == AutomodAction::HoldForApproval,
+    (AutomodEvent::Create, None) => rule.action != AutomodAction::HoldForApproval,
+    _ => false,
+  };
+  if !applies {
+    return false;
+  }
+  if let Some(pattern) = &rule.content_regex {
+    let regex = CONTENT_REGEX_CACHE
+      .try_get_with(pattern.clone(), async {
+        build_and_check_regex(Some(pattern))
+      })
+      .await;
+    let Ok(regex) = regex else {
+      warn!("Invalid regex in automod rule {}", rule.id.0);
+      return false;
+    };
+    if !target.texts.iter().any(|t| regex.is_match(t)) {
+      return false;
+    }
+  }
+  if let Some(domain) = &rule.link_domain
+    && !target.texts.iter().any(|t| links_to_domain(t, domain))
+  {
+    return false;
+  }
+  if let Some(days) = rule.max_account_age_days
+    && target.creator.published_at < Utc::now() - Duration::days(days.into())
+  {
+    return false;
+  }
+  if let Some(score) = rule.max_creator_score
+    && (target.creator.post_score > score || target.creator.comment_score > score)
+  {
+    return false;
+  }
+  if let Some(language_id) = rule.language_id
+    && target.language_id != language_id
+  {
+    return false;
+  }
+  true
+}
+
+/// Whether the text contains a link to the domain or one of its subdomains.
+fn links_to_domain(text: &str, domain: &str) -> bool {
+  let Some(link_regex) = LINK_REGEX.as_ref() else {
+    return false;
+  };
+  let domain = domain.trim().trim_start_matches('.').to_lowercase();
+  link_regex
+    .find_iter(text)
+    .filter_map(|m| Url::parse(m.as_str()).ok())
+    .filter_map(|url| url.host_str().map(str::to_lowercase))
+    .any(|host| host == domain || host.ends_with(&format!(".{domain}")))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_db_schema::{
+    source::{community::CommunityInsertForm, post::PostInsertForm},
+    test_data::TestData,
+  };
+  use lemmy_db_schema_file::enums::ModlogKind;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  fn rule() -> AutomodRuleInsertForm {
+    AutomodRuleInsertForm::new(
+      Default::default(),
+      None,
+      "spam".to_string(),
+      AutomodAction::Remove,
+    )
+  }
+
+  #[test]
+  fn test_links_to_domain() {
+    let text = "check out [this](https://shop.spam.example/buy) and http://fine.org";
+    assert!(links_to_domain(text, "spam.example"));
+    assert!(links_to_domain(text, "SHOP.spam.example"));
+    assert!(links_to_domain(text, "fine.org"));
+    assert!(!links_to_domain(text, "am.example"));
+    assert!(!links_to_domain(
+      "spam.example without link",
+      "spam.example"
+    ));
+  }
+
+  #[test]
+  fn test_validate_automod_rule() {
+    assert_eq!(
+      Some(LemmyErrorType::InvalidAutomodRule),
+      validate_automod_rule(&rule()).err().map(|e| e.error_type)
+    );
+    let with_regex = AutomodRuleInsertForm {
+      content_regex: Some("buy now".to_string()),
+      ..rule()
+    };
+    assert!(validate_automod_rule(&with_regex).is_ok());
+    let permissive = AutomodRuleInsertForm {
+      content_regex: Some(".*".to_string()),
+      ..rule()
+    };
+    assert!(validate_automod_rule(&permissive).is_err());
+    let reply_without_text = AutomodRuleInsertForm {
+      action: AutomodAction::Reply,
+      ..with_regex.clone()
+    };
+    assert!(validate_automod_rule(&reply_without_text).is_err());
+    let reply = AutomodRuleInsertForm {
+      reply_text: Some("Please read the rules".to_string()),
+      ..reply_without_text
+    };
+    assert!(validate_automod_rule(&reply).is_ok());
+    let hold_reported = AutomodRuleInsertForm {
+      action: AutomodAction::HoldForApproval,
+      report_count: Some(3),
+      ..rule()
+    };
+    assert!(validate_automod_rule(&hold_reported).is_err());
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_hold_for_approval() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+    let data = TestData::create(pool).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(
+        data.instance.id,
+        "test_automod_hold".to_string(),
+        "pubkey".to_string(),
+      ),
+    )
+    .await?;
+    let form = AutomodRuleInsertForm {
+      content_regex: Some("buy now".to_string()),
+      ..AutomodRuleInsertForm::new(
+        data.person.id,
+        Some(community.id),
+        "spam".to_string(),
+        AutomodAction::HoldForApproval,
+      )
+    };
+    AutomodRule::create(pool, &form).await?;
+
+    let form = PostInsertForm::new("buy now".to_string(), data.person.id, community.id);
+    let post = Post::create(pool, &form).await?;
+    assert!(automod_post(post.id, AutomodEvent::BeforeCreate, &context).await);
+    assert!(Post::read(pool, post.id).await?.approval_pending);
+
+    let form = CommentInsertForm::new(data.person.id, post.id, community.id, "buy now".to_string());
+    let comment = Comment::create(pool, &form, None).await?;
+    assert!(automod_comment(comment.id, AutomodEvent::BeforeCreate, &context).await);
+    assert!(Comment::read(pool, comment.id).await?.approval_pending);
+
+    // Both are logged with the reason of the rule
+    let holds = Modlog::count_for_person_in_community(
+      pool,
+      community.id,
+      data.person.id,
+      &[ModlogKind::AutomodHold],
+    )
+    .await?;
+    assert_eq!(2, holds);
+
+    data.delete(pool).await?;
+    Ok(())
+  }
+}
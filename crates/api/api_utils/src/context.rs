@@ -72,6 +72,7 @@ impl LemmyContext {
     let secret = Secret {
       id: 0,
       jwt_secret: String::new().into(),
+      vapid_private_key: None,
     };
 
     let rate_limit_cell = RateLimit::with_debug_config();
@@ -1,8 +1,13 @@
+pub mod automod;
 pub mod build_response;
 pub mod claims;
 pub mod context;
 pub mod notify;
 pub mod plugins;
+pub mod push;
+pub mod realtime;
 pub mod request;
 pub mod send_activity;
 pub mod utils;
+pub mod warning_policy;
+pub mod webhooks;
@@ -1,11 +1,19 @@
-use crate::{context::LemmyContext, plugins::plugin_hook_notification};
+use crate::{
+  context::LemmyContext,
+  plugins::plugin_hook_notification,
+  push::send_push_notifications,
+  realtime::RealtimeEvent,
+  webhooks::send_modlog_webhooks,
+};
 use lemmy_db_schema::{
+  newtypes::PostId,
   source::{
     comment::Comment,
     community::{Community, CommunityActions},
     instance::InstanceActions,
     modlog::Modlog,
     notification::{Notification, NotificationInsertForm},
+    notification_preference::NotificationPreference,
     person::{Person, PersonActions},
     post::{Post, PostActions},
   },
@@ -87,6 +95,10 @@ impl NotifyData {
     .flatten()
     .collect();
 
+    let recipient_ids = collected.iter().map(|c| c.recipient_id).collect::<Vec<_>>();
+    let preferences =
+      NotificationPreference::list_for_persons(&mut context.pool(), &recipient_ids).await?;
+
     let mut forms = vec![];
     for c in collected {
       // Dont get notified about own actions
@@ -102,11 +114,15 @@ impl NotifyData {
         continue;
       };
 
-      forms.push(if let Some(comment) = &self.comment {
-        NotificationInsertForm::new_comment(comment, c.recipient_id, c.kind)
-      } else {
-        NotificationInsertForm::new_post(&self.post, c.recipient_id, c.kind)
-      });
+      let preference =
+        NotificationPreference::find_for_person(&preferences, c.recipient_id, c.kind);
+      if preference.in_app {
+        forms.push(if let Some(comment) = &self.comment {
+          NotificationInsertForm::new_comment(comment, c.recipient_id, c.kind)
+        } else {
+          NotificationInsertForm::new_post(&self.post, c.recipient_id, c.kind)
+        });
+      }
 
       let Ok(user_view) = LocalUserView::read_person(&mut context.pool(), c.recipient_id).await
       else {
@@ -115,17 +131,44 @@ impl NotifyData {
       };
 
       if self.do_send_email {
-        send_notification_email(user_view, c.local_url, c.data, context.settings());
+        send_notification_email(
+          user_view,
+          c.local_url,
+          c.data,
+          &preference,
+          &mut context.pool(),
+          context.settings(),
+        )
+        .await?;
       }
     }
+    let mut events = vec![self.realtime_event()];
     if !forms.is_empty() {
       let notifications = Notification::create(&mut context.pool(), &forms).await?;
+      events.extend(notifications.iter().map(RealtimeEvent::from));
+      send_push_notifications(&notifications, &context);
       plugin_hook_notification(notifications, &context).await?;
     }
+    RealtimeEvent::publish_many(events, &context);
 
     Ok(())
   }
 
+  fn realtime_event(&self) -> RealtimeEvent {
+    if let Some(comment) = &self.comment {
+      RealtimeEvent::Comment {
+        comment_id: comment.id,
+        post_id: comment.post_id,
+        community_id: self.community.id,
+      }
+    } else {
+      RealtimeEvent::Post {
+        post_id: self.post.id,
+        community_id: self.community.id,
+      }
+    }
+  }
+
   async fn check_notifications_allowed(
     &self,
     potential_blocker_id: PersonId,
@@ -299,9 +342,17 @@ async fn notify_private_message_internal(
   };
 
   let form = NotificationInsertForm::new_private_message(&view.private_message);
-  let notifications = Notification::create(&mut context.pool(), &[form]).await?;
+  let preference =
+    NotificationPreference::read_for_person(&mut context.pool(), form.recipient_id, form.kind)
+      .await?;
+  let notifications = create_if_shown_in_app(form, &preference, context).await?;
+  RealtimeEvent::publish_many(
+    notifications.iter().map(RealtimeEvent::from).collect(),
+    context,
+  );
 
   if is_create {
+    send_push_notifications(&notifications, context);
     plugin_hook_notification(notifications, context).await?;
     let site_view = SiteView::read_local(&mut context.pool()).await?;
     if !site_view.local_site.email_notifications_disabled {
@@ -313,14 +364,31 @@ async fn notify_private_message_internal(
         local_recipient,
         view.private_message.local_url(context.settings())?,
         d,
+        &preference,
+        &mut context.pool(),
         context.settings(),
-      );
+      )
+      .await?;
     }
   }
   Ok(())
 }
 
+/// Stores the notification, unless the recipient disabled in-app notifications of this type.
+async fn create_if_shown_in_app(
+  form: NotificationInsertForm,
+  preference: &NotificationPreference,
+  context: &LemmyContext,
+) -> LemmyResult<Vec<Notification>> {
+  if !preference.in_app {
+    return Ok(vec![]);
+  }
+  Notification::create(&mut context.pool(), &[form]).await
+}
+
 pub fn notify_mod_action(actions: Vec<Modlog>, context: &LemmyContext) {
+  send_modlog_webhooks(&actions, context);
+
   // Mod actions should notify the target person. If there is no target person then also no
   // notification. This means each mod action can only notify a single person (eg it is not possible
   // to notify all community mods when a community gets removed).
@@ -344,7 +412,15 @@ pub fn notify_mod_action(actions: Vec<Modlog>, context: &LemmyContext) {
       };
 
       let form = NotificationInsertForm::new_mod_action(&action, local_recipient.person.id);
-      let notifications = Notification::create(&mut context.pool(), &[form]).await?;
+      let preference =
+        NotificationPreference::read_for_person(&mut context.pool(), form.recipient_id, form.kind)
+          .await?;
+      let notifications = create_if_shown_in_app(form, &preference, &context).await?;
+      RealtimeEvent::publish_many(
+        notifications.iter().map(RealtimeEvent::from).collect(),
+        &context,
+      );
+      send_push_notifications(&notifications, &context);
       plugin_hook_notification(notifications, &context).await?;
 
       let modlog_url = format!(
@@ -362,19 +438,119 @@ pub fn notify_mod_action(actions: Vec<Modlog>, context: &LemmyContext) {
         local_recipient,
         Url::parse(&modlog_url)?.into(),
         d,
+        &preference,
+        &mut context.pool(),
         context.settings(),
-      );
+      )
+      .await?;
     }
     Ok(())
   })
 }
 
+/// Scores at which the post creator gets notified.
+const SCORE_MILESTONES: [i32; 3] = [10, 100, 1000];
+
+/// Notifies the post creator if the post score passed a milestone since the vote. The same
+/// milestone is only notified once per post.
+pub fn notify_score_milestone(post_id: PostId, previous_score: i32, context: &LemmyContext) {
+  let context = context.clone();
+  spawn_try_task(async move {
+    let post = Post::read(&mut context.pool(), post_id).await?;
+    let Some(milestone) = passed_score_milestone(previous_score, post.score) else {
+      return Ok(());
+    };
+    if post.deleted || post.removed {
+      return Ok(());
+    }
+    let form = NotificationInsertForm {
+      score_milestone: Some(milestone),
+      ..NotificationInsertForm::new_post(&post, post.creator_id, NotificationType::ScoreMilestone)
+    };
+    notify_local_person(form, &context).await
+  })
+}
+
+/// Returns the highest milestone which lies between the two scores, if any.
+fn passed_score_milestone(previous_score: i32, score: i32) -> Option<i32> {
+  SCORE_MILESTONES
+    .into_iter()
+    .rfind(|m| previous_score < *m && score >= *m)
+}
+
+/// Notifies the post creator that a moderator or admin featured their post.
+pub fn notify_post_featured(post: &Post, mod_id: PersonId, context: &LemmyContext) {
+  if post.creator_id == mod_id {
+    return;
+  }
+  let form = NotificationInsertForm {
+    creator_id: mod_id,
+    ..NotificationInsertForm::new_post(post, post.creator_id, NotificationType::PostFeatured)
+  };
+  let context = context.clone();
+  spawn_try_task(async move { notify_local_person(form, &context).await })
+}
+
+/// Notifies the creator of the parent comment, or of the post for top-level comments, that a
+/// moderator distinguished their reply.
+pub fn notify_comment_distinguished(comment: &Comment, context: &LemmyContext) {
+  let comment = comment.clone();
+  let context = context.clone();
+  spawn_try_task(async move {
+    let recipient_id = match comment.parent_comment_id() {
+      Some(parent_id) => {
+        Comment::read(&mut context.pool(), parent_id)
+          .await?
+          .creator_id
+      }
+      None => {
+        Post::read(&mut context.pool(), comment.post_id)
+          .await?
+          .creator_id
+      }
+    };
+    if recipient_id == comment.creator_id {
+      return Ok(());
+    }
+    let form = NotificationInsertForm::new_comment(
+      &comment,
+      recipient_id,
+      NotificationType::CommentDistinguished,
+    );
+    notify_local_person(form, &context).await
+  })
+}
+
+/// Stores and delivers a single notification if the recipient is a local user. These
+/// notifications are not sent by email immediately, only as part of digests.
+async fn notify_local_person(
+  form: NotificationInsertForm,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if LocalUserView::read_person(&mut context.pool(), form.recipient_id)
+    .await
+    .is_err()
+  {
+    return Ok(());
+  }
+  let preference =
+    NotificationPreference::read_for_person(&mut context.pool(), form.recipient_id, form.kind)
+      .await?;
+  let notifications = create_if_shown_in_app(form, &preference, context).await?;
+  RealtimeEvent::publish_many(
+    notifications.iter().map(RealtimeEvent::from).collect(),
+    context,
+  );
+  send_push_notifications(&notifications, context);
+  plugin_hook_notification(notifications, context).await
+}
+
 #[cfg(test)]
 #[expect(clippy::indexing_slicing)]
 mod tests {
   use crate::{
     context::LemmyContext,
-    notify::{NotifyData, notify_private_message_internal},
+    notify::{NotifyData, notify_private_message_internal, passed_score_milestone},
   };
   use lemmy_db_schema::{
     NotificationTypeFilter,
@@ -955,4 +1131,47 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn score_milestones() -> LemmyResult<()> {
+    assert_eq!(None, passed_score_milestone(0, 9));
+    assert_eq!(Some(10), passed_score_milestone(9, 10));
+    assert_eq!(None, passed_score_milestone(10, 11));
+    assert_eq!(Some(100), passed_score_milestone(5, 150));
+    // Downvotes never trigger a milestone
+    assert_eq!(None, passed_score_milestone(100, 99));
+
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    // The same milestone is only stored once per post
+    let form = NotificationInsertForm {
+      score_milestone: Some(10),
+      ..NotificationInsertForm::new_post(
+        &data.timmy_post,
+        data.timmy.person.id,
+        NotificationType::ScoreMilestone,
+      )
+    };
+    let created = Notification::create(pool, std::slice::from_ref(&form)).await?;
+    assert_length!(1, created);
+    let created = Notification::create(pool, &[form]).await?;
+    assert_length!(0, created);
+
+    let form = NotificationInsertForm {
+      score_milestone: Some(100),
+      ..NotificationInsertForm::new_post(
+        &data.timmy_post,
+        data.timmy.person.id,
+        NotificationType::ScoreMilestone,
+      )
+    };
+    let created = Notification::create(pool, &[form]).await?;
+    assert_length!(1, created);
+
+    cleanup(data, pool).await?;
+    Ok(())
+  }
 }
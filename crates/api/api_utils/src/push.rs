@@ -0,0 +1,363 @@
+//! Delivery of notifications through Web Push (RFC 8030). Messages are encrypted with the
+//! `aes128gcm` content coding from RFC 8291, and requests are authenticated with VAPID (RFC 8292).
+
+use crate::{context::LemmyContext, utils::check_url_is_public};
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use chrono::{Duration, Utc};
+use futures::future::join_all;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use lemmy_db_schema::source::{
+  notification::Notification,
+  notification_preference::NotificationPreference,
+  push_subscription::PushSubscription,
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  spawn_try_task,
+};
+use reqwest::{
+  StatusCode,
+  header::{AUTHORIZATION, CONTENT_ENCODING, CONTENT_TYPE},
+};
+use ring::{
+  aead::{AES_128_GCM, Aad, LessSafeKey, Nonce, UnboundKey},
+  agreement::{ECDH_P256, EphemeralPrivateKey, UnparsedPublicKey, agree_ephemeral},
+  hkdf::{HKDF_SHA256, KeyType, Salt},
+  rand::{SecureRandom, SystemRandom},
+  signature::{ECDSA_P256_SHA256_FIXED_SIGNING, EcdsaKeyPair, KeyPair},
+};
+use serde::Serialize;
+use std::time::Duration as StdDuration;
+use tracing::warn;
+use url::Url;
+
+const DELIVERY_TIMEOUT: StdDuration = StdDuration::from_secs(10);
+
+/// How long the push service should keep a message for an offline device, in seconds.
+const PUSH_TTL: &str = "86400";
+
+/// The whole payload is sent as a single record, so this only needs to be larger than the payload.
+const RECORD_SIZE: u32 = 4096;
+
+/// Length of an uncompressed P-256 public key.
+const PUBLIC_KEY_LEN: usize = 65;
+
+const AUTH_SECRET_LEN: usize = 16;
+
+/// Maximum number of push subscriptions per user, each browser or device uses one.
+pub const MAX_PUSH_SUBSCRIPTIONS: i64 = 20;
+
+#[derive(Serialize)]
+struct VapidClaims {
+  aud: String,
+  exp: i64,
+  sub: String,
+}
+
+/// Sends the notifications to all push subscriptions of their recipients. Spawns a task for
+/// background processing.
+pub fn send_push_notifications(notifications: &[Notification], context: &LemmyContext) {
+  if notifications.is_empty() || context.secret().vapid_private_key.is_none() {
+    return;
+  }
+  let notifications = notifications.to_vec();
+  let context = context.clone();
+  spawn_try_task(async move {
+    let recipient_ids = notifications
+      .iter()
+      .map(|n| n.recipient_id)
+      .collect::<Vec<_>>();
+    let subscriptions =
+      PushSubscription::list_for_persons(&mut context.pool(), &recipient_ids).await?;
+    let preferences =
+      NotificationPreference::list_for_persons(&mut context.pool(), &recipient_ids).await?;
+    let mut deliveries = vec![];
+    for notification in &notifications {
+      let preference = NotificationPreference::find_for_person(
+        &preferences,
+        notification.recipient_id,
+        notification.kind,
+      );
+      if !preference.push {
+        continue;
+      }
+      let payload = serde_json::to_vec(notification)?;
+      for (_, subscription) in subscriptions
+        .iter()
+        .filter(|(person_id, _)| *person_id == notification.recipient_id)
+      {
+        deliveries.push(deliver(subscription, payload.clone(), &context));
+      }
+    }
+    join_all(deliveries).await;
+    Ok(())
+  })
+}
+
+async fn deliver(subscription: &PushSubscription, payload: Vec<u8>, context: &LemmyContext) {
+  let res = match send(subscription, &payload, context).await {
+    // The subscription was cancelled by the user or expired
+    Ok(StatusCode::NOT_FOUND | StatusCode::GONE) => {
+      PushSubscription::delete(&mut context.pool(), subscription.id)
+        .await
+        .map(|_| ())
+    }
+    Ok(status) if !status.is_success() => {
+      Err(LemmyErrorType::Unknown(format!("Push service returned {status}")).into())
+    }
+    res => res.map(|_| ()),
+  };
+  if let Err(e) = res {
+    warn!(
+      "Failed to send push notification to {}: {e}",
+      subscription.endpoint
+    );
+  }
+}
+
+async fn send(
+  subscription: &PushSubscription,
+  payload: &[u8],
+  context: &LemmyContext,
+) -> LemmyResult<StatusCode> {
+  let endpoint = Url::parse(&subscription.endpoint)?;
+  check_url_is_public(&endpoint).await?;
+  let ua_public = BASE64_URL_SAFE_NO_PAD.decode(&subscription.p256dh)?;
+  let auth_secret = BASE64_URL_SAFE_NO_PAD.decode(&subscription.auth)?;
+  let body = encrypt(payload, &ua_public, &auth_secret)?;
+  let authorization = vapid_authorization(
+    &endpoint,
+    &vapid_private_key(context)?,
+    context.settings().get_protocol_and_hostname(),
+  )?;
+  let res = context
+    .client()
+    .post(endpoint)
+    .timeout(DELIVERY_TIMEOUT)
+    .header(AUTHORIZATION, authorization)
+    .header("TTL", PUSH_TTL)
+    .header(CONTENT_ENCODING, "aes128gcm")
+    .header(CONTENT_TYPE, "application/octet-stream")
+    .body(body)
+    .send()
+    .await?;
+  Ok(res.status())
+}
+
+/// Checks that a subscription sent by a client can be used for sending push messages.
+pub async fn check_push_subscription(endpoint: &str, p256dh: &str, auth: &str) -> LemmyResult<()> {
+  let endpoint = Url::parse(endpoint).ok();
+  let ua_public = BASE64_URL_SAFE_NO_PAD.decode(p256dh).unwrap_or_default();
+  let auth_secret = BASE64_URL_SAFE_NO_PAD.decode(auth).unwrap_or_default();
+  let Some(endpoint) = endpoint.filter(|e| e.scheme() == "https") else {
+    return Err(LemmyErrorType::InvalidPushSubscription.into());
+  };
+  if ua_public.len() != PUBLIC_KEY_LEN || auth_secret.len() != AUTH_SECRET_LEN {
+    return Err(LemmyErrorType::InvalidPushSubscription.into());
+  }
+  check_url_is_public(&endpoint).await
+}
+
+/// The base64url encoded public key of the instance, which clients need to pass as
+/// `applicationServerKey` when subscribing.
+pub fn vapid_public_key(context: &LemmyContext) -> LemmyResult<String> {
+  public_key(&vapid_private_key(context)?)
+}
+
+fn public_key(pkcs8: &[u8]) -> LemmyResult<String> {
+  let key_pair = EcdsaKeyPair::from_pkcs8(
+    &ECDSA_P256_SHA256_FIXED_SIGNING,
+    pkcs8,
+    &SystemRandom::new(),
+  )?;
+  Ok(BASE64_URL_SAFE_NO_PAD.encode(key_pair.public_key()))
+}
+
+fn vapid_private_key(context: &LemmyContext) -> LemmyResult<Vec<u8>> {
+  let key = context
+    .secret()
+    .vapid_private_key
+    .as_ref()
+    .ok_or(LemmyErrorType::NotFound)?;
+  Ok(BASE64_URL_SAFE_NO_PAD.decode(key)?)
+}
+
+/// Value of the `Authorization` header, which contains a token signed with the VAPID key. The
+/// token is only valid for the origin of the push service.
+fn vapid_authorization(endpoint: &Url, pkcs8: &[u8], contact: String) -> LemmyResult<String> {
+  let claims = VapidClaims {
+    aud: endpoint.origin().ascii_serialization(),
+    exp: (Utc::now() + Duration::hours(12)).timestamp(),
+    sub: contact,
+  };
+  let key = EncodingKey::from_ec_der(pkcs8);
+  let token = encode(&Header::new(Algorithm::ES256), &claims, &key)?;
+  Ok(format!("vapid t={token}, k={}", public_key(pkcs8)?))
+}
+
+/// Encrypts the payload for the browser with the given public key and authentication secret. The
+/// result starts with the header from RFC 8188, followed by a single encrypted record.
+fn encrypt(payload: &[u8], ua_public: &[u8], auth_secret: &[u8]) -> LemmyResult<Vec<u8>> {
+  let rng = SystemRandom::new();
+  let as_private = EphemeralPrivateKey::generate(&ECDH_P256, &rng)?;
+  let as_public = as_private.compute_public_key()?;
+  let ecdh_secret = agree_ephemeral(
+    as_private,
+    &UnparsedPublicKey::new(&ECDH_P256, ua_public),
+    <[u8]>::to_vec,
+  )?;
+
+  let key_info = [&b"WebPush: info\0"[..], ua_public, as_public.as_ref()].concat();
+  let ikm = hkdf(auth_secret, &ecdh_secret, &key_info, 32)?;
+  let mut salt = [0; 16];
+  rng.fill(&mut salt)?;
+  let cek = hkdf(&salt, &ikm, b"Content-Encoding: aes128gcm\0", 16)?;
+  let nonce = hkdf(&salt, &ikm, b"Content-Encoding: nonce\0", 12)?;
+
+  // The delimiter marks the last record, no further padding is added
+  let mut record = [payload, &[2]].concat();
+  LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &cek)?).seal_in_place_append_tag(
+    Nonce::try_assume_unique_for_key(&nonce)?,
+    Aad::empty(),
+    &mut record,
+  )?;
+
+  let key_id = as_public.as_ref();
+  let key_id_len = u8::try_from(key_id.len())?;
+  Ok(
+    [
+      &salt[..],
+      &RECORD_SIZE.to_be_bytes(),
+      &[key_id_len],
+      key_id,
+      &record,
+    ]
+    .concat(),
+  )
+}
+
+struct HkdfLen(usize);
+
+impl KeyType for HkdfLen {
+  fn len(&self) -> usize {
+    self.0
+  }
+}
+
+fn hkdf(salt: &[u8], ikm: &[u8], info: &[u8], len: usize) -> LemmyResult<Vec<u8>> {
+  let prk = Salt::new(HKDF_SHA256, salt).extract(ikm);
+  let mut out = vec![0; len];
+  prk.expand(&[info], HkdfLen(len))?.fill(&mut out)?;
+  Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+  use ring::signature::{ECDSA_P256_SHA256_FIXED, UnparsedPublicKey as SignaturePublicKey};
+
+  /// Decrypts the message in the same way as a browser.
+  #[test]
+  fn test_encrypt() -> LemmyResult<()> {
+    let rng = SystemRandom::new();
+    let ua_private = EphemeralPrivateKey::generate(&ECDH_P256, &rng)?;
+    let ua_public = ua_private.compute_public_key()?;
+    let auth_secret = [7; AUTH_SECRET_LEN];
+    let body = encrypt(b"hello", ua_public.as_ref(), &auth_secret)?;
+
+    let (salt, rest) = body.split_at(16);
+    let (record_size, rest) = rest.split_at(4);
+    let (key_id_len, rest) = rest.split_at(1);
+    let (as_public, record) = rest.split_at(PUBLIC_KEY_LEN);
+    assert_eq!(&RECORD_SIZE.to_be_bytes(), record_size);
+    assert_eq!(&[65], key_id_len);
+
+    let ecdh_secret = agree_ephemeral(
+      ua_private,
+      &UnparsedPublicKey::new(&ECDH_P256, as_public),
+      <[u8]>::to_vec,
+    )?;
+    let key_info = [&b"WebPush: info\0"[..], ua_public.as_ref(), as_public].concat();
+    let ikm = hkdf(&auth_secret, &ecdh_secret, &key_info, 32)?;
+    let cek = hkdf(salt, &ikm, b"Content-Encoding: aes128gcm\0", 16)?;
+    let nonce = hkdf(salt, &ikm, b"Content-Encoding: nonce\0", 12)?;
+    let mut record = record.to_vec();
+    let plaintext = LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &cek)?).open_in_place(
+      Nonce::try_assume_unique_for_key(&nonce)?,
+      Aad::empty(),
+      &mut record,
+    )?;
+    assert_eq!(b"hello\x02", plaintext);
+    Ok(())
+  }
+
+  #[test]
+  fn test_vapid_authorization() -> LemmyResult<()> {
+    let pkcs8 =
+      EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &SystemRandom::new())?;
+    let endpoint = Url::parse("https://push.example.com/send/abc")?;
+    let authorization = vapid_authorization(
+      &endpoint,
+      pkcs8.as_ref(),
+      "https://lemmy.example.com".to_string(),
+    )?;
+
+    let (token, key) = authorization
+      .strip_prefix("vapid t=")
+      .and_then(|a| a.split_once(", k="))
+      .ok_or(LemmyErrorType::NotFound)?;
+    let (message, signature) = token.rsplit_once('.').ok_or(LemmyErrorType::NotFound)?;
+    SignaturePublicKey::new(
+      &ECDSA_P256_SHA256_FIXED,
+      BASE64_URL_SAFE_NO_PAD.decode(key)?,
+    )
+    .verify(
+      message.as_bytes(),
+      &BASE64_URL_SAFE_NO_PAD.decode(signature)?,
+    )?;
+
+    let (_, claims) = message.split_once('.').ok_or(LemmyErrorType::NotFound)?;
+    let claims: serde_json::Value =
+      serde_json::from_slice(&BASE64_URL_SAFE_NO_PAD.decode(claims)?)?;
+    assert_eq!(
+      Some("https://push.example.com"),
+      claims.get("aud").and_then(|a| a.as_str())
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_check_push_subscription() {
+    let p256dh = BASE64_URL_SAFE_NO_PAD.encode([4; PUBLIC_KEY_LEN]);
+    let auth = BASE64_URL_SAFE_NO_PAD.encode([1; AUTH_SECRET_LEN]);
+    // Use an ip address so that the test doesn't depend on dns
+    let endpoint = "https://1.1.1.1/abc";
+    assert!(
+      check_push_subscription(endpoint, &p256dh, &auth)
+        .await
+        .is_ok()
+    );
+    assert!(
+      check_push_subscription("http://1.1.1.1/abc", &p256dh, &auth)
+        .await
+        .is_err()
+    );
+    assert!(
+      check_push_subscription(endpoint, &auth, &auth)
+        .await
+        .is_err()
+    );
+    assert!(
+      check_push_subscription(endpoint, &p256dh, "")
+        .await
+        .is_err()
+    );
+    assert!(
+      check_push_subscription("https://127.0.0.1/abc", &p256dh, &auth)
+        .await
+        .is_err()
+    );
+  }
+}
@@ -0,0 +1,131 @@
+//! Real-time events which are pushed to clients through the event stream. Events are sent as
+//! Postgres notifications, so that they reach the event streams of all Lemmy processes and not
+//! only the process where the event happened.
+
+use crate::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::{CommentId, CommunityId, NotificationId, PostId},
+  source::notification::Notification,
+};
+use lemmy_db_schema_file::PersonId;
+use lemmy_diesel_utils::connection::{listen_notifications, send_notifications};
+use lemmy_utils::spawn_try_task;
+use serde::{Deserialize, Serialize};
+use std::{sync::LazyLock, time::Duration};
+use tokio::{
+  sync::broadcast::{Receiver, Sender, channel},
+  time::sleep,
+};
+use tracing::warn;
+
+const EVENTS_CHANNEL: &str = "lemmy_events";
+
+/// How many events can be buffered for a slow event stream, before it starts skipping events.
+const EVENTS_CAPACITY: usize = 1000;
+
+const LISTEN_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+static EVENTS: LazyLock<Sender<RealtimeEvent>> = LazyLock::new(|| channel(EVENTS_CAPACITY).0);
+
+/// Events only contain ids, because the payload of Postgres notifications is limited to 8000 bytes.
+/// Each event stream reads the full items with the permissions of its own user.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type_", rename_all = "snake_case")]
+pub enum RealtimeEvent {
+  Notification {
+    recipient_id: PersonId,
+    notification_id: NotificationId,
+  },
+  /// Notifications of the person were marked as read or unread.
+  NotificationsRead { person_id: PersonId },
+  /// A post was created or edited.
+  Post {
+    post_id: PostId,
+    community_id: CommunityId,
+  },
+  /// A comment was created or edited.
+  Comment {
+    comment_id: CommentId,
+    post_id: PostId,
+    community_id: CommunityId,
+  },
+}
+
+impl RealtimeEvent {
+  pub fn publish(self, context: &LemmyContext) {
+    Self::publish_many(vec![self], context)
+  }
+
+  /// Sends the events to all Lemmy processes. Spawns a task for background processing.
+  pub fn publish_many(events: Vec<Self>, context: &LemmyContext) {
+    if events.is_empty() {
+      return;
+    }
+    let context = context.clone();
+    spawn_try_task(async move {
+      let payloads = events
+        .iter()
+        .map(serde_json::to_string)
+        .collect::<Result<Vec<_>, _>>()?;
+      send_notifications(&mut context.pool(), EVENTS_CHANNEL, payloads).await
+    })
+  }
+
+  /// Receives all events which are published after this call.
+  pub fn subscribe() -> Receiver<Self> {
+    EVENTS.subscribe()
+  }
+}
+
+impl From<&Notification> for RealtimeEvent {
+  fn from(notification: &Notification) -> Self {
+    RealtimeEvent::Notification {
+      recipient_id: notification.recipient_id,
+      notification_id: notification.id,
+    }
+  }
+}
+
+/// Receives events from all Lemmy processes and forwards them to the event streams of this
+/// process. Runs forever, reconnecting after a delay if the database connection is lost.
+pub async fn listen_realtime_events() {
+  loop {
+    let res = listen_notifications(EVENTS_CHANNEL, |payload| {
+      match serde_json::from_str::<RealtimeEvent>(&payload) {
+        Ok(event) => {
+          // Sending only fails if there are no open event streams
+          EVENTS.send(event).ok();
+        }
+        Err(e) => warn!("Invalid realtime event {payload}: {e}"),
+      }
+    })
+    .await;
+    if let Err(e) = res {
+      warn!("Failed to listen for realtime events: {e}");
+    }
+    sleep(LISTEN_RETRY_DELAY).await;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_realtime_event_payload() -> LemmyResult<()> {
+    let event = RealtimeEvent::Comment {
+      comment_id: CommentId(3),
+      post_id: PostId(2),
+      community_id: CommunityId(1),
+    };
+    let payload = serde_json::to_string(&event)?;
+    assert_eq!(
+      r#"{"type_":"comment","comment_id":3,"post_id":2,"community_id":1}"#,
+      payload
+    );
+    assert_eq!(event, serde_json::from_str(&payload)?);
+    Ok(())
+  }
+}
@@ -1,14 +1,20 @@
 use crate::context::LemmyContext;
 use activitypub_federation::config::Data;
+use chrono::{DateTime, Utc};
 use either::Either;
 use lemmy_db_schema::{
   newtypes::CommunityId,
   source::{
     comment::Comment,
+    comment_report::CommentReport,
     community::Community,
+    community_flair::CommunityFlair,
+    conversation::{Conversation, ConversationMessage},
+    modmail::ModmailMessage,
     multi_community::MultiCommunity,
     person::Person,
     post::Post,
+    post_report::PostReport,
     private_message::PrivateMessage,
     site::Site,
   },
@@ -16,6 +22,7 @@ use lemmy_db_schema::{
 use lemmy_db_schema_file::PersonId;
 use lemmy_db_views_comment::CommentView;
 use lemmy_db_views_community::api::BanFromCommunity;
+use lemmy_db_views_conversation::ConversationMessageView;
 use lemmy_db_views_post::PostView;
 use lemmy_db_views_private_message::PrivateMessageView;
 use lemmy_diesel_utils::dburl::DbUrl;
@@ -44,6 +51,28 @@ pub enum SendActivityData {
     with_replies: bool,
   },
   LockPost(Post, Person, bool, String),
+  /// Removes or restores many posts and comments of a community.
+  BulkRemove {
+    posts: Vec<Post>,
+    comments: Vec<Comment>,
+    moderator: Person,
+    community: Community,
+    reason: String,
+    removed: bool,
+  },
+  BulkLockPosts {
+    posts: Vec<Post>,
+    moderator: Person,
+    community: Community,
+    locked: bool,
+    reason: String,
+  },
+  /// Vote in the poll of a remote post, sent to the post creator.
+  VotePoll {
+    post: Post,
+    actor: Person,
+    options: Vec<String>,
+  },
   FeaturePost(Post, Person, bool),
   CreateComment(Comment),
   UpdateComment(Comment),
@@ -63,8 +92,17 @@ pub enum SendActivityData {
     previous_is_upvote: Option<bool>,
     new_is_upvote: Option<bool>,
   },
+  ReactPostOrComment {
+    object_id: DbUrl,
+    actor: Person,
+    community: Community,
+    emoji: String,
+    emoji_url: Option<DbUrl>,
+    add: bool,
+  },
   FollowCommunity(Community, Person, bool),
   FollowMultiCommunity(MultiCommunity, Person, bool),
+  FollowPerson(Person, Person, bool),
   PrivateCommunityAcceptFollower {
     community_id: CommunityId,
     person_id: PersonId,
@@ -89,6 +127,13 @@ pub enum SendActivityData {
     target: PersonId,
     added: bool,
   },
+  AssignCommunityFlair {
+    actor: Person,
+    community: Community,
+    target: Person,
+    flair: CommunityFlair,
+    added: bool,
+  },
   BanFromCommunity {
     moderator: Person,
     community_id: CommunityId,
@@ -106,12 +151,30 @@ pub enum SendActivityData {
   CreatePrivateMessage(PrivateMessageView),
   UpdatePrivateMessage(PrivateMessageView),
   DeletePrivateMessage(Person, PrivateMessage, bool),
+  CreateConversationMessage(ConversationMessageView),
+  UpdateConversationMessage(ConversationMessageView),
+  DeleteConversationMessage(Person, ConversationMessage, bool),
+  /// Added or removed participants are sent to all participants, so that other instances accept
+  /// their messages.
+  ConversationParticipant {
+    conversation: Conversation,
+    actor: Person,
+    participant: Person,
+    added: bool,
+  },
+  CreateModmailMessage(ModmailMessage),
   DeleteUser(Person, bool),
+  /// Moves the account to a new one, which lists it as an alias.
+  MoveUser {
+    person: Person,
+    target: Person,
+  },
   CreateReport {
     object_id: Url,
     actor: Person,
     receiver: Either<Site, Community>,
     reason: String,
+    rule: Option<Url>,
   },
   SendResolveReport {
     object_id: Url,
@@ -119,8 +182,19 @@ pub enum SendActivityData {
     report_creator: Person,
     receiver: Either<Site, Community>,
   },
+  BulkResolveReports {
+    actor: Person,
+    community: Community,
+    post_reports: Vec<PostReport>,
+    comment_reports: Vec<CommentReport>,
+  },
   UpdateMultiCommunity(MultiCommunity, Person),
-  Warning(Box<Either<PostView, CommentView>>, String, Person),
+  Warning(
+    Box<Either<PostView, CommentView>>,
+    String,
+    Option<DateTime<Utc>>,
+    Person,
+  ),
 }
 
 // TODO: instead of static, move this into LemmyContext. make sure that stopping the process with
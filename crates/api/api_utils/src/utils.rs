@@ -1,45 +1,63 @@
 use crate::{
   claims::Claims,
   context::LemmyContext,
+  notify::notify_mod_action,
   request::{delete_image_alias, fetch_pictrs_proxied_image_details, purge_image_from_pictrs_url},
+  send_activity::{ActivityChannel, SendActivityData},
 };
 use activitypub_federation::config::Data;
 use actix_web::{HttpRequest, http::header::Header};
 use actix_web_httpauth::headers::authorization::{Authorization, Bearer};
-use chrono::{DateTime, Days, Local, TimeZone, Utc};
+use chrono::{DateTime, Days, Duration, Local, TimeZone, Utc};
+use diesel_async::scoped_futures::ScopedFutureExt;
 use enum_map::{EnumMap, enum_map};
 use lemmy_db_schema::{
-  newtypes::{CommunityId, CommunityTagId, ModlogId, PostId, PostOrCommentId},
+  newtypes::{CommunityId, CommunityTagId, ModlogId, PostId, PostOrCommentId, RuleId},
   source::{
     comment::{Comment, CommentActions, CommentLikeForm},
-    community::{Community, CommunityActions, CommunityUpdateForm},
+    community::{
+      Community,
+      CommunityActions,
+      CommunityBlockForm,
+      CommunityPersonBanForm,
+      CommunityUpdateForm,
+    },
     community_tag::{CommunityTag, PostCommunityTag},
+    custom_emoji::CustomEmoji,
     images::{ImageDetails, RemoteImage},
-    instance::InstanceActions,
+    instance::{InstanceActions, InstanceCommunitiesBlockForm, InstancePersonsBlockForm},
     local_site::LocalSite,
     local_site_rate_limit::LocalSiteRateLimit,
     local_site_url_blocklist::LocalSiteUrlBlocklist,
     modlog::{Modlog, ModlogInsertForm},
     oauth_account::OAuthAccount,
-    person::{Person, PersonUpdateForm},
+    person::{Person, PersonActions, PersonBlockForm, PersonUpdateForm},
+    person_report::PersonReport,
     post::{Post, PostActions, PostLikeForm, PostReadCommentsForm},
     private_message::PrivateMessage,
+    reaction::ReactionInsertForm,
     registration_application::RegistrationApplication,
+    rule::Rule,
     site::Site,
   },
-  traits::Likeable,
+  traits::{Bannable, Blockable, Followable, Likeable, Reportable},
 };
 use lemmy_db_schema_file::{
   InstanceId,
   PersonId,
   enums::{FederationMode, ImageMode, RegistrationMode},
 };
+use lemmy_db_views_community::api::BanFromCommunity;
 use lemmy_db_views_community_follower_approval::PendingFollowerView;
 use lemmy_db_views_community_moderator::{CommunityModeratorView, CommunityPersonBanView};
 use lemmy_db_views_local_image::LocalImageView;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::SiteView;
-use lemmy_diesel_utils::{connection::DbPool, dburl::DbUrl, traits::Crud};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  dburl::DbUrl,
+  traits::Crud,
+};
 use lemmy_utils::{
   CACHE_DURATION_FEDERATION,
   CacheLock,
@@ -62,12 +80,16 @@ use lemmy_utils::{
       markdown_check_for_blocked_urls,
     },
     slurs::remove_slurs,
-    validation::build_and_check_regex,
+    validation::{build_and_check_regex, is_valid_reaction},
   },
 };
 use moka::future::Cache;
 use regex::{Regex, RegexSet, escape};
-use std::{collections::HashSet, sync::LazyLock};
+use std::{
+  collections::HashSet,
+  net::{IpAddr, Ipv4Addr},
+  sync::LazyLock,
+};
 use tracing::Instrument;
 use url::{ParseError, Url};
 use urlencoding::encode;
@@ -278,6 +300,61 @@ pub async fn check_community_mod_action(
   Ok(())
 }
 
+/// Reads the rule cited by a report or mod action in the given community. Without a community,
+/// only site rules can be cited.
+pub async fn read_cited_rule(
+  rule_id: Option<RuleId>,
+  community_id: Option<CommunityId>,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<Option<Rule>> {
+  match rule_id {
+    Some(rule_id) => Ok(Some(
+      Rule::read_for_citation(pool, rule_id, community_id).await?,
+    )),
+    None => Ok(None),
+  }
+}
+
+/// Whether new content by the given creator needs to be held in the mod queue of a local
+/// community, according to the pre-moderation settings of the community. Mods and admins are
+/// never held.
+pub async fn requires_approval(
+  creator: &Person,
+  community: &Community,
+  is_post: bool,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<bool> {
+  let enabled = if is_post {
+    community.posts_require_approval
+  } else {
+    community.comments_require_approval
+  };
+  if !community.local || !enabled {
+    return Ok(false);
+  }
+
+  // Without any filters all new content is held, otherwise only content by new accounts or
+  // remote users.
+  let is_new_account = community
+    .approval_max_account_age_days
+    .is_some_and(|days| creator.published_at > Utc::now() - Duration::days(days.into()));
+  let is_remote = community.approval_remote_only && !creator.local;
+  let filtered =
+    community.approval_max_account_age_days.is_some() || community.approval_remote_only;
+  if filtered && !is_new_account && !is_remote {
+    return Ok(false);
+  }
+
+  let is_mod = CommunityModeratorView::check_is_community_moderator(pool, community.id, creator.id)
+    .await
+    .is_ok();
+  let is_admin = creator.local
+    && LocalUserView::read_person(pool, creator.id)
+      .await
+      .is_ok_and(|l| l.local_user.admin);
+  Ok(!is_mod && !is_admin)
+}
+
 /// Don't allow creating reports for removed / deleted posts
 pub fn check_post_deleted_or_removed(post: &Post) -> LemmyResult<()> {
   if post.deleted || post.removed {
@@ -326,6 +403,25 @@ pub async fn check_local_vote_mode(
   Ok(())
 }
 
+/// Validates an emoji reaction sent through the api. Custom emoji shortcodes need to belong to an
+/// emoji of the local instance.
+pub async fn build_reaction_form(
+  emoji: &str,
+  person_id: PersonId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<ReactionInsertForm> {
+  is_valid_reaction(emoji)?;
+  let mut form = ReactionInsertForm::new(person_id, emoji.to_string());
+  if let Some(shortcode) = emoji.strip_prefix(':').and_then(|e| e.strip_suffix(':')) {
+    let custom_emoji = CustomEmoji::read_by_shortcode(pool, shortcode)
+      .await
+      .with_lemmy_type(LemmyErrorType::InvalidReaction)?;
+    form.custom_emoji_id = Some(custom_emoji.id);
+    form.emoji_url = Some(custom_emoji.image_url);
+  }
+  Ok(form)
+}
+
 /// Dont allow bots to do certain actions, like voting
 pub fn check_bot_account(person: &Person) -> LemmyResult<()> {
   if person.bot_account {
@@ -437,6 +533,52 @@ pub async fn get_url_blocklist(context: &LemmyContext) -> LemmyResult<RegexSet>
   )
 }
 
+/// Resolve the host of a url which the server is going to send requests to on behalf of a user
+/// (webhooks, web push), and reject it if any address is not publicly routable.
+pub async fn check_url_is_public(url: &Url) -> LemmyResult<()> {
+  let host = url.host_str().ok_or(LemmyErrorType::InvalidUrl)?;
+  let port = url.port_or_known_default().unwrap_or(443);
+  // Ipv6 hosts are wrapped in brackets
+  let host = host.trim_start_matches('[').trim_end_matches(']');
+  let addrs: Vec<_> = tokio::net::lookup_host((host, port))
+    .await
+    .with_lemmy_type(LemmyErrorType::InvalidUrl)?
+    .collect();
+  if addrs.is_empty() || addrs.iter().any(|a| !is_public_ip(a.ip())) {
+    return Err(LemmyErrorType::UrlResolvesToPrivateAddress.into());
+  }
+  Ok(())
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+  match ip {
+    IpAddr::V4(ip) => is_public_ipv4(ip),
+    IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+      Some(ip) => is_public_ipv4(ip),
+      None => {
+        !(ip.is_loopback()
+          || ip.is_unspecified()
+          || ip.is_multicast()
+          || ip.is_unique_local()
+          || ip.is_unicast_link_local())
+      }
+    },
+  }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+  let [a, b, ..] = ip.octets();
+  // 0.0.0.0/8 and shared address space 100.64.0.0/10
+  let reserved = a == 0 || (a == 100 && (b & 0xc0) == 64);
+  !(reserved
+    || ip.is_loopback()
+    || ip.is_private()
+    || ip.is_link_local()
+    || ip.is_broadcast()
+    || ip.is_multicast()
+    || ip.is_documentation())
+}
+
 // `local_site` is optional so that tests work easily
 pub fn check_nsfw_allowed(nsfw: Option<bool>, local_site: Option<&LocalSite>) -> LemmyResult<()> {
   let is_nsfw = nsfw.unwrap_or_default();
@@ -493,6 +635,21 @@ fn delete_local_user_images(person_id: PersonId, context: &LemmyContext) {
   });
 }
 
+/// Resolves the reports about a user after a site ban. A ban from this instance or from the user's
+/// home instance handles them, bans from other instances only apply there and leave them open.
+pub async fn resolve_person_reports_for_site_ban(
+  banned_person: &Person,
+  site_instance_id: InstanceId,
+  mod_person_id: PersonId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  let local_instance_id = SiteView::read_local(pool).await?.site.instance_id;
+  if site_instance_id == local_instance_id || site_instance_id == banned_person.instance_id {
+    PersonReport::resolve_all_for_object(pool, banned_person.id, mod_person_id).await?;
+  }
+  Ok(())
+}
+
 /// Removes or restores user data.
 pub async fn remove_or_restore_user_data(
   mod_person_id: PersonId,
@@ -730,6 +887,100 @@ pub async fn remove_or_restore_user_data_in_community(
   Ok(())
 }
 
+/// Bans or unbans a person from a community, and federates it. If requested, their posts and
+/// comments in the community are removed or restored in the background. Permission checks are left
+/// to the caller. For bulk bans the parent modlog entry is passed in `bulk_action_parent_id`.
+pub async fn ban_person_from_community(
+  data: &BanFromCommunity,
+  moderator: &Person,
+  expires_at: Option<DateTime<Utc>>,
+  rule_id: Option<RuleId>,
+  bulk_action_parent_id: Option<ModlogId>,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<Modlog> {
+  let my_person_id = moderator.id;
+  let banned_person_id = data.person_id;
+  let community_user_ban_form = CommunityPersonBanForm {
+    ban_expires_at: Some(expires_at),
+    ..CommunityPersonBanForm::new(data.community_id, data.person_id)
+  };
+
+  let pool = &mut context.pool();
+  let conn = &mut get_conn(pool).await?;
+  let tx_data = data.clone();
+  let action = conn
+    .run_transaction(|conn| {
+      async move {
+        if tx_data.ban {
+          CommunityActions::ban(&mut conn.into(), &community_user_ban_form).await?;
+
+          // Also unsubscribe them from the community, if they are subscribed
+          CommunityActions::unfollow(&mut conn.into(), banned_person_id, tx_data.community_id)
+            .await
+            .ok();
+        } else {
+          CommunityActions::unban(&mut conn.into(), &community_user_ban_form).await?;
+        }
+
+        // Mod tables - create ban entry first so bulk actions can reference it as parent
+        let mut form = ModlogInsertForm::mod_ban_from_community(
+          my_person_id,
+          tx_data.community_id,
+          tx_data.person_id,
+          tx_data.ban,
+          expires_at,
+          &tx_data.reason,
+        )
+        .with_rule(rule_id);
+        if let Some(parent_id) = bulk_action_parent_id {
+          form = form.with_bulk_action_parent(parent_id);
+        }
+        let action = Modlog::create(&mut conn.into(), &[form]).await?;
+
+        Ok(action)
+      }
+      .scope_boxed()
+    })
+    .await?;
+  notify_mod_action(action.clone(), context);
+  let ban = action.into_iter().next().ok_or(LemmyErrorType::NotFound)?;
+
+  // Remove/Restore their data in background if that's desired
+  // Cant do this inside transaction because `conn` cannot be passed into spawn_try_task.
+  if data.remove_or_restore_data.unwrap_or(false) {
+    let community_id = data.community_id;
+    let remove_data = data.ban;
+    let reason = data.reason.clone();
+    let ban_id = ban.id;
+    let context = context.clone();
+    spawn_try_task(async move {
+      remove_or_restore_user_data_in_community(
+        community_id,
+        my_person_id,
+        banned_person_id,
+        remove_data,
+        &reason,
+        ban_id,
+        &mut context.pool(),
+      )
+      .await
+    });
+  }
+
+  let target = Person::read(&mut context.pool(), banned_person_id).await?;
+  ActivityChannel::submit_activity(
+    SendActivityData::BanFromCommunity {
+      moderator: moderator.clone(),
+      community_id: data.community_id,
+      target,
+      data: data.clone(),
+    },
+    context,
+  )?;
+
+  Ok(ban)
+}
+
 pub async fn purge_user_account(
   person_id: PersonId,
   local_instance_id: InstanceId,
@@ -764,6 +1015,76 @@ pub async fn purge_user_account(
   Ok(())
 }
 
+/// Marks the old account as moved and transfers its community follows to the new account. The
+/// new account must list the old one in `also_known_as`, so that nobody can take over followers
+/// of another user.
+pub async fn move_person_account(
+  old_person: &Person,
+  new_person: &Person,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  if old_person.id == new_person.id {
+    return Err(LemmyErrorType::CantMoveToYourself.into());
+  }
+  if !new_person.also_known_as.contains(&old_person.ap_id) {
+    return Err(LemmyErrorType::MissingAccountAlias.into());
+  }
+
+  let form = PersonUpdateForm {
+    moved_to_id: Some(Some(new_person.id)),
+    ..Default::default()
+  };
+  Person::update(&mut context.pool(), old_person.id, &form).await?;
+  let followed =
+    CommunityActions::transfer_follows(&mut context.pool(), old_person.id, new_person.id).await?;
+
+  // Follows of remote communities need to be federated by the instance of each account.
+  for community in followed.into_iter().filter(|c| !c.local) {
+    if old_person.local {
+      ActivityChannel::submit_activity(
+        SendActivityData::FollowCommunity(community.clone(), old_person.clone(), false),
+        context,
+      )?;
+    }
+    if new_person.local {
+      ActivityChannel::submit_activity(
+        SendActivityData::FollowCommunity(community, new_person.clone(), true),
+        context,
+      )?;
+    }
+  }
+
+  // Blocks are only stored for local users
+  if new_person.local {
+    transfer_blocks(old_person.id, new_person.id, &mut context.pool()).await?;
+  }
+  Ok(())
+}
+
+async fn transfer_blocks(
+  old_person_id: PersonId,
+  new_person_id: PersonId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  for person in PersonActions::read_blocks_for_person(pool, old_person_id).await? {
+    if person.id != new_person_id {
+      PersonActions::block(pool, &PersonBlockForm::new(new_person_id, person.id)).await?;
+    }
+  }
+  for community in CommunityActions::read_blocks_for_person(pool, old_person_id).await? {
+    CommunityActions::block(pool, &CommunityBlockForm::new(community.id, new_person_id)).await?;
+  }
+  for instance in InstanceActions::read_communities_block_for_person(pool, old_person_id).await? {
+    let form = InstanceCommunitiesBlockForm::new(new_person_id, instance.id);
+    InstanceActions::block_communities(pool, &form).await?;
+  }
+  for instance in InstanceActions::read_persons_block_for_person(pool, old_person_id).await? {
+    let form = InstancePersonsBlockForm::new(new_person_id, instance.id);
+    InstanceActions::block_persons(pool, &form).await?;
+  }
+  Ok(())
+}
+
 pub fn generate_followers_url(ap_id: &DbUrl) -> Result<DbUrl, ParseError> {
   Ok(Url::parse(&format!("{ap_id}/followers"))?.into())
 }
@@ -1048,6 +1369,29 @@ mod tests {
     assert!(password_length_check("looooooooooooooooooooooooooooooooooooooooooooooooooooooooooong").is_err());
   }
 
+  #[tokio::test]
+  async fn test_check_url_is_public() -> LemmyResult<()> {
+    for url in [
+      "https://127.0.0.1/hook",
+      "http://[::1]:8080/hook",
+      "https://localhost/hook",
+      "https://10.1.2.3/hook",
+      "https://169.254.169.254/latest",
+      "https://[::ffff:192.168.0.1]/hook",
+    ] {
+      assert!(
+        check_url_is_public(&Url::parse(url)?).await.is_err(),
+        "{url}"
+      );
+    }
+    assert!(
+      check_url_is_public(&Url::parse("https://1.1.1.1/hook")?)
+        .await
+        .is_ok()
+    );
+    Ok(())
+  }
+
   #[test]
   fn honeypot() {
     assert!(honeypot_check(&None).is_ok());
@@ -1135,6 +1479,7 @@ mod tests {
       unresolved_report_count: 0,
       federation_pending: false,
       locked: false,
+      approval_pending: false,
     };
     assert!(check_comment_depth(&comment).is_ok());
     comment.path = Ltree("0.123.456".to_string());
@@ -0,0 +1,115 @@
+use crate::{context::LemmyContext, utils::ban_person_from_community};
+use activitypub_federation::config::Data;
+use chrono::{Duration, Utc};
+use lemmy_db_schema::source::{community::Community, local_site::LocalSite, modlog::Modlog};
+use lemmy_db_schema_file::PersonId;
+use lemmy_db_views_community::api::BanFromCommunity;
+use lemmy_db_views_community_moderator::{CommunityModeratorView, CommunityPersonBanView};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::SiteView;
+use lemmy_utils::error::LemmyResult;
+use tracing::warn;
+
+/// Users who collect `threshold` warnings within `window_days` are banned for `ban_days`.
+struct WarningPolicy {
+  threshold: i32,
+  window_days: Option<i32>,
+  ban_days: Option<i32>,
+}
+
+impl WarningPolicy {
+  /// The policy of the community, or of the site if the community has none.
+  fn for_community(community: &Community, local_site: &LocalSite) -> Option<Self> {
+    if let Some(threshold) = community.warning_ban_threshold {
+      Some(WarningPolicy {
+        threshold,
+        window_days: community.warning_ban_window_days,
+        ban_days: community.warning_ban_days,
+      })
+    } else {
+      local_site
+        .warning_ban_threshold
+        .map(|threshold| WarningPolicy {
+          threshold,
+          window_days: local_site.warning_ban_window_days,
+          ban_days: local_site.warning_ban_days,
+        })
+    }
+  }
+}
+
+/// Bans the user from the community once their active warnings reach the warning policy. Like
+/// automod actions the ban is issued by the system account, and the warnings which led to it are
+/// linked to it in the modlog. Failures are only logged, so that they don't affect the creation of
+/// the warning.
+pub async fn apply_warning_policy(
+  community: &Community,
+  person_id: PersonId,
+  context: &Data<LemmyContext>,
+) {
+  if let Err(e) = apply_warning_policy_internal(community, person_id, context).await {
+    warn!(
+      "Failed to apply warning policy for person {} in community {}: {e}",
+      person_id.0, community.id.0
+    );
+  }
+}
+
+async fn apply_warning_policy_internal(
+  community: &Community,
+  person_id: PersonId,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  // Only the instance of the community can ban from it
+  if !community.local {
+    return Ok(());
+  }
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  let Some(policy) = WarningPolicy::for_community(community, &local_site) else {
+    return Ok(());
+  };
+
+  // Existing bans are left as they are, and mods or admins are never banned automatically
+  let pool = &mut context.pool();
+  let is_banned = CommunityPersonBanView::check(pool, person_id, community.id)
+    .await
+    .is_err();
+  let is_mod = CommunityModeratorView::check_is_community_moderator(pool, community.id, person_id)
+    .await
+    .is_ok();
+  let is_admin = LocalUserView::read_person(pool, person_id)
+    .await
+    .is_ok_and(|u| u.local_user.admin);
+  if is_banned || is_mod || is_admin {
+    return Ok(());
+  }
+
+  let since = policy
+    .window_days
+    .map(|days| Utc::now() - Duration::days(days.into()));
+  let warnings = Modlog::list_active_warnings(pool, community.id, person_id, since).await?;
+  let count = i32::try_from(warnings.len()).unwrap_or(i32::MAX);
+  if count < policy.threshold {
+    return Ok(());
+  }
+
+  let system_account = SiteView::read_system_account(pool).await?;
+  let expires_at = policy
+    .ban_days
+    .map(|days| Utc::now() + Duration::days(days.into()));
+  let data = BanFromCommunity {
+    community_id: community.id,
+    person_id,
+    ban: true,
+    reason: format!("Automatic ban after {count} warnings"),
+    expires_at: expires_at.map(|e| e.timestamp()),
+    ..Default::default()
+  };
+  let ban =
+    ban_person_from_community(&data, &system_account, expires_at, None, None, context).await?;
+
+  let warning_ids: Vec<_> = warnings.iter().map(|w| w.id).collect();
+  Modlog::set_warning_ban(pool, &warning_ids, ban.id).await?;
+
+  Ok(())
+}
@@ -0,0 +1,192 @@
+use crate::{context::LemmyContext, utils::check_url_is_public};
+use activitypub_federation::config::Data;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use hmac::{Hmac, Mac};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{
+    modlog::Modlog,
+    webhook::{Webhook, WebhookDelivery},
+  },
+};
+use lemmy_db_schema_file::enums::{ModlogKind, WebhookEventType};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  federate_retry_sleep_duration,
+  spawn_try_task,
+};
+use reqwest::header::CONTENT_TYPE;
+use serde::Serialize;
+use sha2::Sha256;
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::warn;
+
+/// Deliveries are given up after this many failed attempts. With the retry delays of
+/// [federate_retry_sleep_duration] this corresponds to a few days.
+const MAX_FAIL_COUNT: i32 = 50;
+
+/// Maximum number of deliveries which are sent at the same time.
+const DELIVERY_BATCH_SIZE: i64 = 50;
+
+/// How long to wait before checking for new deliveries, if the queue is empty.
+const RECHECK_DELAY: Duration = Duration::from_secs(5);
+
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Header which contains the HMAC-SHA256 signature of the request body, as `sha256=<hex>`.
+pub const SIGNATURE_HEADER: &str = "X-Lemmy-Signature";
+const EVENT_HEADER: &str = "X-Lemmy-Event";
+const DELIVERY_HEADER: &str = "X-Lemmy-Delivery";
+
+#[derive(Serialize)]
+struct WebhookBody<'a, T> {
+  event: WebhookEventType,
+  published_at: DateTime<Utc>,
+  data: &'a T,
+}
+
+/// Queues the event for all webhooks which are subscribed to it. Events with a community are
+/// also sent to webhooks of that community, others only to site webhooks.
+pub fn send_webhook<T: Serialize>(
+  event: WebhookEventType,
+  community_id: Option<CommunityId>,
+  data: &T,
+  context: &LemmyContext,
+) {
+  let body = WebhookBody {
+    event,
+    published_at: Utc::now(),
+    data,
+  };
+  let payload = match serde_json::to_value(body) {
+    Ok(p) => p,
+    Err(e) => {
+      warn!("Failed to serialize webhook {event:?}: {e}");
+      return;
+    }
+  };
+  let context = context.clone();
+  spawn_try_task(async move {
+    WebhookDelivery::enqueue(&mut context.pool(), event, community_id, payload).await?;
+    Ok(())
+  });
+}
+
+/// Sends a modlog entry event for each action, and a ban event for site and community bans.
+pub fn send_modlog_webhooks(actions: &[Modlog], context: &LemmyContext) {
+  for action in actions {
+    send_webhook(
+      WebhookEventType::ModlogEntry,
+      action.target_community_id,
+      action,
+      context,
+    );
+    if matches!(
+      action.kind,
+      ModlogKind::AdminBan | ModlogKind::ModBanFromCommunity
+    ) {
+      send_webhook(
+        WebhookEventType::Ban,
+        action.target_community_id,
+        action,
+        context,
+      );
+    }
+  }
+}
+
+/// Sends queued webhook deliveries in a loop. Failed deliveries are retried with the same
+/// increasing delay which is used for federation.
+pub async fn deliver_webhooks(context: Data<LemmyContext>) {
+  loop {
+    let pending =
+      WebhookDelivery::list_pending(&mut context.pool(), MAX_FAIL_COUNT, DELIVERY_BATCH_SIZE)
+        .await
+        .inspect_err(|e| warn!("Failed to load webhook deliveries: {e}"))
+        .unwrap_or_default();
+    if pending.is_empty() {
+      sleep(RECHECK_DELAY).await;
+      continue;
+    }
+    join_all(
+      pending
+        .into_iter()
+        .map(|(delivery, webhook)| deliver(delivery, webhook, &context)),
+    )
+    .await;
+  }
+}
+
+async fn deliver(delivery: WebhookDelivery, webhook: Webhook, context: &LemmyContext) {
+  let res = match send(&delivery, &webhook, context).await {
+    Ok(()) => WebhookDelivery::mark_delivered(&mut context.pool(), delivery.id).await,
+    Err(e) => {
+      let fail_count = delivery.fail_count + 1;
+      let next_attempt_at = Utc::now() + federate_retry_sleep_duration(fail_count);
+      WebhookDelivery::mark_failed(
+        &mut context.pool(),
+        delivery.id,
+        fail_count,
+        next_attempt_at,
+        e.to_string(),
+      )
+      .await
+    }
+  };
+  if let Err(e) = res {
+    warn!("Failed to update webhook delivery {}: {e}", delivery.id.0);
+  }
+}
+
+async fn send(
+  delivery: &WebhookDelivery,
+  webhook: &Webhook,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  // Check again on every delivery, as the dns records may have changed since registration
+  check_url_is_public(webhook.url.inner()).await?;
+  let body = serde_json::to_vec(&delivery.payload)?;
+  let signature = sign_webhook_body(&webhook.secret, &body)?;
+  let event = serde_json::to_value(delivery.event)?;
+  context
+    .client()
+    .post(webhook.url.inner().clone())
+    .timeout(DELIVERY_TIMEOUT)
+    .header(CONTENT_TYPE, "application/json")
+    .header(SIGNATURE_HEADER, format!("sha256={signature}"))
+    .header(EVENT_HEADER, event.as_str().unwrap_or_default())
+    .header(DELIVERY_HEADER, delivery.id.0)
+    .body(body)
+    .send()
+    .await?
+    .error_for_status()?;
+  Ok(())
+}
+
+/// Hex encoded HMAC-SHA256 of the body, which lets the receiver verify that the request came from
+/// this instance.
+pub fn sign_webhook_body(secret: &str, body: &[u8]) -> LemmyResult<String> {
+  let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+    .map_err(|e| LemmyErrorType::Unknown(e.to_string()))?;
+  mac.update(body);
+  Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_sign_webhook_body() -> LemmyResult<()> {
+    // Test vector from RFC 4231, test case 2
+    let signature = sign_webhook_body("Jefe", b"what do ya want for nothing?")?;
+    assert_eq!(
+      "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+      signature
+    );
+    Ok(())
+  }
+}
@@ -2,9 +2,11 @@ use actix_web::{guard, web::*};
 use lemmy_api::{
   comment::{
     distinguish::distinguish_comment,
+    history::get_comment_history,
     like::like_comment,
     list_comment_likes::list_comment_likes,
     lock::lock_comment,
+    react::react_comment,
     save::save_comment,
     warning::create_comment_warning,
   },
@@ -12,7 +14,34 @@ use lemmy_api::{
     add_mod::add_mod_to_community,
     ban::ban_from_community,
     block::user_block_community,
+    bulk::{
+      ban::bulk_ban_from_community,
+      lock::bulk_lock_posts,
+      remove::bulk_remove,
+      resolve_reports::bulk_resolve_reports,
+    },
+    flair::{
+      assign_community_flair,
+      create_community_flair,
+      delete_community_flair,
+      edit_community_flair,
+    },
     follow::follow_community,
+    mod_case::{
+      create_note::create_mod_note,
+      delete_note::delete_mod_note,
+      edit_note::edit_mod_note,
+      get::get_mod_case,
+    },
+    mod_queue::{approve::post_mod_queue_approve, list::get_mod_queue_list},
+    modmail::{
+      assign::assign_modmail,
+      create::create_modmail,
+      list::list_modmail_threads,
+      list_messages::list_modmail_messages,
+      reply::reply_to_modmail,
+      resolve::resolve_modmail,
+    },
     multi_community_follow::follow_multi_community,
     pending_follows::{approve::post_pending_follows_approve, list::get_pending_follows_list},
     random::get_random_community,
@@ -21,6 +50,7 @@ use lemmy_api::{
     update_notifications::edit_community_notifications,
   },
   federation::{
+    account_migration::{move_account, set_account_aliases},
     list_comments::{list_comments, list_comments_slim},
     list_person_content::list_person_content,
     list_persons::list_persons,
@@ -39,6 +69,7 @@ use lemmy_api::{
     change_password::change_password,
     change_password_after_reset::change_password_after_reset,
     export_data::export_user_data,
+    follow_person::user_follow_person,
     generate_totp_secret::generate_totp_secret,
     get_captcha::get_captcha,
     list_hidden::list_person_hidden,
@@ -47,14 +78,18 @@ use lemmy_api::{
     list_media::list_media,
     list_read::list_person_read,
     list_saved::list_person_saved,
+    list_scheduled::list_scheduled,
     login::login,
     logout::logout,
     mark_donation_dialog_shown::mark_donation_dialog_shown,
     note_person::user_note_person,
     notifications::{
       list::list_notifications,
+      list_groups::list_notification_groups,
       mark_all_read::mark_all_notifications_read,
+      mark_group_read::mark_notification_group_as_read,
       mark_notification_read::mark_notification_as_read,
+      push_subscription::{create_push_subscription, delete_push_subscription},
     },
     resend_verification_email::resend_verification_email,
     reset_password::reset_password,
@@ -69,19 +104,23 @@ use lemmy_api::{
     feature::feature_post,
     get_link_metadata::get_link_metadata,
     hide::hide_post,
+    history::get_post_history,
     like::like_post,
     list_post_likes::list_post_likes,
     lock::lock_post,
     mark_many_read::mark_posts_as_read,
     mark_read::mark_post_as_read,
     mod_update::mod_edit_post,
+    react::react_post,
     save::save_post,
     update_notifications::edit_post_notifications,
+    vote_poll::vote_poll,
     warning::create_post_warning,
   },
   reports::{
     comment_report::{create::create_comment_report, resolve::resolve_comment_report},
     community_report::{create::create_community_report, resolve::resolve_community_report},
+    person_report::{create::create_person_report, resolve::resolve_person_report},
     post_report::{create::create_post_report, resolve::resolve_post_report},
     private_message_report::{create::create_pm_report, resolve::resolve_pm_report},
     report_combined::list::list_reports,
@@ -90,6 +129,7 @@ use lemmy_api::{
     admin_allow_instance::admin_allow_instance,
     admin_block_instance::admin_block_instance,
     admin_list_users::admin_list_users,
+    email_outbox::{list_failed::list_failed_emails, resend::resend_email},
     federated_instances::get_federated_instances,
     list_all_media::list_all_media,
     mod_log::get_mod_log,
@@ -107,11 +147,18 @@ use lemmy_api::{
   },
 };
 use lemmy_api_crud::{
+  automod::{
+    create::create_automod_rule,
+    delete::delete_automod_rule,
+    list::list_automod_rules,
+    update::edit_automod_rule,
+  },
   comment::{
     create::create_comment,
     delete::delete_comment,
     read::get_comment,
     remove::remove_comment,
+    schedule::{create_scheduled_comment, delete_scheduled_comment, edit_scheduled_comment},
     update::edit_comment,
   },
   community::{
@@ -121,6 +168,17 @@ use lemmy_api_crud::{
     remove::remove_community,
     update::edit_community,
   },
+  conversation::{
+    create::create_conversation,
+    create_message::create_conversation_message,
+    delete_message::delete_conversation_message,
+    list::list_conversations,
+    list_messages::list_conversation_messages,
+    mark_as_read::mark_conversation_as_read,
+    participant::{add_conversation_participant, remove_conversation_participant},
+    read::get_conversation,
+    update_message::edit_conversation_message,
+  },
   custom_emoji::{
     create::create_custom_emoji,
     delete::delete_custom_emoji,
@@ -150,8 +208,19 @@ use lemmy_api_crud::{
   private_message::{
     create::create_private_message,
     delete::delete_private_message,
+    schedule::{
+      create_scheduled_private_message,
+      delete_scheduled_private_message,
+      edit_scheduled_private_message,
+    },
     update::edit_private_message,
   },
+  rule::{
+    create::create_rule,
+    delete::delete_rule,
+    list::{get_rule_stats, list_rules},
+    update::edit_rule,
+  },
   site::{create::create_site, read::get_site, update::edit_site},
   tagline::{
     create::create_tagline,
@@ -164,28 +233,37 @@ use lemmy_api_crud::{
     delete::delete_account,
     my_user::get_my_user,
   },
-};
-use lemmy_routes::images::{
-  delete::{
-    delete_community_banner,
-    delete_community_icon,
-    delete_image,
-    delete_image_admin,
-    delete_site_banner,
-    delete_site_icon,
-    delete_user_avatar,
-    delete_user_banner,
+  webhook::{
+    create::create_webhook,
+    delete::delete_webhook,
+    list::list_webhooks,
+    update::edit_webhook,
   },
-  download::{get_image, image_proxy},
-  pictrs_health,
-  upload::{
-    upload_community_banner,
-    upload_community_icon,
-    upload_image,
-    upload_site_banner,
-    upload_site_icon,
-    upload_user_avatar,
-    upload_user_banner,
+};
+use lemmy_routes::{
+  events::get_events,
+  images::{
+    delete::{
+      delete_community_banner,
+      delete_community_icon,
+      delete_image,
+      delete_image_admin,
+      delete_site_banner,
+      delete_site_icon,
+      delete_user_avatar,
+      delete_user_banner,
+    },
+    download::{get_image, image_proxy},
+    pictrs_health,
+    upload::{
+      upload_community_banner,
+      upload_community_icon,
+      upload_image,
+      upload_site_banner,
+      upload_site_icon,
+      upload_user_avatar,
+      upload_user_banner,
+    },
   },
 };
 use lemmy_utils::rate_limit::RateLimit;
@@ -245,11 +323,43 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/tag", post().to(create_community_tag))
           .route("/tag", put().to(edit_community_tag))
           .route("/tag", delete().to(delete_community_tag))
+          .route("/flair", post().to(create_community_flair))
+          .route("/flair", put().to(edit_community_flair))
+          .route("/flair", delete().to(delete_community_flair))
+          .route("/flair/assign", post().to(assign_community_flair))
           .route("/notifications", put().to(edit_community_notifications))
           .service(
             scope("/pending_follows")
               .route("/list", get().to(get_pending_follows_list))
               .route("/approve", post().to(post_pending_follows_approve)),
+          )
+          .service(
+            scope("/bulk")
+              .route("/remove", post().to(bulk_remove))
+              .route("/lock", post().to(bulk_lock_posts))
+              .route("/ban_user", post().to(bulk_ban_from_community))
+              .route("/resolve_reports", put().to(bulk_resolve_reports)),
+          )
+          .service(
+            scope("/mod_case")
+              .route("", get().to(get_mod_case))
+              .route("/note", post().to(create_mod_note))
+              .route("/note", put().to(edit_mod_note))
+              .route("/note", delete().to(delete_mod_note)),
+          )
+          .service(
+            scope("/mod_queue")
+              .route("/list", get().to(get_mod_queue_list))
+              .route("/approve", post().to(post_mod_queue_approve)),
+          )
+          .service(
+            scope("/modmail")
+              .route("", post().to(create_modmail))
+              .route("/reply", post().to(reply_to_modmail))
+              .route("/assign", put().to(assign_modmail))
+              .route("/resolve", put().to(resolve_modmail))
+              .route("/list", get().to(list_modmail_threads))
+              .route("/message/list", get().to(list_modmail_messages)),
           ),
       )
       .service(
@@ -285,11 +395,14 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/mark_as_read", post().to(mark_post_as_read))
           .route("/mark_as_read/many", post().to(mark_posts_as_read))
           .route("/hide", post().to(hide_post))
+          .route("/history", get().to(get_post_history))
           .route("/lock", post().to(lock_post))
           .route("/feature", post().to(feature_post))
           .route("/list", get().to(list_posts))
           .route("/like", post().to(like_post))
           .route("/like/list", get().to(list_post_likes))
+          .route("/react", post().to(react_post))
+          .route("/poll/vote", post().to(vote_poll))
           .route("/save", put().to(save_post))
           .route("/report", post().to(create_post_report))
           .route("/report/resolve", put().to(resolve_post_report))
@@ -312,10 +425,15 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("", delete().to(delete_comment))
           .route("/remove", post().to(remove_comment))
           .route("/distinguish", post().to(distinguish_comment))
+          .route("/history", get().to(get_comment_history))
           .route("/like", post().to(like_comment))
           .route("/like/list", get().to(list_comment_likes))
+          .route("/react", post().to(react_comment))
           .route("/save", put().to(save_comment))
           .route("/lock", post().to(lock_comment))
+          .route("/schedule", post().to(create_scheduled_comment))
+          .route("/schedule", put().to(edit_scheduled_comment))
+          .route("/schedule", delete().to(delete_scheduled_comment))
           .route("/list", get().to(list_comments))
           .route("/list/slim", get().to(list_comments_slim))
           .route("/warn", post().to(create_comment_warning))
@@ -328,9 +446,26 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("", post().to(create_private_message))
           .route("", put().to(edit_private_message))
           .route("", delete().to(delete_private_message))
+          .route("/schedule", post().to(create_scheduled_private_message))
+          .route("/schedule", put().to(edit_scheduled_private_message))
+          .route("/schedule", delete().to(delete_scheduled_private_message))
           .route("/report", post().to(create_pm_report))
           .route("/report/resolve", put().to(resolve_pm_report)),
       )
+      // Conversation
+      .service(
+        scope("/conversation")
+          .route("", get().to(get_conversation))
+          .route("", post().to(create_conversation))
+          .route("/list", get().to(list_conversations))
+          .route("/message", post().to(create_conversation_message))
+          .route("/message", put().to(edit_conversation_message))
+          .route("/message/delete", post().to(delete_conversation_message))
+          .route("/message/list", get().to(list_conversation_messages))
+          .route("/participant", post().to(add_conversation_participant))
+          .route("/participant", delete().to(remove_conversation_participant))
+          .route("/mark_as_read", post().to(mark_conversation_as_read)),
+      )
       // Reports
       .service(
         scope("/report")
@@ -361,6 +496,7 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/auth/get_captcha", get().to(get_captcha))
           .route("", get().to(get_my_user))
           .route("/unread_counts", get().to(get_unread_counts))
+          .route("/events", get().to(get_events))
           .service(
             scope("/media")
               .route("", delete().to(delete_image))
@@ -369,11 +505,22 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .service(
             scope("/notification")
               .route("/list", get().to(list_notifications))
+              .route("/list/grouped", get().to(list_notification_groups))
               .route("/mark_as_read/all", post().to(mark_all_notifications_read))
+              .route(
+                "/mark_as_read/group",
+                post().to(mark_notification_group_as_read),
+              )
               .route("/mark_as_read", post().to(mark_notification_as_read)),
           )
+          .service(
+            scope("/push_subscription")
+              .route("", post().to(create_push_subscription))
+              .route("", delete().to(delete_push_subscription)),
+          )
           .route("", delete().to(delete_account))
           .route("/login/list", get().to(list_logins))
+          .route("/scheduled/list", get().to(list_scheduled))
           .route("/validate_auth", get().to(validate_auth))
           .route(
             "/donation_dialog_shown",
@@ -398,6 +545,8 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/hidden", get().to(list_person_hidden))
           .route("/liked", get().to(list_person_liked))
           .route("/settings/save", put().to(save_user_settings))
+          .route("/aliases", put().to(set_account_aliases))
+          .route("/move", post().to(move_account))
           // Account settings import / export have a strict rate limit
           .service(
             scope("/settings")
@@ -423,7 +572,10 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("", get().to(read_person))
           .route("/list", get().to(list_persons))
           .route("/content", get().to(list_person_content))
-          .route("/note", post().to(user_note_person)),
+          .route("/note", post().to(user_note_person))
+          .route("/follow", post().to(user_follow_person))
+          .route("/report", post().to(create_person_report))
+          .route("/report/resolve", put().to(resolve_person_report)),
       )
       // Admin Actions
       .service(
@@ -451,6 +603,11 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           )
           .route("/ban", post().to(ban_from_site))
           .route("/users", get().to(admin_list_users))
+          .service(
+            scope("/email")
+              .route("/failed/list", get().to(list_failed_emails))
+              .route("/resend", post().to(resend_email)),
+          )
           .service(
             scope("/instance")
               .route("/block", post().to(admin_block_instance))
@@ -470,6 +627,28 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("", put().to(edit_oauth_provider))
           .route("", delete().to(delete_oauth_provider)),
       )
+      .service(
+        scope("/webhook")
+          .route("", post().to(create_webhook))
+          .route("", put().to(edit_webhook))
+          .route("", delete().to(delete_webhook))
+          .route("/list", get().to(list_webhooks)),
+      )
+      .service(
+        scope("/automod")
+          .route("", post().to(create_automod_rule))
+          .route("", put().to(edit_automod_rule))
+          .route("", delete().to(delete_automod_rule))
+          .route("/list", get().to(list_automod_rules)),
+      )
+      .service(
+        scope("/rule")
+          .route("", post().to(create_rule))
+          .route("", put().to(edit_rule))
+          .route("", delete().to(delete_rule))
+          .route("/list", get().to(list_rules))
+          .route("/stats", get().to(get_rule_stats)),
+      )
       .service(
         scope("/oauth")
           .wrap(rate_limit.register())
@@ -143,7 +143,7 @@ pub(crate) async fn get_post_v3(
   context: Data<LemmyContext>,
   local_user_view: Option<LocalUserView>,
 ) -> LemmyResult<Json<GetPostResponseV3>> {
-  let post = get_post(data, context, local_user_view).await?.0;
+  let post = Box::pin(get_post(data, context, local_user_view)).await?.0;
   Ok(Json(GetPostResponseV3 {
     post_view: convert_post_view(post.post_view),
     community_view: convert_community_view(post.community_view),
@@ -187,7 +187,9 @@ pub(crate) async fn list_posts_v3(
     limit,
     ..Default::default()
   };
-  let res = list_posts(Query(data), context, local_user_view).await?.0;
+  let res = Box::pin(list_posts(Query(data), context, local_user_view))
+    .await?
+    .0;
   Ok(Json(GetPostsResponseV3 {
     posts: res.into_iter().map(convert_post_view).collect(),
     next_page: None,
@@ -372,6 +374,8 @@ pub(crate) async fn create_post_v3(
     custom_thumbnail,
     tags: None,
     scheduled_publish_time_at: None,
+    scheduled_recurrence: None,
+    poll: None,
   };
   let res = Box::pin(create_post(Json(data), context, local_user_view)).await?;
   convert_post_response(res)
@@ -10,14 +10,17 @@ use crate::protocol::{
     update::Update,
     warn::Warn,
   },
+  conversation::participant::{AddConversationParticipant, RemoveConversationParticipant},
   create_or_update::{note_wrapper::CreateOrUpdateNoteWrapper, page::CreateOrUpdatePage},
   deletion::{delete::Delete, undo_delete::UndoDelete},
   following::{
     accept::AcceptFollow,
     follow::Follow,
+    move_person::MovePerson,
     reject::RejectFollow,
     undo_follow::UndoFollow,
   },
+  reaction::{emoji_react::EmojiReact, undo_emoji_react::UndoEmojiReact},
   voting::{undo_vote::UndoVote, vote::Vote},
 };
 use activitypub_federation::{config::Data, traits::Activity};
@@ -44,8 +47,11 @@ pub enum SharedInboxActivities {
   AcceptFollow(AcceptFollow),
   RejectFollow(RejectFollow),
   UndoFollow(UndoFollow),
+  MovePerson(MovePerson),
   Report(Report),
   ResolveReport(ResolveReport),
+  AddConversationParticipant(AddConversationParticipant),
+  RemoveConversationParticipant(RemoveConversationParticipant),
   AnnounceActivity(AnnounceActivity),
   /// This is a catch-all and needs to be last
   RawAnnouncableActivities(RawAnnouncableActivities),
@@ -59,6 +65,8 @@ pub enum AnnouncableActivities {
   CreateOrUpdatePost(CreateOrUpdatePage),
   Vote(Vote),
   UndoVote(UndoVote),
+  EmojiReact(EmojiReact),
+  UndoEmojiReact(UndoEmojiReact),
   Delete(Delete),
   UndoDelete(UndoDelete),
   UpdateCommunity(Box<Update>),
@@ -83,6 +91,8 @@ impl InCommunity for AnnouncableActivities {
       CreateOrUpdatePost(a) => a.community(context).await,
       Vote(a) => a.community(context).await,
       UndoVote(a) => a.community(context).await,
+      EmojiReact(a) => a.community(context).await,
+      UndoEmojiReact(a) => a.community(context).await,
       Delete(a) => a.community(context).await,
       UndoDelete(a) => a.community(context).await,
       UpdateCommunity(a) => a.community(context).await,
@@ -128,6 +138,7 @@ mod tests {
       "../apub/assets/lemmy/activities/create_or_update/create_comment.json",
     )?;
     test_json::<SharedInboxActivities>("../apub/assets/mastodon/activities/follow.json")?;
+    test_json::<SharedInboxActivities>("../apub/assets/mastodon/activities/move.json")?;
     Ok(())
   }
 }
@@ -22,6 +22,7 @@ use lemmy_api_utils::{
     check_community_deleted_removed,
     remove_or_restore_user_data,
     remove_or_restore_user_data_in_community,
+    resolve_person_reports_for_site_ban,
   },
 };
 use lemmy_apub_objects::{
@@ -145,6 +146,8 @@ impl Activity for BlockUser {
       SiteOrCommunity::Left(site) => {
         let form = InstanceBanForm::new(blocked_person.id, site.instance_id, expires_at);
         InstanceActions::ban(pool, &form).await?;
+        resolve_person_reports_for_site_ban(&blocked_person, site.instance_id, mod_person.id, pool)
+          .await?;
 
         // Mod tables - create ban entry first so bulk actions can reference it as parent
         let form =
@@ -22,7 +22,12 @@ use lemmy_apub_objects::{
     protocol::{Id, InCommunity},
   },
 };
-use lemmy_db_schema::source::{activity::ActivitySendTargets, community::CommunityActions};
+use lemmy_db_schema::source::{
+  activity::ActivitySendTargets,
+  comment::Comment,
+  community::CommunityActions,
+  post::Post,
+};
 use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult, UntranslatedError};
 use serde_json::Value;
 use url::Url;
@@ -66,13 +71,42 @@ impl Activity for RawAnnouncableActivities {
       && community.local
     {
       verify_person_in_community(&ap_id, &community, context).await?;
-      AnnounceActivity::send(self, &community, context).await?;
+      if !is_approval_pending(&self, context).await {
+        AnnounceActivity::send(self, &community, context).await?;
+      }
     }
 
     Ok(())
   }
 }
 
+/// Activities about posts or comments in the mod queue are not announced. The object gets
+/// federated once a mod approves it.
+async fn is_approval_pending(
+  activity: &RawAnnouncableActivities,
+  context: &Data<LemmyContext>,
+) -> bool {
+  let object_id = activity
+    .other
+    .get("object")
+    .and_then(|o| o.get("id").or(Some(o)))
+    .and_then(Value::as_str)
+    .and_then(|o| Url::parse(o).ok());
+  let Some(object_id) = object_id else {
+    return false;
+  };
+  if let Ok(Some(post)) =
+    Post::read_from_apub_id(&mut context.pool(), object_id.clone().into()).await
+  {
+    return post.approval_pending;
+  }
+  Comment::read_from_apub_id(&mut context.pool(), object_id.into())
+    .await
+    .ok()
+    .flatten()
+    .is_some_and(|c| c.approval_pending)
+}
+
 impl Id for RawAnnouncableActivities {
   fn id(&self) -> &Url {
     &self.id
@@ -18,6 +18,7 @@ use lemmy_api_utils::{
 use lemmy_apub_objects::{
   objects::{community::ApubCommunity, person::ApubPerson, post::ApubPost},
   utils::{
+    check_is_mod_or_admin,
     functions::{generate_to, verify_mod_action, verify_visibility},
     protocol::InCommunity,
   },
@@ -27,7 +28,8 @@ use lemmy_db_schema::{
   newtypes::CommunityId,
   source::{
     activity::ActivitySendTargets,
-    community::{Community, CommunityActions, CommunityModeratorForm},
+    community::{Community, CommunityActions, CommunityModeratorForm, CommunityPersonFlairForm},
+    community_flair::CommunityFlair,
     modlog::{Modlog, ModlogInsertForm},
     person::Person,
     post::{Post, PostUpdateForm},
@@ -39,6 +41,30 @@ use lemmy_utils::error::{LemmyError, LemmyResult, UntranslatedError};
 use url::Url;
 
 impl CollectionAdd {
+  async fn send_add_flair(
+    community: &ApubCommunity,
+    flair: &CommunityFlair,
+    person: &ApubPerson,
+    actor: &ApubPerson,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    let id = generate_activity_id(AddType::Add, context)?;
+    let add = CollectionAdd {
+      actor: actor.id().clone().into(),
+      to: generate_to(community)?,
+      object: person.id().clone(),
+      target: flair.ap_id.clone().into(),
+      cc: vec![community.id().clone()],
+      kind: AddType::Add,
+      id: id.clone(),
+      audience: Some(community.ap_id.clone().into()),
+    };
+
+    let activity = AnnouncableActivities::CollectionAdd(add);
+    let inboxes = ActivitySendTargets::to_inbox(person.shared_inbox_or_inbox());
+    send_activity_in_community(activity, actor, community, inboxes, true, context).await
+  }
+
   async fn send_add_mod(
     community: &ApubCommunity,
     added_mod: &ApubPerson,
@@ -157,6 +183,18 @@ impl Activity for CollectionAdd {
         let action = Modlog::create(&mut context.pool(), &[form]).await?;
         notify_mod_action(action, context);
       }
+      CollectionType::Flair(flair_id) => {
+        let person = ObjectId::<ApubPerson>::from(self.object)
+          .dereference(context)
+          .await?;
+        // Users can only assign flair to themselves through their own instance, so remote
+        // assignments for local communities need to come from a moderator.
+        if community.local {
+          check_is_mod_or_admin(&mut context.pool(), actor.id, community.id).await?;
+        }
+        let form = CommunityPersonFlairForm::new(community.id, person.id, flair_id);
+        CommunityFlair::assign(&mut context.pool(), &form).await?;
+      }
     }
     Ok(())
   }
@@ -183,6 +221,24 @@ pub(crate) async fn send_add_mod_to_community(
   }
 }
 
+pub(crate) async fn send_assign_community_flair(
+  actor: Person,
+  community: Community,
+  target: Person,
+  flair: CommunityFlair,
+  added: bool,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let actor: ApubPerson = actor.into();
+  let community: ApubCommunity = community.into();
+  let target: ApubPerson = target.into();
+  if added {
+    CollectionAdd::send_add_flair(&community, &flair, &target, &actor, &context).await
+  } else {
+    CollectionRemove::send_remove_flair(&community, &flair, &target, &actor, &context).await
+  }
+}
+
 pub(crate) async fn send_feature_post(
   post: Post,
   actor: Person,
@@ -18,6 +18,7 @@ use lemmy_api_utils::{
 use lemmy_apub_objects::{
   objects::{community::ApubCommunity, person::ApubPerson, post::ApubPost},
   utils::{
+    check_is_mod_or_admin,
     functions::{generate_to, verify_mod_action, verify_visibility},
     protocol::InCommunity,
   },
@@ -27,6 +28,7 @@ use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
     community::{Community, CommunityActions, CommunityModeratorForm},
+    community_flair::CommunityFlair,
     local_user::LocalUser,
     modlog::{Modlog, ModlogInsertForm},
     post::{Post, PostUpdateForm},
@@ -37,6 +39,30 @@ use lemmy_utils::error::{LemmyError, LemmyResult, UntranslatedError};
 use url::Url;
 
 impl CollectionRemove {
+  pub(super) async fn send_remove_flair(
+    community: &ApubCommunity,
+    flair: &CommunityFlair,
+    person: &ApubPerson,
+    actor: &ApubPerson,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    let id = generate_activity_id(RemoveType::Remove, context)?;
+    let remove = CollectionRemove {
+      actor: actor.id().clone().into(),
+      to: generate_to(community)?,
+      object: person.id().clone(),
+      target: flair.ap_id.clone().into(),
+      id: id.clone(),
+      cc: vec![community.id().clone()],
+      kind: RemoveType::Remove,
+      audience: Some(community.ap_id.clone().into()),
+    };
+
+    let activity = AnnouncableActivities::CollectionRemove(remove);
+    let inboxes = ActivitySendTargets::to_inbox(person.shared_inbox_or_inbox());
+    send_activity_in_community(activity, actor, community, inboxes, true, context).await
+  }
+
   pub(super) async fn send_remove_mod(
     community: &ApubCommunity,
     removed_mod: &ApubPerson,
@@ -160,6 +186,22 @@ impl Activity for CollectionRemove {
         let action = Modlog::create(&mut context.pool(), &[form]).await?;
         notify_mod_action(action, context);
       }
+      CollectionType::Flair(flair_id) => {
+        let person = ObjectId::<ApubPerson>::from(self.object)
+          .dereference(context)
+          .await?;
+        if community.local {
+          check_is_mod_or_admin(&mut context.pool(), actor.id, community.id).await?;
+        }
+        // Ignore the removal if the person has received another flair in the meantime
+        let current_flair_id = CommunityActions::read(&mut context.pool(), community.id, person.id)
+          .await
+          .ok()
+          .and_then(|a| a.flair_id);
+        if current_flair_id == Some(flair_id) {
+          CommunityFlair::unassign(&mut context.pool(), community.id, person.id).await?;
+        }
+      }
     }
     Ok(())
   }
@@ -30,12 +30,14 @@ use lemmy_apub_objects::{
 use lemmy_db_schema::source::{
   activity::ActivitySendTargets,
   comment::Comment,
+  community::Community,
   modlog::{Modlog, ModlogInsertForm},
   person::Person,
   post::{Post, PostUpdateForm},
 };
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::error::{LemmyError, LemmyResult};
+use tracing::warn;
 use url::Url;
 
 #[async_trait::async_trait]
@@ -163,7 +165,46 @@ pub(crate) async fn send_lock(
   context: Data<LemmyContext>,
 ) -> LemmyResult<()> {
   let community: ApubCommunity = post_or_comment_community(&object, &context).await?.into();
-  let id = generate_activity_id(LockType::Lock, &context)?;
+  send_lock_in_community(object, community, actor, locked, reason, &context).await
+}
+
+/// Locks or unlocks many posts of the same community, without reading the community for each.
+pub(crate) async fn send_bulk_lock_posts(
+  posts: Vec<Post>,
+  community: Community,
+  actor: Person,
+  locked: bool,
+  reason: String,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let community: ApubCommunity = community.into();
+  for post in posts {
+    let ap_id = post.ap_id.clone();
+    let res = send_lock_in_community(
+      PostOrComment::Left(post.into()),
+      community.clone(),
+      actor.clone(),
+      locked,
+      reason.clone(),
+      &context,
+    )
+    .await;
+    if let Err(e) = res {
+      warn!("Failed to federate lock of {ap_id}: {e}");
+    }
+  }
+  Ok(())
+}
+
+async fn send_lock_in_community(
+  object: PostOrComment,
+  community: ApubCommunity,
+  actor: Person,
+  locked: bool,
+  reason: String,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let id = generate_activity_id(LockType::Lock, context)?;
   let community_id = community.ap_id.inner().clone();
   let ap_id = match object {
     PostOrComment::Left(p) => p.ap_id.clone(),
@@ -183,7 +224,7 @@ pub(crate) async fn send_lock(
   let activity = if locked {
     AnnouncableActivities::Lock(lock)
   } else {
-    let id = generate_activity_id(UndoType::Undo, &context)?;
+    let id = generate_activity_id(UndoType::Undo, context)?;
     let undo = UndoLockPageOrNote {
       actor: lock.actor.clone(),
       to: generate_to(&community)?,
@@ -202,7 +243,7 @@ pub(crate) async fn send_lock(
     &community,
     ActivitySendTargets::empty(),
     true,
-    &context,
+    context,
   )
   .await?;
   Ok(())
@@ -83,6 +83,20 @@ pub(crate) async fn send_activity_in_community(
   Ok(())
 }
 
+/// Federates content by a remote user which a mod approved from the queue of a local community.
+/// The original activity can't be signed by this instance, so it is only announced to the
+/// community followers.
+pub(crate) async fn announce_approved_in_community(
+  activity: AnnouncableActivities,
+  community: &ApubCommunity,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  if !community.visibility.can_federate() {
+    return Ok(());
+  }
+  AnnounceActivity::send(activity.try_into()?, community, context).await
+}
+
 async fn report_inboxes(
   object_id: ObjectId<ReportableObjects>,
   receiver: &Either<ApubSite, ApubCommunity>,
@@ -16,12 +16,14 @@ use activitypub_federation::{
 };
 use either::Either;
 use lemmy_api_utils::{
+  automod::{AutomodEvent, automod_comment, automod_post},
   context::LemmyContext,
   utils::{
     check_comment_deleted_or_removed,
     check_community_deleted_removed,
     check_post_deleted_or_removed,
   },
+  webhooks::send_webhook,
 };
 use lemmy_apub_objects::{
   objects::{
@@ -34,16 +36,20 @@ use lemmy_apub_objects::{
   utils::functions::{verify_person_in_community, verify_person_in_site_or_community},
 };
 use lemmy_db_schema::{
+  newtypes::{CommunityId, RuleId},
   source::{
     comment_report::{CommentReport, CommentReportForm},
     community::Community,
     community_report::{CommunityReport, CommunityReportForm},
+    person_report::{PersonReport, PersonReportForm},
     post::Post,
     post_report::{PostReport, PostReportForm},
     private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
+    rule::Rule,
   },
   traits::Reportable,
 };
+use lemmy_db_schema_file::enums::WebhookEventType;
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::error::{LemmyError, LemmyResult};
 use url::Url;
@@ -54,6 +60,7 @@ impl Report {
     actor: &ApubPerson,
     receiver: &Either<ApubSite, ApubCommunity>,
     reason: Option<String>,
+    rule: Option<Url>,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<Self> {
     let kind = FlagType::Flag;
@@ -64,6 +71,7 @@ impl Report {
       object: ReportObject::Lemmy(object_id.clone()),
       summary: reason,
       content: None,
+      rule,
       kind,
       id: id.clone(),
       audience: receiver.as_ref().right().map(|c| c.ap_id.clone().into()),
@@ -75,13 +83,22 @@ impl Report {
     actor: &ApubPerson,
     receiver: &Either<ApubSite, ApubCommunity>,
     reason: String,
+    rule: Option<Url>,
     context: Data<LemmyContext>,
   ) -> LemmyResult<()> {
-    let report = Self::new(&object_id, actor, receiver, Some(reason), &context)?;
+    let report = Self::new(&object_id, actor, receiver, Some(reason), rule, &context)?;
     let inboxes = report_inboxes(object_id, receiver, actor, &context).await?;
 
     send_lemmy_activity(&context, report, actor, inboxes, false).await
   }
+
+  /// The cited rule, if it is known and belongs to the reported community.
+  async fn rule_id(&self, community_id: CommunityId, context: &LemmyContext) -> Option<RuleId> {
+    let rule = Rule::read_apub(&mut context.pool(), &self.rule.clone()?.into())
+      .await
+      .ok()?;
+    (rule.community_id == Some(community_id)).then_some(rule.id)
+  }
 }
 
 #[async_trait::async_trait]
@@ -121,7 +138,8 @@ impl Activity for Report {
       ReportableObjects::Right(Either::Left(community)) => {
         check_community_deleted_removed(&community)?;
       }
-      ReportableObjects::Right(Either::Right(_private_message)) => {}
+      ReportableObjects::Right(Either::Right(Either::Left(_private_message))) => {}
+      ReportableObjects::Right(Either::Right(Either::Right(_person))) => {}
     }
     Ok(())
   }
@@ -139,8 +157,16 @@ impl Activity for Report {
           reason,
           original_post_body: post.body.clone(),
           violates_instance_rules: false,
+          rule_id: self.rule_id(post.community_id, context).await,
         };
-        PostReport::report(&mut context.pool(), &report_form).await?;
+        let report = PostReport::report(&mut context.pool(), &report_form).await?;
+        send_webhook(
+          WebhookEventType::NewReport,
+          Some(post.community_id),
+          &report,
+          context,
+        );
+        automod_post(post.id, AutomodEvent::Report, context).await;
       }
       ReportableObjects::Left(PostOrComment::Right(comment)) => {
         let report_form = CommentReportForm {
@@ -149,8 +175,16 @@ impl Activity for Report {
           original_comment_text: comment.content.clone(),
           reason,
           violates_instance_rules: false,
+          rule_id: self.rule_id(comment.community_id, context).await,
         };
-        CommentReport::report(&mut context.pool(), &report_form).await?;
+        let report = CommentReport::report(&mut context.pool(), &report_form).await?;
+        send_webhook(
+          WebhookEventType::NewReport,
+          Some(comment.community_id),
+          &report,
+          context,
+        );
+        automod_comment(comment.id, AutomodEvent::Report, context).await;
       }
       ReportableObjects::Right(Either::Left(community)) => {
         let report_form = CommunityReportForm {
@@ -164,16 +198,32 @@ impl Activity for Report {
           original_community_summary: community.summary.clone(),
           original_community_sidebar: community.sidebar.clone(),
         };
-        CommunityReport::report(&mut context.pool(), &report_form).await?;
+        let report = CommunityReport::report(&mut context.pool(), &report_form).await?;
+        send_webhook(WebhookEventType::NewReport, None, &report, context);
       }
-      ReportableObjects::Right(Either::Right(private_message)) => {
+      ReportableObjects::Right(Either::Right(Either::Left(private_message))) => {
         let form = PrivateMessageReportForm {
           creator_id: actor.id,
           private_message_id: private_message.id,
           original_pm_text: private_message.content.clone(),
           reason,
         };
-        PrivateMessageReport::report(&mut context.pool(), &form).await?;
+        let report = PrivateMessageReport::report(&mut context.pool(), &form).await?;
+        send_webhook(WebhookEventType::NewReport, None, &report, context);
+      }
+      ReportableObjects::Right(Either::Right(Either::Right(person))) => {
+        let form = PersonReportForm {
+          creator_id: actor.id,
+          person_id: person.id,
+          original_person_name: person.name.clone(),
+          original_person_display_name: person.display_name.clone(),
+          original_person_bio: person.bio.clone(),
+          original_person_avatar: person.avatar.clone(),
+          original_person_banner: person.banner.clone(),
+          reason,
+        };
+        let report = PersonReport::report(&mut context.pool(), &form).await?;
+        send_webhook(WebhookEventType::NewReport, None, &report, context);
       }
     };
 
@@ -32,14 +32,20 @@ use lemmy_apub_objects::{
 };
 use lemmy_db_schema::{
   source::{
+    comment::Comment,
     comment_report::CommentReport,
+    community::Community,
     community_report::CommunityReport,
+    person::Person,
+    person_report::PersonReport,
+    post::Post,
     post_report::PostReport,
     private_message_report::PrivateMessageReport,
   },
   traits::Reportable,
 };
 use lemmy_utils::error::{LemmyError, LemmyResult};
+use tracing::warn;
 use url::Url;
 
 impl ResolveReport {
@@ -52,7 +58,7 @@ impl ResolveReport {
   ) -> LemmyResult<()> {
     let kind = ResolveType::Resolve;
     let id = generate_activity_id(kind.clone(), &context)?;
-    let report = Report::new(&object_id, report_creator, receiver, None, &context)?;
+    let report = Report::new(&object_id, report_creator, receiver, None, None, &context)?;
     let resolve = ResolveReport {
       actor: actor.id().clone().into(),
       to: [receiver.id().clone().into()],
@@ -67,6 +73,61 @@ impl ResolveReport {
   }
 }
 
+/// Resolves many post and comment reports of the same community, without reading the community
+/// for each. Failures are logged, so that one unreachable inbox doesn't stop the other reports.
+pub(crate) async fn send_bulk_resolve_reports(
+  actor: Person,
+  community: Community,
+  post_reports: Vec<PostReport>,
+  comment_reports: Vec<CommentReport>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let actor: ApubPerson = actor.into();
+  let post_ids: Vec<_> = post_reports.iter().map(|r| r.post_id).collect();
+  let posts = Post::read_many_in_community(&mut context.pool(), &post_ids, community.id).await?;
+  let comment_ids: Vec<_> = comment_reports.iter().map(|r| r.comment_id).collect();
+  let comments =
+    Comment::read_many_in_community(&mut context.pool(), &comment_ids, community.id).await?;
+  let creator_ids: Vec<_> = post_reports
+    .iter()
+    .map(|r| r.creator_id)
+    .chain(comment_reports.iter().map(|r| r.creator_id))
+    .collect();
+  let creators = Person::read_many(&mut context.pool(), &creator_ids).await?;
+
+  let mut reports = vec![];
+  for report in post_reports {
+    if let Some(post) = posts.iter().find(|p| p.id == report.post_id) {
+      reports.push((post.ap_id.clone(), report.creator_id));
+    }
+  }
+  for report in comment_reports {
+    if let Some(comment) = comments.iter().find(|c| c.id == report.comment_id) {
+      reports.push((comment.ap_id.clone(), report.creator_id));
+    }
+  }
+
+  let receiver = Either::Right(community.into());
+  for (object_id, report_creator_id) in reports {
+    let Some(report_creator) = creators.iter().find(|p| p.id == report_creator_id) else {
+      continue;
+    };
+    let object_id: Url = object_id.into();
+    let res = ResolveReport::send(
+      ObjectId::from(object_id.clone()),
+      &actor,
+      &report_creator.clone().into(),
+      &receiver,
+      context.clone(),
+    )
+    .await;
+    if let Err(e) = res {
+      warn!("Failed to federate resolved report about {object_id}: {e}");
+    }
+  }
+  Ok(())
+}
+
 #[async_trait::async_trait]
 impl Activity for ResolveReport {
   type DataType = LemmyContext;
@@ -105,7 +166,7 @@ impl Activity for ResolveReport {
         CommunityReport::resolve_apub(&mut context.pool(), community.id, reporter.id, actor.id)
           .await?;
       }
-      ReportableObjects::Right(Either::Right(private_message)) => {
+      ReportableObjects::Right(Either::Right(Either::Left(private_message))) => {
         PrivateMessageReport::resolve_apub(
           &mut context.pool(),
           private_message.id,
@@ -114,6 +175,9 @@ impl Activity for ResolveReport {
         )
         .await?;
       }
+      ReportableObjects::Right(Either::Right(Either::Right(person))) => {
+        PersonReport::resolve_apub(&mut context.pool(), person.id, reporter.id, actor.id).await?;
+      }
     };
 
     let receiver = object.to[0].dereference(context).await?;
@@ -11,7 +11,11 @@ use activitypub_federation::{
   traits::{Activity, Object},
 };
 use either::Either;
-use lemmy_api_utils::{context::LemmyContext, utils::check_community_deleted_removed};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::check_community_deleted_removed,
+  webhooks::send_modlog_webhooks,
+};
 use lemmy_apub_objects::{
   objects::{community::ApubCommunity, multi_community::ApubMultiCommunity, person::ApubPerson},
   utils::{
@@ -127,7 +131,8 @@ impl Activity for Update {
         if old_community.visibility != community.visibility {
           let actor = self.actor.dereference(context).await?;
           let form = ModlogInsertForm::mod_change_community_visibility(actor.id, old_community.id);
-          Modlog::create(&mut context.pool(), &[form]).await?;
+          let actions = Modlog::create(&mut context.pool(), &[form]).await?;
+          send_modlog_webhooks(&actions, context);
         }
       }
       Either::Right(m) => {
@@ -5,11 +5,13 @@ use crate::{
   send_lemmy_activity,
 };
 use activitypub_federation::{config::Data, fetch::object_id::ObjectId, traits::Activity};
+use chrono::{DateTime, Utc};
 use either::Either;
 use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
   utils::check_community_deleted_removed,
+  warning_policy::apply_warning_policy,
 };
 use lemmy_apub_objects::{
   objects::{PostOrComment, person::ApubPerson},
@@ -32,6 +34,7 @@ impl Warn {
     actor: &ApubPerson,
     recipient: ObjectId<ApubPerson>,
     reason: String,
+    expires_at: Option<DateTime<Utc>>,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<Self> {
     let kind = WarnType::Warn;
@@ -44,12 +47,14 @@ impl Warn {
       kind,
       id,
       audience: community.clone().ap_id.into(),
+      end_time: expires_at,
     })
   }
 
   pub(crate) async fn send(
     post_or_comment: Either<PostView, CommentView>,
     reason: String,
+    expires_at: Option<DateTime<Utc>>,
     actor: ApubPerson,
     context: Data<LemmyContext>,
   ) -> LemmyResult<()> {
@@ -58,15 +63,21 @@ impl Warn {
       .clone()
       .either(|p| p.post.ap_id, |c| c.comment.ap_id);
     let community = post_or_comment.either(|p| p.community, |c| c.community);
+    let community_inbox = (!community.local).then(|| community.inbox_url.clone());
     let warn = Self::new(
       object_id.into(),
       &community,
       &actor,
       recipient.ap_id.clone().into(),
       reason,
+      expires_at,
       &context,
     )?;
-    let inbox = ActivitySendTargets::to_inbox(recipient.inbox_url.into());
+    let mut inbox = ActivitySendTargets::to_inbox(recipient.inbox_url.into());
+    // The instance of the community needs the warning too, to apply its warning policy
+    if let Some(community_inbox) = community_inbox {
+      inbox.add_inbox(community_inbox.into());
+    }
 
     send_lemmy_activity(&context, warn, &actor, inbox, false).await
   }
@@ -112,8 +123,14 @@ impl Activity for Warn {
         &self.summary,
       ),
     };
-    let action = Modlog::create(&mut context.pool(), &[form]).await?;
+    let action =
+      Modlog::create(&mut context.pool(), &[form.with_expires_at(self.end_time)]).await?;
+    let warned_person_id = action.first().and_then(|a| a.target_person_id);
     notify_mod_action(action, context);
+
+    if let Some(person_id) = warned_person_id {
+      apply_warning_policy(&community, person_id, context).await;
+    }
     Ok(())
   }
 }
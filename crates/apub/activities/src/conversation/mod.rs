@@ -0,0 +1,150 @@
+use crate::{
+  generate_activity_id,
+  protocol::conversation::participant::{
+    AddConversationParticipant,
+    RemoveConversationParticipant,
+  },
+  send_lemmy_activity,
+  verify_person,
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::activity::{AddType, RemoveType},
+  protocol::verification::{verify_domains_match, verify_urls_match},
+  traits::{Activity, Actor, Object},
+};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::objects::{
+  conversation_message::add_conversation_participants,
+  person::ApubPerson,
+};
+use lemmy_db_schema::source::{
+  activity::ActivitySendTargets,
+  conversation::{Conversation, ConversationParticipant},
+  person::Person,
+};
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
+use url::Url;
+
+/// Sends the membership change to all participants of the conversation. When removing someone,
+/// this needs to be called before the removal so that they are informed as well.
+pub(crate) async fn send_conversation_participant(
+  conversation: Conversation,
+  actor: Person,
+  participant: Person,
+  added: bool,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let actor: ApubPerson = actor.into();
+  let participants = Conversation::list_participants(&mut context.pool(), conversation.id).await?;
+  let mut inbox = ActivitySendTargets::empty();
+  let mut to = vec![];
+  for p in participants {
+    let p: ApubPerson = p.into();
+    if !p.local {
+      inbox.add_inbox(p.shared_inbox_or_inbox());
+    }
+    to.push(p.id().clone().into());
+  }
+
+  let object = participant.ap_id.into();
+  let context_url = conversation.ap_id.into();
+  if added {
+    let add = AddConversationParticipant {
+      actor: actor.id().clone().into(),
+      to,
+      object,
+      context: context_url,
+      kind: AddType::Add,
+      id: generate_activity_id(AddType::Add, &context)?,
+    };
+    send_lemmy_activity(&context, add, &actor, inbox, true).await
+  } else {
+    let remove = RemoveConversationParticipant {
+      actor: actor.id().clone().into(),
+      to,
+      object,
+      context: context_url,
+      kind: RemoveType::Remove,
+      id: generate_activity_id(RemoveType::Remove, &context)?,
+    };
+    send_lemmy_activity(&context, remove, &actor, inbox, true).await
+  }
+}
+
+#[async_trait::async_trait]
+impl Activity for AddConversationParticipant {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    verify_person(&self.actor, context).await?;
+    verify_domains_match(self.actor.inner(), &self.id)?;
+    // Only the conversation creator can add people, so the actor is on the same instance as the
+    // conversation
+    verify_domains_match(self.actor.inner(), &self.context)?;
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    let actor = self.actor.dereference(context).await?;
+    let conversation =
+      Conversation::read_or_create_from_apub_id(&mut context.pool(), self.context.into(), actor.id)
+        .await?;
+    if conversation.creator_id != actor.id {
+      return Err(LemmyErrorType::OnlyConversationCreatorCanAddParticipants.into());
+    }
+    // Includes the existing participants, in case the conversation wasn't known here before
+    let mut recipients = self.to;
+    recipients.push(self.object);
+    add_conversation_participants(conversation.id, &actor, &recipients, context).await
+  }
+}
+
+#[async_trait::async_trait]
+impl Activity for RemoveConversationParticipant {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    verify_person(&self.actor, context).await?;
+    verify_domains_match(self.actor.inner(), &self.id)?;
+    // Everyone can leave, but only the conversation creator can remove others
+    if verify_urls_match(self.actor.inner(), self.object.inner()).is_err() {
+      verify_domains_match(self.actor.inner(), &self.context)?;
+    }
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    let Some(conversation) =
+      Conversation::read_from_apub_id(&mut context.pool(), self.context.into()).await?
+    else {
+      return Ok(());
+    };
+    let actor = self.actor.dereference(context).await?;
+    ConversationParticipant::read(&mut context.pool(), conversation.id, actor.id).await?;
+    let person = self.object.dereference(context).await?;
+    if person.id != actor.id && conversation.creator_id != actor.id {
+      return Err(LemmyErrorType::OnlyConversationCreatorCanRemoveParticipants.into());
+    }
+    ConversationParticipant::remove(&mut context.pool(), conversation.id, person.id).await?;
+    Ok(())
+  }
+}
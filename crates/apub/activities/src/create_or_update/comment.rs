@@ -1,6 +1,6 @@
 use crate::{
   activity_lists::AnnouncableActivities,
-  community::send_activity_in_community,
+  community::{announce_approved_in_community, send_activity_in_community},
   create_or_update::{parse_apub_mentions, tagged_user_inboxes},
   generate_activity_id,
   protocol::{CreateOrUpdateType, create_or_update::note::CreateOrUpdateNote},
@@ -11,6 +11,7 @@ use activitypub_federation::{
   traits::{Activity, Object},
 };
 use lemmy_api_utils::{
+  automod::{AutomodEvent, automod_comment},
   context::LemmyContext,
   notify::NotifyData,
   utils::{
@@ -80,6 +81,10 @@ impl CreateOrUpdateNote {
     // to convert this to NoteWrapper, by serializing and then deserializing again.
     let converted = from_value(to_value(create_or_update)?)?;
     let activity = AnnouncableActivities::CreateOrUpdateNoteWrapper(converted);
+    // Only happens for remote comments approved from the mod queue
+    if !person.local && community.local {
+      return announce_approved_in_community(activity, &community, &context).await;
+    }
     send_activity_in_community(activity, &person, &community, inboxes, false, &context).await
   }
 }
@@ -121,6 +126,7 @@ impl Activity for CreateOrUpdateNote {
     // Need to do this check here instead of Note::from_json because we need the person who
     // send the activity, not the comment author.
     let existing_comment = self.object.id.dereference_local(context).await.ok();
+    let is_new = existing_comment.is_none();
     let (post, _) = self.object.get_parents(context).await?;
     if let (Some(distinguished), Some(existing_comment)) =
       (self.object.distinguished, existing_comment)
@@ -139,6 +145,22 @@ impl Activity for CreateOrUpdateNote {
     // Calculate initial hot_rank
     Comment::update_hot_rank(&mut context.pool(), comment.id).await?;
 
+    // Only check comments which are new to this instance. Automod can hold them for approval, so
+    // this needs to happen before the community announces them.
+    let mut approval_pending = comment.approval_pending;
+    if self.kind == CreateOrUpdateType::Create && is_new {
+      approval_pending =
+        approval_pending || automod_comment(comment.id, AutomodEvent::BeforeCreate, context).await;
+      if !approval_pending {
+        automod_comment(comment.id, AutomodEvent::Create, context).await;
+      }
+    }
+
+    // Notifications for comments in the mod queue are sent once approved
+    if approval_pending {
+      return Ok(());
+    }
+
     let do_send_email =
       self.kind == CreateOrUpdateType::Create && !site_view.local_site.email_notifications_disabled;
     let actor = self.actor.dereference(context).await?;
@@ -0,0 +1,80 @@
+use crate::{
+  generate_activity_id,
+  protocol::{
+    CreateOrUpdateType,
+    create_or_update::conversation_message::CreateOrUpdateConversationMessage,
+  },
+  send_lemmy_activity,
+  verify_person,
+};
+use activitypub_federation::{
+  config::Data,
+  protocol::verification::{verify_domains_match, verify_urls_match},
+  traits::{Activity, Actor, Object},
+};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::objects::{
+  conversation_message::ApubConversationMessage,
+  person::ApubPerson,
+};
+use lemmy_db_schema::source::{activity::ActivitySendTargets, conversation::Conversation};
+use lemmy_db_views_conversation::ConversationMessageView;
+use lemmy_utils::error::{LemmyError, LemmyResult};
+use url::Url;
+
+pub(crate) async fn send_create_or_update_conversation_message(
+  message_view: ConversationMessageView,
+  kind: CreateOrUpdateType,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let actor: ApubPerson = message_view.creator.into();
+  let conversation_id = message_view.conversation_message.conversation_id;
+  let object = ApubConversationMessage(message_view.conversation_message)
+    .into_json(&context)
+    .await?;
+
+  // Each remote participant gets the message in their own inbox
+  let participants = Conversation::list_participants(&mut context.pool(), conversation_id).await?;
+  let mut inbox = ActivitySendTargets::empty();
+  for participant in participants.into_iter().filter(|p| !p.local) {
+    let participant: ApubPerson = participant.into();
+    inbox.add_inbox(participant.shared_inbox_or_inbox());
+  }
+
+  let id = generate_activity_id(kind.clone(), &context)?;
+  let create_or_update = CreateOrUpdateConversationMessage {
+    id,
+    actor: actor.id().clone().into(),
+    to: object.to.clone(),
+    object,
+    kind,
+  };
+  send_lemmy_activity(&context, create_or_update, &actor, inbox, true).await
+}
+
+#[async_trait::async_trait]
+impl Activity for CreateOrUpdateConversationMessage {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    verify_person(&self.actor, context).await?;
+    verify_domains_match(self.actor.inner(), self.object.id.inner())?;
+    verify_urls_match(self.actor.inner(), self.object.attributed_to.inner())?;
+    ApubConversationMessage::verify(&self.object, self.actor.inner(), context).await?;
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    ApubConversationMessage::from_json(self.object, context).await?;
+    Ok(())
+  }
+}
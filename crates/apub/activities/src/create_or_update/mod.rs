@@ -5,7 +5,10 @@ use lemmy_db_schema::source::{activity::ActivitySendTargets, person::Person};
 use lemmy_utils::error::LemmyResult;
 
 pub mod comment;
+pub mod conversation_message;
+pub mod modmail_message;
 pub(crate) mod note_wrapper;
+pub mod poll_vote;
 pub mod post;
 pub mod private_message;
 
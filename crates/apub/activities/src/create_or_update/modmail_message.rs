@@ -0,0 +1,104 @@
+use crate::{
+  generate_activity_id,
+  protocol::{CreateOrUpdateType, create_or_update::modmail_message::CreateOrUpdateModmailMessage},
+  send_lemmy_activity,
+};
+use activitypub_federation::{
+  config::Data,
+  protocol::verification::{verify_domains_match, verify_urls_match},
+  traits::{Activity, Actor, Object},
+};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::objects::{
+  community::ApubCommunity,
+  modmail_message::ApubModmailMessage,
+  person::ApubPerson,
+};
+use lemmy_db_schema::source::{
+  activity::ActivitySendTargets,
+  community::Community,
+  modmail::{ModmailMessage, ModmailThread},
+  person::Person,
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyError, LemmyResult};
+use url::Url;
+
+/// Messages of a user go to the community, and replies of the moderators are sent by the community
+/// to the user. Nothing needs to be sent if the recipient is local.
+pub(crate) async fn send_create_modmail_message(
+  message: ModmailMessage,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  if message.internal {
+    return Ok(());
+  }
+  let thread = ModmailThread::read(&mut context.pool(), message.modmail_thread_id).await?;
+  let community: ApubCommunity = Community::read(&mut context.pool(), thread.community_id)
+    .await?
+    .into();
+  let person: ApubPerson = Person::read(&mut context.pool(), thread.creator_id)
+    .await?
+    .into();
+  let object = ApubModmailMessage(message.clone())
+    .into_json(&context)
+    .await?;
+  let id = generate_activity_id(CreateOrUpdateType::Create, &context)?;
+
+  if message.from_moderator {
+    if person.local {
+      return Ok(());
+    }
+    let create = CreateOrUpdateModmailMessage {
+      id,
+      actor: community.id().clone().into(),
+      to: [person.id().clone().into()],
+      object,
+      kind: CreateOrUpdateType::Create,
+    };
+    let inbox = ActivitySendTargets::to_inbox(person.shared_inbox_or_inbox());
+    send_lemmy_activity(&context, create, &community, inbox, true).await
+  } else {
+    if community.local {
+      return Ok(());
+    }
+    let create = CreateOrUpdateModmailMessage {
+      id,
+      actor: person.id().clone().into(),
+      to: [community.id().clone().into()],
+      object,
+      kind: CreateOrUpdateType::Create,
+    };
+    let inbox = ActivitySendTargets::to_inbox(community.shared_inbox_or_inbox());
+    send_lemmy_activity(&context, create, &person, inbox, true).await
+  }
+}
+
+#[async_trait::async_trait]
+impl Activity for CreateOrUpdateModmailMessage {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    let [to] = &self.to;
+    let [object_to] = &self.object.to;
+    verify_domains_match(self.actor.inner(), self.object.id.inner())?;
+    verify_urls_match(self.actor.inner(), self.object.attributed_to.inner())?;
+    verify_urls_match(to.inner(), object_to.inner())?;
+    ApubModmailMessage::verify(&self.object, self.actor.inner(), context).await?;
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    ApubModmailMessage::from_json(self.object, context).await?;
+    Ok(())
+  }
+}
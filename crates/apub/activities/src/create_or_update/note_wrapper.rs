@@ -1,19 +1,23 @@
 use crate::protocol::create_or_update::{
+  conversation_message::CreateOrUpdateConversationMessage,
+  modmail_message::CreateOrUpdateModmailMessage,
   note::CreateOrUpdateNote,
   note_wrapper::CreateOrUpdateNoteWrapper,
   page::CreateOrUpdatePage,
+  poll_vote::CreatePollVote,
   private_message::CreateOrUpdatePrivateMessage,
 };
 use activitypub_federation::{config::Data, traits::Activity};
 use lemmy_api_utils::context::LemmyContext;
 use lemmy_apub_objects::{objects::community::ApubCommunity, utils::protocol::InCommunity};
+use lemmy_db_schema::source::conversation::Conversation;
 use lemmy_utils::error::{LemmyError, LemmyResult};
 use serde_json::{from_value, to_value};
 use url::Url;
 
-/// In Activitypub, both private messages and comments are represented by `type: Note` which
-/// makes it difficult to distinguish them. This wrapper handles receiving of both types, and
-/// routes them to the correct handler.
+/// In Activitypub, private messages, comments and poll votes are represented by `type: Note`
+/// which makes it difficult to distinguish them. This wrapper handles receiving of these types,
+/// and routes them to the correct handler.
 #[async_trait::async_trait]
 impl Activity for CreateOrUpdateNoteWrapper {
   type DataType = LemmyContext;
@@ -59,6 +63,51 @@ impl Activity for CreateOrUpdateNoteWrapper {
       return Ok(());
     }
 
+    // Votes in polls are also sent as `Create/Note`, with the chosen option in `name`.
+    let poll_vote = from_value::<CreatePollVote>(val.clone());
+    if let Ok(poll_vote) = poll_vote {
+      CreatePollVote::verify(&poll_vote, context).await?;
+      CreatePollVote::receive(poll_vote, context).await?;
+      return Ok(());
+    }
+
+    // Messages addressed to several people, or belonging to a known conversation, are group
+    // conversations.
+    let conversation_message = from_value::<CreateOrUpdateConversationMessage>(val.clone());
+    if let Ok(conversation_message) = conversation_message
+      && (conversation_message.object.to.len() > 1
+        || Conversation::read_from_apub_id(
+          &mut context.pool(),
+          conversation_message.object.context.clone().into(),
+        )
+        .await?
+        .is_some())
+    {
+      CreateOrUpdateConversationMessage::verify(&conversation_message, context).await?;
+      CreateOrUpdateConversationMessage::receive(conversation_message, context).await?;
+      return Ok(());
+    }
+
+    // Messages from or to a community are modmail. If the sender or recipient can't be fetched as
+    // a community, try to handle it as a private message instead.
+    let modmail = from_value::<CreateOrUpdateModmailMessage>(val.clone());
+    if let Ok(modmail) = modmail
+      && (modmail
+        .object
+        .attributed_to
+        .dereference(context)
+        .await
+        .is_ok_and(|a| a.is_right())
+        || modmail.object.to[0]
+          .dereference(context)
+          .await
+          .is_ok_and(|r| r.is_right()))
+    {
+      CreateOrUpdateModmailMessage::verify(&modmail, context).await?;
+      CreateOrUpdateModmailMessage::receive(modmail, context).await?;
+      return Ok(());
+    }
+
     // If any of the previous checks failed, we are dealing with a private message.
     let private_message = from_value(val)?;
     CreateOrUpdatePrivateMessage::verify(&private_message, context).await?;
@@ -0,0 +1,112 @@
+use crate::{
+  generate_activity_id,
+  protocol::create_or_update::poll_vote::{CreatePollVote, PollVoteNote},
+  send_lemmy_activity,
+  verify_person,
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::{activity::CreateType, object::NoteType},
+  protocol::verification::{verify_domains_match, verify_urls_match},
+  traits::{Activity, Actor, Object},
+};
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::objects::person::ApubPerson;
+use lemmy_db_schema::source::{
+  activity::ActivitySendTargets,
+  person::Person,
+  poll::{Poll, PollOption, PollVote, PollVoteForm},
+  post::Post,
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
+use url::Url;
+
+pub(crate) async fn send_poll_vote(
+  post: Post,
+  actor: Person,
+  options: Vec<String>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let actor: ApubPerson = actor.into();
+  let creator: ApubPerson = Person::read(&mut context.pool(), post.creator_id)
+    .await?
+    .into();
+  let inbox = ActivitySendTargets::to_inbox(creator.shared_inbox_or_inbox());
+
+  // Each option is sent as a separate activity, same as Mastodon does.
+  for name in options {
+    let vote = CreatePollVote {
+      id: generate_activity_id(CreateType::Create, &context)?,
+      actor: actor.id().clone().into(),
+      to: [creator.id().clone().into()],
+      object: PollVoteNote {
+        kind: NoteType::Note,
+        id: generate_activity_id(NoteType::Note, &context)?,
+        attributed_to: actor.id().clone().into(),
+        to: [creator.id().clone().into()],
+        name,
+        in_reply_to: post.ap_id.clone().into(),
+      },
+      kind: CreateType::Create,
+    };
+    send_lemmy_activity(&context, vote, &actor, inbox.clone(), true).await?;
+  }
+  Ok(())
+}
+
+#[async_trait::async_trait]
+impl Activity for CreatePollVote {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    verify_person(&self.actor, context).await?;
+    verify_domains_match(self.actor.inner(), &self.object.id)?;
+    verify_urls_match(self.actor.inner(), self.object.attributed_to.inner())?;
+    // Votes are only accepted for polls on this instance, the updated vote counts are then
+    // federated with `Update/Question`.
+    let post = self.object.in_reply_to.dereference_local(context).await?;
+    if !post.local {
+      return Err(LemmyErrorType::InvalidPoll.into());
+    }
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    let post = self.object.in_reply_to.dereference_local(context).await?;
+    let person = self.actor.dereference(context).await?;
+    let poll = Poll::read(&mut context.pool(), post.id)
+      .await?
+      .ok_or(LemmyErrorType::InvalidPoll)?;
+    if poll.end_time_at.is_some_and(|e| e < Utc::now()) {
+      return Err(LemmyErrorType::PollClosed.into());
+    }
+    let option = PollOption::list_for_post(&mut context.pool(), post.id)
+      .await?
+      .into_iter()
+      .find(|o| o.name == self.object.name)
+      .ok_or(LemmyErrorType::InvalidPoll)?;
+
+    if poll.multiple_choice {
+      let form = PollVoteForm {
+        poll_option_id: option.id,
+        person_id: person.id,
+        post_id: post.id,
+      };
+      PollVote::add(&mut context.pool(), &form).await?;
+    } else {
+      PollVote::vote(&mut context.pool(), post.id, person.id, &[option.id]).await?;
+    }
+    Poll::mark_votes_changed(&mut context.pool(), post.id).await
+  }
+}
@@ -1,6 +1,6 @@
 use crate::{
   activity_lists::AnnouncableActivities,
-  community::send_activity_in_community,
+  community::{announce_approved_in_community, send_activity_in_community},
   create_or_update::{parse_apub_mentions, tagged_user_inboxes},
   generate_activity_id,
   protocol::{CreateOrUpdateType, create_or_update::page::CreateOrUpdatePage},
@@ -12,6 +12,7 @@ use activitypub_federation::{
 };
 use chrono::Utc;
 use lemmy_api_utils::{
+  automod::{AutomodEvent, automod_post},
   context::LemmyContext,
   notify::NotifyData,
   utils::check_community_deleted_removed,
@@ -77,6 +78,10 @@ impl CreateOrUpdatePage {
       CreateOrUpdatePage::new(post.into(), &person, &community, kind, &context).await?;
     let inboxes = tagged_user_inboxes(&create_or_update.object.tag, &context).await?;
     let activity = AnnouncableActivities::CreateOrUpdatePost(create_or_update);
+    // Only happens for remote posts approved from the mod queue
+    if !person.local && community.local {
+      return announce_approved_in_community(activity, &community, &context).await;
+    }
     send_activity_in_community(activity, &person, &community, inboxes, false, &context).await?;
     Ok(())
   }
@@ -110,6 +115,7 @@ impl Activity for CreateOrUpdatePage {
       verify_urls_match(self.actor.inner(), self.object.creator()?.inner()).is_ok();
     let original_post =
       Post::read_from_apub_id(&mut context.pool(), self.object.id.clone().into()).await;
+    let is_new = matches!(original_post, Ok(None));
     let is_mod_action = verify_mod_action(&self.actor, self.object.id.inner(), &community, context)
       .await
       .is_ok();
@@ -146,6 +152,22 @@ impl Activity for CreateOrUpdatePage {
     // Calculate initial hot_rank for post
     Post::update_ranks(&mut context.pool(), post.id).await?;
 
+    // Only check posts which are new to this instance. Automod can hold them for approval, so
+    // this needs to happen before the community announces them.
+    let mut approval_pending = post.approval_pending;
+    if self.kind == CreateOrUpdateType::Create && is_new {
+      approval_pending =
+        approval_pending || automod_post(post.id, AutomodEvent::BeforeCreate, context).await;
+      if !approval_pending {
+        automod_post(post.id, AutomodEvent::Create, context).await;
+      }
+    }
+
+    // Notifications for posts in the mod queue are sent once approved
+    if approval_pending {
+      return Ok(());
+    }
+
     let do_send_email =
       self.kind == CreateOrUpdateType::Create && !site_view.local_site.email_notifications_disabled;
     let actor = self.actor.dereference(context).await?;
@@ -235,7 +235,9 @@ pub(crate) async fn receive_remove_action(
       }
     }
     // TODO these need to be implemented yet, for now, return errors
-    DeletableObjects::PrivateMessage(_) => return Err(LemmyErrorType::NotFound.into()),
+    DeletableObjects::PrivateMessage(_) | DeletableObjects::ConversationMessage(_) => {
+      return Err(LemmyErrorType::NotFound.into());
+    }
     DeletableObjects::Person(_) => return Err(LemmyErrorType::NotFound.into()),
   }
   Ok(())
@@ -21,6 +21,7 @@ use lemmy_apub_objects::{
   objects::{
     comment::ApubComment,
     community::ApubCommunity,
+    conversation_message::ApubConversationMessage,
     person::ApubPerson,
     post::ApubPost,
     private_message::ApubPrivateMessage,
@@ -40,6 +41,11 @@ use lemmy_db_schema::source::{
   activity::ActivitySendTargets,
   comment::{Comment, CommentUpdateForm},
   community::{Community, CommunityUpdateForm},
+  conversation::{
+    Conversation,
+    ConversationMessage as DbConversationMessage,
+    ConversationMessageUpdateForm,
+  },
   person::Person,
   post::{Post, PostUpdateForm},
   private_message::{PrivateMessage as DbPrivateMessage, PrivateMessageUpdateForm},
@@ -47,8 +53,12 @@ use lemmy_db_schema::source::{
 use lemmy_db_schema_file::enums::CommunityVisibility;
 use lemmy_db_views_site::{SiteView, api::DeleteUserForm};
 use lemmy_diesel_utils::traits::Crud;
-use lemmy_utils::{error::LemmyResult, spawn_try_task};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  spawn_try_task,
+};
 use std::ops::Deref;
+use tracing::warn;
 use url::Url;
 
 pub mod delete;
@@ -106,6 +116,44 @@ pub(crate) async fn send_apub_delete_in_community(
   .await
 }
 
+/// Removes or restores many posts and comments of the same community, without reading the
+/// community for each.
+pub(crate) async fn send_apub_bulk_remove_in_community(
+  moderator: Person,
+  community: Community,
+  posts: Vec<Post>,
+  comments: Vec<Comment>,
+  reason: String,
+  removed: bool,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let objects = posts
+    .into_iter()
+    .map(|p| DeletableObjects::Post(p.into()))
+    .chain(
+      comments
+        .into_iter()
+        .map(|c| DeletableObjects::Comment(c.into())),
+    );
+  for object in objects {
+    let ap_id = object.id().clone();
+    let res = send_apub_delete_in_community(
+      moderator.clone(),
+      community.clone(),
+      object,
+      Some(reason.clone()),
+      removed,
+      None,
+      context,
+    )
+    .await;
+    if let Err(e) = res {
+      warn!("Failed to federate removal of {ap_id}: {e}");
+    }
+  }
+  Ok(())
+}
+
 pub(crate) async fn send_apub_delete_private_message(
   actor: &ApubPerson,
   pm: DbPrivateMessage,
@@ -145,6 +193,35 @@ pub(crate) async fn send_apub_delete_private_message(
   Ok(())
 }
 
+/// Sent to the instances of all other participants of the conversation.
+pub(crate) async fn send_apub_delete_conversation_message(
+  actor: &ApubPerson,
+  message: DbConversationMessage,
+  deleted: bool,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let participants =
+    Conversation::list_participants(&mut context.pool(), message.conversation_id).await?;
+  let mut inbox = ActivitySendTargets::empty();
+  let mut to = vec![];
+  for participant in participants.into_iter().filter(|p| p.id != actor.id) {
+    let participant: ApubPerson = participant.into();
+    if !participant.local {
+      inbox.add_inbox(participant.shared_inbox_or_inbox());
+    }
+    to.push(participant.id().clone());
+  }
+
+  let deletable = DeletableObjects::ConversationMessage(message.into());
+  if deleted {
+    let delete = Delete::new(actor, deletable, to, None, None, None, &context)?;
+    send_lemmy_activity(&context, delete, actor, inbox, true).await
+  } else {
+    let undo = UndoDelete::new(actor, deletable, to, None, None, None, &context)?;
+    send_lemmy_activity(&context, undo, actor, inbox, true).await
+  }
+}
+
 pub async fn send_apub_delete_user(
   person: Person,
   remove_data: bool,
@@ -176,6 +253,7 @@ pub enum DeletableObjects {
   Comment(ApubComment),
   Post(ApubPost),
   PrivateMessage(ApubPrivateMessage),
+  ConversationMessage(ApubConversationMessage),
 }
 
 impl DeletableObjects {
@@ -198,6 +276,9 @@ impl DeletableObjects {
     if let Some(p) = ApubPrivateMessage::read_from_id(ap_id.clone(), context).await? {
       return Ok(DeletableObjects::PrivateMessage(p));
     }
+    if let Some(m) = ApubConversationMessage::read_from_id(ap_id.clone(), context).await? {
+      return Ok(DeletableObjects::ConversationMessage(m));
+    }
     Err(diesel::NotFound.into())
   }
 
@@ -208,6 +289,7 @@ impl DeletableObjects {
       DeletableObjects::Comment(c) => c.ap_id.inner(),
       DeletableObjects::Post(p) => p.ap_id.inner(),
       DeletableObjects::PrivateMessage(p) => p.ap_id.inner(),
+      DeletableObjects::ConversationMessage(m) => m.ap_id.inner(),
     }
   }
 }
@@ -262,6 +344,13 @@ pub(crate) async fn verify_delete_activity(
       verify_person(&activity.actor, context).await?;
       verify_domains_match(activity.actor.inner(), activity.object.id())?;
     }
+    DeletableObjects::ConversationMessage(m) => {
+      verify_person(&activity.actor, context).await?;
+      let actor = activity.actor.dereference(context).await?;
+      if actor.id != m.creator_id {
+        return Err(LemmyErrorType::EditConversationMessageNotAllowed.into());
+      }
+    }
   }
   Ok(())
 }
@@ -367,6 +456,17 @@ async fn receive_delete_action(
       )
       .await?;
     }
+    DeletableObjects::ConversationMessage(message) => {
+      DbConversationMessage::update(
+        &mut context.pool(),
+        message.id,
+        &ConversationMessageUpdateForm {
+          deleted: Some(deleted),
+          ..Default::default()
+        },
+      )
+      .await?;
+    }
   }
   Ok(())
 }
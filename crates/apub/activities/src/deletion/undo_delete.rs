@@ -218,7 +218,9 @@ impl UndoDelete {
         }
       }
       // TODO these need to be implemented yet, for now, return errors
-      DeletableObjects::PrivateMessage(_) => return Err(LemmyErrorType::NotFound.into()),
+      DeletableObjects::PrivateMessage(_) | DeletableObjects::ConversationMessage(_) => {
+        return Err(LemmyErrorType::NotFound.into());
+      }
       DeletableObjects::Person(_) => return Err(LemmyErrorType::NotFound.into()),
     }
     Ok(())
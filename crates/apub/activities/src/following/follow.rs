@@ -11,7 +11,7 @@ use activitypub_federation::{
 };
 use either::Either::*;
 use lemmy_api_utils::{context::LemmyContext, utils::check_community_deleted_removed};
-use lemmy_apub_objects::objects::{CommunityOrMulti, person::ApubPerson};
+use lemmy_apub_objects::objects::{UserOrCommunityOrMulti, person::ApubPerson};
 use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
@@ -31,7 +31,7 @@ use url::Url;
 impl Follow {
   pub(in crate::following) fn new(
     actor: &ApubPerson,
-    target: &CommunityOrMulti,
+    target: &UserOrCommunityOrMulti,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<Follow> {
     Ok(Follow {
@@ -45,7 +45,7 @@ impl Follow {
 
   pub async fn send(
     actor: &ApubPerson,
-    target: &CommunityOrMulti,
+    target: &UserOrCommunityOrMulti,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
     let follow = Follow::new(actor, target, context)?;
@@ -8,7 +8,7 @@ use crate::protocol::following::{
 use activitypub_federation::{config::Data, kinds::activity::FollowType, traits::Activity};
 use either::Either::*;
 use lemmy_api_utils::context::LemmyContext;
-use lemmy_apub_objects::objects::{CommunityOrMulti, UserOrCommunityOrMulti, person::ApubPerson};
+use lemmy_apub_objects::objects::{UserOrCommunityOrMulti, person::ApubPerson};
 use lemmy_db_schema::{
   newtypes::CommunityId,
   source::{activity::ActivitySendTargets, community::Community, person::Person},
@@ -20,11 +20,12 @@ use serde::Serialize;
 
 pub(crate) mod accept;
 pub(crate) mod follow;
+pub(crate) mod move_person;
 pub(crate) mod reject;
 pub(crate) mod undo_follow;
 
 pub async fn send_follow(
-  target: CommunityOrMulti,
+  target: UserOrCommunityOrMulti,
   person: Person,
   follow: bool,
   context: &Data<LemmyContext>,
@@ -0,0 +1,63 @@
+use crate::{
+  generate_activity_id,
+  protocol::following::move_person::MovePerson,
+  send_lemmy_activity,
+  verify_person,
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::activity::MoveType,
+  protocol::verification::verify_urls_match,
+  traits::{Activity, Object},
+};
+use lemmy_api_utils::{context::LemmyContext, utils::move_person_account};
+use lemmy_apub_objects::objects::person::ApubPerson;
+use lemmy_db_schema::source::activity::ActivitySendTargets;
+use lemmy_utils::error::{LemmyError, LemmyResult};
+use url::Url;
+
+impl MovePerson {
+  pub async fn send(
+    actor: &ApubPerson,
+    target: &ApubPerson,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    let move_ = MovePerson {
+      actor: actor.id().clone().into(),
+      object: actor.id().clone().into(),
+      target: target.id().clone().into(),
+      kind: MoveType::Move,
+      id: generate_activity_id(MoveType::Move, context)?,
+    };
+    // Like account deletion, this is sent to all instances so that they can update their follows
+    let inboxes = ActivitySendTargets::to_all_instances();
+    send_lemmy_activity(context, move_, actor, inboxes, true).await
+  }
+}
+
+#[async_trait::async_trait]
+impl Activity for MovePerson {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    verify_urls_match(self.actor.inner(), self.object.inner())?;
+    verify_person(&self.actor, context).await?;
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    let old_person = self.object.dereference(context).await?;
+    // Always fetch the new account, so that a recently added alias is known
+    let new_person = self.target.dereference_forced(context).await?;
+    move_person_account(&old_person, &new_person, context).await
+  }
+}
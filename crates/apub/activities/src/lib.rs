@@ -1,13 +1,22 @@
 use crate::{
   block::{send_ban_from_community, send_ban_from_site},
   community::{
-    collection_add::{send_add_mod_to_community, send_feature_post},
-    lock::send_lock,
+    collection_add::{send_add_mod_to_community, send_assign_community_flair, send_feature_post},
+    lock::{send_bulk_lock_posts, send_lock},
+    resolve_report::send_bulk_resolve_reports,
     update::{send_update_community, send_update_multi_community},
   },
-  create_or_update::private_message::send_create_or_update_pm,
+  conversation::send_conversation_participant,
+  create_or_update::{
+    conversation_message::send_create_or_update_conversation_message,
+    modmail_message::send_create_modmail_message,
+    poll_vote::send_poll_vote,
+    private_message::send_create_or_update_pm,
+  },
   deletion::{
     DeletableObjects,
+    send_apub_bulk_remove_in_community,
+    send_apub_delete_conversation_message,
     send_apub_delete_in_community,
     send_apub_delete_private_message,
     send_apub_delete_user,
@@ -17,7 +26,9 @@ use crate::{
     CreateOrUpdateType,
     community::{report::Report, resolve_report::ResolveReport, warn::Warn},
     create_or_update::{note::CreateOrUpdateNote, page::CreateOrUpdatePage},
+    following::move_person::MovePerson,
   },
+  reaction::send_react_activity,
   voting::send_like_activity,
 };
 use activitypub_federation::{
@@ -53,10 +64,12 @@ use uuid::Uuid;
 pub mod activity_lists;
 pub mod block;
 pub mod community;
+pub mod conversation;
 pub mod create_or_update;
 pub mod deletion;
 pub mod following;
 pub mod protocol;
+pub mod reaction;
 pub mod voting;
 
 const MOD_ACTION_DEFAULT_REASON: &str = "No reason provided";
@@ -190,6 +203,11 @@ pub async fn match_outgoing_activities(
         )
         .await
       }
+      VotePoll {
+        post,
+        actor,
+        options,
+      } => send_poll_vote(post, actor, options, context).await,
       LockPost(post, actor, locked, reason) => {
         send_lock(
           PostOrComment::Left(post.into()),
@@ -200,6 +218,26 @@ pub async fn match_outgoing_activities(
         )
         .await
       }
+      BulkRemove {
+        posts,
+        comments,
+        moderator,
+        community,
+        reason,
+        removed,
+      } => {
+        send_apub_bulk_remove_in_community(
+          moderator, community, posts, comments, reason, removed, &context,
+        )
+        .await
+      }
+      BulkLockPosts {
+        posts,
+        moderator,
+        community,
+        locked,
+        reason,
+      } => send_bulk_lock_posts(posts, community, moderator, locked, reason, context).await,
       FeaturePost(post, actor, featured) => send_feature_post(post, actor, featured, context).await,
       CreateComment(comment) => {
         let creator_id = comment.creator_id;
@@ -264,11 +302,34 @@ pub async fn match_outgoing_activities(
         )
         .await
       }
+      ReactPostOrComment {
+        object_id,
+        actor,
+        community,
+        emoji,
+        emoji_url,
+        add,
+      } => send_react_activity(object_id, actor, community, emoji, emoji_url, add, context).await,
       FollowCommunity(community, person, follow) => {
-        send_follow(Either::Left(community.into()), person, follow, &context).await
+        send_follow(
+          Either::Right(Either::Left(community.into())),
+          person,
+          follow,
+          &context,
+        )
+        .await
       }
       FollowMultiCommunity(multi, person, follow) => {
-        send_follow(Either::Right(multi.into()), person, follow, &context).await
+        send_follow(
+          Either::Right(Either::Right(multi.into())),
+          person,
+          follow,
+          &context,
+        )
+        .await
+      }
+      FollowPerson(target, person, follow) => {
+        send_follow(Either::Left(target.into()), person, follow, &context).await
       }
       UpdateCommunity(actor, community) => send_update_community(community, actor, context).await,
       DeleteCommunity(actor, community, removed) => {
@@ -300,6 +361,13 @@ pub async fn match_outgoing_activities(
         target,
         added,
       } => send_add_mod_to_community(moderator, community_id, target, added, context).await,
+      AssignCommunityFlair {
+        actor,
+        community,
+        target,
+        flair,
+        added,
+      } => send_assign_community_flair(actor, community, target, flair, added, context).await,
       BanFromCommunity {
         moderator,
         community_id,
@@ -334,18 +402,41 @@ pub async fn match_outgoing_activities(
       DeletePrivateMessage(person, pm, deleted) => {
         send_apub_delete_private_message(&person.into(), pm, deleted, context).await
       }
+      CreateConversationMessage(message) => {
+        send_create_or_update_conversation_message(message, CreateOrUpdateType::Create, context)
+          .await
+      }
+      UpdateConversationMessage(message) => {
+        send_create_or_update_conversation_message(message, CreateOrUpdateType::Update, context)
+          .await
+      }
+      DeleteConversationMessage(person, message, deleted) => {
+        send_apub_delete_conversation_message(&person.into(), message, deleted, context).await
+      }
+      ConversationParticipant {
+        conversation,
+        actor,
+        participant,
+        added,
+      } => send_conversation_participant(conversation, actor, participant, added, context).await,
+      CreateModmailMessage(message) => send_create_modmail_message(message, context).await,
       DeleteUser(person, remove_data) => send_apub_delete_user(person, remove_data, context).await,
+      MoveUser { person, target } => {
+        MovePerson::send(&person.into(), &target.into(), &context).await
+      }
       CreateReport {
         object_id,
         actor,
         receiver,
         reason,
+        rule,
       } => {
         Report::send(
           ObjectId::from(object_id),
           &actor.into(),
           &receiver.map_either(Into::into, Into::into),
           reason,
+          rule,
           context,
         )
         .await
@@ -365,6 +456,14 @@ pub async fn match_outgoing_activities(
         )
         .await
       }
+      BulkResolveReports {
+        actor,
+        community,
+        post_reports,
+        comment_reports,
+      } => {
+        send_bulk_resolve_reports(actor, community, post_reports, comment_reports, context).await
+      }
       PrivateCommunityAcceptFollower {
         community_id,
         person_id,
@@ -384,8 +483,8 @@ pub async fn match_outgoing_activities(
       UpdateMultiCommunity(multi, actor) => {
         send_update_multi_community(multi, actor, context).await
       }
-      Warning(post_or_comment, reason, actor) => {
-        Warn::send(*post_or_comment, reason, actor.into(), context).await
+      Warning(post_or_comment, reason, expires_at, actor) => {
+        Warn::send(*post_or_comment, reason, expires_at, actor.into(), context).await
       }
     }
   })
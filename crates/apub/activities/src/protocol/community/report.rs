@@ -25,6 +25,8 @@ pub struct Report {
   pub(crate) summary: Option<String>,
   /// Report reason as sent by Mastodon
   pub(crate) content: Option<String>,
+  /// The community rule which was broken (Lemmy extension)
+  pub(crate) rule: Option<Url>,
   #[serde(rename = "type")]
   pub(crate) kind: FlagType,
   pub(crate) id: Url,
@@ -57,15 +59,24 @@ impl ReportObject {
     match self {
       ReportObject::Lemmy(l) => l.dereference(context).await,
       ReportObject::Mastodon(objects) => {
+        let mut reported_person = None;
         for o in objects {
           // Find the first reported item which can be dereferenced as post or comment (Lemmy can
-          // only handle one item per report).
-          let deref = ObjectId::from(o.clone()).dereference(context).await;
-          if deref.is_ok() {
-            return deref;
+          // only handle one item per report). The reported user is only used as fallback.
+          match ObjectId::<ReportableObjects>::from(o.clone())
+            .dereference(context)
+            .await
+          {
+            Ok(Either::Right(Either::Right(Either::Right(p)))) => {
+              reported_person.get_or_insert(p);
+            }
+            Ok(deref) => return Ok(deref),
+            Err(_) => {}
           }
         }
-        Err(LemmyErrorType::NotFound.into())
+        reported_person
+          .map(|p| Either::Right(Either::Right(Either::Right(p))))
+          .ok_or(LemmyErrorType::NotFound.into())
       }
     }
   }
@@ -77,16 +88,23 @@ impl ReportObject {
     match self {
       ReportObject::Lemmy(l) => Ok(l.clone()),
       ReportObject::Mastodon(objects) => {
+        let mut reported_person = None;
         for o in objects {
           // Same logic as above, but return the ID and not the object itself.
-          let deref = ObjectId::<ReportableObjects>::from(o.clone())
+          match ObjectId::<ReportableObjects>::from(o.clone())
             .dereference(context)
-            .await;
-          if deref.is_ok() {
-            return Ok(o.clone().into());
+            .await
+          {
+            Ok(Either::Right(Either::Right(Either::Right(_)))) => {
+              reported_person.get_or_insert(o);
+            }
+            Ok(_) => return Ok(o.clone().into()),
+            Err(_) => {}
           }
         }
-        Err(LemmyErrorType::NotFound.into())
+        reported_person
+          .map(|o| o.clone().into())
+          .ok_or(LemmyErrorType::NotFound.into())
       }
     }
   }
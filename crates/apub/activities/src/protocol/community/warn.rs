@@ -3,6 +3,7 @@ use activitypub_federation::{
   fetch::object_id::ObjectId,
   protocol::{helpers::deserialize_one, verification::verify_urls_match},
 };
+use chrono::{DateTime, Utc};
 use either::Either;
 use lemmy_api_utils::context::LemmyContext;
 use lemmy_apub_objects::{
@@ -13,6 +14,7 @@ use lemmy_db_schema::source::{community::Community, post::Post};
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::error::LemmyResult;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 use strum::Display;
 use url::Url;
 
@@ -21,6 +23,7 @@ pub enum WarnType {
   Warn,
 }
 
+#[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Warn {
@@ -33,6 +36,8 @@ pub struct Warn {
   pub(crate) kind: WarnType,
   pub(crate) id: Url,
   pub(crate) audience: ObjectId<ApubCommunity>,
+  /// After this time the warning doesn't count toward the warning policy anymore
+  pub(crate) end_time: Option<DateTime<Utc>>,
 }
 
 impl InCommunity for Warn {
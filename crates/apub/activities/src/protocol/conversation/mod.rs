@@ -0,0 +1,22 @@
+pub mod participant;
+
+#[cfg(test)]
+mod tests {
+  use crate::protocol::conversation::participant::{
+    AddConversationParticipant,
+    RemoveConversationParticipant,
+  };
+  use lemmy_apub_objects::utils::test::test_parse_lemmy_item;
+  use lemmy_utils::error::LemmyResult;
+
+  #[test]
+  fn test_parse_lemmy_conversation_participant() -> LemmyResult<()> {
+    test_parse_lemmy_item::<AddConversationParticipant>(
+      "../apub/assets/lemmy/activities/conversation/add_participant.json",
+    )?;
+    test_parse_lemmy_item::<RemoveConversationParticipant>(
+      "../apub/assets/lemmy/activities/conversation/remove_participant.json",
+    )?;
+    Ok(())
+  }
+}
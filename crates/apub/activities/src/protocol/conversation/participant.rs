@@ -0,0 +1,38 @@
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::activity::{AddType, RemoveType},
+};
+use lemmy_apub_objects::objects::person::ApubPerson;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Adds a person to a group conversation. Sent to all participants, so that their instances
+/// accept messages from the new participant.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddConversationParticipant {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  /// All participants, including the added person
+  pub(crate) to: Vec<ObjectId<ApubPerson>>,
+  pub(crate) object: ObjectId<ApubPerson>,
+  /// The conversation, same as `context` of its messages. Unlike `target` of `CollectionAdd`,
+  /// this field is required and used to tell both activities apart.
+  pub(crate) context: Url,
+  #[serde(rename = "type")]
+  pub(crate) kind: AddType,
+  pub(crate) id: Url,
+}
+
+/// Removes a person from a group conversation, or the actor leaves it. Also sent to the removed
+/// person.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveConversationParticipant {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  pub(crate) to: Vec<ObjectId<ApubPerson>>,
+  pub(crate) object: ObjectId<ApubPerson>,
+  pub(crate) context: Url,
+  #[serde(rename = "type")]
+  pub(crate) kind: RemoveType,
+  pub(crate) id: Url,
+}
@@ -0,0 +1,19 @@
+use crate::protocol::CreateOrUpdateType;
+use activitypub_federation::fetch::object_id::ObjectId;
+use lemmy_apub_objects::{
+  objects::person::ApubPerson,
+  protocol::conversation_message::ConversationMessage,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrUpdateConversationMessage {
+  pub(crate) id: Url,
+  pub(crate) actor: ObjectId<ApubPerson>,
+  pub(crate) to: Vec<ObjectId<ApubPerson>>,
+  pub(crate) object: ConversationMessage,
+  #[serde(rename = "type")]
+  pub(crate) kind: CreateOrUpdateType,
+}
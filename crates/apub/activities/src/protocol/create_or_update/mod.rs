@@ -1,12 +1,17 @@
+pub mod conversation_message;
+pub mod modmail_message;
 pub mod note;
 pub(crate) mod note_wrapper;
 pub mod page;
+pub mod poll_vote;
 pub mod private_message;
 
 #[cfg(test)]
 mod tests {
   use super::note_wrapper::{CreateOrUpdateNoteWrapper, NoteWrapper};
   use crate::protocol::create_or_update::{
+    conversation_message::CreateOrUpdateConversationMessage,
+    modmail_message::CreateOrUpdateModmailMessage,
     note::CreateOrUpdateNote,
     page::CreateOrUpdatePage,
     private_message::CreateOrUpdatePrivateMessage,
@@ -28,6 +33,18 @@ mod tests {
     test_parse_lemmy_item::<CreateOrUpdatePrivateMessage>(
       "../apub/assets/lemmy/activities/create_or_update/create_private_message.json",
     )?;
+    test_parse_lemmy_item::<CreateOrUpdateConversationMessage>(
+      "../apub/assets/lemmy/activities/create_or_update/create_conversation_message.json",
+    )?;
+    test_parse_lemmy_item::<CreateOrUpdateNoteWrapper>(
+      "../apub/assets/lemmy/activities/create_or_update/create_conversation_message.json",
+    )?;
+    test_parse_lemmy_item::<CreateOrUpdateModmailMessage>(
+      "../apub/assets/lemmy/activities/create_or_update/create_modmail_message.json",
+    )?;
+    test_parse_lemmy_item::<CreateOrUpdateNoteWrapper>(
+      "../apub/assets/lemmy/activities/create_or_update/create_modmail_message.json",
+    )?;
     test_parse_lemmy_item::<CreateOrUpdateNoteWrapper>(
       "../apub/assets/lemmy/activities/create_or_update/create_comment.json",
     )?;
@@ -0,0 +1,17 @@
+use crate::protocol::CreateOrUpdateType;
+use activitypub_federation::{fetch::object_id::ObjectId, protocol::helpers::deserialize_one};
+use lemmy_apub_objects::{objects::UserOrCommunity, protocol::modmail_message::ModmailMessage};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrUpdateModmailMessage {
+  pub(crate) id: Url,
+  pub(crate) actor: ObjectId<UserOrCommunity>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<UserOrCommunity>; 1],
+  pub(crate) object: ModmailMessage,
+  #[serde(rename = "type")]
+  pub(crate) kind: CreateOrUpdateType,
+}
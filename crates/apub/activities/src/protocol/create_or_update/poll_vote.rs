@@ -0,0 +1,37 @@
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::{activity::CreateType, object::NoteType},
+  protocol::helpers::deserialize_one,
+};
+use lemmy_apub_objects::objects::{person::ApubPerson, post::ApubPost};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Vote in a poll. This is sent directly to the poll creator, and follows the format used by
+/// Mastodon.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePollVote {
+  pub(crate) id: Url,
+  pub(crate) actor: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  pub(crate) object: PollVoteNote,
+  #[serde(rename = "type")]
+  pub(crate) kind: CreateType,
+}
+
+/// A single chosen option, identified by its `name`. Multiple choice polls receive one activity
+/// per option.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollVoteNote {
+  #[serde(rename = "type")]
+  pub(crate) kind: NoteType,
+  pub(crate) id: Url,
+  pub(crate) attributed_to: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  pub(crate) name: String,
+  pub(crate) in_reply_to: ObjectId<ApubPost>,
+}
@@ -62,7 +62,7 @@ impl InCommunity for Delete {
       }
       DeletableObjects::Post(p) => p.community_id,
       DeletableObjects::Person(_) => return Err(anyhow!("Person is not part of community").into()),
-      DeletableObjects::PrivateMessage(_) => {
+      DeletableObjects::PrivateMessage(_) | DeletableObjects::ConversationMessage(_) => {
         return Err(anyhow!("Private message is not part of community").into());
       }
     };
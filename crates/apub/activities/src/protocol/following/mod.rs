@@ -1,12 +1,18 @@
 pub(crate) mod accept;
 pub mod follow;
+pub mod move_person;
 pub(crate) mod reject;
 pub mod undo_follow;
 
 #[cfg(test)]
 mod tests {
-  use crate::protocol::following::{accept::AcceptFollow, follow::Follow, undo_follow::UndoFollow};
-  use lemmy_apub_objects::utils::test::test_parse_lemmy_item;
+  use crate::protocol::following::{
+    accept::AcceptFollow,
+    follow::Follow,
+    move_person::MovePerson,
+    undo_follow::UndoFollow,
+  };
+  use lemmy_apub_objects::utils::test::{test_json, test_parse_lemmy_item};
   use lemmy_utils::error::LemmyResult;
 
   #[test]
@@ -18,4 +24,11 @@ mod tests {
     )?;
     Ok(())
   }
+
+  #[test]
+  fn test_parse_move_person() -> LemmyResult<()> {
+    test_parse_lemmy_item::<MovePerson>("../apub/assets/lemmy/activities/following/move.json")?;
+    test_json::<MovePerson>("../apub/assets/mastodon/activities/move.json")?;
+    Ok(())
+  }
 }
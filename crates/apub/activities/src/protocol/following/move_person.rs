@@ -0,0 +1,19 @@
+use activitypub_federation::{fetch::object_id::ObjectId, kinds::activity::MoveType};
+use lemmy_apub_objects::objects::person::ApubPerson;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Announces that a user has moved to a new account. Follows of the old account are transferred
+/// to the new one.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MovePerson {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  /// The old account, same as actor
+  pub(crate) object: ObjectId<ApubPerson>,
+  /// The new account, which needs to list the old account in `alsoKnownAs`
+  pub(crate) target: ObjectId<ApubPerson>,
+  #[serde(rename = "type")]
+  pub(crate) kind: MoveType,
+  pub(crate) id: Url,
+}
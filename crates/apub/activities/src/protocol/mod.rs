@@ -9,9 +9,11 @@ use url::Url;
 
 pub mod block;
 pub mod community;
+pub mod conversation;
 pub mod create_or_update;
 pub mod deletion;
 pub mod following;
+pub mod reaction;
 pub mod voting;
 
 #[derive(Clone, Debug, Display, Deserialize, Serialize, PartialEq, Eq)]
@@ -62,9 +64,11 @@ mod tests {
       note::CreateOrUpdateNote,
       note_wrapper::CreateOrUpdateNoteWrapper,
       page::CreateOrUpdatePage,
+      poll_vote::CreatePollVote,
     },
     deletion::delete::Delete,
     following::{accept::AcceptFollow, follow::Follow, undo_follow::UndoFollow},
+    reaction::emoji_react::EmojiReact,
     voting::{undo_vote::UndoVote, vote::Vote},
   };
   use lemmy_apub_objects::utils::test::test_json;
@@ -81,6 +85,7 @@ mod tests {
     test_json::<CreateOrUpdateNote>("../apub/assets/pleroma/activities/create_note.json")?;
     test_json::<Delete>("../apub/assets/pleroma/activities/delete.json")?;
     test_json::<Follow>("../apub/assets/pleroma/activities/follow.json")?;
+    test_json::<EmojiReact>("../apub/assets/pleroma/activities/emoji_react.json")?;
     Ok(())
   }
 
@@ -93,6 +98,14 @@ mod tests {
     test_json::<Vote>("../apub/assets/mastodon/activities/like_page.json")?;
     test_json::<UndoVote>("../apub/assets/mastodon/activities/undo_like_page.json")?;
     test_json::<Report>("../apub/assets/mastodon/activities/flag.json")?;
+    test_json::<CreatePollVote>("../apub/assets/mastodon/activities/create_poll_vote.json")?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_parse_misskey_activities() -> LemmyResult<()> {
+    let like = test_json::<Vote>("../apub/assets/misskey/activities/like_reaction.json")?;
+    assert_eq!(Some(":blobcat_heart@.:"), like.inner().reaction());
     Ok(())
   }
 
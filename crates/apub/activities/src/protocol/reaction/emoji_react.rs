@@ -0,0 +1,54 @@
+use super::EmojiTag;
+use crate::post_or_comment_community;
+use activitypub_federation::{
+  config::Data,
+  fetch::object_id::ObjectId,
+  protocol::{helpers::deserialize_skip_error, verification::verify_urls_match},
+};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::{
+  objects::{PostOrComment, community::ApubCommunity, person::ApubPerson},
+  utils::protocol::{Id, InCommunity},
+};
+use lemmy_utils::error::LemmyResult;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmojiReact {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  pub(crate) object: ObjectId<PostOrComment>,
+  #[serde(rename = "type")]
+  pub(crate) kind: EmojiReactType,
+  pub(crate) id: Url,
+  /// The unicode emoji, or the shortcode of a custom emoji like `:lemmy:`
+  pub(crate) content: String,
+  /// Contains the image for custom emojis
+  #[serde(default, deserialize_with = "deserialize_skip_error")]
+  pub(crate) tag: Vec<EmojiTag>,
+  pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+}
+
+#[derive(Clone, Debug, Display, Deserialize, Serialize)]
+pub enum EmojiReactType {
+  EmojiReact,
+}
+
+impl InCommunity for EmojiReact {
+  async fn community(&self, context: &Data<LemmyContext>) -> LemmyResult<ApubCommunity> {
+    let post_or_comment = self.object.dereference(context).await?;
+    let community = post_or_comment_community(&post_or_comment, context).await?;
+    if let Some(audience) = &self.audience {
+      verify_urls_match(audience.inner(), community.ap_id.inner())?;
+    }
+    Ok(community.into())
+  }
+}
+
+impl Id for EmojiReact {
+  fn id(&self) -> &Url {
+    &self.id
+  }
+}
@@ -0,0 +1,38 @@
+use lemmy_apub_objects::utils::protocol::ImageObject;
+use serde::{Deserialize, Serialize};
+
+pub mod emoji_react;
+pub mod undo_emoji_react;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum EmojiType {
+  Emoji,
+}
+
+/// Custom emoji which is used as reaction, sent in the `tag` field of the activity.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmojiTag {
+  #[serde(rename = "type")]
+  pub(crate) kind: EmojiType,
+  pub(crate) name: String,
+  pub(crate) icon: ImageObject,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::protocol::reaction::{emoji_react::EmojiReact, undo_emoji_react::UndoEmojiReact};
+  use lemmy_apub_objects::utils::test::test_parse_lemmy_item;
+  use lemmy_utils::error::LemmyResult;
+
+  #[test]
+  fn test_parse_lemmy_reaction() -> LemmyResult<()> {
+    test_parse_lemmy_item::<EmojiReact>(
+      "../apub/assets/lemmy/activities/reaction/emoji_react.json",
+    )?;
+    test_parse_lemmy_item::<UndoEmojiReact>(
+      "../apub/assets/lemmy/activities/reaction/undo_emoji_react.json",
+    )?;
+    Ok(())
+  }
+}
@@ -1,8 +1,8 @@
-use crate::post_or_comment_community;
+use crate::{post_or_comment_community, protocol::reaction::EmojiTag};
 use activitypub_federation::{
   config::Data,
   fetch::object_id::ObjectId,
-  protocol::verification::verify_urls_match,
+  protocol::{helpers::deserialize_skip_error, verification::verify_urls_match},
 };
 use lemmy_api_utils::context::LemmyContext;
 use lemmy_apub_objects::{
@@ -23,6 +23,15 @@ pub struct Vote {
   pub(crate) kind: VoteType,
   pub(crate) id: Url,
   pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+  /// Misskey sends emoji reactions as `Like` with the emoji as content.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) content: Option<String>,
+  #[serde(
+    deserialize_with = "deserialize_skip_error",
+    default,
+    skip_serializing_if = "Vec::is_empty"
+  )]
+  pub(crate) tag: Vec<EmojiTag>,
 }
 
 #[derive(Clone, Debug, Display, Deserialize, Serialize, PartialEq, Eq)]
@@ -47,6 +56,16 @@ impl From<&VoteType> for bool {
   }
 }
 
+impl Vote {
+  /// Returns the emoji if this is a reaction in Misskey format, instead of a plain upvote.
+  pub(crate) fn reaction(&self) -> Option<&str> {
+    self
+      .content
+      .as_deref()
+      .filter(|c| self.kind == VoteType::Like && !c.is_empty())
+  }
+}
+
 impl InCommunity for Vote {
   async fn community(&self, context: &Data<LemmyContext>) -> LemmyResult<ApubCommunity> {
     let post_or_comment = self.object.dereference(context).await?;
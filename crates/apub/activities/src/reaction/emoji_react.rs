@@ -0,0 +1,85 @@
+use crate::{
+  generate_activity_id,
+  protocol::reaction::{
+    EmojiTag,
+    EmojiType,
+    emoji_react::{EmojiReact, EmojiReactType},
+  },
+  reaction::receive_reaction,
+};
+use activitypub_federation::{
+  config::Data,
+  fetch::object_id::ObjectId,
+  traits::{Activity, Object},
+};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_bot_account, check_community_deleted_removed},
+};
+use lemmy_apub_objects::{
+  objects::{PostOrComment, community::ApubCommunity, person::ApubPerson},
+  utils::{
+    functions::verify_person_in_community,
+    protocol::{ImageObject, InCommunity},
+  },
+};
+use lemmy_diesel_utils::dburl::DbUrl;
+use lemmy_utils::error::{LemmyError, LemmyResult};
+use url::Url;
+
+impl EmojiReact {
+  pub(in crate::reaction) fn new(
+    object_id: ObjectId<PostOrComment>,
+    actor: &ApubPerson,
+    community: &ApubCommunity,
+    emoji: String,
+    emoji_url: Option<DbUrl>,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<EmojiReact> {
+    let tag = emoji_url
+      .map(|url| EmojiTag {
+        kind: EmojiType::Emoji,
+        name: emoji.clone(),
+        icon: ImageObject::new(url),
+      })
+      .into_iter()
+      .collect();
+    Ok(EmojiReact {
+      actor: actor.id().clone().into(),
+      object: object_id,
+      kind: EmojiReactType::EmojiReact,
+      id: generate_activity_id(EmojiReactType::EmojiReact, context)?,
+      content: emoji,
+      tag,
+      audience: Some(community.ap_id.clone().into()),
+    })
+  }
+}
+
+#[async_trait::async_trait]
+impl Activity for EmojiReact {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    let community = self.community(context).await?;
+    check_community_deleted_removed(&community)?;
+    verify_person_in_community(&self.actor, &community, context).await?;
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    let actor = self.actor.dereference(context).await?;
+    let object = self.object.dereference(context).await?;
+    check_bot_account(&actor.0)?;
+    receive_reaction(&self.content, &self.tag, &actor, &object, context).await
+  }
+}
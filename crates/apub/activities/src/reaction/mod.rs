@@ -0,0 +1,94 @@
+use crate::{
+  activity_lists::AnnouncableActivities,
+  community::send_activity_in_community,
+  protocol::reaction::{EmojiTag, emoji_react::EmojiReact, undo_emoji_react::UndoEmojiReact},
+};
+use activitypub_federation::{config::Data, fetch::object_id::ObjectId};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::objects::{PostOrComment, community::ApubCommunity, person::ApubPerson};
+use lemmy_db_schema::{
+  newtypes::PostOrCommentId,
+  source::{
+    activity::ActivitySendTargets,
+    community::Community,
+    person::Person,
+    reaction::{Reaction, ReactionInsertForm},
+  },
+};
+use lemmy_diesel_utils::dburl::DbUrl;
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_reaction};
+
+pub mod emoji_react;
+pub mod undo_emoji_react;
+
+pub(crate) async fn send_react_activity(
+  object_id: DbUrl,
+  actor: Person,
+  community: Community,
+  emoji: String,
+  emoji_url: Option<DbUrl>,
+  add: bool,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let object_id: ObjectId<PostOrComment> = object_id.into();
+  let actor: ApubPerson = actor.into();
+  let community: ApubCommunity = community.into();
+
+  let empty = ActivitySendTargets::empty();
+  let react = EmojiReact::new(object_id, &actor, &community, emoji, emoji_url, &context)?;
+  let activity = if add {
+    AnnouncableActivities::EmojiReact(react)
+  } else {
+    let undo = UndoEmojiReact::new(react, &actor, &community, &context)?;
+    AnnouncableActivities::UndoEmojiReact(undo)
+  };
+  send_activity_in_community(activity, &actor, &community, empty, false, &context).await
+}
+
+/// Stores a reaction received over federation. The image of a custom emoji is taken from the
+/// matching activity tag.
+pub(crate) async fn receive_reaction(
+  emoji: &str,
+  tags: &[EmojiTag],
+  actor: &ApubPerson,
+  object: &PostOrComment,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let emoji = normalize_emoji(emoji);
+  is_valid_reaction(&emoji)?;
+  let emoji_url = tags
+    .iter()
+    .find(|t| t.name.trim_matches(':') == emoji.trim_matches(':'))
+    .map(|t| t.icon.url.clone().into());
+  let mut form = ReactionInsertForm::new(actor.id, emoji);
+  form.emoji_url = emoji_url;
+  match object {
+    PostOrComment::Left(p) => form.post_id = Some(p.id),
+    PostOrComment::Right(c) => form.comment_id = Some(c.id),
+  }
+  Reaction::add(&mut context.pool(), &form).await
+}
+
+pub(crate) async fn undo_reaction(
+  emoji: &str,
+  actor: &ApubPerson,
+  object: &PostOrComment,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let target = match object {
+    PostOrComment::Left(p) => PostOrCommentId::Post(p.id),
+    PostOrComment::Right(c) => PostOrCommentId::Comment(c.id),
+  };
+  Reaction::remove(
+    &mut context.pool(),
+    actor.id,
+    target,
+    &normalize_emoji(emoji),
+  )
+  .await
+}
+
+/// Misskey marks custom emojis from the sending instance with `@.`, like `:blobcat@.:`.
+fn normalize_emoji(emoji: &str) -> String {
+  emoji.replace("@.:", ":")
+}
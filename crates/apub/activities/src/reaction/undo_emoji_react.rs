@@ -0,0 +1,69 @@
+use crate::{
+  generate_activity_id,
+  protocol::{
+    IdOrNestedObject,
+    reaction::{emoji_react::EmojiReact, undo_emoji_react::UndoEmojiReact},
+  },
+  reaction::undo_reaction,
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::activity::UndoType,
+  protocol::verification::verify_urls_match,
+  traits::{Activity, Object},
+};
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_deleted_removed};
+use lemmy_apub_objects::{
+  objects::{community::ApubCommunity, person::ApubPerson},
+  utils::{functions::verify_person_in_community, protocol::InCommunity},
+};
+use lemmy_utils::error::{LemmyError, LemmyResult};
+use url::Url;
+
+impl UndoEmojiReact {
+  pub(in crate::reaction) fn new(
+    react: EmojiReact,
+    actor: &ApubPerson,
+    community: &ApubCommunity,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<Self> {
+    Ok(UndoEmojiReact {
+      actor: actor.id().clone().into(),
+      object: IdOrNestedObject::NestedObject(react),
+      kind: UndoType::Undo,
+      id: generate_activity_id(UndoType::Undo, context)?,
+      audience: Some(community.ap_id.clone().into()),
+    })
+  }
+}
+
+#[async_trait::async_trait]
+impl Activity for UndoEmojiReact {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    let object = self.object.dereference(context).await?;
+    let community = object.community(context).await?;
+    check_community_deleted_removed(&community)?;
+    verify_person_in_community(&self.actor, &community, context).await?;
+    verify_urls_match(self.actor.inner(), object.actor.inner())?;
+    object.verify(context).await?;
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    let actor = self.actor.dereference(context).await?;
+    let react = self.object.dereference(context).await?;
+    let object = react.object.dereference(context).await?;
+    undo_reaction(&react.content, &actor, &object, context).await
+  }
+}
@@ -9,6 +9,7 @@ use crate::{
 use activitypub_federation::{config::Data, fetch::object_id::ObjectId};
 use lemmy_api_utils::{
   context::LemmyContext,
+  notify::notify_score_milestone,
   plugins::{plugin_hook_after, plugin_hook_before},
 };
 use lemmy_apub_objects::objects::{
@@ -90,6 +91,7 @@ async fn vote_post(
   like_form = plugin_hook_before("post_before_vote", like_form).await?;
   let like = PostActions::like(&mut context.pool(), &like_form).await?;
   plugin_hook_after("post_after_vote", &like);
+  notify_score_milestone(post.id, post.score, context);
   Ok(())
 }
 
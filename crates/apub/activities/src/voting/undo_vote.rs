@@ -4,6 +4,7 @@ use crate::{
     IdOrNestedObject,
     voting::{undo_vote::UndoVote, vote::Vote},
   },
+  reaction::undo_reaction,
   voting::{undo_vote_comment, undo_vote_post},
 };
 use activitypub_federation::{
@@ -62,8 +63,11 @@ impl Activity for UndoVote {
 
   async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
     let actor = self.actor.dereference(context).await?;
-    let object = self.object.dereference(context).await?;
-    let object = object.object.dereference(context).await?;
+    let vote = self.object.dereference(context).await?;
+    let object = vote.object.dereference(context).await?;
+    if let Some(emoji) = vote.reaction() {
+      return undo_reaction(emoji, &actor, &object, context).await;
+    }
     match object {
       PostOrComment::Left(p) => undo_vote_post(actor, &p, context).await,
       PostOrComment::Right(c) => undo_vote_comment(actor, &c, context).await,
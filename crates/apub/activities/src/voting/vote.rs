@@ -1,6 +1,7 @@
 use crate::{
   generate_activity_id,
   protocol::voting::vote::{Vote, VoteType},
+  reaction::receive_reaction,
   voting::{undo_vote_comment, undo_vote_post, vote_comment, vote_post},
 };
 use activitypub_federation::{
@@ -35,6 +36,8 @@ impl Vote {
       kind: kind.clone(),
       id: generate_activity_id(kind, context)?,
       audience: Some(community.ap_id.clone().into()),
+      content: None,
+      tag: vec![],
     })
   }
 }
@@ -65,6 +68,10 @@ impl Activity for Vote {
 
     check_bot_account(&actor.0)?;
 
+    if let Some(emoji) = self.reaction() {
+      return receive_reaction(emoji, &self.tag, &actor, &object, context).await;
+    }
+
     // Check for enabled federation votes
     let local_site = SiteView::read_local(&mut context.pool())
       .await
@@ -1,8 +1,19 @@
 use super::ActorPath;
-use crate::protocol::collections::url_collection::UrlCollection;
-use activitypub_federation::{config::Data, traits::Object};
+use crate::protocol::collections::{
+  group_followers::GroupFollowers,
+  url_collection::UrlCollection,
+};
+use activitypub_federation::{
+  actix_web::response::create_http_response,
+  config::Data,
+  kinds::collection::CollectionType,
+  traits::Object,
+};
 use actix_web::{HttpResponse, web::Path};
-use lemmy_api_utils::{context::LemmyContext, utils::generate_outbox_url};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{generate_followers_url, generate_outbox_url},
+};
 use lemmy_apub_objects::objects::person::ApubPerson;
 use lemmy_db_schema::{source::person::Person, traits::ApubActor};
 use lemmy_utils::{
@@ -35,3 +46,20 @@ pub(crate) async fn get_apub_person_outbox(
   let outbox_id = generate_outbox_url(&person.ap_id)?.to_string();
   UrlCollection::new_empty_response(outbox_id)
 }
+
+/// Only the number of followers is exposed, not the followers themselves.
+pub(crate) async fn get_apub_person_followers(
+  info: Path<ActorPath>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  let person = Person::read_from_name(&mut context.pool(), &info.name, None, false)
+    .await?
+    .ok_or(LemmyErrorType::NotFound)?;
+  let followers = GroupFollowers {
+    id: generate_followers_url(&person.ap_id)?.into(),
+    r#type: CollectionType::Collection,
+    total_items: person.follower_count,
+    items: vec![],
+  };
+  Ok(create_http_response(followers, &FEDERATION_CONTEXT)?)
+}
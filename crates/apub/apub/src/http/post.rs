@@ -28,6 +28,10 @@ async fn get_post(
   let id = PostId(info.post_id.parse::<i32>()?);
   // Can't use PostView here because it excludes deleted/removed/local-only items
   let post: ApubPost = Post::read(&mut context.pool(), id).await?.into();
+  // Posts in the mod queue are only federated once approved
+  if post.approval_pending {
+    return Err(LemmyErrorType::NotFound.into());
+  }
   let community = Community::read(&mut context.pool(), post.community_id).await?;
 
   check_community_content_fetchable(&community, request, context).await?;
@@ -11,7 +11,7 @@ use crate::http::{
     get_apub_person_multi_community_follows,
   },
   get_activity,
-  person::{get_apub_person_http, get_apub_person_outbox},
+  person::{get_apub_person_followers, get_apub_person_http, get_apub_person_outbox},
   post::{get_apub_post, get_apub_post_context},
   shared_inbox,
   site::{get_apub_site_http, get_apub_site_outbox},
@@ -46,6 +46,10 @@ pub fn config(cfg: &mut web::ServiceConfig) {
     )
     .route("/u/{name}", web::get().to(get_apub_person_http))
     .route("/u/{name}/outbox", web::get().to(get_apub_person_outbox))
+    .route(
+      "/u/{name}/followers",
+      web::get().to(get_apub_person_followers),
+    )
     .route("/m/{name}", web::get().to(get_apub_person_multi_community))
     .route(
       "/m/{name}/following",
@@ -29,10 +29,11 @@ use chrono::{DateTime, Utc};
 use lemmy_api_utils::{
   context::LemmyContext,
   plugins::{plugin_hook_after, plugin_hook_before},
-  utils::{get_url_blocklist, process_markdown, slur_regex},
+  utils::{get_url_blocklist, process_markdown, requires_approval, slur_regex},
 };
 use lemmy_db_schema::source::{
   comment::{Comment, CommentInsertForm, CommentUpdateForm},
+  comment_revision::CommentRevision,
   community::Community,
   person::Person,
   post::Post,
@@ -213,6 +214,17 @@ impl Object for ApubComment {
         .await?,
     );
 
+    let orig_comment = Comment::read_from_apub_id(&mut context.pool(), note.id.clone().into())
+      .await
+      .ok()
+      .flatten();
+    // New comments in local communities may need to be held in the mod queue
+    let approval_pending = if orig_comment.is_none() {
+      let community = Community::read(&mut context.pool(), post.community_id).await?;
+      Some(requires_approval(&creator, &community, false, &mut context.pool()).await?)
+    } else {
+      None
+    };
     let mut form = CommentInsertForm {
       creator_id: creator.id,
       post_id: post.id,
@@ -228,6 +240,7 @@ impl Object for ApubComment {
       language_id,
       federation_pending: Some(false),
       locked: None,
+      approval_pending,
     };
     form = plugin_hook_before("federated_comment_before_receive", form).await?;
     let parent_comment_path = parent_comment.map(|t| t.0.path);
@@ -240,6 +253,10 @@ impl Object for ApubComment {
     )
     .await?;
     plugin_hook_after("federated_comment_after_receive", &comment);
+
+    if let Some(orig_comment) = &orig_comment {
+      CommentRevision::create_if_changed(&mut context.pool(), orig_comment, &comment).await?;
+    }
     Ok(comment.into())
   }
 }
@@ -1,6 +1,9 @@
 use crate::{
   objects::instance::fetch_instance_actor_for_object,
-  protocol::{group::Group, tags::ApubCommunityTag},
+  protocol::{
+    group::Group,
+    tags::{ApubCommunityFlair, ApubCommunityRule, ApubCommunityTag},
+  },
   utils::{
     functions::{
       GetActorType,
@@ -35,7 +38,9 @@ use lemmy_db_schema::{
   source::{
     actor_language::CommunityLanguage,
     community::{Community, CommunityInsertForm, CommunityUpdateForm},
+    community_flair::CommunityFlair,
     community_tag::CommunityTag,
+    rule::Rule,
   },
   traits::ApubActor,
 };
@@ -118,6 +123,16 @@ impl Object for ApubCommunity {
     let langs = CommunityLanguage::read(&mut data.pool(), community_id).await?;
     let language = LanguageTag::new_multiple(langs, &mut data.pool()).await?;
     let community_tags = CommunityTag::read_for_community(&mut data.pool(), community_id).await?;
+    let flair = CommunityFlair::read_for_community(&mut data.pool(), community_id)
+      .await?
+      .into_iter()
+      .map(ApubCommunityFlair::to_json)
+      .collect();
+    let rules = Rule::list(&mut data.pool(), Some(community_id))
+      .await?
+      .into_iter()
+      .map(ApubCommunityRule::to_json)
+      .collect();
     let group = Group {
       kind: GroupType::Group,
       id: self.id().clone().into(),
@@ -149,6 +164,8 @@ impl Object for ApubCommunity {
         .into_iter()
         .map(ApubCommunityTag::to_json)
         .collect(),
+      flair,
+      rules,
     };
     Ok(group)
   }
@@ -250,6 +267,28 @@ impl Object for ApubCommunity {
     let existing_tags = CommunityTag::read_for_community(&mut context.pool(), community.id).await?;
     CommunityTag::update_many(&mut context.pool(), new_tags, existing_tags).await?;
 
+    let new_flairs = group
+      .flair
+      .iter()
+      .map(|f| f.to_insert_form(community.id))
+      .collect();
+    let existing_flairs =
+      CommunityFlair::read_for_community(&mut context.pool(), community.id).await?;
+    CommunityFlair::update_many(&mut context.pool(), new_flairs, existing_flairs).await?;
+
+    // Rules of local communities are managed here. Ignore rules with a foreign id, so that a
+    // group can't overwrite the rules of other communities.
+    if !community.local {
+      let new_rules = group
+        .rules
+        .iter()
+        .filter(|r| r.id.domain() == group.id.inner().domain())
+        .map(|r| r.to_insert_form(community.id))
+        .collect();
+      let existing_rules = Rule::list(&mut context.pool(), Some(community.id)).await?;
+      Rule::update_many(&mut context.pool(), new_rules, existing_rules).await?;
+    }
+
     let community: ApubCommunity = community.into();
 
     // These collections are not necessary for Lemmy to work, so ignore errors. Reset request count
@@ -0,0 +1,204 @@
+use crate::{
+  objects::ApubPerson,
+  protocol::{conversation_message::ConversationMessage, private_message::PrivateMessageType},
+  utils::{
+    functions::{check_apub_id_valid_with_strictness, read_from_string_or_source},
+    markdown_links::markdown_rewrite_remote_links,
+    protocol::Source,
+  },
+};
+use activitypub_federation::{
+  config::Data,
+  fetch::object_id::ObjectId,
+  protocol::{
+    values::MediaTypeHtml,
+    verification::{verify_domains_match, verify_is_remote_object},
+  },
+  traits::Object,
+};
+use chrono::Utc;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_private_messages_enabled, get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::{
+  newtypes::ConversationId,
+  source::{
+    conversation::{
+      Conversation,
+      ConversationMessage as DbConversationMessage,
+      ConversationMessageInsertForm,
+      ConversationParticipant,
+    },
+    instance::InstanceActions,
+    person::{Person, PersonActions},
+  },
+  traits::Blockable,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType, LemmyResult},
+  utils::markdown::markdown_to_html,
+};
+use std::ops::Deref;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub struct ApubConversationMessage(pub DbConversationMessage);
+
+impl Deref for ApubConversationMessage {
+  type Target = DbConversationMessage;
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl From<DbConversationMessage> for ApubConversationMessage {
+  fn from(m: DbConversationMessage) -> Self {
+    ApubConversationMessage(m)
+  }
+}
+
+#[async_trait::async_trait]
+impl Object for ApubConversationMessage {
+  type DataType = LemmyContext;
+  type Kind = ConversationMessage;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    self.ap_id.inner()
+  }
+
+  async fn read_from_id(
+    object_id: Url,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<Option<Self>> {
+    Ok(
+      DbConversationMessage::read_from_apub_id(&mut context.pool(), object_id.into())
+        .await?
+        .map(Into::into),
+    )
+  }
+
+  async fn delete(&self, _context: &Data<Self::DataType>) -> LemmyResult<()> {
+    // do nothing, because conversation messages can't be fetched over http
+    Err(LemmyErrorType::NotFound.into())
+  }
+
+  async fn into_json(self, context: &Data<Self::DataType>) -> LemmyResult<ConversationMessage> {
+    let creator = Person::read(&mut context.pool(), self.creator_id).await?;
+    let conversation = Conversation::read(&mut context.pool(), self.conversation_id).await?;
+    let to = Conversation::list_participants(&mut context.pool(), self.conversation_id)
+      .await?
+      .into_iter()
+      .filter(|p| p.id != self.creator_id)
+      .map(|p| p.ap_id.into())
+      .collect();
+
+    Ok(ConversationMessage {
+      kind: PrivateMessageType::Note,
+      id: self.ap_id.clone().into(),
+      attributed_to: creator.ap_id.into(),
+      to,
+      context: conversation.ap_id.into(),
+      content: markdown_to_html(&self.content),
+      media_type: Some(MediaTypeHtml::Html),
+      source: Some(Source::new(self.content.clone())),
+      published: Some(self.published_at),
+      updated: self.updated_at,
+    })
+  }
+
+  async fn verify(
+    note: &ConversationMessage,
+    expected_domain: &Url,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<()> {
+    verify_domains_match(note.id.inner(), expected_domain)?;
+    verify_domains_match(note.attributed_to.inner(), note.id.inner())?;
+    verify_is_remote_object(&note.id, context)?;
+
+    check_apub_id_valid_with_strictness(note.id.inner(), false, context).await?;
+    let person = note.attributed_to.dereference(context).await?;
+    InstanceActions::check_ban(&mut context.pool(), person.id, person.instance_id).await?;
+    Ok(())
+  }
+
+  async fn from_json(
+    note: ConversationMessage,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<ApubConversationMessage> {
+    let creator = note.attributed_to.dereference(context).await?;
+    let conversation_ap_id = note.context.clone().into();
+    let conversation =
+      match Conversation::read_from_apub_id(&mut context.pool(), conversation_ap_id).await? {
+        // Only participants may write into an existing conversation
+        Some(conversation) => {
+          ConversationParticipant::read(&mut context.pool(), conversation.id, creator.id).await?;
+          conversation
+        }
+        None => {
+          // Only people from the conversation's instance can start it
+          verify_domains_match(&note.context, note.attributed_to.inner())?;
+          let conversation = Conversation::read_or_create_from_apub_id(
+            &mut context.pool(),
+            note.context.clone().into(),
+            creator.id,
+          )
+          .await?;
+          add_conversation_participants(conversation.id, &creator, &note.to, context).await?;
+          conversation
+        }
+      };
+
+    let slur_regex = slur_regex(context).await?;
+    let url_blocklist = get_url_blocklist(context).await?;
+    let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+
+    let content = read_from_string_or_source(&note.content, &None, &note.source);
+    let content =
+      process_markdown(&content, &slur_regex, &url_blocklist, &local_site, context).await?;
+    let content = markdown_rewrite_remote_links(content, context).await;
+
+    let form = ConversationMessageInsertForm {
+      published_at: note.published,
+      updated_at: note.updated,
+      ap_id: Some(note.id.into()),
+      local: Some(false),
+      ..ConversationMessageInsertForm::new(conversation.id, creator.id, content)
+    };
+    let timestamp = note.updated.or(note.published).unwrap_or_else(Utc::now);
+    let message = DbConversationMessage::insert_apub(&mut context.pool(), timestamp, &form).await?;
+    Ok(message.into())
+  }
+}
+
+/// Adds the sender and recipients of a message or activity to a conversation. Local users who
+/// blocked the sender or disabled private messages aren't added.
+pub async fn add_conversation_participants(
+  conversation_id: ConversationId,
+  sender: &Person,
+  recipients: &[ObjectId<ApubPerson>],
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let mut participant_ids = vec![sender.id];
+  for recipient in recipients {
+    let recipient = recipient.dereference(context).await?;
+    if PersonActions::read_block(&mut context.pool(), recipient.id, sender.id)
+      .await
+      .is_err()
+    {
+      continue;
+    }
+    if let Ok(local_user) = LocalUserView::read_person(&mut context.pool(), recipient.id).await
+      && check_private_messages_enabled(&local_user).is_err()
+    {
+      continue;
+    }
+    participant_ids.push(recipient.id);
+  }
+  ConversationParticipant::add(&mut context.pool(), conversation_id, &participant_ids).await?;
+  Ok(())
+}
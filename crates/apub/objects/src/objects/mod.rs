@@ -1,6 +1,8 @@
 pub mod comment;
 pub mod community;
+pub mod conversation_message;
 pub mod instance;
+pub mod modmail_message;
 pub mod multi_community;
 pub mod multi_community_collection;
 pub mod person;
@@ -22,7 +24,8 @@ pub type PostOrComment = Either<ApubPost, ApubComment>;
 
 pub type SearchableObjects = Either<Either<PostOrComment, UserOrCommunity>, ApubMultiCommunity>;
 
-pub type ReportableObjects = Either<PostOrComment, Either<ApubCommunity, ApubPrivateMessage>>;
+pub type ReportableObjects =
+  Either<PostOrComment, Either<ApubCommunity, Either<ApubPrivateMessage, ApubPerson>>>;
 
 pub type UserOrCommunity = Either<ApubPerson, ApubCommunity>;
 
@@ -0,0 +1,163 @@
+use crate::{
+  protocol::{modmail_message::ModmailMessage, private_message::PrivateMessageType},
+  utils::{
+    functions::{check_apub_id_valid_with_strictness, read_from_string_or_source},
+    markdown_links::markdown_rewrite_remote_links,
+    protocol::Source,
+  },
+};
+use activitypub_federation::{
+  config::Data,
+  protocol::{
+    values::MediaTypeHtml,
+    verification::{verify_domains_match, verify_is_remote_object},
+  },
+  traits::Object,
+};
+use either::Either;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::source::{
+  community::Community,
+  instance::InstanceActions,
+  modmail::{ModmailMessage as DbModmailMessage, ModmailMessageInsertForm, ModmailThread},
+  person::Person,
+};
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType, LemmyResult, UntranslatedError},
+  utils::markdown::markdown_to_html,
+};
+use std::ops::Deref;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub struct ApubModmailMessage(pub DbModmailMessage);
+
+impl Deref for ApubModmailMessage {
+  type Target = DbModmailMessage;
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl From<DbModmailMessage> for ApubModmailMessage {
+  fn from(m: DbModmailMessage) -> Self {
+    ApubModmailMessage(m)
+  }
+}
+
+#[async_trait::async_trait]
+impl Object for ApubModmailMessage {
+  type DataType = LemmyContext;
+  type Kind = ModmailMessage;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    self.ap_id.inner()
+  }
+
+  async fn read_from_id(
+    object_id: Url,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<Option<Self>> {
+    Ok(
+      DbModmailMessage::read_from_apub_id(&mut context.pool(), object_id.into())
+        .await?
+        .map(Into::into),
+    )
+  }
+
+  async fn delete(&self, _context: &Data<Self::DataType>) -> LemmyResult<()> {
+    // do nothing, because modmail can't be fetched over http
+    Err(LemmyErrorType::NotFound.into())
+  }
+
+  async fn into_json(self, context: &Data<Self::DataType>) -> LemmyResult<ModmailMessage> {
+    let thread = ModmailThread::read(&mut context.pool(), self.modmail_thread_id).await?;
+    let community = Community::read(&mut context.pool(), thread.community_id).await?;
+    let person = Person::read(&mut context.pool(), thread.creator_id).await?;
+
+    // Replies of the moderators are sent as the community
+    let (attributed_to, to) = if self.from_moderator {
+      (community.ap_id, person.ap_id)
+    } else {
+      (person.ap_id, community.ap_id)
+    };
+    Ok(ModmailMessage {
+      kind: PrivateMessageType::Note,
+      id: self.ap_id.clone().into(),
+      attributed_to: attributed_to.into(),
+      to: [to.into()],
+      content: markdown_to_html(&self.content),
+      media_type: Some(MediaTypeHtml::Html),
+      source: Some(Source::new(self.content.clone())),
+      published: Some(self.published_at),
+    })
+  }
+
+  async fn verify(
+    note: &ModmailMessage,
+    expected_domain: &Url,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<()> {
+    verify_domains_match(note.id.inner(), expected_domain)?;
+    verify_domains_match(note.attributed_to.inner(), note.id.inner())?;
+    verify_is_remote_object(&note.id, context)?;
+
+    check_apub_id_valid_with_strictness(note.id.inner(), false, context).await?;
+    if let Either::Left(person) = note.attributed_to.dereference(context).await? {
+      InstanceActions::check_ban(&mut context.pool(), person.id, person.instance_id).await?;
+    }
+    Ok(())
+  }
+
+  async fn from_json(
+    note: ModmailMessage,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<ApubModmailMessage> {
+    let [to] = &note.to;
+    let author = note.attributed_to.dereference(context).await?;
+    let recipient = to.dereference(context).await?;
+    let (thread, creator_id, from_moderator) = match (author, recipient) {
+      // Modmail is only handled on the home instance of the community
+      (Either::Left(person), Either::Right(community)) if community.local => {
+        let thread =
+          ModmailThread::read_or_create(&mut context.pool(), community.id, person.id).await?;
+        if thread.resolved {
+          ModmailThread::reopen(&mut context.pool(), thread.id).await?;
+        }
+        (thread, Some(person.id), false)
+      }
+      // The moderator who wrote a reply isn't known
+      (Either::Right(community), Either::Left(person)) if person.local => {
+        let thread =
+          ModmailThread::read_or_create(&mut context.pool(), community.id, person.id).await?;
+        (thread, None, true)
+      }
+      _ => return Err(UntranslatedError::InvalidModmail.into()),
+    };
+
+    let slur_regex = slur_regex(context).await?;
+    let url_blocklist = get_url_blocklist(context).await?;
+    let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+
+    let content = read_from_string_or_source(&note.content, &None, &note.source);
+    let content =
+      process_markdown(&content, &slur_regex, &url_blocklist, &local_site, context).await?;
+    let content = markdown_rewrite_remote_links(content, context).await;
+
+    let form = ModmailMessageInsertForm {
+      from_moderator: Some(from_moderator),
+      published_at: note.published,
+      ap_id: Some(note.id.into()),
+      local: Some(false),
+      ..ModmailMessageInsertForm::new(thread.id, creator_id, content)
+    };
+    let message = DbModmailMessage::insert_apub(&mut context.pool(), &form).await?;
+    Ok(message.into())
+  }
+}
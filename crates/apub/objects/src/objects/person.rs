@@ -20,6 +20,7 @@ use chrono::{DateTime, Utc};
 use lemmy_api_utils::{
   context::LemmyContext,
   utils::{
+    generate_followers_url,
     generate_outbox_url,
     get_url_blocklist,
     process_markdown_opt,
@@ -99,12 +100,21 @@ impl Object for ApubPerson {
     self.deleted
   }
 
-  async fn into_json(self, _context: &Data<Self::DataType>) -> LemmyResult<Person> {
+  async fn into_json(self, context: &Data<Self::DataType>) -> LemmyResult<Person> {
     let kind = if self.bot_account {
       UserTypes::Service
     } else {
       UserTypes::Person
     };
+    let moved_to = match self.moved_to_id {
+      Some(moved_to_id) => Some(
+        DbPerson::read(&mut context.pool(), moved_to_id)
+          .await?
+          .ap_id
+          .into(),
+      ),
+      None => None,
+    };
 
     let person = Person {
       kind,
@@ -118,10 +128,17 @@ impl Object for ApubPerson {
       matrix_user_id: self.matrix_user_id.clone(),
       published: Some(self.published_at),
       outbox: generate_outbox_url(&self.ap_id)?.into(),
+      followers: Some(generate_followers_url(&self.ap_id)?.into()),
       endpoints: None,
       public_key: self.public_key(),
       updated: self.updated_at,
       inbox: self.inbox_url.clone().into(),
+      also_known_as: self
+        .also_known_as
+        .iter()
+        .map(|a| a.inner().clone())
+        .collect(),
+      moved_to,
     };
     Ok(person)
   }
@@ -157,6 +174,12 @@ impl Object for ApubPerson {
       .map(|s| remove_slurs(&s, &slur_regex))
       .map(|d| truncate_for_db(&d, DISPLAY_NAME_MAX_LENGTH));
 
+    // Only link the new account if it is already known, to avoid fetching a chain of accounts
+    let moved_to_id = match person.moved_to {
+      Some(moved_to) => moved_to.dereference_local(context).await.ok().map(|p| p.id),
+      None => None,
+    };
+
     let person_form = PersonInsertForm {
       name: person.preferred_username,
       display_name,
@@ -181,6 +204,8 @@ impl Object for ApubPerson {
       ),
       matrix_user_id: person.matrix_user_id,
       instance_id,
+      also_known_as: Some(person.also_known_as.into_iter().map(Into::into).collect()),
+      moved_to_id,
     };
     let person = DbPerson::upsert(&mut context.pool(), &person_form).await?;
 
@@ -1,6 +1,6 @@
 use crate::{
   protocol::{
-    page::{Attachment, Page, PageType},
+    page::{Attachment, Page, PageType, PollOption as ApubPollOption, PollOptionReplies},
     tags::{ApubCommunityTag, ApubTag, Hashtag, HashtagType},
   },
   utils::{
@@ -19,6 +19,7 @@ use crate::{
 };
 use activitypub_federation::{
   config::Data,
+  kinds::{collection::CollectionType, object::NoteType},
   protocol::{
     values::MediaTypeMarkdownOrHtml,
     verification::{verify_domains_match, verify_is_remote_object},
@@ -36,17 +37,22 @@ use lemmy_api_utils::{
     check_nsfw_allowed,
     get_url_blocklist,
     process_markdown_opt,
+    requires_approval,
     slur_regex,
     update_post_tags,
   },
+  webhooks::send_webhook,
 };
 use lemmy_db_schema::source::{
   community::Community,
   community_tag::CommunityTag,
   local_site::LocalSite,
   person::Person,
+  poll::{Poll, PollInsertForm, PollOption, PollOptionInsertForm},
   post::{Post, PostInsertForm, PostUpdateForm},
+  post_revision::PostRevision,
 };
+use lemmy_db_schema_file::enums::WebhookEventType;
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::traits::Crud;
@@ -157,8 +163,33 @@ impl Object for ApubPost {
     let maa = collect_non_local_mentions(self.body.as_deref(), None, &community, context).await?;
     tags.extend(maa.mentions);
 
+    // Posts with a poll are sent as `Question`, with the options and their vote counts in the
+    // same format as Mastodon.
+    let poll = Poll::read(&mut context.pool(), self.id).await?;
+    let (kind, one_of, any_of, end_time) = if let Some(poll) = poll {
+      let options = PollOption::list_for_post(&mut context.pool(), self.id)
+        .await?
+        .into_iter()
+        .map(|o| ApubPollOption {
+          kind: NoteType::Note,
+          name: o.name,
+          replies: Some(PollOptionReplies {
+            kind: CollectionType::Collection,
+            total_items: o.vote_count,
+          }),
+        })
+        .collect();
+      if poll.multiple_choice {
+        (PageType::Question, None, Some(options), poll.end_time_at)
+      } else {
+        (PageType::Question, Some(options), None, poll.end_time_at)
+      }
+    } else {
+      (PageType::Page, None, None, None)
+    };
+
     let page = Page {
-      kind: PageType::Page,
+      kind,
       id: self.ap_id.clone().into(),
       attributed_to: AttributedTo::Lemmy(creator.ap_id.into()),
       to: generate_to(&community)?,
@@ -177,6 +208,9 @@ impl Object for ApubPost {
       in_reply_to: None,
       tag: tags,
       context: Some(context_url(&self.ap_id)),
+      one_of,
+      any_of,
+      end_time,
     };
     Ok(page)
   }
@@ -288,7 +322,16 @@ impl Object for ApubPost {
       .await?,
     );
 
-    let orig_post = Post::read_from_apub_id(&mut context.pool(), page.id.clone().into()).await;
+    let orig_post = Post::read_from_apub_id(&mut context.pool(), page.id.clone().into())
+      .await
+      .ok()
+      .flatten();
+    // New posts in local communities may need to be held in the mod queue
+    let approval_pending = if orig_post.is_none() {
+      Some(requires_approval(&creator, &community, true, &mut context.pool()).await?)
+    } else {
+      None
+    };
     let mut form = PostInsertForm {
       url: url.map(Into::into),
       body,
@@ -301,6 +344,7 @@ impl Object for ApubPost {
       // May be a local post which is updated by remote mod.
       local: Some(page.id.is_local(context)),
       language_id,
+      approval_pending,
       ..PostInsertForm::new(name, creator.id, community.id)
     };
     form = plugin_hook_before("federated_post_before_receive", form).await?;
@@ -309,13 +353,27 @@ impl Object for ApubPost {
     let post = Post::insert_apub(&mut context.pool(), timestamp, &form).await?;
     plugin_hook_after("federated_post_after_receive", &post);
 
+    match &orig_post {
+      Some(orig_post) => {
+        PostRevision::create_if_changed(&mut context.pool(), orig_post, &post).await?;
+      }
+      None if !post.approval_pending => send_webhook(
+        WebhookEventType::NewPost,
+        Some(post.community_id),
+        &post,
+        context,
+      ),
+      None => {}
+    }
+
     update_apub_post_tags(&page, &post, context).await?;
+    update_apub_post_poll(&page, &post, context).await?;
 
     let post_ = post.clone();
     let context_ = context.clone();
 
     // Avoid regenerating metadata if the post already existed with the same url
-    let no_generate_metadata = orig_post.ok().flatten().is_some_and(|p| p.url == post.url);
+    let no_generate_metadata = orig_post.is_some_and(|p| p.url == post.url);
     if !no_generate_metadata {
       // Generates a post thumbnail in background task, because some sites can be very slow to
       // respond.
@@ -349,6 +407,44 @@ pub async fn update_apub_post_tags(
   Ok(())
 }
 
+/// Stores the poll options of a `Question`, including the vote counts from the origin instance.
+/// Removes the poll if the post isn't a `Question` anymore.
+pub async fn update_apub_post_poll(
+  page: &Page,
+  post: &Post,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let Some(options) = page.one_of.as_ref().or(page.any_of.as_ref()) else {
+    // The poll was removed, or the post never had one
+    Poll::delete(&mut context.pool(), post.id).await?;
+    return Ok(());
+  };
+  let form = PollInsertForm {
+    post_id: post.id,
+    multiple_choice: page.any_of.is_some(),
+    end_time_at: page.end_time,
+    updated_at: page.updated,
+  };
+  let options = options
+    .iter()
+    .zip(0..)
+    .map(|(o, display_order)| PollOptionInsertForm {
+      post_id: post.id,
+      name: o.name.clone(),
+      display_order,
+      // Votes on local polls are counted by the database.
+      vote_count: (!post.local).then(|| {
+        o.replies
+          .as_ref()
+          .map(|r| r.total_items)
+          .unwrap_or_default()
+      }),
+    })
+    .collect::<Vec<_>>();
+  Poll::upsert(&mut context.pool(), &form, &options).await?;
+  Ok(())
+}
+
 pub async fn append_attachments_to_body(
   content: &Option<String>,
   attachments: &[Attachment],
@@ -0,0 +1,34 @@
+use crate::{
+  objects::{conversation_message::ApubConversationMessage, person::ApubPerson},
+  protocol::private_message::PrivateMessageType,
+  utils::protocol::Source,
+};
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  protocol::{helpers::deserialize_skip_error, values::MediaTypeHtml},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+
+/// A message in a group conversation. Unlike a private message it is addressed to several people,
+/// and `context` identifies the conversation which it belongs to.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationMessage {
+  #[serde(rename = "type")]
+  pub(crate) kind: PrivateMessageType,
+  pub id: ObjectId<ApubConversationMessage>,
+  pub attributed_to: ObjectId<ApubPerson>,
+  pub to: Vec<ObjectId<ApubPerson>>,
+  pub context: Url,
+  pub(crate) content: String,
+
+  pub(crate) media_type: Option<MediaTypeHtml>,
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) source: Option<Source>,
+  pub(crate) published: Option<DateTime<Utc>>,
+  pub(crate) updated: Option<DateTime<Utc>>,
+}
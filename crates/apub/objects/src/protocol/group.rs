@@ -1,6 +1,6 @@
 use crate::{
   objects::community::ApubCommunity,
-  protocol::tags::ApubCommunityTag,
+  protocol::tags::{ApubCommunityFlair, ApubCommunityRule, ApubCommunityTag},
   utils::protocol::{AttributedTo, Endpoints, ImageObject, LanguageTag, Source},
 };
 use activitypub_federation::{
@@ -64,4 +64,11 @@ pub struct Group {
   pub(crate) discoverable: Option<bool>,
   #[serde(deserialize_with = "deserialize_skip_error", default)]
   pub(crate) tag: Vec<ApubCommunityTag>,
+  /// Person flairs defined by the community. Assignments are federated separately with
+  /// Add/Remove activities, using the flair as collection.
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) flair: Vec<ApubCommunityFlair>,
+  /// Numbered rules of the community
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) rules: Vec<ApubCommunityRule>,
 }
@@ -1,5 +1,7 @@
+pub mod conversation_message;
 pub mod group;
 pub mod instance;
+pub mod modmail_message;
 pub mod multi_community;
 pub mod note;
 pub mod page;
@@ -10,8 +12,10 @@ pub mod tags;
 #[cfg(test)]
 mod tests {
   use super::{
+    conversation_message::ConversationMessage,
     group::Group,
     instance::Instance,
+    modmail_message::ModmailMessage,
     note::Note,
     page::Page,
     person::Person,
@@ -29,6 +33,10 @@ mod tests {
     test_parse_lemmy_item::<Page>("../apub/assets/lemmy/objects/page.json")?;
     test_parse_lemmy_item::<Note>("../apub/assets/lemmy/objects/comment.json")?;
     test_parse_lemmy_item::<PrivateMessage>("../apub/assets/lemmy/objects/private_message.json")?;
+    test_parse_lemmy_item::<ConversationMessage>(
+      "../apub/assets/lemmy/objects/conversation_message.json",
+    )?;
+    test_parse_lemmy_item::<ModmailMessage>("../apub/assets/lemmy/objects/modmail_message.json")?;
     test_parse_lemmy_item::<Tombstone>("../apub/assets/lemmy/objects/tombstone.json")?;
     Ok(())
   }
@@ -53,6 +61,7 @@ mod tests {
     test_json::<Note>("../apub/assets/mastodon/objects/note_1.json")?;
     test_json::<Note>("../apub/assets/mastodon/objects/note_2.json")?;
     test_json::<Page>("../apub/assets/mastodon/objects/page.json")?;
+    test_json::<Page>("../apub/assets/mastodon/objects/question.json")?;
     Ok(())
   }
 
@@ -0,0 +1,35 @@
+use crate::{
+  objects::{UserOrCommunity, modmail_message::ApubModmailMessage},
+  protocol::private_message::PrivateMessageType,
+  utils::protocol::Source,
+};
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  protocol::{
+    helpers::{deserialize_one, deserialize_skip_error},
+    values::MediaTypeHtml,
+  },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A direct message between a person and a community. Messages to the community go to its
+/// moderators, and their replies are attributed to the community.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailMessage {
+  #[serde(rename = "type")]
+  pub(crate) kind: PrivateMessageType,
+  pub id: ObjectId<ApubModmailMessage>,
+  pub attributed_to: ObjectId<UserOrCommunity>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub to: [ObjectId<UserOrCommunity>; 1],
+  pub(crate) content: String,
+
+  pub(crate) media_type: Option<MediaTypeHtml>,
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) source: Option<Source>,
+  pub(crate) published: Option<DateTime<Utc>>,
+}
@@ -14,8 +14,9 @@ use activitypub_federation::{
   config::Data,
   fetch::object_id::ObjectId,
   kinds::{
+    collection::CollectionType,
     link::LinkType,
-    object::{DocumentType, ImageType},
+    object::{DocumentType, ImageType, NoteType},
   },
   protocol::{
     helpers::{deserialize_one_or_many, deserialize_skip_error},
@@ -40,6 +41,8 @@ pub enum PageType {
   Note,
   Video,
   Event,
+  /// A post with a poll
+  Question,
 }
 
 #[skip_serializing_none]
@@ -78,6 +81,29 @@ pub struct Page {
   #[serde(deserialize_with = "deserialize_skip_error", default)]
   pub tag: Vec<ApubTag>,
   pub(crate) context: Option<String>,
+  /// Options of a single choice poll, only for `Question`.
+  pub(crate) one_of: Option<Vec<PollOption>>,
+  /// Options of a multiple choice poll, only for `Question`.
+  pub(crate) any_of: Option<Vec<PollOption>>,
+  pub(crate) end_time: Option<DateTime<Utc>>,
+}
+
+/// Poll option as used by Mastodon, the vote count is in `replies.totalItems`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollOption {
+  #[serde(rename = "type")]
+  pub(crate) kind: NoteType,
+  pub(crate) name: String,
+  pub(crate) replies: Option<PollOptionReplies>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollOptionReplies {
+  #[serde(rename = "type")]
+  pub(crate) kind: CollectionType,
+  pub(crate) total_items: i32,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
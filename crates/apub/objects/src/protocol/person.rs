@@ -5,7 +5,7 @@ use crate::{
 use activitypub_federation::{
   fetch::object_id::ObjectId,
   protocol::{
-    helpers::{deserialize_last, deserialize_skip_error},
+    helpers::{deserialize_last, deserialize_one_or_many, deserialize_skip_error},
     public_key::PublicKey,
   },
 };
@@ -33,6 +33,7 @@ pub struct Person {
   pub(crate) inbox: Url,
   /// mandatory field in activitypub, lemmy currently serves an empty outbox
   pub(crate) outbox: Url,
+  pub(crate) followers: Option<Url>,
   pub(crate) public_key: PublicKey,
   /// displayname
   pub(crate) name: Option<String>,
@@ -50,4 +51,10 @@ pub struct Person {
   pub(crate) endpoints: Option<Endpoints>,
   pub(crate) published: Option<DateTime<Utc>>,
   pub(crate) updated: Option<DateTime<Utc>>,
+  /// Other accounts of the same user, which are allowed to move to this account
+  #[serde(deserialize_with = "deserialize_one_or_many", default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) also_known_as: Vec<Url>,
+  /// The account which this person has moved to
+  pub(crate) moved_to: Option<ObjectId<ApubPerson>>,
 }
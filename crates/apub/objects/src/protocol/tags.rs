@@ -2,7 +2,11 @@ use crate::objects::UserOrCommunity;
 use activitypub_federation::{fetch::object_id::ObjectId, kinds::link::MentionType};
 use lemmy_db_schema::{
   newtypes::CommunityId,
-  source::community_tag::{CommunityTag, CommunityTagInsertForm},
+  source::{
+    community_flair::{CommunityFlair, CommunityFlairInsertForm},
+    community_tag::{CommunityTag, CommunityTagInsertForm},
+    rule::{Rule, RuleInsertForm},
+  },
 };
 use lemmy_db_schema_file::enums::TagColor;
 use serde::{Deserialize, Serialize};
@@ -103,3 +107,88 @@ impl ApubCommunityTag {
     }
   }
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+enum CommunityFlairType {
+  #[default]
+  CommunityPersonFlair,
+}
+
+/// A flair that a community owns, which is assigned to community members.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ApubCommunityFlair {
+  #[serde(rename = "type")]
+  kind: CommunityFlairType,
+  pub id: Url,
+  pub name: Option<String>,
+  pub preferred_username: String,
+  pub color: Option<TagColor>,
+  pub self_assignable: Option<bool>,
+}
+
+impl ApubCommunityFlair {
+  pub fn to_json(flair: CommunityFlair) -> Self {
+    ApubCommunityFlair {
+      kind: Default::default(),
+      id: flair.ap_id.into(),
+      name: flair.display_name,
+      preferred_username: flair.name,
+      color: Some(flair.color),
+      self_assignable: Some(flair.self_assignable),
+    }
+  }
+
+  pub fn to_insert_form(&self, community_id: CommunityId) -> CommunityFlairInsertForm {
+    CommunityFlairInsertForm {
+      ap_id: self.id.clone().into(),
+      name: self.preferred_username.clone(),
+      display_name: self.name.clone(),
+      community_id,
+      color: self.color,
+      self_assignable: self.self_assignable,
+      deleted: Some(false),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+enum CommunityRuleType {
+  #[default]
+  CommunityRule,
+}
+
+/// A numbered rule of a community, which can be cited by reports and mod actions.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ApubCommunityRule {
+  #[serde(rename = "type")]
+  kind: CommunityRuleType,
+  pub id: Url,
+  pub name: String,
+  pub content: Option<String>,
+  #[serde(default)]
+  pub position: i32,
+}
+
+impl ApubCommunityRule {
+  pub fn to_json(rule: Rule) -> Self {
+    ApubCommunityRule {
+      kind: Default::default(),
+      id: rule.ap_id.into(),
+      name: rule.title,
+      content: rule.description,
+      position: rule.position,
+    }
+  }
+
+  pub fn to_insert_form(&self, community_id: CommunityId) -> RuleInsertForm {
+    RuleInsertForm {
+      description: self.content.clone(),
+      ap_id: Some(self.id.clone().into()),
+      local: Some(false),
+      deleted: Some(false),
+      ..RuleInsertForm::new(Some(community_id), self.position, self.name.clone())
+    }
+  }
+}
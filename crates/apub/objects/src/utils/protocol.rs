@@ -49,7 +49,7 @@ pub struct ImageObject {
 }
 
 impl ImageObject {
-  pub(crate) fn new(url: DbUrl) -> Self {
+  pub fn new(url: DbUrl) -> Self {
     ImageObject {
       kind: ImageType::Image,
       url: url.into(),
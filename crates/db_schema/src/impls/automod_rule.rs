@@ -0,0 +1,218 @@
+use crate::{
+  newtypes::{AutomodRuleId, CommentId, CommunityId, PostId},
+  source::automod_rule::{AutomodRule, AutomodRuleInsertForm, AutomodRuleUpdateForm},
+};
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  PgSortExpressionMethods,
+  QueryDsl,
+  dsl::insert_into,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::{automod_rule, automod_rule_trigger};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for AutomodRule {
+  type InsertForm = AutomodRuleInsertForm;
+  type UpdateForm = AutomodRuleUpdateForm;
+  type IdType = AutomodRuleId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(automod_rule::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    rule_id: AutomodRuleId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(automod_rule::table.find(rule_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl AutomodRule {
+  /// Lists the rules of a community, or the site rules if no community is given.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = automod_rule::table.order_by(automod_rule::id).into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(automod_rule::community_id.eq(community_id)),
+      None => query.filter(automod_rule::community_id.is_null()),
+    };
+    query
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// The enabled rules which apply to content in the given community, site rules first.
+  pub async fn list_enabled(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    automod_rule::table
+      .filter(automod_rule::enabled)
+      .filter(
+        automod_rule::community_id
+          .is_null()
+          .or(automod_rule::community_id.eq(community_id)),
+      )
+      .order_by((
+        automod_rule::community_id.asc().nulls_first(),
+        automod_rule::id,
+      ))
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Remembers that the rule was applied to the post or comment. Returns false if it was already
+  /// applied before, so that report count rules only trigger once.
+  pub async fn mark_triggered(
+    pool: &mut DbPool<'_>,
+    rule_id: AutomodRuleId,
+    post_id: PostId,
+    comment_id: Option<CommentId>,
+  ) -> LemmyResult<bool> {
+    let conn = &mut get_conn(pool).await?;
+    let inserted = insert_into(automod_rule_trigger::table)
+      .values((
+        automod_rule_trigger::automod_rule_id.eq(rule_id),
+        automod_rule_trigger::post_id.eq(post_id),
+        automod_rule_trigger::comment_id.eq(comment_id),
+      ))
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)?;
+    Ok(inserted > 0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    automod_rule::{AutomodRule, AutomodRuleInsertForm, AutomodRuleUpdateForm},
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+    post::{Post, PostInsertForm},
+  };
+  use lemmy_db_schema_file::enums::AutomodAction;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_automod_rules() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "automod")).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "ruled".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+    let other_community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "unruled".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+
+    let community_rule = AutomodRule::create(
+      pool,
+      &AutomodRuleInsertForm {
+        content_regex: Some("(?i)buy now".to_string()),
+        ..AutomodRuleInsertForm::new(
+          person.id,
+          Some(community.id),
+          "spam".to_string(),
+          AutomodAction::Remove,
+        )
+      },
+    )
+    .await?;
+    let site_rule = AutomodRule::create(
+      pool,
+      &AutomodRuleInsertForm {
+        report_count: Some(3),
+        ..AutomodRuleInsertForm::new(person.id, None, "reported".to_string(), AutomodAction::Lock)
+      },
+    )
+    .await?;
+    assert!(community_rule.check_posts && community_rule.check_comments);
+
+    // Rules need at least one condition, and the reply action needs a reply text
+    let no_condition =
+      AutomodRuleInsertForm::new(person.id, None, "all".to_string(), AutomodAction::Report);
+    assert!(AutomodRule::create(pool, &no_condition).await.is_err());
+    let no_reply_text = AutomodRuleInsertForm {
+      language_id: Some(Default::default()),
+      ..AutomodRuleInsertForm::new(person.id, None, "reply".to_string(), AutomodAction::Reply)
+    };
+    assert!(AutomodRule::create(pool, &no_reply_text).await.is_err());
+
+    assert_eq!(
+      vec![community_rule.clone()],
+      AutomodRule::list(pool, Some(community.id)).await?
+    );
+    assert_eq!(
+      vec![site_rule.clone()],
+      AutomodRule::list(pool, None).await?
+    );
+    assert_eq!(
+      vec![site_rule.clone(), community_rule.clone()],
+      AutomodRule::list_enabled(pool, community.id).await?
+    );
+    assert_eq!(
+      vec![site_rule.clone()],
+      AutomodRule::list_enabled(pool, other_community.id).await?
+    );
+
+    // Report count rules only trigger once per item
+    let post = Post::create(
+      pool,
+      &PostInsertForm::new("reported".to_string(), person.id, community.id),
+    )
+    .await?;
+    assert!(AutomodRule::mark_triggered(pool, site_rule.id, post.id, None).await?);
+    assert!(!AutomodRule::mark_triggered(pool, site_rule.id, post.id, None).await?);
+
+    // Disabled rules are not applied
+    let form = AutomodRuleUpdateForm {
+      enabled: Some(false),
+      ..Default::default()
+    };
+    AutomodRule::update(pool, site_rule.id, &form).await?;
+    assert_eq!(
+      vec![community_rule],
+      AutomodRule::list_enabled(pool, community.id).await?
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -27,7 +27,7 @@ use diesel_uplete::{UpleteCount, uplete};
 use lemmy_db_schema_file::{
   InstanceId,
   PersonId,
-  schema::{comment, comment_actions, community, post},
+  schema::{comment, comment_actions, comment_revision, community, post},
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -48,6 +48,17 @@ impl Comment {
   ) -> LemmyResult<Vec<Self>> {
     let conn = &mut get_conn(pool).await?;
 
+    // Otherwise the old content would still be readable in the edit history
+    let creator_comments = comment::table
+      .filter(comment::creator_id.eq(creator_id))
+      .select(comment::id);
+    diesel::delete(
+      comment_revision::table.filter(comment_revision::comment_id.eq_any(creator_comments)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+
     diesel::update(comment::table.filter(comment::creator_id.eq(creator_id)))
       .set((
         comment::content.eq(DELETED_REPLACEMENT_TEXT),
@@ -304,6 +315,37 @@ impl Comment {
       .with_lemmy_type(LemmyErrorType::CouldntUpdate)
   }
 
+  /// Reads the given comments, skipping those which are not in the community.
+  pub async fn read_many_in_community(
+    pool: &mut DbPool<'_>,
+    comment_ids: &[CommentId],
+    community_id: CommunityId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    comment::table
+      .inner_join(post::table)
+      .filter(comment::id.eq_any(comment_ids))
+      .filter(post::community_id.eq(community_id))
+      .select(Self::as_select())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn update_many(
+    pool: &mut DbPool<'_>,
+    comment_ids: &[CommentId],
+    form: &CommentUpdateForm,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(comment::table)
+      .filter(comment::id.eq_any(comment_ids))
+      .set(form)
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
   pub async fn read_ap_ids_for_post(
     post_id: PostId,
     pool: &mut DbPool<'_>,
@@ -314,6 +356,7 @@ impl Comment {
       .filter(not(comment::deleted))
       .filter(not(comment::removed))
       .filter(not(comment::federation_pending))
+      .filter(not(comment::approval_pending))
       .order_by(comment::id)
       .select(comment::ap_id)
       .get_results(conn)
@@ -522,6 +565,7 @@ mod tests {
       unresolved_report_count: 0,
       federation_pending: false,
       locked: false,
+      approval_pending: false,
     };
 
     let child_comment_form = CommentInsertForm::new(
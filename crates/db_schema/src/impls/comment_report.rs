@@ -1,5 +1,5 @@
 use crate::{
-  newtypes::{CommentId, CommentReportId, PostId},
+  newtypes::{CommentId, CommentReportId, CommunityId, PostId},
   source::comment_report::{CommentReport, CommentReportForm, UpdateCommentReportForm},
   traits::Reportable,
 };
@@ -15,7 +15,7 @@ use diesel_async::RunQueryDsl;
 use diesel_ltree::{Ltree, LtreeExtensions};
 use lemmy_db_schema_file::{
   PersonId,
-  schema::{comment, comment_report},
+  schema::{comment, comment_report, post},
 };
 use lemmy_diesel_utils::connection::{DbPool, get_conn};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
@@ -144,4 +144,43 @@ impl CommentReport {
     .await
     .with_lemmy_type(LemmyErrorType::CouldntUpdate)
   }
+
+  pub async fn resolve_all_for_comments(
+    pool: &mut DbPool<'_>,
+    comment_ids: &[CommentId],
+    by_resolver_id: PersonId,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(comment_report::table.filter(comment_report::comment_id.eq_any(comment_ids)))
+      .set((
+        comment_report::resolved.eq(true),
+        comment_report::resolver_id.eq(by_resolver_id),
+        comment_report::updated_at.eq(Utc::now()),
+      ))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  /// Resolves or unresolves the given reports. Reports about comments in other communities are
+  /// left unchanged.
+  pub async fn update_resolved_in_community(
+    pool: &mut DbPool<'_>,
+    report_ids: &[CommentReportId],
+    community_id: CommunityId,
+    form: &UpdateCommentReportForm,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let community_comments = comment::table
+      .inner_join(post::table)
+      .filter(post::community_id.eq(community_id))
+      .select(comment::id);
+    update(comment_report::table)
+      .filter(comment_report::id.eq_any(report_ids))
+      .filter(comment_report::comment_id.eq_any(community_comments))
+      .set(form)
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
 }
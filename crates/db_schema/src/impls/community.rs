@@ -1,6 +1,6 @@
 use crate::{
   diesel::{DecoratableTarget, JoinOnDsl, OptionalExtension},
-  newtypes::CommunityId,
+  newtypes::{CommunityFlairId, CommunityId},
   source::{
     actor_language::CommunityLanguage,
     community::{
@@ -29,12 +29,12 @@ use diesel::{
   select,
   update,
 };
-use diesel_async::RunQueryDsl;
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
 use diesel_uplete::{UpleteCount, uplete};
 use lemmy_db_schema_file::{
   PersonId,
   enums::{CommunityFollowerState, CommunityNotificationsMode, CommunityVisibility, ListingType},
-  schema::{comment, community, community_actions, instance, local_user, post},
+  schema::{comment, community, community_actions, community_flair, instance, local_user, post},
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -116,6 +116,8 @@ impl CommunityActions {
 pub enum CollectionType {
   Moderators,
   Featured,
+  /// The members which have a flair, the collection url is the ap_id of the flair.
+  Flair(CommunityFlairId),
 }
 
 impl Community {
@@ -148,8 +150,8 @@ impl Community {
     Ok(community_)
   }
 
-  /// Get the community which has a given moderators or featured url, also return the collection
-  /// type
+  /// Get the community which has a given moderators, featured or flair url, also return the
+  /// collection type
   pub async fn get_by_collection_url(
     pool: &mut DbPool<'_>,
     url: &DbUrl,
@@ -170,7 +172,15 @@ impl Community {
       if let Ok(c) = res {
         Ok((c, CollectionType::Featured))
       } else {
-        Err(LemmyErrorType::NotFound.into())
+        let (c, flair_id) = community_flair::table
+          .inner_join(community::table)
+          .filter(community_flair::ap_id.eq(url))
+          .filter(community_flair::deleted.eq(false))
+          .select((community::all_columns, community_flair::id))
+          .first::<(Community, CommunityFlairId)>(conn)
+          .await
+          .with_lemmy_type(LemmyErrorType::NotFound)?;
+        Ok((c, CollectionType::Flair(flair_id)))
       }
     }
   }
@@ -399,6 +409,78 @@ impl CommunityActions {
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
 
+  /// Moves all community follows of a person to another account, after the person migrated to a
+  /// new instance. Existing follows of the new account are left unchanged. Returns all communities
+  /// which the old account followed, so that the changes can be federated.
+  pub async fn transfer_follows(
+    pool: &mut DbPool<'_>,
+    old_person_id: PersonId,
+    new_person_id: PersonId,
+  ) -> LemmyResult<Vec<Community>> {
+    let conn = &mut get_conn(pool).await?;
+
+    conn
+      .run_transaction(|conn| {
+        async move {
+          let followed = community_actions::table
+            .inner_join(community::table)
+            .filter(community_actions::person_id.eq(old_person_id))
+            .filter(community_actions::followed_at.is_not_null())
+            .select(Community::as_select())
+            .load::<Community>(conn)
+            .await?;
+          let already_followed = community_actions::table
+            .filter(community_actions::person_id.eq(new_person_id))
+            .filter(community_actions::followed_at.is_not_null())
+            .select(community_actions::community_id)
+            .load::<CommunityId>(conn)
+            .await?;
+          let follows = community_actions::table
+            .filter(community_actions::person_id.eq(old_person_id))
+            .filter(community_actions::followed_at.is_not_null())
+            .filter(not(
+              community_actions::community_id.eq_any(already_followed),
+            ))
+            .select((
+              community_actions::community_id,
+              community_actions::follow_state.assume_not_null(),
+              community_actions::followed_at.assume_not_null(),
+            ))
+            .load::<(CommunityId, CommunityFollowerState, DateTime<Utc>)>(conn)
+            .await?;
+
+          for (community_id, follow_state, followed_at) in &follows {
+            let form = CommunityFollowerForm {
+              followed_at: *followed_at,
+              ..CommunityFollowerForm::new(*community_id, new_person_id, *follow_state)
+            };
+            insert_into(community_actions::table)
+              .values(&form)
+              .on_conflict((
+                community_actions::community_id,
+                community_actions::person_id,
+              ))
+              .do_update()
+              .set(&form)
+              .execute(conn)
+              .await
+              .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+          }
+
+          uplete(community_actions::table.filter(community_actions::person_id.eq(old_person_id)))
+            .set_null(community_actions::followed_at)
+            .set_null(community_actions::follow_state)
+            .set_null(community_actions::follow_approver_id)
+            .get_result::<UpleteCount>(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+          Ok(followed)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
   pub async fn update_notification_state(
     community_id: CommunityId,
     person_id: PersonId,
@@ -505,7 +587,7 @@ impl Followable for CommunityActions {
   }
   async fn follow_accepted(
     pool: &mut DbPool<'_>,
-    community_id: CommunityId,
+    community_id: Self::IdType,
     person_id: PersonId,
   ) -> LemmyResult<Self> {
     let conn = &mut get_conn(pool).await?;
@@ -748,6 +830,13 @@ mod tests {
       unresolved_report_count: 0,
       interactions_month: 0,
       local_removed: false,
+      posts_require_approval: false,
+      comments_require_approval: false,
+      approval_max_account_age_days: None,
+      approval_remote_only: false,
+      warning_ban_threshold: None,
+      warning_ban_window_days: None,
+      warning_ban_days: None,
     };
 
     let community_follower_form = CommunityFollowerForm::new(
@@ -980,4 +1069,62 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_transfer_follows() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let old_person =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "mover_old")).await?;
+    let new_person =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "mover_new")).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "moved_to".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+    let private_community = Community::create(
+      pool,
+      &CommunityInsertForm::new(
+        instance.id,
+        "moved_private".to_string(),
+        "pubkey".to_string(),
+      ),
+    )
+    .await?;
+
+    // The new account already follows one of the communities
+    for person_id in [old_person.id, new_person.id] {
+      let form =
+        CommunityFollowerForm::new(community.id, person_id, CommunityFollowerState::Accepted);
+      CommunityActions::follow(pool, &form).await?;
+    }
+    let form = CommunityFollowerForm::new(
+      private_community.id,
+      old_person.id,
+      CommunityFollowerState::ApprovalRequired,
+    );
+    CommunityActions::follow(pool, &form).await?;
+
+    let followed = CommunityActions::transfer_follows(pool, old_person.id, new_person.id).await?;
+    assert_eq!(2, followed.len());
+
+    assert!(
+      CommunityActions::list_subscribed_community_ids(pool, old_person.id)
+        .await?
+        .is_empty()
+    );
+    let follow = CommunityActions::read(pool, private_community.id, new_person.id).await?;
+    assert_eq!(
+      Some(CommunityFollowerState::ApprovalRequired),
+      follow.follow_state
+    );
+    assert_eq!(1, Community::read(pool, community.id).await?.subscribers);
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
 }
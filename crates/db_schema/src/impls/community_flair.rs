@@ -0,0 +1,272 @@
+use crate::{
+  diesel::SelectableHelper,
+  newtypes::{CommunityFlairId, CommunityId},
+  source::{
+    community::{CommunityActions, CommunityPersonFlairForm},
+    community_flair::{
+      CommunityFlair,
+      CommunityFlairInsertForm,
+      CommunityFlairUpdateForm,
+      CommunityFlairsView,
+      PersonFlair,
+    },
+  },
+};
+use diesel::{
+  ExpressionMethods,
+  QueryDsl,
+  deserialize::FromSql,
+  insert_into,
+  pg::{Pg, PgValue},
+  serialize::ToSql,
+  sql_types::{Json, Nullable},
+  upsert::excluded,
+};
+use diesel_async::RunQueryDsl;
+use diesel_uplete::{UpleteCount, uplete};
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{community_actions, community_flair},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  dburl::DbUrl,
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use std::collections::HashSet;
+
+impl Crud for CommunityFlair {
+  type InsertForm = CommunityFlairInsertForm;
+  type UpdateForm = CommunityFlairUpdateForm;
+  type IdType = CommunityFlairId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_flair::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    pid: CommunityFlairId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(community_flair::table.find(pid))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl CommunityFlair {
+  pub async fn read_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    community_flair::table
+      .filter(community_flair::community_id.eq(community_id))
+      .filter(community_flair::deleted.eq(false))
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Upserts the flairs of a remote community, and marks flairs which are missing as deleted.
+  pub async fn update_many(
+    pool: &mut DbPool<'_>,
+    mut forms: Vec<CommunityFlairInsertForm>,
+    existing_flairs: Vec<CommunityFlair>,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let new_flair_ids = forms
+      .iter()
+      .map(|flair| flair.ap_id.clone())
+      .collect::<HashSet<_>>();
+    let delete_forms = existing_flairs
+      .into_iter()
+      .filter(|flair| !new_flair_ids.contains(&flair.ap_id))
+      .map(|f| CommunityFlairInsertForm {
+        ap_id: f.ap_id,
+        name: f.name,
+        display_name: None,
+        community_id: f.community_id,
+        color: Some(f.color),
+        self_assignable: Some(f.self_assignable),
+        deleted: Some(true),
+      });
+    forms.extend(delete_forms);
+    if forms.is_empty() {
+      return Ok(vec![]);
+    }
+
+    insert_into(community_flair::table)
+      .values(&forms)
+      .on_conflict(community_flair::ap_id)
+      .do_update()
+      .set((
+        community_flair::display_name.eq(excluded(community_flair::display_name)),
+        community_flair::color.eq(excluded(community_flair::color)),
+        community_flair::self_assignable.eq(excluded(community_flair::self_assignable)),
+        community_flair::deleted.eq(excluded(community_flair::deleted)),
+      ))
+      .get_results::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  pub async fn read_apub(pool: &mut DbPool<'_>, ap_id: &DbUrl) -> LemmyResult<CommunityFlair> {
+    let conn = &mut get_conn(pool).await?;
+    community_flair::table
+      .filter(community_flair::ap_id.eq(ap_id))
+      .filter(community_flair::deleted.eq(false))
+      .select(Self::as_select())
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn assign(
+    pool: &mut DbPool<'_>,
+    form: &CommunityPersonFlairForm,
+  ) -> LemmyResult<CommunityActions> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_actions::table)
+      .values(form)
+      .on_conflict((
+        community_actions::person_id,
+        community_actions::community_id,
+      ))
+      .do_update()
+      .set(form)
+      .returning(CommunityActions::as_select())
+      .get_result::<CommunityActions>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  pub async fn unassign(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> LemmyResult<UpleteCount> {
+    let conn = &mut get_conn(pool).await?;
+    uplete(community_actions::table.find((person_id, community_id)))
+      .set_null(community_actions::flair_id)
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl FromSql<Nullable<Json>, Pg> for CommunityFlairsView {
+  fn from_sql(bytes: PgValue) -> diesel::deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Json, Pg>>::from_sql(bytes)?;
+    Ok(serde_json::from_value::<CommunityFlairsView>(value)?)
+  }
+  fn from_nullable_sql(
+    bytes: Option<<Pg as diesel::backend::Backend>::RawValue<'_>>,
+  ) -> diesel::deserialize::Result<Self> {
+    match bytes {
+      Some(bytes) => Self::from_sql(bytes),
+      None => Ok(Self(vec![])),
+    }
+  }
+}
+
+impl ToSql<Nullable<Json>, Pg> for CommunityFlairsView {
+  fn to_sql(&self, out: &mut diesel::serialize::Output<Pg>) -> diesel::serialize::Result {
+    let value = serde_json::to_value(self)?;
+    <serde_json::Value as ToSql<Json, Pg>>::to_sql(&value, &mut out.reborrow())
+  }
+}
+
+impl FromSql<Json, Pg> for PersonFlair {
+  fn from_sql(bytes: PgValue) -> diesel::deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Json, Pg>>::from_sql(bytes)?;
+    Ok(serde_json::from_value::<PersonFlair>(value)?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    impls::community::CollectionType,
+    source::{
+      community::{Community, CommunityActions, CommunityInsertForm, CommunityPersonFlairForm},
+      community_flair::{CommunityFlair, CommunityFlairInsertForm, CommunityFlairUpdateForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_community_flair() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(instance.id, "flair_person");
+    let person = Person::create(pool, &person_form).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "flair".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+
+    let flair_form = CommunityFlairInsertForm {
+      ap_id: Url::parse(&format!("{}/flair/team_a", community.ap_id))?.into(),
+      name: "team_a".to_owned(),
+      display_name: Some("Team A".to_owned()),
+      community_id: community.id,
+      color: None,
+      self_assignable: Some(true),
+      deleted: None,
+    };
+    let flair = CommunityFlair::create(pool, &flair_form).await?;
+    assert!(flair.self_assignable);
+
+    let form = CommunityPersonFlairForm::new(community.id, person.id, flair.id);
+    let actions = CommunityFlair::assign(pool, &form).await?;
+    assert_eq!(Some(flair.id), actions.flair_id);
+    // Flair assignments are federated with the flair as collection
+    let (flair_community, collection_type) =
+      Community::get_by_collection_url(pool, &flair.ap_id).await?;
+    assert_eq!(community.id, flair_community.id);
+    assert!(matches!(collection_type, CollectionType::Flair(id) if id == flair.id));
+
+    CommunityFlair::unassign(pool, community.id, person.id).await?;
+    assert!(
+      CommunityActions::read(pool, community.id, person.id)
+        .await
+        .is_err()
+    );
+
+    // Deleted flairs are not listed
+    let update_form = CommunityFlairUpdateForm {
+      deleted: Some(true),
+      ..Default::default()
+    };
+    CommunityFlair::update(pool, flair.id, &update_form).await?;
+    assert!(
+      CommunityFlair::read_for_community(pool, community.id)
+        .await?
+        .is_empty()
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
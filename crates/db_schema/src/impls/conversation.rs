@@ -0,0 +1,316 @@
+use crate::{
+  diesel::{DecoratableTarget, OptionalExtension},
+  newtypes::{ConversationId, ConversationMessageId},
+  source::{
+    conversation::{
+      Conversation,
+      ConversationInsertForm,
+      ConversationMessage,
+      ConversationMessageInsertForm,
+      ConversationMessageUpdateForm,
+      ConversationParticipant,
+      ConversationParticipantForm,
+    },
+    person::Person,
+  },
+};
+use chrono::{DateTime, Utc};
+use diesel::{
+  ExpressionMethods,
+  NullableExpressionMethods,
+  QueryDsl,
+  SelectableHelper,
+  delete,
+  dsl::insert_into,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{conversation, conversation_message, conversation_participant, person},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  dburl::DbUrl,
+  traits::Crud,
+  utils::{functions::coalesce, now},
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for Conversation {
+  type InsertForm = ConversationInsertForm;
+  type UpdateForm = ConversationInsertForm;
+  type IdType = ConversationId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(conversation::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(conversation::table.find(conversation_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl Conversation {
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: DbUrl,
+  ) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    conversation::table
+      .filter(conversation::ap_id.eq(object_id))
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Returns the conversation for a federated message, creating it if this is the first message
+  /// which this instance receives from it.
+  pub async fn read_or_create_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: DbUrl,
+    creator_id: PersonId,
+  ) -> LemmyResult<Self> {
+    if let Some(conversation) = Self::read_from_apub_id(pool, object_id.clone()).await? {
+      return Ok(conversation);
+    }
+    let form = ConversationInsertForm {
+      ap_id: Some(object_id),
+      local: Some(false),
+      ..ConversationInsertForm::new(creator_id)
+    };
+    let conn = &mut get_conn(pool).await?;
+    insert_into(conversation::table)
+      .values(&form)
+      .on_conflict(conversation::ap_id)
+      .do_update()
+      .set(&form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn list_participants(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+  ) -> LemmyResult<Vec<Person>> {
+    let conn = &mut get_conn(pool).await?;
+    conversation_participant::table
+      .inner_join(person::table)
+      .filter(conversation_participant::conversation_id.eq(conversation_id))
+      .order_by(conversation_participant::published_at)
+      .select(Person::as_select())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl ConversationParticipant {
+  /// Fails with [LemmyErrorType::NotAConversationParticipant] if the person isn't part of the
+  /// conversation.
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_id: PersonId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    conversation_participant::table
+      .find((conversation_id, person_id))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotAConversationParticipant)
+  }
+
+  /// Adds the persons to the conversation. Existing participants are ignored.
+  pub async fn add(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_ids: &[PersonId],
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    let forms = person_ids
+      .iter()
+      .map(|person_id| ConversationParticipantForm::new(conversation_id, *person_id))
+      .collect::<Vec<_>>();
+    insert_into(conversation_participant::table)
+      .values(forms)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn remove(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_id: PersonId,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    delete(conversation_participant::table.find((conversation_id, person_id)))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotAConversationParticipant)
+  }
+
+  pub async fn mark_as_read(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_id: PersonId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(conversation_participant::table.find((conversation_id, person_id)))
+      .set(conversation_participant::last_read_at.eq(now().nullable()))
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotAConversationParticipant)
+  }
+}
+
+impl Crud for ConversationMessage {
+  type InsertForm = ConversationMessageInsertForm;
+  type UpdateForm = ConversationMessageUpdateForm;
+  type IdType = ConversationMessageId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(conversation_message::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    conversation_message_id: ConversationMessageId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(conversation_message::table.find(conversation_message_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl ConversationMessage {
+  pub async fn insert_apub(
+    pool: &mut DbPool<'_>,
+    timestamp: DateTime<Utc>,
+    form: &ConversationMessageInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(conversation_message::table)
+      .values(form)
+      .on_conflict(conversation_message::ap_id)
+      .filter_target(
+        coalesce(
+          conversation_message::updated_at,
+          conversation_message::published_at,
+        )
+        .lt(timestamp),
+      )
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: DbUrl,
+  ) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    conversation_message::table
+      .filter(conversation_message::ap_id.eq(object_id))
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    conversation::{
+      Conversation,
+      ConversationInsertForm,
+      ConversationMessage,
+      ConversationMessageInsertForm,
+      ConversationParticipant,
+    },
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_conversation() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let alice = Person::create(pool, &PersonInsertForm::test_form(instance.id, "alice")).await?;
+    let bob = Person::create(pool, &PersonInsertForm::test_form(instance.id, "bob")).await?;
+    let carol = Person::create(pool, &PersonInsertForm::test_form(instance.id, "carol")).await?;
+
+    let conversation = Conversation::create(pool, &ConversationInsertForm::new(alice.id)).await?;
+    assert!(
+      conversation
+        .ap_id
+        .to_string()
+        .ends_with(&format!("/conversation/{}", conversation.id.0))
+    );
+    ConversationParticipant::add(pool, conversation.id, &[alice.id, bob.id, alice.id]).await?;
+    ConversationParticipant::add(pool, conversation.id, &[carol.id]).await?;
+    let participants = Conversation::list_participants(pool, conversation.id).await?;
+    assert_eq!(
+      vec![alice.id, bob.id, carol.id],
+      participants.iter().map(|p| p.id).collect::<Vec<_>>()
+    );
+
+    // Messages move the conversation to the top
+    let form = ConversationMessageInsertForm::new(conversation.id, bob.id, "hi all".to_string());
+    let message = ConversationMessage::create(pool, &form).await?;
+    let conversation = Conversation::read(pool, conversation.id).await?;
+    assert_eq!(message.published_at, conversation.last_message_at);
+
+    // Each participant has their own read state
+    let read = ConversationParticipant::mark_as_read(pool, conversation.id, carol.id).await?;
+    assert!(read.last_read_at.is_some());
+    let unread = ConversationParticipant::read(pool, conversation.id, bob.id).await?;
+    assert_eq!(None, unread.last_read_at);
+
+    ConversationParticipant::remove(pool, conversation.id, carol.id).await?;
+    let err = ConversationParticipant::read(pool, conversation.id, carol.id).await;
+    assert_eq!(
+      Some(LemmyErrorType::NotAConversationParticipant),
+      err.err().map(|e| e.error_type)
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -8,7 +8,7 @@ use crate::{
 use diesel::{ExpressionMethods, QueryDsl, dsl::insert_into};
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema_file::schema::{
-  custom_emoji::dsl::custom_emoji,
+  custom_emoji::dsl::{custom_emoji, shortcode},
   custom_emoji_keyword::dsl::{custom_emoji_id, custom_emoji_keyword},
 };
 use lemmy_diesel_utils::{
@@ -45,6 +45,20 @@ impl Crud for CustomEmoji {
   }
 }
 
+impl CustomEmoji {
+  pub async fn read_by_shortcode(
+    pool: &mut DbPool<'_>,
+    emoji_shortcode: &str,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    custom_emoji
+      .filter(shortcode.eq(emoji_shortcode))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
 impl CustomEmojiKeyword {
   pub async fn create_from_keywords(
     pool: &mut DbPool<'_>,
@@ -0,0 +1,238 @@
+use crate::{
+  newtypes::EmailOutboxId,
+  source::email_outbox::{
+    EmailOutbox,
+    EmailOutboxInsertForm,
+    EmailOutboxUpdateForm,
+    email_outbox_keys as key,
+  },
+  utils::limit_fetch,
+};
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper, dsl::insert_into};
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
+use i_love_jesus::SortDirection;
+use lemmy_db_schema_file::schema::email_outbox;
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  pagination::{
+    CursorData,
+    PagedResponse,
+    PaginationCursor,
+    PaginationCursorConversion,
+    paginate_response,
+  },
+  traits::Crud,
+  utils::now,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for EmailOutbox {
+  type InsertForm = EmailOutboxInsertForm;
+  type UpdateForm = EmailOutboxUpdateForm;
+  type IdType = EmailOutboxId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(email_outbox::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    id: EmailOutboxId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(email_outbox::table.find(id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl PaginationCursorConversion for EmailOutbox {
+  type PaginatedType = EmailOutbox;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    EmailOutbox::read(pool, EmailOutboxId(cursor.id()?)).await
+  }
+}
+
+impl EmailOutbox {
+  /// Claims emails which are due to be sent, by moving their next attempt to `lease_until`. Rows
+  /// which are locked by another worker are skipped, so that multiple Lemmy processes can send
+  /// from the same outbox without sending any email twice. If the worker dies before marking the
+  /// email as sent or failed, it is picked up again once the lease expires.
+  pub async fn claim_pending(
+    pool: &mut DbPool<'_>,
+    lease_until: DateTime<Utc>,
+    limit: i64,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .run_transaction(|conn| {
+        async move {
+          let pending: Vec<EmailOutboxId> = email_outbox::table
+            .filter(email_outbox::sent_at.is_null())
+            .filter(email_outbox::failed_at.is_null())
+            .filter(email_outbox::next_attempt_at.le(now()))
+            .order_by(email_outbox::id)
+            .limit(limit)
+            .select(email_outbox::id)
+            .for_update()
+            .skip_locked()
+            .load(conn)
+            .await?;
+          diesel::update(email_outbox::table.filter(email_outbox::id.eq_any(pending)))
+            .set(email_outbox::next_attempt_at.eq(lease_until))
+            .returning(Self::as_returning())
+            .get_results(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Emails which gave up after too many failed attempts, newest first.
+  pub async fn list_failed(
+    pool: &mut DbPool<'_>,
+    page_cursor: Option<PaginationCursor>,
+    limit: Option<i64>,
+  ) -> LemmyResult<PagedResponse<Self>> {
+    let limit = limit_fetch(limit, None)?;
+    let query = email_outbox::table
+      .filter(email_outbox::failed_at.is_not_null())
+      .limit(limit)
+      .into_boxed();
+    let paginated_query = Self::paginate(query, &page_cursor, SortDirection::Desc, pool)
+      .await?
+      .then_order_by(key::published_at)
+      .then_order_by(key::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    paginate_response(res, limit, page_cursor)
+  }
+
+  /// Queues a failed email again, with a fresh number of attempts.
+  pub async fn resend(pool: &mut DbPool<'_>, id: EmailOutboxId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      email_outbox::table
+        .find(id)
+        .filter(email_outbox::failed_at.is_not_null()),
+    )
+    .set((
+      email_outbox::failed_at.eq(None::<DateTime<Utc>>),
+      email_outbox::fail_count.eq(0),
+      email_outbox::next_attempt_at.eq(now()),
+    ))
+    .get_result::<Self>(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::email_outbox::{EmailOutbox, EmailOutboxInsertForm, EmailOutboxUpdateForm};
+  use chrono::{Duration, Utc};
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_email_outbox() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let form = |subject: &str| {
+      EmailOutboxInsertForm::new(
+        "alice@example.com".to_string(),
+        "alice".to_string(),
+        subject.to_string(),
+        "<p>hello</p>".to_string(),
+      )
+    };
+    let first = EmailOutbox::create(pool, &form("first")).await?;
+    let second = EmailOutbox::create(pool, &form("second")).await?;
+
+    // Claimed emails are not handed out again until the lease expires
+    let lease_until = Utc::now() + Duration::minutes(5);
+    let claimed = EmailOutbox::claim_pending(pool, lease_until, 1).await?;
+    assert_eq!(
+      vec![first.id],
+      claimed.iter().map(|e| e.id).collect::<Vec<_>>()
+    );
+    let claimed = EmailOutbox::claim_pending(pool, lease_until, 10).await?;
+    assert_eq!(
+      vec![second.id],
+      claimed.iter().map(|e| e.id).collect::<Vec<_>>()
+    );
+    assert!(
+      EmailOutbox::claim_pending(pool, lease_until, 10)
+        .await?
+        .is_empty()
+    );
+
+    // One is sent, the other one gives up
+    let sent_form = EmailOutboxUpdateForm {
+      sent_at: Some(Some(Utc::now())),
+      ..Default::default()
+    };
+    EmailOutbox::update(pool, first.id, &sent_form).await?;
+    let failed_form = EmailOutboxUpdateForm {
+      fail_count: Some(10),
+      last_error: Some(Some("connection refused".to_string())),
+      failed_at: Some(Some(Utc::now())),
+      ..Default::default()
+    };
+    EmailOutbox::update(pool, second.id, &failed_form).await?;
+
+    let failed = EmailOutbox::list_failed(pool, None, None).await?;
+    assert_eq!(1, failed.items.len());
+    let failed = failed.items.first().ok_or(LemmyErrorType::NotFound)?;
+    assert_eq!(second.id, failed.id);
+    assert_eq!(Some("connection refused".to_string()), failed.last_error);
+
+    // Sent emails can't be resent, failed emails are queued again
+    assert!(EmailOutbox::resend(pool, first.id).await.is_err());
+    let resent = EmailOutbox::resend(pool, second.id).await?;
+    assert_eq!(0, resent.fail_count);
+    assert_eq!(None, resent.failed_at);
+    assert!(
+      EmailOutbox::list_failed(pool, None, None)
+        .await?
+        .items
+        .is_empty()
+    );
+    let claimed = EmailOutbox::claim_pending(pool, lease_until, 10).await?;
+    assert_eq!(
+      vec![second.id],
+      claimed.iter().map(|e| e.id).collect::<Vec<_>>()
+    );
+
+    EmailOutbox::delete(pool, first.id).await?;
+    EmailOutbox::delete(pool, second.id).await?;
+    Ok(())
+  }
+}
@@ -86,6 +86,16 @@ impl Instance {
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
 
+  /// Read an existing instance by domain, case insensitive.
+  pub async fn read_by_domain(pool: &mut DbPool<'_>, domain: &str) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    instance::table
+      .filter(lower(instance::domain).eq(domain.to_lowercase()))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   pub async fn update(
     pool: &mut DbPool<'_>,
     instance_id: InstanceId,
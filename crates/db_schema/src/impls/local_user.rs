@@ -319,6 +319,7 @@ pub trait LocalUserOptionHelper {
   fn is_admin(&self) -> bool;
   fn show_nsfw(&self, site: &Site) -> bool;
   fn hide_posts_with_media(&self) -> bool;
+  fn interface_language(&self) -> Option<String>;
 }
 
 impl LocalUserOptionHelper for Option<&LocalUser> {
@@ -351,6 +352,10 @@ impl LocalUserOptionHelper for Option<&LocalUser> {
   fn hide_posts_with_media(&self) -> bool {
     self.map(|l| l.hide_posts_with_media).unwrap_or(false)
   }
+
+  fn interface_language(&self) -> Option<String> {
+    self.map(|l| l.interface_language.clone())
+  }
 }
 
 impl LocalUserInsertForm {
@@ -1,5 +1,5 @@
 use crate::{
-  newtypes::{CommunityId, ModlogId},
+  newtypes::{CommunityId, ModlogId, RuleId},
   source::{
     comment::Comment,
     modlog::{Modlog, ModlogInsertForm},
@@ -8,7 +8,12 @@ use crate::{
   },
 };
 use chrono::{DateTime, Utc};
-use diesel::{QueryDsl, dsl::insert_into};
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  QueryDsl,
+  dsl::{insert_into, update},
+};
 use diesel_async::RunQueryDsl;
 #[cfg(feature = "full")]
 use lemmy_db_schema_file::schema::modlog;
@@ -37,9 +42,89 @@ impl Modlog {
       .await
       .with_lemmy_type(LemmyErrorType::CouldntCreate)
   }
+
+  /// Counts the modlog entries of the given kinds against a user in a community. Reverts, like
+  /// unbans, are not counted.
+  pub async fn count_for_person_in_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+    kinds: &[ModlogKind],
+  ) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    modlog::table
+      .filter(modlog::target_community_id.eq(community_id))
+      .filter(modlog::target_person_id.eq(person_id))
+      .filter(modlog::kind.eq_any(kinds))
+      .filter(modlog::is_revert.eq(false))
+      .count()
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Lists the warnings against a user in a community which count toward the warning policy. These
+  /// are warnings which didn't expire and didn't lead to a ban yet. With `since`, older warnings
+  /// are skipped too.
+  pub async fn list_active_warnings(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+    since: Option<DateTime<Utc>>,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = modlog::table
+      .filter(modlog::target_community_id.eq(community_id))
+      .filter(modlog::target_person_id.eq(person_id))
+      .filter(modlog::kind.eq_any([ModlogKind::ModWarnPost, ModlogKind::ModWarnComment]))
+      .filter(modlog::warning_ban_id.is_null())
+      .filter(
+        modlog::expires_at
+          .is_null()
+          .or(modlog::expires_at.gt(Utc::now())),
+      )
+      .order_by(modlog::published_at.asc())
+      .into_boxed();
+    if let Some(since) = since {
+      query = query.filter(modlog::published_at.gt(since));
+    }
+    query
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Links the warnings to the automatic ban which they led to.
+  pub async fn set_warning_ban(
+    pool: &mut DbPool<'_>,
+    warning_ids: &[ModlogId],
+    ban_id: ModlogId,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(modlog::table.filter(modlog::id.eq_any(warning_ids)))
+      .set(modlog::warning_ban_id.eq(ban_id))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
 }
 
 impl<'a> ModlogInsertForm<'a> {
+  /// Cites the rule which was broken.
+  pub fn with_rule(self, rule_id: Option<RuleId>) -> Self {
+    Self { rule_id, ..self }
+  }
+  /// Lets the entry expire, eg for warnings which should only count for some time.
+  pub fn with_expires_at(self, expires_at: Option<DateTime<Utc>>) -> Self {
+    Self { expires_at, ..self }
+  }
+  /// Logs the action as part of a bulk action.
+  pub fn with_bulk_action_parent(self, bulk_action_parent_id: ModlogId) -> Self {
+    Self {
+      bulk_action_parent_id: Some(bulk_action_parent_id),
+      ..self
+    }
+  }
   pub fn admin_ban(
     mod_person: &Person,
     target_person_id: PersonId,
@@ -150,6 +235,46 @@ impl<'a> ModlogInsertForm<'a> {
       ..ModlogInsertForm::new(ModlogKind::ModWarnPost, false, mod_person_id)
     }
   }
+  /// An automod rule reported the post, or the comment if given.
+  pub fn automod_report(
+    mod_person_id: PersonId,
+    post: &Post,
+    comment: Option<&Comment>,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      kind: ModlogKind::AutomodReport,
+      ..Self::automod_reply(mod_person_id, post, comment, reason)
+    }
+  }
+  /// An automod rule held the post, or the comment if given, for approval.
+  pub fn automod_hold(
+    mod_person_id: PersonId,
+    post: &Post,
+    comment: Option<&Comment>,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      kind: ModlogKind::AutomodHold,
+      ..Self::automod_reply(mod_person_id, post, comment, reason)
+    }
+  }
+  /// An automod rule replied to the post, or the comment if given.
+  pub fn automod_reply(
+    mod_person_id: PersonId,
+    post: &Post,
+    comment: Option<&Comment>,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      target_comment_id: comment.map(|c| c.id),
+      target_post_id: Some(post.id),
+      target_community_id: Some(post.community_id),
+      target_person_id: Some(comment.map(|c| c.creator_id).unwrap_or(post.creator_id)),
+      ..ModlogInsertForm::new(ModlogKind::AutomodReply, false, mod_person_id)
+    }
+  }
   pub fn admin_remove_community(
     mod_person: &Person,
     community_id: CommunityId,
@@ -195,6 +320,35 @@ impl<'a> ModlogInsertForm<'a> {
       ..ModlogInsertForm::new(ModlogKind::ModBanFromCommunity, !removed, mod_person_id)
     }
   }
+  /// Parent for the removals, locks or bans of a bulk action in a community.
+  pub fn mod_bulk_action(
+    mod_person_id: PersonId,
+    community_id: CommunityId,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      target_community_id: Some(community_id),
+      ..ModlogInsertForm::new(ModlogKind::ModBulkAction, false, mod_person_id)
+    }
+  }
+  /// A report about the post, or the comment if given, was resolved as part of a bulk action. The
+  /// creator of the content is not stored, so that they don't get notified about the report.
+  pub fn mod_resolve_report(
+    mod_person_id: PersonId,
+    post: &Post,
+    comment: Option<&Comment>,
+    resolved: bool,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      target_comment_id: comment.map(|c| c.id),
+      target_post_id: Some(post.id),
+      target_community_id: Some(post.community_id),
+      ..ModlogInsertForm::new(ModlogKind::ModResolveReport, !resolved, mod_person_id)
+    }
+  }
   pub fn mod_add_to_community(
     mod_person_id: PersonId,
     community_id: CommunityId,
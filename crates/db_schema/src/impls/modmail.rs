@@ -0,0 +1,215 @@
+use crate::{
+  diesel::OptionalExtension,
+  newtypes::{CommunityId, ModmailMessageId, ModmailThreadId},
+  source::modmail::{
+    ModmailMessage,
+    ModmailMessageInsertForm,
+    ModmailThread,
+    ModmailThreadInsertForm,
+    ModmailThreadUpdateForm,
+  },
+};
+use diesel::{ExpressionMethods, QueryDsl, dsl::insert_into, upsert::excluded};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{modmail_message, modmail_thread},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  dburl::DbUrl,
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for ModmailThread {
+  type InsertForm = ModmailThreadInsertForm;
+  type UpdateForm = ModmailThreadUpdateForm;
+  type IdType = ModmailThreadId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(modmail_thread::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    modmail_thread_id: ModmailThreadId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(modmail_thread::table.find(modmail_thread_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl ModmailThread {
+  /// There is only a single thread for each user and community, so this returns the existing
+  /// thread if there is one.
+  pub async fn read_or_create(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    creator_id: PersonId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(modmail_thread::table)
+      .values(ModmailThreadInsertForm::new(community_id, creator_id))
+      .on_conflict((modmail_thread::community_id, modmail_thread::creator_id))
+      // A no-op update, so that the existing row is returned
+      .do_update()
+      .set(modmail_thread::community_id.eq(excluded(modmail_thread::community_id)))
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// A new message from the user reopens the thread.
+  pub async fn reopen(
+    pool: &mut DbPool<'_>,
+    modmail_thread_id: ModmailThreadId,
+  ) -> LemmyResult<()> {
+    let form = ModmailThreadUpdateForm {
+      resolved: Some(false),
+      resolver_id: Some(None),
+      ..Default::default()
+    };
+    Self::update(pool, modmail_thread_id, &form).await?;
+    Ok(())
+  }
+}
+
+impl Crud for ModmailMessage {
+  type InsertForm = ModmailMessageInsertForm;
+  type UpdateForm = ModmailMessageInsertForm;
+  type IdType = ModmailMessageId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(modmail_message::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    modmail_message_id: ModmailMessageId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(modmail_message::table.find(modmail_message_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl ModmailMessage {
+  pub async fn insert_apub(
+    pool: &mut DbPool<'_>,
+    form: &ModmailMessageInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(modmail_message::table)
+      .values(form)
+      .on_conflict(modmail_message::ap_id)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: DbUrl,
+  ) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    modmail_message::table
+      .filter(modmail_message::ap_id.eq(object_id))
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    modmail::{ModmailMessage, ModmailMessageInsertForm, ModmailThread, ModmailThreadUpdateForm},
+    person::{Person, PersonInsertForm},
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_modmail_thread() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let user = Person::create(pool, &PersonInsertForm::test_form(instance.id, "user")).await?;
+    let moderator = Person::create(pool, &PersonInsertForm::test_form(instance.id, "mod")).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "modmail".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+
+    // There is only one thread per user and community
+    let thread = ModmailThread::read_or_create(pool, community.id, user.id).await?;
+    let thread_again = ModmailThread::read_or_create(pool, community.id, user.id).await?;
+    assert_eq!(thread.id, thread_again.id);
+
+    let form = ModmailMessageInsertForm::new(thread.id, Some(user.id), "help".to_string());
+    let message = ModmailMessage::create(pool, &form).await?;
+    assert!(
+      message
+        .ap_id
+        .to_string()
+        .ends_with(&format!("/modmail_message/{}", message.id.0))
+    );
+    let thread = ModmailThread::read(pool, thread.id).await?;
+    assert_eq!(message.published_at, thread.last_message_at);
+
+    // Internal notes don't move the thread up
+    let form = ModmailMessageInsertForm {
+      internal: Some(true),
+      from_moderator: Some(true),
+      ..ModmailMessageInsertForm::new(thread.id, Some(moderator.id), "spam?".to_string())
+    };
+    ModmailMessage::create(pool, &form).await?;
+    let thread = ModmailThread::read(pool, thread.id).await?;
+    assert_eq!(message.published_at, thread.last_message_at);
+
+    let form = ModmailThreadUpdateForm {
+      resolved: Some(true),
+      resolver_id: Some(Some(moderator.id)),
+      ..Default::default()
+    };
+    let resolved = ModmailThread::update(pool, thread.id, &form).await?;
+    assert!(resolved.resolved);
+    ModmailThread::reopen(pool, thread.id).await?;
+    let reopened = ModmailThread::read(pool, thread.id).await?;
+    assert!(!reopened.resolved);
+    assert_eq!(None, reopened.resolver_id);
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
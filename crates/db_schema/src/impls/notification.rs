@@ -9,7 +9,7 @@ use diesel::{
   dsl::{insert_into, update},
 };
 use diesel_async::RunQueryDsl;
-use lemmy_db_schema_file::{PersonId, schema::notification};
+use lemmy_db_schema_file::{PersonId, enums::NotificationType, schema::notification};
 use lemmy_diesel_utils::connection::{DbPool, get_conn};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
@@ -97,6 +97,27 @@ impl Notification {
     .with_lemmy_type(LemmyErrorType::NotFound)
   }
 
+  /// Marks all notifications of a group, meaning those with the same type and post.
+  pub async fn mark_read_by_group_and_person(
+    pool: &mut DbPool<'_>,
+    kind: NotificationType,
+    post_id: PostId,
+    recipient_id: PersonId,
+    read: bool,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      notification::table
+        .filter(notification::kind.eq(kind))
+        .filter(notification::post_id.eq(post_id))
+        .filter(notification::recipient_id.eq(recipient_id)),
+    )
+    .set(notification::read.eq(read))
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   /// Only for tests
   pub async fn delete(pool: &mut DbPool<'_>, id: NotificationId) -> LemmyResult<()> {
     let conn = &mut get_conn(pool).await?;
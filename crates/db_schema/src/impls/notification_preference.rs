@@ -0,0 +1,216 @@
+use crate::{
+  newtypes::LocalUserId,
+  source::notification_preference::{LocalUserNotificationPreferenceForm, NotificationPreference},
+};
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper, insert_into, upsert::excluded};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  PersonId,
+  enums::NotificationType,
+  schema::{local_user, local_user_notification_preference},
+};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use strum::IntoEnumIterator;
+
+impl NotificationPreference {
+  pub fn all_channels(kind: NotificationType) -> Self {
+    Self {
+      kind,
+      in_app: true,
+      email: true,
+      push: true,
+    }
+  }
+
+  /// The default for types which the user didn't change. Notifications about votes and features
+  /// of your own content are opt-in, all other types are delivered through all channels.
+  pub fn default_for(kind: NotificationType) -> Self {
+    use NotificationType::*;
+    let enabled = !matches!(kind, ScoreMilestone | PostFeatured | CommentDistinguished);
+    Self {
+      kind,
+      in_app: enabled,
+      email: enabled,
+      push: enabled,
+    }
+  }
+
+  /// Returns the preferences for every notification type, including defaults for types which
+  /// the user didn't change.
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    for_local_user_id: LocalUserId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let stored: Vec<Self> = local_user_notification_preference::table
+      .filter(local_user_notification_preference::local_user_id.eq(for_local_user_id))
+      .select(Self::as_select())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    Ok(
+      NotificationType::iter()
+        .map(|kind| {
+          stored
+            .iter()
+            .find(|p| p.kind == kind)
+            .copied()
+            .unwrap_or(Self::default_for(kind))
+        })
+        .collect(),
+    )
+  }
+
+  /// Returns the preference of a person for a single notification type. Remote users always get
+  /// the default.
+  pub async fn read_for_person(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    kind: NotificationType,
+  ) -> LemmyResult<Self> {
+    let preferences = Self::list_for_persons(pool, &[person_id]).await?;
+    Ok(Self::find_for_person(&preferences, person_id, kind))
+  }
+
+  /// Returns the preference of a person for a single notification type, from the result of
+  /// [NotificationPreference::list_for_persons].
+  pub fn find_for_person(
+    preferences: &[(PersonId, Self)],
+    person_id: PersonId,
+    kind: NotificationType,
+  ) -> Self {
+    preferences
+      .iter()
+      .find(|(p_id, p)| *p_id == person_id && p.kind == kind)
+      .map(|(_, p)| *p)
+      .unwrap_or(Self::default_for(kind))
+  }
+
+  /// Returns the stored preferences of the given persons. Types without a stored preference are
+  /// not included.
+  pub async fn list_for_persons(
+    pool: &mut DbPool<'_>,
+    person_ids: &[PersonId],
+  ) -> LemmyResult<Vec<(PersonId, Self)>> {
+    let conn = &mut get_conn(pool).await?;
+    local_user_notification_preference::table
+      .inner_join(local_user::table)
+      .filter(local_user::person_id.eq_any(person_ids))
+      .select((local_user::person_id, Self::as_select()))
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn update(
+    pool: &mut DbPool<'_>,
+    preferences: Vec<Self>,
+    for_local_user_id: LocalUserId,
+  ) -> LemmyResult<usize> {
+    if preferences.is_empty() {
+      return Ok(0);
+    }
+    let conn = &mut get_conn(pool).await?;
+    let forms = preferences
+      .into_iter()
+      .map(|p| LocalUserNotificationPreferenceForm {
+        local_user_id: for_local_user_id,
+        kind: p.kind,
+        in_app: p.in_app,
+        email: p.email,
+        push: p.push,
+      })
+      .collect::<Vec<_>>();
+    insert_into(local_user_notification_preference::table)
+      .values(forms)
+      .on_conflict((
+        local_user_notification_preference::local_user_id,
+        local_user_notification_preference::kind,
+      ))
+      .do_update()
+      .set((
+        local_user_notification_preference::in_app
+          .eq(excluded(local_user_notification_preference::in_app)),
+        local_user_notification_preference::email
+          .eq(excluded(local_user_notification_preference::email)),
+        local_user_notification_preference::push
+          .eq(excluded(local_user_notification_preference::push)),
+      ))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    notification_preference::NotificationPreference,
+    person::{Person, PersonInsertForm},
+  };
+  use lemmy_db_schema_file::enums::NotificationType;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_notification_preference() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "prefs")).await?;
+    let local_user =
+      LocalUser::create(pool, &LocalUserInsertForm::test_form(person.id), vec![]).await?;
+
+    // Without stored preferences, everything except the opt-in types is enabled
+    let read = NotificationPreference::read(pool, local_user.id).await?;
+    assert_eq!(8, read.len());
+    assert_eq!(
+      5,
+      read
+        .iter()
+        .filter(|p| p.in_app && p.email && p.push)
+        .count()
+    );
+    assert!(
+      !NotificationPreference::read_for_person(pool, person.id, NotificationType::PostFeatured)
+        .await?
+        .in_app
+    );
+
+    let no_reply_emails = NotificationPreference {
+      email: false,
+      ..NotificationPreference::all_channels(NotificationType::Reply)
+    };
+    NotificationPreference::update(pool, vec![no_reply_emails], local_user.id).await?;
+    let only_in_app = NotificationPreference {
+      email: false,
+      push: false,
+      ..NotificationPreference::all_channels(NotificationType::Reply)
+    };
+    NotificationPreference::update(pool, vec![only_in_app], local_user.id).await?;
+
+    let read = NotificationPreference::read(pool, local_user.id).await?;
+    assert!(read.contains(&only_in_app));
+    assert!(read.contains(&NotificationPreference::all_channels(
+      NotificationType::Mention
+    )));
+    assert_eq!(
+      only_in_app,
+      NotificationPreference::read_for_person(pool, person.id, NotificationType::Reply).await?
+    );
+    assert_eq!(
+      NotificationPreference::all_channels(NotificationType::ModAction),
+      NotificationPreference::read_for_person(pool, person.id, NotificationType::ModAction).await?
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -1,6 +1,6 @@
 use crate::{
   diesel::{BoolExpressionMethods, NullableExpressionMethods, OptionalExtension},
-  newtypes::{CommunityId, LocalUserId},
+  newtypes::LocalUserId,
   source::person::{
     Person,
     PersonActions,
@@ -95,6 +95,17 @@ impl Person {
       .with_lemmy_type(LemmyErrorType::CouldntUpdate)
   }
 
+  /// Reads the given persons, skipping deleted ones.
+  pub async fn read_many(pool: &mut DbPool<'_>, person_ids: &[PersonId]) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    person::table
+      .filter(person::deleted.eq(false))
+      .filter(person::id.eq_any(person_ids))
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   pub async fn delete_account(
     pool: &mut DbPool<'_>,
     person_id: PersonId,
@@ -233,9 +244,21 @@ impl Followable for PersonActions {
       .with_lemmy_type(LemmyErrorType::AlreadyExists)
   }
 
-  /// Currently no user following
-  async fn follow_accepted(_: &mut DbPool<'_>, _: CommunityId, _: PersonId) -> LemmyResult<Self> {
-    Err(LemmyErrorType::NotFound.into())
+  async fn follow_accepted(
+    pool: &mut DbPool<'_>,
+    target_id: Self::IdType,
+    person_id: PersonId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    let find_action = person_actions::table
+      .find((person_id, target_id))
+      .filter(person_actions::followed_at.is_not_null());
+    diesel::update(find_action)
+      .set(person_actions::follow_pending.eq(Some(false)))
+      .returning(Self::as_select())
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
   }
 
   async fn unfollow(
@@ -459,6 +482,9 @@ mod tests {
       post_score: 0,
       comment_count: 0,
       comment_score: 0,
+      also_known_as: vec![],
+      moved_to_id: None,
+      follower_count: 0,
     };
 
     let read_person = Person::read(pool, data.person.id).await?;
@@ -500,9 +526,32 @@ mod tests {
     let followers = PersonActions::follower_inboxes(pool, data.person.id).await?;
     assert_eq!(vec![person_2.inbox_url], followers);
 
+    // A pending follow gets accepted
+    let person_3 = Person::create(
+      pool,
+      &PersonInsertForm::test_form(data.instance.id, "pending_follower"),
+    )
+    .await?;
+    let pending_form = PersonFollowerForm::new(data.person.id, person_3.id, true);
+    let pending = PersonActions::follow(pool, &pending_form).await?;
+    assert_eq!(Some(true), pending.follow_pending);
+    let accepted = PersonActions::follow_accepted(pool, data.person.id, person_3.id).await?;
+    assert_eq!(Some(false), accepted.follow_pending);
+    assert!(
+      PersonActions::follow_accepted(pool, person_3.id, data.person.id)
+        .await
+        .is_err()
+    );
+
+    // The follower count is updated by a trigger
+    let read_person = Person::read(pool, data.person.id).await?;
+    assert_eq!(2, read_person.follower_count);
+
     let unfollow =
       PersonActions::unfollow(pool, follow_form.person_id, follow_form.target_id).await?;
     assert_eq!(UpleteCount::only_deleted(1), unfollow);
+    let read_person = Person::read(pool, data.person.id).await?;
+    assert_eq!(1, read_person.follower_count);
 
     data.delete(pool).await?;
     Ok(())
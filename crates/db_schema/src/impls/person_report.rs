@@ -0,0 +1,94 @@
+use crate::{
+  newtypes::PersonReportId,
+  source::person_report::{PersonReport, PersonReportForm, UpdatePersonReportForm},
+  traits::Reportable,
+};
+use chrono::Utc;
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  QueryDsl,
+  dsl::{insert_into, update},
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{PersonId, schema::person_report};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Reportable for PersonReport {
+  type Form = PersonReportForm;
+  type UpdateForm = UpdatePersonReportForm;
+  type IdType = PersonReportId;
+  type ObjectIdType = PersonId;
+  /// creates a person report and returns it
+  ///
+  /// * `conn` - the postgres connection
+  /// * `person_report_form` - the filled PersonReportForm to insert
+  async fn report(pool: &mut DbPool<'_>, form: &Self::Form) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(person_report::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// resolve a person report
+  ///
+  /// * `conn` - the postgres connection
+  /// * `report_id` - the id of the report to resolve
+  /// * `form` - update report form
+  async fn update_resolved(
+    pool: &mut DbPool<'_>,
+    report_id_: Self::IdType,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(person_report::table.find(report_id_))
+      .set(form)
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  async fn resolve_apub(
+    pool: &mut DbPool<'_>,
+    object_id: Self::ObjectIdType,
+    report_creator_id: PersonId,
+    resolver_id: PersonId,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      person_report::table.filter(
+        person_report::person_id
+          .eq(object_id)
+          .and(person_report::creator_id.eq(report_creator_id)),
+      ),
+    )
+    .set((
+      person_report::resolved.eq(true),
+      person_report::resolver_id.eq(resolver_id),
+      person_report::updated_at.eq(Utc::now()),
+    ))
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  async fn resolve_all_for_object(
+    pool: &mut DbPool<'_>,
+    person_id_: Self::ObjectIdType,
+    by_resolver_id: PersonId,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(person_report::table.filter(person_report::person_id.eq(person_id_)))
+      .set((
+        person_report::resolved.eq(true),
+        person_report::resolver_id.eq(by_resolver_id),
+        person_report::updated_at.eq(Utc::now()),
+      ))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
@@ -0,0 +1,306 @@
+use crate::{
+  newtypes::{PollOptionId, PostId},
+  source::poll::{
+    Poll,
+    PollInsertForm,
+    PollOption,
+    PollOptionInsertForm,
+    PollView,
+    PollVote,
+    PollVoteForm,
+    PostPollVotedForm,
+  },
+};
+use diesel::{
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+  delete,
+  deserialize::FromSql,
+  insert_into,
+  pg::{Pg, PgValue},
+  sql_types::Json,
+  update,
+};
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
+use diesel_uplete::{UpleteCount, uplete};
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{poll, poll_option, poll_vote, post_actions},
+};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Poll {
+  /// Creates or updates the poll of a post. Options which are not included anymore are removed
+  /// together with their votes, existing options keep their votes.
+  pub async fn upsert(
+    pool: &mut DbPool<'_>,
+    form: &PollInsertForm,
+    options: &[PollOptionInsertForm],
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+
+    conn
+      .run_transaction(|conn| {
+        async move {
+          let poll = insert_into(poll::table)
+            .values(form)
+            .on_conflict(poll::post_id)
+            .do_update()
+            .set(form)
+            .get_result::<Self>(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntCreate)?;
+
+          let names = options.iter().map(|o| o.name.clone()).collect::<Vec<_>>();
+          delete(
+            poll_option::table
+              .filter(poll_option::post_id.eq(form.post_id))
+              .filter(poll_option::name.ne_all(names)),
+          )
+          .execute(conn)
+          .await
+          .with_lemmy_type(LemmyErrorType::Deleted)?;
+
+          for option in options {
+            insert_into(poll_option::table)
+              .values(option)
+              .on_conflict((poll_option::post_id, poll_option::name))
+              .do_update()
+              .set(option)
+              .execute(conn)
+              .await
+              .with_lemmy_type(LemmyErrorType::CouldntCreate)?;
+          }
+          Ok(poll)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Removes the poll of a post, together with its options and votes.
+  pub async fn delete(pool: &mut DbPool<'_>, post_id: PostId) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    delete(poll::table.find(post_id))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::Deleted)
+  }
+
+  /// Marks the vote counts of a local poll for federation by [Poll::take_votes_changed].
+  pub async fn mark_votes_changed(pool: &mut DbPool<'_>, post_id: PostId) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    update(poll::table.find(post_id))
+      .set(poll::votes_changed.eq(true))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    Ok(())
+  }
+
+  /// Returns the posts whose poll votes changed since the last call, and resets the mark.
+  pub async fn take_votes_changed(pool: &mut DbPool<'_>) -> LemmyResult<Vec<PostId>> {
+    let conn = &mut get_conn(pool).await?;
+    update(poll::table.filter(poll::votes_changed))
+      .set(poll::votes_changed.eq(false))
+      .returning(poll::post_id)
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, post_id: PostId) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    poll::table
+      .find(post_id)
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl PollOption {
+  pub async fn list_for_post(pool: &mut DbPool<'_>, post_id: PostId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    poll_option::table
+      .filter(poll_option::post_id.eq(post_id))
+      .order_by(poll_option::display_order)
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl PollVote {
+  /// Replaces all votes of the person in the given poll. An empty list of options removes the
+  /// vote. This also sets `post_actions.poll_voted_at`, which is used to read the votes in
+  /// `PostView`.
+  pub async fn vote(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    person_id: PersonId,
+    option_ids: &[PollOptionId],
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+
+    conn
+      .run_transaction(|conn| {
+        async move {
+          delete(
+            poll_vote::table
+              .filter(poll_vote::post_id.eq(post_id))
+              .filter(poll_vote::person_id.eq(person_id)),
+          )
+          .execute(conn)
+          .await
+          .with_lemmy_type(LemmyErrorType::Deleted)?;
+
+          if option_ids.is_empty() {
+            uplete(
+              post_actions::table
+                .filter(post_actions::post_id.eq(post_id))
+                .filter(post_actions::person_id.eq(person_id)),
+            )
+            .set_null(post_actions::poll_voted_at)
+            .get_result::<UpleteCount>(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+            return Ok(vec![]);
+          }
+
+          let form = PostPollVotedForm::new(post_id, person_id);
+          insert_into(post_actions::table)
+            .values(&form)
+            .on_conflict((post_actions::person_id, post_actions::post_id))
+            .do_update()
+            .set(&form)
+            .execute(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+
+          let forms = option_ids
+            .iter()
+            .map(|poll_option_id| PollVoteForm {
+              poll_option_id: *poll_option_id,
+              person_id,
+              post_id,
+            })
+            .collect::<Vec<_>>();
+          insert_into(poll_vote::table)
+            .values(forms)
+            .get_results(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntCreate)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Adds a single vote, keeping other votes of the person in the same poll.
+  pub async fn add(pool: &mut DbPool<'_>, form: &PollVoteForm) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(poll_vote::table)
+      .values(form)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)?;
+    Ok(())
+  }
+}
+
+impl FromSql<Json, Pg> for PollView {
+  fn from_sql(bytes: PgValue) -> diesel::deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Json, Pg>>::from_sql(bytes)?;
+    Ok(serde_json::from_value::<PollView>(value)?)
+  }
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing)]
+mod tests {
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+    poll::{Poll, PollInsertForm, PollOption, PollOptionInsertForm, PollVote},
+    post::{Post, PostInsertForm},
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_poll_votes() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "voter")).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "poll".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+    let post = Post::create(
+      pool,
+      &PostInsertForm::new("A poll".into(), person.id, community.id),
+    )
+    .await?;
+
+    let form = PollInsertForm {
+      post_id: post.id,
+      multiple_choice: false,
+      end_time_at: None,
+      updated_at: None,
+    };
+    let option_form = |name: &str, display_order| PollOptionInsertForm {
+      post_id: post.id,
+      name: name.to_string(),
+      display_order,
+      vote_count: None,
+    };
+    Poll::upsert(pool, &form, &[option_form("a", 0), option_form("b", 1)]).await?;
+    let options = PollOption::list_for_post(pool, post.id).await?;
+    assert_eq!(2, options.len());
+
+    // Voting again replaces the previous vote, and the counts are updated by triggers
+    PollVote::vote(pool, post.id, person.id, &[options[0].id]).await?;
+    PollVote::vote(pool, post.id, person.id, &[options[1].id]).await?;
+    let counts = PollOption::list_for_post(pool, post.id)
+      .await?
+      .into_iter()
+      .map(|o| o.vote_count)
+      .collect::<Vec<_>>();
+    assert_eq!(vec![0, 1], counts);
+
+    // Editing the poll keeps votes for existing options, and removes the others
+    Poll::upsert(pool, &form, &[option_form("b", 0), option_form("c", 1)]).await?;
+    let options = PollOption::list_for_post(pool, post.id).await?;
+    let names = options.iter().map(|o| o.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(vec!["b", "c"], names);
+    assert_eq!(1, options[0].vote_count);
+
+    let votes = PollVote::vote(pool, post.id, person.id, &[]).await?;
+    assert!(votes.is_empty());
+    let options = PollOption::list_for_post(pool, post.id).await?;
+    assert_eq!(0, options[0].vote_count);
+
+    // Changed votes are only returned once
+    Poll::mark_votes_changed(pool, post.id).await?;
+    assert_eq!(vec![post.id], Poll::take_votes_changed(pool).await?);
+    assert!(Poll::take_votes_changed(pool).await?.is_empty());
+
+    assert_eq!(1, Poll::delete(pool, post.id).await?);
+    assert!(Poll::read(pool, post.id).await?.is_none());
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -32,7 +32,7 @@ use lemmy_db_schema_file::{
   InstanceId,
   PersonId,
   enums::PostNotificationsMode,
-  schema::{community, local_user, person, post, post_actions},
+  schema::{community, local_user, person, post, post_actions, post_revision},
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -129,6 +129,7 @@ impl Post {
       .filter(post::local.eq(true))
       .filter(post::deleted.eq(false))
       .filter(post::removed.eq(false))
+      .filter(post::approval_pending.eq(false))
       .filter(post::published_at.ge(Utc::now().naive_utc() - SITEMAP_DAYS))
       .order(post::published_at.desc())
       .limit(SITEMAP_LIMIT)
@@ -143,6 +144,15 @@ impl Post {
   ) -> LemmyResult<Vec<Self>> {
     let conn = &mut get_conn(pool).await?;
 
+    // Otherwise the old content would still be readable in the edit history
+    let creator_posts = post::table
+      .filter(post::creator_id.eq(for_creator_id))
+      .select(post::id);
+    diesel::delete(post_revision::table.filter(post_revision::post_id.eq_any(creator_posts)))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+
     diesel::update(post::table.filter(post::creator_id.eq(for_creator_id)))
       .set((
         post::name.eq(DELETED_REPLACEMENT_TEXT),
@@ -241,6 +251,35 @@ impl Post {
       .with_lemmy_type(LemmyErrorType::CouldntUpdate)
   }
 
+  /// Reads the given posts, skipping those which are not in the community.
+  pub async fn read_many_in_community(
+    pool: &mut DbPool<'_>,
+    post_ids: &[PostId],
+    community_id: CommunityId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    post::table
+      .filter(post::id.eq_any(post_ids))
+      .filter(post::community_id.eq(community_id))
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn update_many(
+    pool: &mut DbPool<'_>,
+    post_ids: &[PostId],
+    form: &PostUpdateForm,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    update(post::table)
+      .filter(post::id.eq_any(post_ids))
+      .set(form)
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
   pub fn is_post_creator(person_id: PersonId, post_creator_id: PersonId) -> bool {
     person_id == post_creator_id
   }
@@ -296,6 +335,24 @@ impl Post {
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
 
+  /// The posts of the user which are not published yet, the next one first.
+  pub async fn list_user_scheduled_posts(
+    person_id: PersonId,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+
+    post::table
+      .filter(post::scheduled_publish_time_at.is_not_null())
+      .filter(not(post::deleted.or(post::removed)))
+      .filter(post::creator_id.eq(person_id))
+      .order_by(post::scheduled_publish_time_at.asc())
+      .select(Self::as_select())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   pub async fn update_ranks(pool: &mut DbPool<'_>, post_id: PostId) -> LemmyResult<Self> {
     let conn = &mut get_conn(pool).await?;
 
@@ -665,6 +722,8 @@ mod tests {
       scaled_rank: RANK_DEFAULT,
       unresolved_report_count: 0,
       federation_pending: false,
+      approval_pending: false,
+      scheduled_recurrence: None,
     };
 
     // Post Like
@@ -694,6 +753,11 @@ mod tests {
     // Scheduled post count
     let scheduled_post_count = Post::user_scheduled_post_count(inserted_person.id, pool).await?;
     assert_eq!(1, scheduled_post_count);
+    let scheduled_posts = Post::list_user_scheduled_posts(inserted_person.id, pool).await?;
+    assert_eq!(
+      vec![inserted_scheduled_post.id],
+      scheduled_posts.iter().map(|p| p.id).collect::<Vec<_>>()
+    );
 
     let form = PostLikeForm::new(inserted_post.id, inserted_person.id, None);
     PostActions::like(pool, &form).await?;
@@ -1,5 +1,5 @@
 use crate::{
-  newtypes::{PostId, PostReportId},
+  newtypes::{CommunityId, PostId, PostReportId},
   source::post_report::{PostReport, PostReportForm, UpdatePostReportForm},
   traits::Reportable,
 };
@@ -11,7 +11,10 @@ use diesel::{
   dsl::{insert_into, update},
 };
 use diesel_async::RunQueryDsl;
-use lemmy_db_schema_file::{PersonId, schema::post_report};
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{post, post_report},
+};
 use lemmy_diesel_utils::connection::{DbPool, get_conn};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
@@ -85,6 +88,46 @@ impl Reportable for PostReport {
   }
 }
 
+impl PostReport {
+  pub async fn resolve_all_for_posts(
+    pool: &mut DbPool<'_>,
+    post_ids: &[PostId],
+    by_resolver_id: PersonId,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(post_report::table.filter(post_report::post_id.eq_any(post_ids)))
+      .set((
+        post_report::resolved.eq(true),
+        post_report::resolver_id.eq(by_resolver_id),
+        post_report::updated_at.eq(Utc::now()),
+      ))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  /// Resolves or unresolves the given reports. Reports about posts in other communities are left
+  /// unchanged.
+  pub async fn update_resolved_in_community(
+    pool: &mut DbPool<'_>,
+    report_ids: &[PostReportId],
+    community_id: CommunityId,
+    form: &UpdatePostReportForm,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let community_posts = post::table
+      .filter(post::community_id.eq(community_id))
+      .select(post::id);
+    update(post_report::table)
+      .filter(post_report::id.eq_any(report_ids))
+      .filter(post_report::post_id.eq_any(community_posts))
+      .set(form)
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
 #[cfg(test)]
 mod tests {
 
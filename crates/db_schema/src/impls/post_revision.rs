@@ -0,0 +1,135 @@
+use crate::{
+  newtypes::PostId,
+  source::{
+    post::Post,
+    post_revision::{PostRevision, PostRevisionForm},
+  },
+};
+use diesel::{ExpressionMethods, QueryDsl, dsl::insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::post_revision;
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl PostRevision {
+  /// Stores the previous version of an edited post. Nothing is stored if the content is
+  /// unchanged, for example when only the nsfw flag was edited.
+  pub async fn create_if_changed(
+    pool: &mut DbPool<'_>,
+    orig: &Post,
+    updated: &Post,
+  ) -> LemmyResult<Option<Self>> {
+    if orig.name == updated.name
+      && orig.url == updated.url
+      && orig.body == updated.body
+      && orig.alt_text == updated.alt_text
+    {
+      return Ok(None);
+    }
+    let form = PostRevisionForm {
+      post_id: orig.id,
+      name: orig.name.clone(),
+      url: orig.url.clone(),
+      body: orig.body.clone(),
+      alt_text: orig.alt_text.clone(),
+      published_at: orig.updated_at.unwrap_or(orig.published_at),
+    };
+    let conn = &mut get_conn(pool).await?;
+    insert_into(post_revision::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .map(Some)
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// Lists previous versions of the post, newest first.
+  pub async fn list_for_post(pool: &mut DbPool<'_>, post_id: PostId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    post_revision::table
+      .filter(post_revision::post_id.eq(post_id))
+      .order_by(post_revision::published_at.desc())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+    post::{Post, PostInsertForm, PostUpdateForm},
+    post_revision::PostRevision,
+  };
+  use chrono::Utc;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_post_revisions() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "editor")).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "revisions".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+    let original = Post::create(
+      pool,
+      &PostInsertForm::new("First title".into(), person.id, community.id),
+    )
+    .await?;
+
+    // Changing only the nsfw flag doesn't create a revision
+    let form = PostUpdateForm {
+      nsfw: Some(true),
+      ..Default::default()
+    };
+    let updated = Post::update(pool, original.id, &form).await?;
+    assert!(
+      PostRevision::create_if_changed(pool, &original, &updated)
+        .await?
+        .is_none()
+    );
+
+    let form = PostUpdateForm {
+      name: Some("Second title".into()),
+      updated_at: Some(Some(Utc::now())),
+      ..Default::default()
+    };
+    let second = Post::update(pool, original.id, &form).await?;
+    PostRevision::create_if_changed(pool, &updated, &second).await?;
+
+    let form = PostUpdateForm {
+      body: Some(Some("Some body".into())),
+      updated_at: Some(Some(Utc::now())),
+      ..Default::default()
+    };
+    let third = Post::update(pool, original.id, &form).await?;
+    PostRevision::create_if_changed(pool, &second, &third).await?;
+
+    let revisions = PostRevision::list_for_post(pool, original.id).await?;
+    let names = revisions
+      .iter()
+      .map(|r| r.name.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(vec!["Second title", "First title"], names);
+    let published = revisions.iter().map(|r| r.published_at).collect::<Vec<_>>();
+    assert_eq!(
+      vec![second.updated_at.unwrap_or_default(), original.published_at],
+      published
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
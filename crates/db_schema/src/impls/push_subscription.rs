@@ -0,0 +1,161 @@
+use crate::{
+  newtypes::{LocalUserId, PushSubscriptionId},
+  source::push_subscription::{PushSubscription, PushSubscriptionInsertForm},
+};
+use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, SelectableHelper, delete, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{local_user, push_subscription},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for PushSubscription {
+  type InsertForm = PushSubscriptionInsertForm;
+  type UpdateForm = PushSubscriptionInsertForm;
+  type IdType = PushSubscriptionId;
+
+  /// Browsers use the same endpoint when a subscription is registered again, so in that case the
+  /// existing subscription is replaced.
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(push_subscription::table)
+      .values(form)
+      .on_conflict(push_subscription::endpoint)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    id: PushSubscriptionId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(push_subscription::table.find(id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl PushSubscription {
+  /// Lists the subscriptions of the given persons, together with their person id.
+  pub async fn list_for_persons(
+    pool: &mut DbPool<'_>,
+    person_ids: &[PersonId],
+  ) -> LemmyResult<Vec<(PersonId, Self)>> {
+    let conn = &mut get_conn(pool).await?;
+    push_subscription::table
+      .inner_join(local_user::table.on(local_user::id.eq(push_subscription::local_user_id)))
+      .filter(local_user::person_id.eq_any(person_ids))
+      .select((local_user::person_id, Self::as_select()))
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Number of subscriptions of the user, not counting the one with the given endpoint.
+  pub async fn count_for_local_user(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    except_endpoint: &str,
+  ) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    push_subscription::table
+      .filter(push_subscription::local_user_id.eq(local_user_id))
+      .filter(push_subscription::endpoint.ne(except_endpoint))
+      .count()
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn delete_by_endpoint(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    endpoint: &str,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      push_subscription::table
+        .filter(push_subscription::local_user_id.eq(local_user_id))
+        .filter(push_subscription::endpoint.eq(endpoint)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+    push_subscription::{PushSubscription, PushSubscriptionInsertForm},
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_push_subscription() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "push")).await?;
+    let local_user =
+      LocalUser::create(pool, &LocalUserInsertForm::test_form(person.id), vec![]).await?;
+
+    let endpoint = "https://push.example.com/abc".to_string();
+    let form = PushSubscriptionInsertForm::new(
+      local_user.id,
+      endpoint.clone(),
+      "key".to_string(),
+      "auth".to_string(),
+    );
+    let subscription = PushSubscription::create(pool, &form).await?;
+
+    // Registering the same endpoint again replaces the keys
+    let form = PushSubscriptionInsertForm {
+      auth: "auth2".to_string(),
+      ..form
+    };
+    let updated = PushSubscription::create(pool, &form).await?;
+    assert_eq!(subscription.id, updated.id);
+    assert_eq!("auth2", updated.auth);
+
+    let list = PushSubscription::list_for_persons(pool, &[person.id]).await?;
+    assert_eq!(vec![(person.id, updated)], list);
+
+    let count = PushSubscription::count_for_local_user(pool, local_user.id, &endpoint).await?;
+    assert_eq!(0, count);
+    let count =
+      PushSubscription::count_for_local_user(pool, local_user.id, "https://push.example.com/def")
+        .await?;
+    assert_eq!(1, count);
+
+    PushSubscription::delete_by_endpoint(pool, local_user.id, &endpoint).await?;
+    assert!(
+      PushSubscription::list_for_persons(pool, &[person.id])
+        .await?
+        .is_empty()
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,153 @@
+use crate::{
+  newtypes::PostOrCommentId,
+  source::reaction::{
+    CommentReactedForm,
+    PostReactedForm,
+    Reaction,
+    ReactionCountsView,
+    ReactionInsertForm,
+  },
+};
+use diesel::{
+  ExpressionMethods,
+  QueryDsl,
+  delete,
+  deserialize::FromSql,
+  dsl::{exists, not},
+  insert_into,
+  pg::{Pg, PgValue},
+  select,
+  serialize::ToSql,
+  sql_types::{Json, Nullable},
+};
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
+use diesel_uplete::{UpleteCount, uplete};
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{comment_actions, post_actions, reaction},
+};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Reaction {
+  /// Adds a reaction to a post or comment, and sets `reacted_at` in the post or comment actions
+  /// which is used to read the own reactions in views. Adding the same reaction twice does nothing.
+  pub async fn add(pool: &mut DbPool<'_>, form: &ReactionInsertForm) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let form = form.clone();
+
+    conn
+      .run_transaction(|conn| {
+        async move {
+          insert_into(reaction::table)
+            .values(&form)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntCreate)?;
+
+          if let Some(post_id) = form.post_id {
+            let actions_form = PostReactedForm::new(post_id, form.person_id);
+            insert_into(post_actions::table)
+              .values(&actions_form)
+              .on_conflict((post_actions::person_id, post_actions::post_id))
+              .do_update()
+              .set(&actions_form)
+              .execute(conn)
+              .await
+              .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+          } else if let Some(comment_id) = form.comment_id {
+            let actions_form = CommentReactedForm::new(comment_id, form.person_id);
+            insert_into(comment_actions::table)
+              .values(&actions_form)
+              .on_conflict((comment_actions::person_id, comment_actions::comment_id))
+              .do_update()
+              .set(&actions_form)
+              .execute(conn)
+              .await
+              .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+          }
+          Ok(())
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Removes a reaction, and clears `reacted_at` if the person has no other reactions left on the
+  /// post or comment.
+  pub async fn remove(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    target: PostOrCommentId,
+    emoji: &str,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let emoji = emoji.to_string();
+
+    conn
+      .run_transaction(|conn| {
+        async move {
+          match target {
+            PostOrCommentId::Post(post_id) => {
+              let find = reaction::table
+                .filter(reaction::person_id.eq(person_id))
+                .filter(reaction::post_id.eq(post_id));
+              delete(find.filter(reaction::emoji.eq(emoji)))
+                .execute(conn)
+                .await
+                .with_lemmy_type(LemmyErrorType::Deleted)?;
+              if select(not(exists(find))).get_result::<bool>(conn).await? {
+                uplete(post_actions::table.find((person_id, post_id)))
+                  .set_null(post_actions::reacted_at)
+                  .get_result::<UpleteCount>(conn)
+                  .await
+                  .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+              }
+            }
+            PostOrCommentId::Comment(comment_id) => {
+              let find = reaction::table
+                .filter(reaction::person_id.eq(person_id))
+                .filter(reaction::comment_id.eq(comment_id));
+              delete(find.filter(reaction::emoji.eq(emoji)))
+                .execute(conn)
+                .await
+                .with_lemmy_type(LemmyErrorType::Deleted)?;
+              if select(not(exists(find))).get_result::<bool>(conn).await? {
+                uplete(comment_actions::table.find((person_id, comment_id)))
+                  .set_null(comment_actions::reacted_at)
+                  .get_result::<UpleteCount>(conn)
+                  .await
+                  .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+              }
+            }
+          }
+          Ok(())
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+}
+
+impl FromSql<Nullable<Json>, Pg> for ReactionCountsView {
+  fn from_sql(bytes: PgValue) -> diesel::deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Json, Pg>>::from_sql(bytes)?;
+    Ok(serde_json::from_value::<ReactionCountsView>(value)?)
+  }
+  fn from_nullable_sql(
+    bytes: Option<<Pg as diesel::backend::Backend>::RawValue<'_>>,
+  ) -> diesel::deserialize::Result<Self> {
+    match bytes {
+      Some(bytes) => Self::from_sql(bytes),
+      None => Ok(Self(vec![])),
+    }
+  }
+}
+
+impl ToSql<Nullable<Json>, Pg> for ReactionCountsView {
+  fn to_sql(&self, out: &mut diesel::serialize::Output<Pg>) -> diesel::serialize::Result {
+    let value = serde_json::to_value(self)?;
+    <serde_json::Value as ToSql<Json, Pg>>::to_sql(&value, &mut out.reborrow())
+  }
+}
@@ -0,0 +1,380 @@
+use crate::{
+  diesel::SelectableHelper,
+  newtypes::{CommunityId, RuleId},
+  source::rule::{Rule, RuleInsertForm, RuleStats, RuleUpdateForm},
+};
+use diesel::{
+  ExpressionMethods,
+  QueryDsl,
+  dsl::{count_star, max, not},
+  insert_into,
+  upsert::excluded,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  enums::ModlogKind,
+  schema::{comment_report, modlog, post_report, rule},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  dburl::DbUrl,
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use std::collections::HashSet;
+
+impl Crud for Rule {
+  type InsertForm = RuleInsertForm;
+  type UpdateForm = RuleUpdateForm;
+  type IdType = RuleId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(rule::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    rule_id: RuleId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(rule::table.find(rule_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl Rule {
+  /// The rules of a community, or the site rules if no community is given, in order of their
+  /// position.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = rule::table
+      .filter(not(rule::deleted))
+      .order_by((rule::position, rule::id))
+      .into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(rule::community_id.eq(community_id)),
+      None => query.filter(rule::community_id.is_null()),
+    };
+    query
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// The position after the last rule, for appending a new rule.
+  pub async fn next_position(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+  ) -> LemmyResult<i32> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = rule::table
+      .filter(not(rule::deleted))
+      .select(max(rule::position))
+      .into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(rule::community_id.eq(community_id)),
+      None => query.filter(rule::community_id.is_null()),
+    };
+    let last = query
+      .first::<Option<i32>>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    Ok(last.unwrap_or_default() + 1)
+  }
+
+  /// Reads a rule which is cited for an action in the given community. This can be a rule of the
+  /// community itself or a site rule. Without a community, only site rules can be cited.
+  pub async fn read_for_citation(
+    pool: &mut DbPool<'_>,
+    rule_id: RuleId,
+    community_id: Option<CommunityId>,
+  ) -> LemmyResult<Self> {
+    Ok(
+      Self::read(pool, rule_id)
+        .await
+        .ok()
+        .filter(|r| !r.deleted && r.community_id.is_none_or(|c| Some(c) == community_id))
+        .ok_or(LemmyErrorType::InvalidRule)?,
+    )
+  }
+
+  pub async fn read_apub(pool: &mut DbPool<'_>, ap_id: &DbUrl) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    rule::table
+      .filter(rule::ap_id.eq(ap_id))
+      .filter(not(rule::deleted))
+      .select(Self::as_select())
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Upserts the rules of a remote community, and marks rules which are missing as deleted.
+  pub async fn update_many(
+    pool: &mut DbPool<'_>,
+    mut forms: Vec<RuleInsertForm>,
+    existing_rules: Vec<Rule>,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let new_rule_ids = forms
+      .iter()
+      .filter_map(|r| r.ap_id.clone())
+      .collect::<HashSet<_>>();
+    let delete_forms = existing_rules
+      .into_iter()
+      .filter(|r| !new_rule_ids.contains(&r.ap_id))
+      .map(|r| RuleInsertForm {
+        ap_id: Some(r.ap_id),
+        local: Some(r.local),
+        deleted: Some(true),
+        ..RuleInsertForm::new(r.community_id, r.position, r.title)
+      });
+    forms.extend(delete_forms);
+    if forms.is_empty() {
+      return Ok(vec![]);
+    }
+
+    insert_into(rule::table)
+      .values(&forms)
+      .on_conflict(rule::ap_id)
+      .do_update()
+      .set((
+        rule::position.eq(excluded(rule::position)),
+        rule::title.eq(excluded(rule::title)),
+        rule::description.eq(excluded(rule::description)),
+        rule::deleted.eq(excluded(rule::deleted)),
+      ))
+      .get_results::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  /// Counts how often each rule of the community (or each site rule) was cited by reports,
+  /// removals and bans. Reverted actions are not counted.
+  pub async fn stats(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+  ) -> LemmyResult<Vec<RuleStats>> {
+    let rules = Self::list(pool, community_id).await?;
+    let rule_ids: Vec<RuleId> = rules.iter().map(|r| r.id).collect();
+    let conn = &mut get_conn(pool).await?;
+
+    let mut reports: Vec<(Option<RuleId>, bool, i64)> = post_report::table
+      .filter(post_report::rule_id.eq_any(&rule_ids))
+      .group_by((post_report::rule_id, post_report::resolved))
+      .select((post_report::rule_id, post_report::resolved, count_star()))
+      .load(conn)
+      .await?;
+    let comment_reports: Vec<(Option<RuleId>, bool, i64)> = comment_report::table
+      .filter(comment_report::rule_id.eq_any(&rule_ids))
+      .group_by((comment_report::rule_id, comment_report::resolved))
+      .select((
+        comment_report::rule_id,
+        comment_report::resolved,
+        count_star(),
+      ))
+      .load(conn)
+      .await?;
+    reports.extend(comment_reports);
+
+    let actions: Vec<(Option<RuleId>, ModlogKind, i64)> = modlog::table
+      .filter(modlog::rule_id.eq_any(&rule_ids))
+      .filter(not(modlog::is_revert))
+      .group_by((modlog::rule_id, modlog::kind))
+      .select((modlog::rule_id, modlog::kind, count_star()))
+      .load(conn)
+      .await?;
+
+    Ok(
+      rule_ids
+        .into_iter()
+        .map(|rule_id| {
+          let mut stats = RuleStats {
+            rule_id,
+            report_count: 0,
+            unresolved_report_count: 0,
+            removal_count: 0,
+            ban_count: 0,
+          };
+          for (_, resolved, count) in reports.iter().filter(|r| r.0 == Some(rule_id)) {
+            stats.report_count += count;
+            if !resolved {
+              stats.unresolved_report_count += count;
+            }
+          }
+          for (_, kind, count) in actions.iter().filter(|a| a.0 == Some(rule_id)) {
+            match kind {
+              ModlogKind::ModRemovePost | ModlogKind::ModRemoveComment => {
+                stats.removal_count += count
+              }
+              ModlogKind::ModBanFromCommunity | ModlogKind::AdminBan => stats.ban_count += count,
+              _ => {}
+            }
+          }
+          stats
+        })
+        .collect(),
+    )
+  }
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing)]
+mod tests {
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      modlog::{Modlog, ModlogInsertForm},
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      post_report::{PostReport, PostReportForm},
+      rule::{Rule, RuleInsertForm, RuleStats, RuleUpdateForm},
+    },
+    traits::Reportable,
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_rule() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "rules")).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "rules".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+    let other_community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "other_rules".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+
+    let position = Rule::next_position(pool, Some(community.id)).await?;
+    assert_eq!(1, position);
+    let form = RuleInsertForm::new(Some(community.id), position, "Be nice".to_string());
+    let be_nice = Rule::create(pool, &form).await?;
+    assert!(
+      be_nice
+        .ap_id
+        .inner()
+        .path()
+        .ends_with(&format!("/rule/{}", be_nice.id.0))
+    );
+
+    // Inserted before the first rule
+    let form = RuleInsertForm::new(Some(community.id), 0, "No spam".to_string());
+    let no_spam = Rule::create(pool, &form).await?;
+    let site_rule = Rule::create(pool, &RuleInsertForm::new(None, 1, "Site".to_string())).await?;
+
+    let rules = Rule::list(pool, Some(community.id)).await?;
+    assert_eq!(vec![no_spam.clone(), be_nice.clone()], rules);
+    assert_eq!(vec![site_rule.clone()], Rule::list(pool, None).await?);
+    assert_eq!(2, Rule::next_position(pool, Some(community.id)).await?);
+
+    // Site rules can be cited everywhere, community rules only in their community
+    Rule::read_for_citation(pool, site_rule.id, Some(community.id)).await?;
+    Rule::read_for_citation(pool, be_nice.id, Some(community.id)).await?;
+    let err = Rule::read_for_citation(pool, be_nice.id, Some(other_community.id)).await;
+    assert_eq!(
+      Some(LemmyErrorType::InvalidRule),
+      err.err().map(|e| e.error_type)
+    );
+    assert!(
+      Rule::read_for_citation(pool, be_nice.id, None)
+        .await
+        .is_err()
+    );
+
+    let post = Post::create(
+      pool,
+      &PostInsertForm::new("rule post".to_string(), person.id, community.id),
+    )
+    .await?;
+    let report_form = PostReportForm {
+      creator_id: person.id,
+      post_id: post.id,
+      original_post_name: post.name.clone(),
+      reason: "spam".to_string(),
+      rule_id: Some(no_spam.id),
+      ..Default::default()
+    };
+    PostReport::report(pool, &report_form).await?;
+    let form = ModlogInsertForm::mod_remove_post(person.id, &post, true, "spam", None)
+      .with_rule(Some(no_spam.id));
+    Modlog::create(pool, &[form]).await?;
+
+    let stats = Rule::stats(pool, Some(community.id)).await?;
+    assert_eq!(
+      vec![
+        RuleStats {
+          rule_id: no_spam.id,
+          report_count: 1,
+          unresolved_report_count: 1,
+          removal_count: 1,
+          ban_count: 0,
+        },
+        RuleStats {
+          rule_id: be_nice.id,
+          report_count: 0,
+          unresolved_report_count: 0,
+          removal_count: 0,
+          ban_count: 0,
+        }
+      ],
+      stats
+    );
+
+    // Deleted rules are hidden and can't be cited anymore
+    let form = RuleUpdateForm {
+      deleted: Some(true),
+      ..Default::default()
+    };
+    Rule::update(pool, be_nice.id, &form).await?;
+    assert_eq!(
+      vec![no_spam.clone()],
+      Rule::list(pool, Some(community.id)).await?
+    );
+    assert!(
+      Rule::read_for_citation(pool, be_nice.id, Some(community.id))
+        .await
+        .is_err()
+    );
+
+    // Rules which are missing from a remote update are marked as deleted
+    let form = RuleInsertForm {
+      ap_id: Some(no_spam.ap_id.clone()),
+      description: Some("No ads".to_string()),
+      ..RuleInsertForm::new(Some(community.id), 0, "No spam".to_string())
+    };
+    let existing = Rule::list(pool, Some(community.id)).await?;
+    let updated = Rule::update_many(pool, vec![form], existing).await?;
+    assert_eq!(Some("No ads".to_string()), updated[0].description);
+    let existing = Rule::list(pool, Some(community.id)).await?;
+    Rule::update_many(pool, vec![], existing).await?;
+    assert!(Rule::list(pool, Some(community.id)).await?.is_empty());
+
+    Rule::delete(pool, site_rule.id).await?;
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,237 @@
+use crate::{
+  newtypes::{ScheduledCommentId, ScheduledPrivateMessageId},
+  source::scheduled::{
+    ScheduledComment,
+    ScheduledCommentInsertForm,
+    ScheduledCommentUpdateForm,
+    ScheduledPrivateMessage,
+    ScheduledPrivateMessageInsertForm,
+    ScheduledPrivateMessageUpdateForm,
+  },
+};
+use diesel::{ExpressionMethods, QueryDsl, dsl::insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{scheduled_comment, scheduled_private_message},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+  utils::now,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for ScheduledComment {
+  type InsertForm = ScheduledCommentInsertForm;
+  type UpdateForm = ScheduledCommentUpdateForm;
+  type IdType = ScheduledCommentId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(scheduled_comment::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    scheduled_comment_id: ScheduledCommentId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(scheduled_comment::table.find(scheduled_comment_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl ScheduledComment {
+  /// All pending comments of the user, the next one first.
+  pub async fn list_for_creator(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    scheduled_comment::table
+      .filter(scheduled_comment::creator_id.eq(creator_id))
+      .order_by(scheduled_comment::scheduled_publish_time_at.asc())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Comments whose scheduled time has passed.
+  pub async fn list_due(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    scheduled_comment::table
+      .filter(scheduled_comment::scheduled_publish_time_at.le(now()))
+      .order_by(scheduled_comment::scheduled_publish_time_at.asc())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn count_for_creator(pool: &mut DbPool<'_>, creator_id: PersonId) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    scheduled_comment::table
+      .filter(scheduled_comment::creator_id.eq(creator_id))
+      .count()
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl Crud for ScheduledPrivateMessage {
+  type InsertForm = ScheduledPrivateMessageInsertForm;
+  type UpdateForm = ScheduledPrivateMessageUpdateForm;
+  type IdType = ScheduledPrivateMessageId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(scheduled_private_message::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    scheduled_private_message_id: ScheduledPrivateMessageId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(scheduled_private_message::table.find(scheduled_private_message_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl ScheduledPrivateMessage {
+  /// All pending private messages of the user, the next one first.
+  pub async fn list_for_creator(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    scheduled_private_message::table
+      .filter(scheduled_private_message::creator_id.eq(creator_id))
+      .order_by(scheduled_private_message::scheduled_publish_time_at.asc())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Private messages whose scheduled time has passed.
+  pub async fn list_due(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    scheduled_private_message::table
+      .filter(scheduled_private_message::scheduled_publish_time_at.le(now()))
+      .order_by(scheduled_private_message::scheduled_publish_time_at.asc())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn count_for_creator(pool: &mut DbPool<'_>, creator_id: PersonId) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    scheduled_private_message::table
+      .filter(scheduled_private_message::creator_id.eq(creator_id))
+      .count()
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+    post::{Post, PostInsertForm},
+    scheduled::{
+      ScheduledComment,
+      ScheduledCommentInsertForm,
+      ScheduledCommentUpdateForm,
+      ScheduledPrivateMessage,
+      ScheduledPrivateMessageInsertForm,
+    },
+  };
+  use chrono::{Days, Utc};
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_scheduled() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "sched")).await?;
+    let other = Person::create(pool, &PersonInsertForm::test_form(instance.id, "sched2")).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "sched".into(), "pubkey".to_string()),
+    )
+    .await?;
+    let post = Post::create(
+      pool,
+      &PostInsertForm::new("sched".into(), person.id, community.id),
+    )
+    .await?;
+
+    let tomorrow = Utc::now() + Days::new(1);
+    let yesterday = Utc::now() - Days::new(1);
+    let later = ScheduledComment::create(
+      pool,
+      &ScheduledCommentInsertForm::new(person.id, post.id, "later".into(), tomorrow),
+    )
+    .await?;
+    let due = ScheduledComment::create(
+      pool,
+      &ScheduledCommentInsertForm::new(person.id, post.id, "due".into(), yesterday),
+    )
+    .await?;
+    ScheduledPrivateMessage::create(
+      pool,
+      &ScheduledPrivateMessageInsertForm::new(person.id, other.id, "hi".into(), tomorrow),
+    )
+    .await?;
+
+    let listed = ScheduledComment::list_for_creator(pool, person.id).await?;
+    assert_eq!(vec![due.clone(), later.clone()], listed);
+    assert_eq!(vec![due], ScheduledComment::list_due(pool).await?);
+    assert_eq!(
+      2,
+      ScheduledComment::count_for_creator(pool, person.id).await?
+    );
+    assert!(ScheduledPrivateMessage::list_due(pool).await?.is_empty());
+    assert_eq!(
+      1,
+      ScheduledPrivateMessage::count_for_creator(pool, person.id).await?
+    );
+
+    let form = ScheduledCommentUpdateForm {
+      content: Some("edited".into()),
+      ..Default::default()
+    };
+    let updated = ScheduledComment::update(pool, later.id, &form).await?;
+    assert_eq!("edited", updated.content);
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
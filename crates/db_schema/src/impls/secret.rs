@@ -1,13 +1,24 @@
 use crate::source::secret::Secret;
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use diesel::{ExpressionMethods, QueryDsl, update};
 use diesel_async::RunQueryDsl;
-use lemmy_db_schema_file::schema::secret::dsl::secret;
+use lemmy_db_schema_file::schema::secret::dsl::{secret, vapid_private_key};
 use lemmy_diesel_utils::connection::{DbPool, get_conn};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use ring::{
+  rand::SystemRandom,
+  signature::{ECDSA_P256_SHA256_FIXED_SIGNING, EcdsaKeyPair},
+};
 
 impl Secret {
   /// Initialize the Secrets from the DB.
   /// Warning: You should only call this once.
   pub async fn init(pool: &mut DbPool<'_>) -> LemmyResult<Secret> {
+    let secrets = Self::read_secrets(pool).await?;
+    if secrets.vapid_private_key.is_some() {
+      return Ok(secrets);
+    }
+    Self::init_vapid_key(pool).await?;
     Self::read_secrets(pool).await
   }
 
@@ -18,4 +29,18 @@ impl Secret {
       .await
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
+
+  /// Generates the VAPID keypair on first startup. If multiple processes start at the same time,
+  /// only the first key is stored.
+  async fn init_vapid_key(pool: &mut DbPool<'_>) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let pkcs8 =
+      EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &SystemRandom::new())?;
+    update(secret.filter(vapid_private_key.is_null()))
+      .set(vapid_private_key.eq(BASE64_URL_SAFE_NO_PAD.encode(pkcs8)))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    Ok(())
+  }
 }
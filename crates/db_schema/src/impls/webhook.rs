@@ -0,0 +1,283 @@
+use crate::{
+  newtypes::{CommunityId, WebhookDeliveryId, WebhookId},
+  source::webhook::{Webhook, WebhookDelivery, WebhookInsertForm, WebhookUpdateForm},
+};
+use chrono::{DateTime, Utc};
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  IntoSql,
+  PgArrayExpressionMethods,
+  QueryDsl,
+  SelectableHelper,
+  dsl::insert_into,
+  sql_types::Jsonb,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  enums::WebhookEventType,
+  schema::{sql_types::WebhookEventEnum, webhook, webhook_delivery},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+  utils::now,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for Webhook {
+  type InsertForm = WebhookInsertForm;
+  type UpdateForm = WebhookUpdateForm;
+  type IdType = WebhookId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(webhook::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    webhook_id: WebhookId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(webhook::table.find(webhook_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl Webhook {
+  /// Lists the webhooks of a community, or the site webhooks if no community is given.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = webhook::table.order_by(webhook::id).into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(webhook::community_id.eq(community_id)),
+      None => query.filter(webhook::community_id.is_null()),
+    };
+    query
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl WebhookDelivery {
+  /// Queues a delivery for each enabled webhook which is subscribed to the event. Site webhooks
+  /// receive all events, community webhooks only those of their own community.
+  pub async fn enqueue(
+    pool: &mut DbPool<'_>,
+    event: WebhookEventType,
+    community_id: Option<CommunityId>,
+    payload: serde_json::Value,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    let matching = webhook::table
+      .filter(webhook::enabled)
+      .filter(webhook::events.contains(vec![event]))
+      .filter(
+        webhook::community_id
+          .is_null()
+          .or(webhook::community_id.eq(community_id)),
+      )
+      .select((
+        webhook::id,
+        event.into_sql::<WebhookEventEnum>(),
+        payload.into_sql::<Jsonb>(),
+      ));
+    insert_into(webhook_delivery::table)
+      .values(matching)
+      .into_columns((
+        webhook_delivery::webhook_id,
+        webhook_delivery::event,
+        webhook_delivery::payload,
+      ))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// Deliveries which are due to be sent, together with their webhook. Deliveries which failed
+  /// `max_fail_count` times are given up.
+  pub async fn list_pending(
+    pool: &mut DbPool<'_>,
+    max_fail_count: i32,
+    limit: i64,
+  ) -> LemmyResult<Vec<(Self, Webhook)>> {
+    let conn = &mut get_conn(pool).await?;
+    webhook_delivery::table
+      .inner_join(webhook::table)
+      .filter(webhook_delivery::delivered_at.is_null())
+      .filter(webhook_delivery::next_attempt_at.le(now()))
+      .filter(webhook_delivery::fail_count.lt(max_fail_count))
+      .filter(webhook::enabled)
+      .order_by(webhook_delivery::id)
+      .limit(limit)
+      .select((Self::as_select(), Webhook::as_select()))
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn mark_delivered(pool: &mut DbPool<'_>, id: WebhookDeliveryId) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(webhook_delivery::table.find(id))
+      .set(webhook_delivery::delivered_at.eq(Utc::now()))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    Ok(())
+  }
+
+  pub async fn mark_failed(
+    pool: &mut DbPool<'_>,
+    id: WebhookDeliveryId,
+    fail_count: i32,
+    next_attempt_at: DateTime<Utc>,
+    error: String,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(webhook_delivery::table.find(id))
+      .set((
+        webhook_delivery::fail_count.eq(fail_count),
+        webhook_delivery::next_attempt_at.eq(next_attempt_at),
+        webhook_delivery::last_error.eq(error),
+      ))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+    webhook::{Webhook, WebhookDelivery, WebhookInsertForm, WebhookUpdateForm},
+  };
+  use chrono::Utc;
+  use lemmy_db_schema_file::enums::WebhookEventType;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_webhook_deliveries() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "hooker")).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "hooked".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+    let other_community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "unhooked".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+
+    let url = Url::parse("https://example.com/hook")?;
+    let site_webhook = Webhook::create(
+      pool,
+      &WebhookInsertForm::new(
+        person.id,
+        None,
+        url.clone().into(),
+        "secret".to_string(),
+        vec![
+          WebhookEventType::NewPost,
+          WebhookEventType::NewRegistrationApplication,
+        ],
+      ),
+    )
+    .await?;
+    let community_webhook = Webhook::create(
+      pool,
+      &WebhookInsertForm::new(
+        person.id,
+        Some(community.id),
+        url.into(),
+        "secret".to_string(),
+        vec![WebhookEventType::NewPost],
+      ),
+    )
+    .await?;
+    assert_eq!(
+      vec![community_webhook.clone()],
+      Webhook::list(pool, Some(community.id)).await?
+    );
+    assert_eq!(vec![site_webhook.clone()], Webhook::list(pool, None).await?);
+
+    // Posts in the community go to both webhooks, other posts only to the site webhook
+    let payload = json!({"event": "new_post"});
+    let queued = WebhookDelivery::enqueue(
+      pool,
+      WebhookEventType::NewPost,
+      Some(community.id),
+      payload.clone(),
+    )
+    .await?;
+    assert_eq!(2, queued);
+    let queued = WebhookDelivery::enqueue(
+      pool,
+      WebhookEventType::NewPost,
+      Some(other_community.id),
+      payload.clone(),
+    )
+    .await?;
+    assert_eq!(1, queued);
+    let queued = WebhookDelivery::enqueue(pool, WebhookEventType::Ban, None, payload).await?;
+    assert_eq!(0, queued);
+
+    // Disabled webhooks don't get new deliveries
+    let form = WebhookUpdateForm {
+      enabled: Some(false),
+      ..Default::default()
+    };
+    Webhook::update(pool, community_webhook.id, &form).await?;
+    let queued = WebhookDelivery::enqueue(
+      pool,
+      WebhookEventType::NewPost,
+      Some(community.id),
+      json!({}),
+    )
+    .await?;
+    assert_eq!(1, queued);
+
+    // The pending deliveries of the disabled webhook are not sent either
+    let pending = WebhookDelivery::list_pending(pool, 3, 10).await?;
+    assert_eq!(3, pending.len());
+    assert!(pending.iter().all(|(_, w)| w.id == site_webhook.id));
+
+    // Deliveries are retried until they reach the maximum number of fails
+    let (first, _) = pending.first().cloned().ok_or(LemmyErrorType::NotFound)?;
+    let (second, _) = pending.get(1).cloned().ok_or(LemmyErrorType::NotFound)?;
+    WebhookDelivery::mark_delivered(pool, first.id).await?;
+    WebhookDelivery::mark_failed(pool, second.id, 3, Utc::now(), "timeout".to_string()).await?;
+    assert_eq!(1, WebhookDelivery::list_pending(pool, 3, 10).await?.len());
+    assert_eq!(2, WebhookDelivery::list_pending(pool, 4, 10).await?.len());
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -135,6 +135,19 @@ pub enum SearchType {
   MultiCommunities,
 }
 
+/// The order of search results.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+pub enum SearchSortType {
+  #[default]
+  /// Posts are sorted by hot rank, everything else by newest first
+  Default,
+  /// Best full-text matches first
+  Relevance,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -198,6 +211,7 @@ pub enum ReportType {
   Comments,
   PrivateMessages,
   Communities,
+  Persons,
 }
 
 #[derive(
@@ -261,6 +275,9 @@ pub type Person1AliasAllColumnsTuple = (
   AliasedField<aliases::Person1, person::post_score>,
   AliasedField<aliases::Person1, person::comment_count>,
   AliasedField<aliases::Person1, person::comment_score>,
+  AliasedField<aliases::Person1, person::also_known_as>,
+  AliasedField<aliases::Person1, person::moved_to_id>,
+  AliasedField<aliases::Person1, person::follower_count>,
 );
 
 #[cfg(feature = "full")]
@@ -288,6 +305,9 @@ pub type Person2AliasAllColumnsTuple = (
   AliasedField<aliases::Person2, person::post_score>,
   AliasedField<aliases::Person2, person::comment_count>,
   AliasedField<aliases::Person2, person::comment_score>,
+  AliasedField<aliases::Person2, person::also_known_as>,
+  AliasedField<aliases::Person2, person::moved_to_id>,
+  AliasedField<aliases::Person2, person::follower_count>,
 );
 
 #[cfg(feature = "full")]
@@ -0,0 +1,91 @@
+use crate::newtypes::{AutomodRuleId, CommunityId, LanguageId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::automod_rule;
+use lemmy_db_schema_file::{PersonId, enums::AutomodAction};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = automod_rule))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An automod rule, which applies its action to new posts and comments that match all of its
+/// conditions.
+pub struct AutomodRule {
+  pub id: AutomodRuleId,
+  pub creator_id: PersonId,
+  /// If set, the rule only applies to this community. Otherwise to the whole site.
+  pub community_id: Option<CommunityId>,
+  /// Used as reason for the modlog entry and for reports.
+  pub reason: String,
+  pub action: AutomodAction,
+  /// The comment text for the reply action.
+  pub reply_text: Option<String>,
+  pub check_posts: bool,
+  pub check_comments: bool,
+  /// Matches the title, body or url of posts and the content of comments.
+  pub content_regex: Option<String>,
+  /// Matches post links to this domain or its subdomains.
+  pub link_domain: Option<String>,
+  /// Matches creators whose account is at most this many days old.
+  pub max_account_age_days: Option<i32>,
+  /// Matches creators whose post and comment score are both at most this value.
+  pub max_creator_score: Option<i32>,
+  /// Matches content once it has received this many reports.
+  pub report_count: Option<i32>,
+  pub language_id: Option<LanguageId>,
+  pub enabled: bool,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = automod_rule))]
+pub struct AutomodRuleInsertForm {
+  pub creator_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+  pub action: AutomodAction,
+  #[new(default)]
+  pub reply_text: Option<String>,
+  #[new(default)]
+  pub check_posts: Option<bool>,
+  #[new(default)]
+  pub check_comments: Option<bool>,
+  #[new(default)]
+  pub content_regex: Option<String>,
+  #[new(default)]
+  pub link_domain: Option<String>,
+  #[new(default)]
+  pub max_account_age_days: Option<i32>,
+  #[new(default)]
+  pub max_creator_score: Option<i32>,
+  #[new(default)]
+  pub report_count: Option<i32>,
+  #[new(default)]
+  pub language_id: Option<LanguageId>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = automod_rule))]
+pub struct AutomodRuleUpdateForm {
+  pub reason: Option<String>,
+  pub action: Option<AutomodAction>,
+  pub reply_text: Option<Option<String>>,
+  pub check_posts: Option<bool>,
+  pub check_comments: Option<bool>,
+  pub content_regex: Option<Option<String>>,
+  pub link_domain: Option<Option<String>>,
+  pub max_account_age_days: Option<Option<i32>>,
+  pub max_creator_score: Option<Option<i32>>,
+  pub report_count: Option<Option<i32>>,
+  pub language_id: Option<Option<LanguageId>>,
+  pub enabled: Option<bool>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
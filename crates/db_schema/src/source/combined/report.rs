@@ -3,6 +3,7 @@ use crate::newtypes::{
   CommentReportId,
   CommunityId,
   CommunityReportId,
+  PersonReportId,
   PostId,
   PostReportId,
   PrivateMessageId,
@@ -43,4 +44,5 @@ pub struct ReportCombined {
   pub comment_id: Option<CommentId>,
   pub community_id: Option<CommunityId>,
   pub private_message_id: Option<PrivateMessageId>,
+  pub person_report_id: Option<PersonReportId>,
 }
@@ -70,6 +70,9 @@ pub struct Comment {
   /// This field is a dupe of post.community_id, but necessary for join performance.
   #[serde(skip)]
   pub community_id: CommunityId,
+  /// The comment is held in the mod queue, and only visible to its creator and the mods until it
+  /// is approved.
+  pub approval_pending: bool,
 }
 
 #[derive(Debug, Clone, derive_new::new, Serialize, Deserialize)]
@@ -100,6 +103,8 @@ pub struct CommentInsertForm {
   pub federation_pending: Option<bool>,
   #[new(default)]
   pub locked: Option<bool>,
+  #[new(default)]
+  pub approval_pending: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -117,6 +122,7 @@ pub struct CommentUpdateForm {
   pub language_id: Option<LanguageId>,
   pub federation_pending: Option<bool>,
   pub locked: Option<bool>,
+  pub approval_pending: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -143,6 +149,8 @@ pub struct CommentActions {
   pub comment_id: CommentId,
   /// True if upvoted, false if downvoted. Upvote is greater than downvote.
   pub vote_is_upvote: Option<bool>,
+  /// When you last reacted with an emoji, only set while you have any reactions.
+  pub reacted_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone)]
@@ -0,0 +1,34 @@
+use crate::newtypes::{CommentId, CommentRevisionId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::comment_revision;
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(
+  feature = "full",
+  derive(Identifiable, Queryable, Selectable, Associations)
+)]
+#[cfg_attr(feature = "full", diesel(belongs_to(crate::source::comment::Comment)))]
+#[cfg_attr(feature = "full", diesel(table_name = comment_revision))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A previous version of a comment, before it was edited.
+pub struct CommentRevision {
+  pub id: CommentRevisionId,
+  pub comment_id: CommentId,
+  pub content: String,
+  /// When this version was published, which is the time of the comment creation or of the
+  /// previous edit.
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = comment_revision))]
+pub struct CommentRevisionForm {
+  pub comment_id: CommentId,
+  pub content: String,
+  pub published_at: DateTime<Utc>,
+}
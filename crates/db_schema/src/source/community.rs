@@ -1,4 +1,7 @@
-use crate::{newtypes::CommunityId, source::placeholder_apub_url};
+use crate::{
+  newtypes::{CommunityFlairId, CommunityId},
+  source::placeholder_apub_url,
+};
 use chrono::{DateTime, Utc};
 use lemmy_db_schema_file::{
   InstanceId,
@@ -94,6 +97,22 @@ pub struct Community {
   pub report_count: i16,
   pub unresolved_report_count: i16,
   pub local_removed: bool,
+  /// Whether new posts are held in the mod queue until approved by a moderator.
+  pub posts_require_approval: bool,
+  /// Whether new comments are held in the mod queue until approved by a moderator.
+  pub comments_require_approval: bool,
+  /// If set, only content from accounts younger than this many days is held for approval.
+  pub approval_max_account_age_days: Option<i32>,
+  /// If true, only content from remote users is held for approval.
+  pub approval_remote_only: bool,
+  /// Number of warnings after which a user is automatically banned from the community. If not
+  /// set, the warning policy of the site is used.
+  pub warning_ban_threshold: Option<i32>,
+  /// Only warnings from this many days count toward the ban. If not set, all warnings which
+  /// haven't expired count.
+  pub warning_ban_window_days: Option<i32>,
+  /// How many days the automatic ban lasts. If not set, the ban is permanent.
+  pub warning_ban_days: Option<i32>,
 }
 
 #[derive(Debug, Clone, derive_new::new)]
@@ -145,6 +164,20 @@ pub struct CommunityInsertForm {
   pub summary: Option<String>,
   #[new(default)]
   pub local_removed: Option<bool>,
+  #[new(default)]
+  pub posts_require_approval: Option<bool>,
+  #[new(default)]
+  pub comments_require_approval: Option<bool>,
+  #[new(default)]
+  pub approval_max_account_age_days: Option<i32>,
+  #[new(default)]
+  pub approval_remote_only: Option<bool>,
+  #[new(default)]
+  pub warning_ban_threshold: Option<i32>,
+  #[new(default)]
+  pub warning_ban_window_days: Option<i32>,
+  #[new(default)]
+  pub warning_ban_days: Option<i32>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -173,6 +206,13 @@ pub struct CommunityUpdateForm {
   pub visibility: Option<CommunityVisibility>,
   pub summary: Option<Option<String>>,
   pub local_removed: Option<bool>,
+  pub posts_require_approval: Option<bool>,
+  pub comments_require_approval: Option<bool>,
+  pub approval_max_account_age_days: Option<Option<i32>>,
+  pub approval_remote_only: Option<bool>,
+  pub warning_ban_threshold: Option<Option<i32>>,
+  pub warning_ban_window_days: Option<Option<i32>>,
+  pub warning_ban_days: Option<Option<i32>>,
 }
 
 #[skip_serializing_none]
@@ -218,6 +258,8 @@ pub struct CommunityActions {
   /// immediately.
   #[serde(skip)]
   pub follow_activity_id: Option<DbUrl>,
+  /// The flair which this person has in the community.
+  pub flair_id: Option<CommunityFlairId>,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -267,3 +309,12 @@ pub struct CommunityBlockForm {
   #[new(value = "Utc::now()")]
   pub blocked_at: DateTime<Utc>,
 }
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_actions))]
+pub struct CommunityPersonFlairForm {
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  pub flair_id: CommunityFlairId,
+}
@@ -0,0 +1,125 @@
+use crate::newtypes::{ConversationId, ConversationMessageId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use {
+  i_love_jesus::CursorKeysModule,
+  lemmy_db_schema_file::schema::{conversation, conversation_message, conversation_participant},
+};
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, CursorKeysModule)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = conversation))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", cursor_keys_module(name = conversation_keys))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A private conversation between several people.
+pub struct Conversation {
+  pub id: ConversationId,
+  pub creator_id: PersonId,
+  /// Sent as `context` of the messages, so that all instances group them into the same
+  /// conversation.
+  pub ap_id: DbUrl,
+  pub local: bool,
+  pub published_at: DateTime<Utc>,
+  /// Time of the newest message, for sorting.
+  pub last_message_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation))]
+pub struct ConversationInsertForm {
+  pub creator_id: PersonId,
+  #[new(default)]
+  pub ap_id: Option<DbUrl>,
+  #[new(default)]
+  pub local: Option<bool>,
+  #[new(default)]
+  pub published_at: Option<DateTime<Utc>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation_participant))]
+#[cfg_attr(feature = "full", diesel(primary_key(conversation_id, person_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A member of a conversation, with their own read state.
+pub struct ConversationParticipant {
+  pub conversation_id: ConversationId,
+  pub person_id: PersonId,
+  /// Messages published after this time are unread.
+  pub last_read_at: Option<DateTime<Utc>>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation_participant))]
+pub struct ConversationParticipantForm {
+  pub conversation_id: ConversationId,
+  pub person_id: PersonId,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, CursorKeysModule)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = conversation_message))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", cursor_keys_module(name = conversation_message_keys))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A message in a conversation.
+pub struct ConversationMessage {
+  pub id: ConversationMessageId,
+  pub conversation_id: ConversationId,
+  pub creator_id: PersonId,
+  pub content: String,
+  pub ap_id: DbUrl,
+  pub local: bool,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+  pub deleted: bool,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation_message))]
+pub struct ConversationMessageInsertForm {
+  pub conversation_id: ConversationId,
+  pub creator_id: PersonId,
+  pub content: String,
+  #[new(default)]
+  pub ap_id: Option<DbUrl>,
+  #[new(default)]
+  pub local: Option<bool>,
+  #[new(default)]
+  pub published_at: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub updated_at: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub deleted: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation_message))]
+pub struct ConversationMessageUpdateForm {
+  pub content: Option<String>,
+  pub deleted: Option<bool>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
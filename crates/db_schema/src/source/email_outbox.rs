@@ -0,0 +1,57 @@
+use crate::newtypes::EmailOutboxId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use {i_love_jesus::CursorKeysModule, lemmy_db_schema_file::schema::email_outbox};
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, CursorKeysModule)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = email_outbox))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", cursor_keys_module(name = email_outbox_keys))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An outgoing email, which is retried until it is sent or gives up after too many failures.
+pub struct EmailOutbox {
+  pub id: EmailOutboxId,
+  pub recipient_email: String,
+  pub recipient_name: String,
+  pub subject: String,
+  /// The email body. Not exposed in the api, as it may contain password reset or verification
+  /// tokens.
+  #[serde(skip)]
+  pub html: String,
+  pub fail_count: i32,
+  pub last_error: Option<String>,
+  pub next_attempt_at: DateTime<Utc>,
+  pub sent_at: Option<DateTime<Utc>>,
+  /// Set once the email gave up after too many failed attempts.
+  pub failed_at: Option<DateTime<Utc>>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = email_outbox))]
+pub struct EmailOutboxInsertForm {
+  pub recipient_email: String,
+  pub recipient_name: String,
+  pub subject: String,
+  pub html: String,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = email_outbox))]
+pub struct EmailOutboxUpdateForm {
+  pub fail_count: Option<i32>,
+  pub last_error: Option<Option<String>>,
+  pub next_attempt_at: Option<DateTime<Utc>>,
+  pub sent_at: Option<Option<DateTime<Utc>>>,
+  pub failed_at: Option<Option<DateTime<Utc>>>,
+}
@@ -6,6 +6,7 @@ use lemmy_db_schema_file::{
   PersonId,
   enums::{
     CommentSortType,
+    EditHistoryVisibility,
     FederationMode,
     ImageMode,
     ListingType,
@@ -115,6 +116,16 @@ pub struct LocalSite {
   pub image_upload_disabled: bool,
   /// How many active invite links a user can have
   pub max_invites_per_user_allowed: i32,
+  /// Who can see previous versions of edited posts and comments.
+  pub edit_history_visibility: EditHistoryVisibility,
+  /// Number of warnings after which a user is automatically banned from a community. Applies to
+  /// local communities which don't have their own warning policy.
+  pub warning_ban_threshold: Option<i32>,
+  /// Only warnings from this many days count toward the ban. If not set, all warnings which
+  /// haven't expired count.
+  pub warning_ban_window_days: Option<i32>,
+  /// How many days the automatic ban lasts. If not set, the ban is permanent.
+  pub warning_ban_days: Option<i32>,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -195,6 +206,14 @@ pub struct LocalSiteInsertForm {
   pub image_upload_disabled: Option<bool>,
   #[new(default)]
   pub max_invites_per_user_allowed: Option<i32>,
+  #[new(default)]
+  pub edit_history_visibility: Option<EditHistoryVisibility>,
+  #[new(default)]
+  pub warning_ban_threshold: Option<i32>,
+  #[new(default)]
+  pub warning_ban_window_days: Option<i32>,
+  #[new(default)]
+  pub warning_ban_days: Option<i32>,
 }
 
 #[derive(Clone, Default)]
@@ -239,4 +258,8 @@ pub struct LocalSiteUpdateForm {
   pub image_allow_video_uploads: Option<bool>,
   pub image_upload_disabled: Option<bool>,
   pub max_invites_per_user_allowed: Option<i32>,
+  pub edit_history_visibility: Option<EditHistoryVisibility>,
+  pub warning_ban_threshold: Option<Option<i32>>,
+  pub warning_ban_window_days: Option<Option<i32>>,
+  pub warning_ban_days: Option<Option<i32>>,
 }
@@ -4,16 +4,21 @@ use url::Url;
 #[cfg(feature = "full")]
 pub mod activity;
 pub mod actor_language;
+pub mod automod_rule;
 pub mod combined;
 pub mod comment;
 pub mod comment_report;
+pub mod comment_revision;
 pub mod community;
 #[cfg(feature = "full")]
 pub mod community_community_follow;
+pub mod community_flair;
 pub mod community_report;
 pub mod community_tag;
+pub mod conversation;
 pub mod custom_emoji;
 pub mod custom_emoji_keyword;
+pub mod email_outbox;
 pub mod email_verification;
 pub mod federation_allowlist;
 pub mod federation_blocklist;
@@ -28,21 +33,32 @@ pub mod local_site_url_blocklist;
 pub mod local_user;
 pub mod local_user_invite;
 pub mod login_token;
+pub mod mod_note;
 pub mod modlog;
+pub mod modmail;
 pub mod multi_community;
 pub mod notification;
+pub mod notification_preference;
 pub mod oauth_account;
 pub mod oauth_provider;
 pub mod password_reset_request;
 pub mod person;
+pub mod person_report;
+pub mod poll;
 pub mod post;
 pub mod post_report;
+pub mod post_revision;
 pub mod private_message;
 pub mod private_message_report;
+pub mod push_subscription;
+pub mod reaction;
 pub mod registration_application;
+pub mod rule;
+pub mod scheduled;
 pub mod secret;
 pub mod site;
 pub mod tagline;
+pub mod webhook;
 
 /// Default value for columns like [community::Community.inbox_url] which are marked as serde(skip).
 ///
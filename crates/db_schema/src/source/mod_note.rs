@@ -0,0 +1,45 @@
+use crate::newtypes::{CommunityId, ModNoteId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::mod_note;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_note))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A note about a user, which all moderators of the community can see.
+pub struct ModNote {
+  pub id: ModNoteId,
+  pub community_id: CommunityId,
+  /// The user which the note is about.
+  pub target_person_id: PersonId,
+  /// The moderator who wrote the note.
+  pub creator_id: PersonId,
+  pub content: String,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_note))]
+pub struct ModNoteInsertForm {
+  pub community_id: CommunityId,
+  pub target_person_id: PersonId,
+  pub creator_id: PersonId,
+  pub content: String,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_note))]
+pub struct ModNoteUpdateForm {
+  pub content: Option<String>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
@@ -1,4 +1,4 @@
-use crate::newtypes::{CommentId, CommunityId, ModlogId, PostId};
+use crate::newtypes::{CommentId, CommunityId, ModlogId, PostId, RuleId};
 use chrono::{DateTime, Utc};
 #[cfg(feature = "full")]
 use i_love_jesus::CursorKeysModule;
@@ -38,6 +38,10 @@ pub struct Modlog {
   pub published_at: DateTime<Utc>,
   pub bulk_action_parent_id: Option<ModlogId>,
   pub child_count: i32,
+  /// The community or site rule which was cited for this action.
+  pub rule_id: Option<RuleId>,
+  /// For warnings, the automatic ban which they led to.
+  pub warning_ban_id: Option<ModlogId>,
 }
 
 #[derive(derive_new::new)]
@@ -63,4 +67,6 @@ pub struct ModlogInsertForm<'a> {
   pub(crate) target_instance_id: Option<InstanceId>,
   #[new(default)]
   pub(crate) expires_at: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub(crate) rule_id: Option<RuleId>,
 }
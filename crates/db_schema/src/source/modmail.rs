@@ -0,0 +1,101 @@
+use crate::newtypes::{CommunityId, ModmailMessageId, ModmailThreadId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use {
+  i_love_jesus::CursorKeysModule,
+  lemmy_db_schema_file::schema::{modmail_message, modmail_thread},
+};
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, CursorKeysModule)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = modmail_thread))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", cursor_keys_module(name = modmail_thread_keys))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The modmail of a user with the moderators of a community.
+pub struct ModmailThread {
+  pub id: ModmailThreadId,
+  pub community_id: CommunityId,
+  /// The user who contacted the moderators.
+  pub creator_id: PersonId,
+  /// The moderator who takes care of this thread.
+  pub assignee_id: Option<PersonId>,
+  pub resolved: bool,
+  pub resolver_id: Option<PersonId>,
+  pub published_at: DateTime<Utc>,
+  pub last_message_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = modmail_thread))]
+pub struct ModmailThreadInsertForm {
+  pub community_id: CommunityId,
+  pub creator_id: PersonId,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = modmail_thread))]
+pub struct ModmailThreadUpdateForm {
+  pub assignee_id: Option<Option<PersonId>>,
+  pub resolved: Option<bool>,
+  pub resolver_id: Option<Option<PersonId>>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, CursorKeysModule)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = modmail_message))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", cursor_keys_module(name = modmail_message_keys))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A message in a modmail thread.
+pub struct ModmailMessage {
+  pub id: ModmailMessageId,
+  pub modmail_thread_id: ModmailThreadId,
+  /// Empty for replies from a remote community, where the moderator isn't known.
+  pub creator_id: Option<PersonId>,
+  /// A reply by the moderators, which is shown as written by the community.
+  pub from_moderator: bool,
+  /// A note between moderators, which the user can't see.
+  pub internal: bool,
+  pub content: String,
+  pub ap_id: DbUrl,
+  pub local: bool,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = modmail_message))]
+pub struct ModmailMessageInsertForm {
+  pub modmail_thread_id: ModmailThreadId,
+  pub creator_id: Option<PersonId>,
+  pub content: String,
+  #[new(default)]
+  pub from_moderator: Option<bool>,
+  #[new(default)]
+  pub internal: Option<bool>,
+  #[new(default)]
+  pub ap_id: Option<DbUrl>,
+  #[new(default)]
+  pub local: Option<bool>,
+  #[new(default)]
+  pub published_at: Option<DateTime<Utc>>,
+}
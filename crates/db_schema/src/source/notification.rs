@@ -37,6 +37,8 @@ pub struct Notification {
   pub instance_id: Option<InstanceId>,
   #[serde(skip)]
   pub community_id: Option<CommunityId>,
+  /// The score which a post reached, for score milestone notifications.
+  pub score_milestone: Option<i32>,
 }
 
 #[derive(derive_new::new)]
@@ -58,6 +60,8 @@ pub struct NotificationInsertForm {
   pub instance_id: Option<InstanceId>,
   #[new(default)]
   pub community_id: Option<CommunityId>,
+  #[new(default)]
+  pub score_milestone: Option<i32>,
 }
 
 impl NotificationInsertForm {
@@ -0,0 +1,33 @@
+use crate::newtypes::LocalUserId;
+use lemmy_db_schema_file::enums::NotificationType;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::local_user_notification_preference;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(table_name = local_user_notification_preference))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Through which channels a user receives notifications of the given type.
+pub struct NotificationPreference {
+  pub kind: NotificationType,
+  /// Show the notification in the inbox.
+  pub in_app: bool,
+  /// Send an email, if email notifications are enabled for the user.
+  pub email: bool,
+  /// Send a push notification to registered devices. Push notifications are only sent for
+  /// notifications which are also shown in the inbox.
+  pub push: bool,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = local_user_notification_preference))]
+pub struct LocalUserNotificationPreferenceForm {
+  pub local_user_id: LocalUserId,
+  pub kind: NotificationType,
+  pub in_app: bool,
+  pub email: bool,
+  pub push: bool,
+}
@@ -59,6 +59,12 @@ pub struct Person {
   pub comment_count: i32,
   #[serde(skip)]
   pub comment_score: i32,
+  /// Other accounts of the same user. Only these are allowed to move to this account.
+  pub also_known_as: Vec<DbUrl>,
+  /// The account to which this person has moved.
+  pub moved_to_id: Option<PersonId>,
+  /// The number of persons following this person.
+  pub follower_count: i32,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -96,6 +102,10 @@ pub struct PersonInsertForm {
   pub matrix_user_id: Option<String>,
   #[new(default)]
   pub bot_account: Option<bool>,
+  #[new(default)]
+  pub also_known_as: Option<Vec<DbUrl>>,
+  #[new(default)]
+  pub moved_to_id: Option<PersonId>,
 }
 
 #[derive(Clone, Default)]
@@ -116,6 +126,8 @@ pub struct PersonUpdateForm {
   pub inbox_url: Option<DbUrl>,
   pub matrix_user_id: Option<Option<String>>,
   pub bot_account: Option<bool>,
+  pub also_known_as: Option<Vec<DbUrl>>,
+  pub moved_to_id: Option<Option<PersonId>>,
 }
 
 #[skip_serializing_none]
@@ -131,7 +143,7 @@ pub struct PersonUpdateForm {
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
 pub struct PersonActions {
-  #[serde(skip)]
+  /// When the person was followed.
   pub followed_at: Option<DateTime<Utc>>,
   /// When the person was blocked.
   pub blocked_at: Option<DateTime<Utc>>,
@@ -139,7 +151,7 @@ pub struct PersonActions {
   pub person_id: PersonId,
   #[serde(skip)]
   pub target_id: PersonId,
-  #[serde(skip)]
+  /// Whether the follow still needs to be accepted by the remote instance.
   pub follow_pending: Option<bool>,
   /// When the person was noted.
   pub noted_at: Option<DateTime<Utc>>,
@@ -0,0 +1,115 @@
+use crate::newtypes::{PollOptionId, PostId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use {
+  diesel::FromSqlRow,
+  lemmy_db_schema_file::schema::{poll, poll_option, poll_vote, post_actions},
+};
+
+/// A poll which is attached to a post.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = poll))]
+#[cfg_attr(feature = "full", diesel(primary_key(post_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct Poll {
+  pub post_id: PostId,
+  /// Whether voters can pick more than one option.
+  pub multiple_choice: bool,
+  /// After this time no more votes are accepted. Null means the poll never closes.
+  pub end_time_at: Option<DateTime<Utc>>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+  /// Set when votes of a local poll changed, until the new counts are federated.
+  #[serde(skip)]
+  pub votes_changed: bool,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = poll))]
+#[cfg_attr(feature = "full", diesel(treat_none_as_null = true))]
+pub struct PollInsertForm {
+  pub post_id: PostId,
+  pub multiple_choice: bool,
+  pub end_time_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// One of the answers which can be chosen in a poll.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_option))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct PollOption {
+  pub id: PollOptionId,
+  pub post_id: PostId,
+  pub name: String,
+  pub display_order: i32,
+  pub vote_count: i32,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_option))]
+pub struct PollOptionInsertForm {
+  pub post_id: PostId,
+  pub name: String,
+  pub display_order: i32,
+  /// Only set for remote polls, local vote counts are calculated by triggers.
+  pub vote_count: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_vote))]
+#[cfg_attr(feature = "full", diesel(primary_key(poll_option_id, person_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PollVote {
+  pub poll_option_id: PollOptionId,
+  pub person_id: PersonId,
+  pub post_id: PostId,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_vote))]
+pub struct PollVoteForm {
+  pub poll_option_id: PollOptionId,
+  pub person_id: PersonId,
+  pub post_id: PostId,
+}
+
+#[derive(derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = post_actions))]
+pub struct PostPollVotedForm {
+  pub post_id: PostId,
+  pub person_id: PersonId,
+  #[new(value = "Utc::now()")]
+  pub poll_voted_at: DateTime<Utc>,
+}
+
+/// A poll with its options, and the options which the current user voted for.
+///
+/// This is read as a single json value, so that it can be included in `PostView`.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(FromSqlRow))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct PollView {
+  pub poll: Poll,
+  pub options: Vec<PollOption>,
+  #[serde(default)]
+  pub my_votes: Vec<PollOptionId>,
+}
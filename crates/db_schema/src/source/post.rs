@@ -1,6 +1,9 @@
 use crate::newtypes::{CommunityId, LanguageId, PostId};
 use chrono::{DateTime, Utc};
-use lemmy_db_schema_file::{PersonId, enums::PostNotificationsMode};
+use lemmy_db_schema_file::{
+  PersonId,
+  enums::{PostNotificationsMode, ScheduleRecurrence},
+};
 use lemmy_diesel_utils::dburl::DbUrl;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -88,6 +91,12 @@ pub struct Post {
   pub federation_pending: bool,
   pub embed_video_width: Option<i32>,
   pub embed_video_height: Option<i32>,
+  /// The post is held in the mod queue, and only visible to its creator and the mods until it is
+  /// approved.
+  pub approval_pending: bool,
+  /// For scheduled posts, a copy of the post is scheduled again with this interval once it is
+  /// published.
+  pub scheduled_recurrence: Option<ScheduleRecurrence>,
 }
 
 // TODO: FromBytes, ToBytes are only needed to develop wasm plugin, could be behind feature flag
@@ -144,6 +153,10 @@ pub struct PostInsertForm {
   pub scheduled_publish_time_at: Option<DateTime<Utc>>,
   #[new(default)]
   pub federation_pending: Option<bool>,
+  #[new(default)]
+  pub approval_pending: Option<bool>,
+  #[new(default)]
+  pub scheduled_recurrence: Option<ScheduleRecurrence>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -174,6 +187,8 @@ pub struct PostUpdateForm {
   pub alt_text: Option<Option<String>>,
   pub scheduled_publish_time_at: Option<Option<DateTime<Utc>>>,
   pub federation_pending: Option<bool>,
+  pub approval_pending: Option<bool>,
+  pub scheduled_recurrence: Option<Option<ScheduleRecurrence>>,
 }
 
 #[skip_serializing_none]
@@ -210,6 +225,10 @@ pub struct PostActions {
   /// True if upvoted, false if downvoted. Upvote is greater than downvote.
   pub vote_is_upvote: Option<bool>,
   pub notifications: Option<PostNotificationsMode>,
+  /// When you last voted in the poll of this post.
+  pub poll_voted_at: Option<DateTime<Utc>>,
+  /// When you last reacted with an emoji, only set while you have any reactions.
+  pub reacted_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -1,4 +1,4 @@
-use crate::newtypes::{PostId, PostReportId};
+use crate::newtypes::{PostId, PostReportId, RuleId};
 use chrono::{DateTime, Utc};
 use lemmy_db_schema_file::PersonId;
 #[cfg(feature = "full")]
@@ -36,6 +36,8 @@ pub struct PostReport {
   pub updated_at: Option<DateTime<Utc>>,
   pub violates_instance_rules: bool,
   pub conclusion: Option<String>,
+  /// The community or site rule which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Clone, Default)]
@@ -49,6 +51,7 @@ pub struct PostReportForm {
   pub original_post_body: Option<String>,
   pub reason: String,
   pub violates_instance_rules: bool,
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Clone, Default)]
@@ -0,0 +1,35 @@
+use crate::newtypes::{LocalUserId, PushSubscriptionId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::push_subscription;
+
+/// A Web Push subscription of a browser or app. Not exposed in the API, because the keys allow
+/// sending push messages to the user.
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = push_subscription))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PushSubscription {
+  pub id: PushSubscriptionId,
+  pub local_user_id: LocalUserId,
+  /// The push service url where messages are sent.
+  pub endpoint: String,
+  /// Public key of the browser, for encrypting messages.
+  pub p256dh: String,
+  /// Authentication secret of the browser, for encrypting messages.
+  pub auth: String,
+  pub expires_at: Option<DateTime<Utc>>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = push_subscription))]
+pub struct PushSubscriptionInsertForm {
+  pub local_user_id: LocalUserId,
+  pub endpoint: String,
+  pub p256dh: String,
+  pub auth: String,
+  #[new(default)]
+  pub expires_at: Option<DateTime<Utc>>,
+}
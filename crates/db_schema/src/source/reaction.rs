@@ -0,0 +1,92 @@
+use crate::newtypes::{CommentId, CustomEmojiId, PostId, ReactionId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use {
+  diesel::{AsExpression, FromSqlRow, sql_types::Nullable},
+  lemmy_db_schema_file::schema::{comment_actions, post_actions, reaction},
+};
+
+/// An emoji reaction on a post or comment.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = reaction))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct Reaction {
+  pub id: ReactionId,
+  pub person_id: PersonId,
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  /// A unicode emoji, or a custom emoji shortcode like `:lemmy:`.
+  pub emoji: String,
+  /// Set if the emoji is a local custom emoji.
+  pub custom_emoji_id: Option<CustomEmojiId>,
+  /// The image of a remote custom emoji.
+  pub emoji_url: Option<DbUrl>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = reaction))]
+pub struct ReactionInsertForm {
+  pub person_id: PersonId,
+  #[new(default)]
+  pub post_id: Option<PostId>,
+  #[new(default)]
+  pub comment_id: Option<CommentId>,
+  pub emoji: String,
+  #[new(default)]
+  pub custom_emoji_id: Option<CustomEmojiId>,
+  #[new(default)]
+  pub emoji_url: Option<DbUrl>,
+}
+
+#[derive(derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = post_actions))]
+pub struct PostReactedForm {
+  pub post_id: PostId,
+  pub person_id: PersonId,
+  #[new(value = "Utc::now()")]
+  pub reacted_at: DateTime<Utc>,
+}
+
+#[derive(derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = comment_actions))]
+pub struct CommentReactedForm {
+  pub comment_id: CommentId,
+  pub person_id: PersonId,
+  #[new(value = "Utc::now()")]
+  pub reacted_at: DateTime<Utc>,
+}
+
+/// The number of reactions with a given emoji on a post or comment.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ReactionCount {
+  pub emoji: String,
+  /// The image for custom emojis.
+  pub image_url: Option<DbUrl>,
+  pub count: i32,
+  /// Whether the current user reacted with this emoji.
+  pub reacted: bool,
+}
+
+/// We wrap this in a struct so we can implement FromSqlRow<Json> for it
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Default)]
+#[serde(transparent)]
+#[cfg_attr(feature = "full", derive(FromSqlRow, AsExpression))]
+#[cfg_attr(feature = "full", diesel(sql_type = Nullable<diesel::sql_types::Json>))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ReactionCountsView(pub Vec<ReactionCount>);
@@ -0,0 +1,70 @@
+use crate::newtypes::{CommunityId, RuleId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::rule;
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = rule))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A rule of a community or of the site, which can be cited by reports, removals and bans.
+pub struct Rule {
+  pub id: RuleId,
+  pub ap_id: DbUrl,
+  pub local: bool,
+  /// If set, the rule belongs to this community. Otherwise it is a site rule.
+  pub community_id: Option<CommunityId>,
+  /// Rules are numbered in ascending order of their position.
+  pub position: i32,
+  pub title: String,
+  pub description: Option<String>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+  pub deleted: bool,
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = rule))]
+pub struct RuleInsertForm {
+  pub community_id: Option<CommunityId>,
+  pub position: i32,
+  pub title: String,
+  #[new(default)]
+  pub description: Option<String>,
+  #[new(default)]
+  pub ap_id: Option<DbUrl>,
+  #[new(default)]
+  pub local: Option<bool>,
+  #[new(default)]
+  pub deleted: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = rule))]
+pub struct RuleUpdateForm {
+  pub position: Option<i32>,
+  pub title: Option<String>,
+  pub description: Option<Option<String>>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+  pub deleted: Option<bool>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// How often a rule was cited by reports and moderator actions.
+pub struct RuleStats {
+  pub rule_id: RuleId,
+  pub report_count: i64,
+  pub unresolved_report_count: i64,
+  pub removal_count: i64,
+  pub ban_count: i64,
+}
@@ -0,0 +1,99 @@
+use crate::newtypes::{
+  CommentId,
+  LanguageId,
+  PostId,
+  ScheduledCommentId,
+  ScheduledPrivateMessageId,
+};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::{scheduled_comment, scheduled_private_message};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = scheduled_comment))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A comment which gets created once its scheduled time is reached.
+pub struct ScheduledComment {
+  pub id: ScheduledCommentId,
+  pub creator_id: PersonId,
+  pub post_id: PostId,
+  pub parent_id: Option<CommentId>,
+  pub content: String,
+  pub language_id: Option<LanguageId>,
+  /// Distinguish the comment as moderator after creating it.
+  pub distinguished: bool,
+  pub scheduled_publish_time_at: DateTime<Utc>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = scheduled_comment))]
+pub struct ScheduledCommentInsertForm {
+  pub creator_id: PersonId,
+  pub post_id: PostId,
+  pub content: String,
+  pub scheduled_publish_time_at: DateTime<Utc>,
+  #[new(default)]
+  pub parent_id: Option<CommentId>,
+  #[new(default)]
+  pub language_id: Option<LanguageId>,
+  #[new(default)]
+  pub distinguished: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = scheduled_comment))]
+pub struct ScheduledCommentUpdateForm {
+  pub content: Option<String>,
+  pub language_id: Option<Option<LanguageId>>,
+  pub distinguished: Option<bool>,
+  pub scheduled_publish_time_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = scheduled_private_message))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A private message which gets sent once its scheduled time is reached.
+pub struct ScheduledPrivateMessage {
+  pub id: ScheduledPrivateMessageId,
+  pub creator_id: PersonId,
+  pub recipient_id: PersonId,
+  pub content: String,
+  pub scheduled_publish_time_at: DateTime<Utc>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = scheduled_private_message))]
+pub struct ScheduledPrivateMessageInsertForm {
+  pub creator_id: PersonId,
+  pub recipient_id: PersonId,
+  pub content: String,
+  pub scheduled_publish_time_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = scheduled_private_message))]
+pub struct ScheduledPrivateMessageUpdateForm {
+  pub content: Option<String>,
+  pub scheduled_publish_time_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
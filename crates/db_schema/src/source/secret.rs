@@ -9,4 +9,6 @@ use lemmy_diesel_utils::sensitive::SensitiveString;
 pub struct Secret {
   pub id: i32,
   pub jwt_secret: SensitiveString,
+  /// Base64url encoded PKCS#8 document of the VAPID keypair, used to sign Web Push requests.
+  pub vapid_private_key: Option<SensitiveString>,
 }
@@ -0,0 +1,69 @@
+use crate::newtypes::{CommunityId, WebhookDeliveryId, WebhookId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::{webhook, webhook_delivery};
+use lemmy_db_schema_file::{PersonId, enums::WebhookEventType};
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An outgoing webhook, which is called with a signed POST request for each of its events.
+pub struct Webhook {
+  pub id: WebhookId,
+  pub creator_id: PersonId,
+  /// If set, only events from this community are sent. Otherwise events from the whole site.
+  pub community_id: Option<CommunityId>,
+  pub url: DbUrl,
+  /// Used to sign the request body with HMAC-SHA256.
+  pub secret: String,
+  pub events: Vec<WebhookEventType>,
+  pub enabled: bool,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook))]
+pub struct WebhookInsertForm {
+  pub creator_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub url: DbUrl,
+  pub secret: String,
+  pub events: Vec<WebhookEventType>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook))]
+pub struct WebhookUpdateForm {
+  pub url: Option<DbUrl>,
+  pub events: Option<Vec<WebhookEventType>>,
+  pub enabled: Option<bool>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
+
+#[cfg(feature = "full")]
+#[derive(PartialEq, Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = webhook_delivery)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+/// A single call of a webhook, which is retried until it succeeds.
+pub struct WebhookDelivery {
+  pub id: WebhookDeliveryId,
+  pub webhook_id: WebhookId,
+  pub event: WebhookEventType,
+  /// The full request body
+  pub payload: serde_json::Value,
+  pub fail_count: i32,
+  pub last_error: Option<String>,
+  pub next_attempt_at: DateTime<Utc>,
+  pub delivered_at: Option<DateTime<Utc>>,
+  pub published_at: DateTime<Utc>,
+}
@@ -32,6 +32,13 @@ pub fn filter_is_subscribed() -> IsSubscribedType {
   community_actions::follow_state.eq(Some(CommunityFollowerState::Accepted))
 }
 
+type IsFollowedPersonType = Eq<person_actions::follow_pending, Option<bool>>;
+
+/// Only content whose creator is followed by the user, with the follow already accepted.
+pub fn filter_is_followed_person() -> IsFollowedPersonType {
+  person_actions::follow_pending.eq(Some(false))
+}
+
 type CommunityVisibilityType = NotEq<community::visibility, CommunityVisibility>;
 
 type CommunityVisibilityNotUnlistedOrSubscribedType = Or<CommunityVisibilityType, IsSubscribedType>;
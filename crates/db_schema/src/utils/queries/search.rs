@@ -0,0 +1,37 @@
+use diesel::{
+  Expression,
+  define_sql_function,
+  expression::AsExpression,
+  pg::Pg,
+  sql_types::{Nullable, Text},
+};
+use lemmy_db_schema_file::schema::sql_types::Tsvector;
+
+#[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+#[diesel(postgres_type(name = "tsquery", schema = "pg_catalog"))]
+pub struct Tsquery;
+
+diesel::infix_operator!(Matches, " @@ ", backend: Pg);
+
+define_sql_function! {
+  /// Converts a search term in websearch syntax to a tsquery, stemmed for the given language code.
+  #[sql_name = "r.search_query"]
+  fn search_query(search_term: Text, language_code: Nullable<Text>) -> Tsquery;
+}
+
+define_sql_function! {
+  /// Only keeps the lexemes of the title (or name) from a search vector.
+  #[sql_name = "r.search_vector_title"]
+  fn search_vector_title(search_vector: Tsvector) -> Tsvector;
+}
+
+define_sql_function!(fn ts_rank(search_vector: Tsvector, query: Tsquery) -> Float);
+
+pub trait TsvectorMatches: Expression<SqlType = Tsvector> + Sized {
+  /// The `@@` operator, true if the search vector matches the query.
+  fn matches<T: AsExpression<Tsquery>>(self, query: T) -> Matches<Self, T::Expression> {
+    Matches::new(self, query.as_expression())
+  }
+}
+
+impl<T: Expression<SqlType = Tsvector>> TsvectorMatches for T {}
@@ -28,10 +28,12 @@ use lemmy_db_schema_file::{
     comment,
     community,
     community_actions,
+    community_flair,
     community_tag,
     instance_actions,
     local_user,
     person,
+    poll,
     post,
     post_community_tag,
   },
@@ -245,6 +247,7 @@ pub fn comment_select_remove_deletes() -> _ {
     comment::federation_pending,
     comment::locked,
     comment::community_id,
+    comment::approval_pending,
   )
 }
 
@@ -303,6 +306,8 @@ pub fn post_select_remove_deletes() -> _ {
     post::federation_pending,
     post::embed_video_width,
     post::embed_video_height,
+    post::approval_pending,
+    post::scheduled_recurrence,
   )
 }
 
@@ -319,6 +324,61 @@ pub fn post_community_tags_fragment() -> _ {
     .single_value()
 }
 
+#[diesel::dsl::auto_type]
+/// Gets the poll of a post with its options, and the options which the user voted for. Requires
+/// that post_actions is joined for the current user.
+pub fn post_poll_fragment() -> _ {
+  let sel: SqlLiteral<Json> = diesel::dsl::sql::<diesel::sql_types::Json>(
+    "json_build_object(
+      'poll', to_json(poll.*),
+      'options', (SELECT coalesce(json_agg(poll_option.* ORDER BY poll_option.display_order), '[]')
+        FROM poll_option WHERE poll_option.post_id = poll.post_id),
+      'my_votes', (SELECT coalesce(json_agg(poll_vote.poll_option_id), '[]')
+        FROM poll_vote WHERE poll_vote.post_id = poll.post_id
+        AND poll_vote.person_id = post_actions.person_id))",
+  );
+  poll::table
+    .select(sel)
+    .filter(poll::post_id.eq(post::id))
+    .single_value()
+}
+
+#[diesel::dsl::auto_type]
+/// Gets the emoji reaction counts of a post. The own reactions are found with the person_id of
+/// post_actions, so this requires that post_actions is joined for the current user.
+pub fn post_reactions_fragment() -> _ {
+  let sel: SqlLiteral<diesel::sql_types::Nullable<Json>> =
+    diesel::dsl::sql::<diesel::sql_types::Nullable<Json>>(
+      "(SELECT json_agg(r ORDER BY r.count DESC, r.emoji) FROM (
+      SELECT reaction.emoji,
+        min(coalesce(custom_emoji.image_url, reaction.emoji_url)) AS image_url,
+        count(*)::int AS count,
+        coalesce(bool_or(reaction.person_id = post_actions.person_id), false) AS reacted
+      FROM reaction LEFT JOIN custom_emoji ON custom_emoji.id = reaction.custom_emoji_id
+      WHERE reaction.post_id = post.id
+      GROUP BY reaction.emoji) AS r)",
+    );
+  sel
+}
+
+#[diesel::dsl::auto_type]
+/// Gets the emoji reaction counts of a comment. Requires that comment_actions is joined for the
+/// current user.
+pub fn comment_reactions_fragment() -> _ {
+  let sel: SqlLiteral<diesel::sql_types::Nullable<Json>> =
+    diesel::dsl::sql::<diesel::sql_types::Nullable<Json>>(
+      "(SELECT json_agg(r ORDER BY r.count DESC, r.emoji) FROM (
+      SELECT reaction.emoji,
+        min(coalesce(custom_emoji.image_url, reaction.emoji_url)) AS image_url,
+        count(*)::int AS count,
+        coalesce(bool_or(reaction.person_id = comment_actions.person_id), false) AS reacted
+      FROM reaction LEFT JOIN custom_emoji ON custom_emoji.id = reaction.custom_emoji_id
+      WHERE reaction.comment_id = comment.id
+      GROUP BY reaction.emoji) AS r)",
+    );
+  sel
+}
+
 #[diesel::dsl::auto_type]
 /// Gets the tags available within a specific community
 pub fn community_tags_fragment() -> _ {
@@ -336,6 +396,40 @@ pub fn community_tags_fragment() -> _ {
     .single_value()
 }
 
+#[diesel::dsl::auto_type]
+/// Gets the person flairs available within a specific community
+pub fn community_flairs_fragment() -> _ {
+  let sel: SqlLiteral<Json> =
+    diesel::dsl::sql::<diesel::sql_types::Json>("json_agg(community_flair.*)");
+  community_flair::table
+    .select(sel)
+    .filter(community_flair::community_id.eq(community::id))
+    .filter(
+      community_flair::deleted
+        .eq(false)
+        // Show deleted flairs for admins and mods
+        .or(local_user_community_can_mod()),
+    )
+    .single_value()
+}
+
+#[diesel::dsl::auto_type]
+/// Gets the flair which the creator has in the community. Requires that creator_community_actions
+/// is joined.
+pub fn creator_community_flair_fragment() -> _ {
+  let sel: SqlLiteral<Json> =
+    diesel::dsl::sql::<diesel::sql_types::Json>("to_json(community_flair.*)");
+  community_flair::table
+    .select(sel)
+    .filter(
+      community_flair::id
+        .nullable()
+        .eq(creator_community_actions.field(community_actions::flair_id)),
+    )
+    .filter(community_flair::deleted.eq(false))
+    .single_value()
+}
+
 /// The select for the person1 alias.
 pub fn person1_select() -> Person1AliasAllColumnsTuple {
   person1.fields(person::all_columns)
@@ -345,3 +439,17 @@ pub fn person1_select() -> Person1AliasAllColumnsTuple {
 pub fn person2_select() -> Person2AliasAllColumnsTuple {
   person2.fields(person::all_columns)
 }
+
+#[diesel::dsl::auto_type]
+/// Counts the messages in a conversation which the participant hasn't read yet. Own messages
+/// are never unread. Requires that conversation_participant is joined for the current user.
+pub fn conversation_unread_count() -> _ {
+  let sel: SqlLiteral<diesel::sql_types::BigInt> = diesel::dsl::sql::<diesel::sql_types::BigInt>(
+    "(SELECT count(*) FROM conversation_message
+      WHERE conversation_message.conversation_id = conversation.id
+        AND conversation_message.creator_id != conversation_participant.person_id
+        AND conversation_message.published_at
+          > coalesce(conversation_participant.last_read_at, '-infinity'))",
+  );
+  sel
+}
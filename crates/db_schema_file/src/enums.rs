@@ -1,7 +1,7 @@
 #[cfg(feature = "full")]
 use diesel_derive_enum::DbEnum;
 use serde::{Deserialize, Serialize};
-use strum::Display;
+use strum::{Display, EnumIter};
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
 #[serde(rename_all = "snake_case")]
@@ -70,6 +70,8 @@ pub enum ListingType {
   ModeratorView,
   /// Communities which are recommended by local instance admins
   Suggested,
+  /// Content by persons you follow, from all communities.
+  FollowedPersons,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
@@ -206,6 +208,25 @@ pub enum ImageMode {
   ProxyAllImages,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::EditHistoryVisibilityEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// Who can see previous versions of edited posts and comments.
+pub enum EditHistoryVisibility {
+  /// Only community moderators and admins
+  #[default]
+  ModsOnly,
+  /// Everyone who can see the post or comment
+  Everyone,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "full", derive(DbEnum))]
 #[cfg_attr(
@@ -315,7 +336,7 @@ pub enum CommunityNotificationsMode {
   Mute,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash, EnumIter)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "full", derive(DbEnum))]
 #[cfg_attr(
@@ -334,6 +355,12 @@ pub enum NotificationType {
   Subscribed,
   PrivateMessage,
   ModAction,
+  /// Your post reached a score of 10, 100 or 1000.
+  ScoreMilestone,
+  /// Your post was featured by a moderator or admin.
+  PostFeatured,
+  /// A moderator distinguished their reply to you.
+  CommentDistinguished,
 }
 
 #[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
@@ -371,4 +398,92 @@ pub enum ModlogKind {
   ModLockComment,
   ModWarnComment,
   ModWarnPost,
+  AutomodReport,
+  AutomodReply,
+  AutomodHold,
+  ModBulkAction,
+  ModResolveReport,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::WebhookEventEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// The events which a webhook can be subscribed to.
+pub enum WebhookEventType {
+  /// A post, comment, community or private message was reported
+  #[default]
+  NewReport,
+  /// A new user applied for registration. Only sent to site webhooks.
+  NewRegistrationApplication,
+  NewPost,
+  ModlogEntry,
+  /// A user was banned from the site or from a community
+  Ban,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::AutomodActionEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// What an automod rule does with a matching post or comment.
+pub enum AutomodAction {
+  #[default]
+  Remove,
+  Lock,
+  /// Hold new content in the mod queue, so that it is only published once a mod approves it.
+  HoldForApproval,
+  /// Report the content to the mods.
+  Report,
+  /// Reply to the content with the canned text of the rule.
+  Reply,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::EmailDigestCadenceEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// How often notification emails are sent.
+pub enum EmailDigestCadence {
+  /// Send one email for each notification.
+  #[default]
+  Immediate,
+  /// Send one email with all new notifications every hour.
+  Hourly,
+  Daily,
+  Weekly,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::ScheduleRecurrenceEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// How often a scheduled post is repeated.
+pub enum ScheduleRecurrence {
+  Daily,
+  Weekly,
+  Monthly,
 }
@@ -1,6 +1,10 @@
 // @generated automatically by Diesel CLI.
 
 pub mod sql_types {
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "automod_action_enum"))]
+  pub struct AutomodActionEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "actor_type_enum"))]
   pub struct ActorTypeEnum;
@@ -21,6 +25,14 @@ pub mod sql_types {
   #[diesel(postgres_type(name = "community_visibility"))]
   pub struct CommunityVisibility;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "edit_history_visibility_enum"))]
+  pub struct EditHistoryVisibilityEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "email_digest_cadence_enum"))]
+  pub struct EmailDigestCadenceEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "federation_mode_enum"))]
   pub struct FederationModeEnum;
@@ -61,13 +73,60 @@ pub mod sql_types {
   #[diesel(postgres_type(name = "registration_mode_enum"))]
   pub struct RegistrationModeEnum;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "schedule_recurrence_enum"))]
+  pub struct ScheduleRecurrenceEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "tag_color_enum"))]
   pub struct TagColorEnum;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+  pub struct Tsvector;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "vote_show_enum"))]
   pub struct VoteShowEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "webhook_event_enum"))]
+  pub struct WebhookEventEnum;
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AutomodActionEnum;
+
+    automod_rule (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        community_id -> Nullable<Int4>,
+        reason -> Text,
+        action -> AutomodActionEnum,
+        reply_text -> Nullable<Text>,
+        check_posts -> Bool,
+        check_comments -> Bool,
+        content_regex -> Nullable<Text>,
+        link_domain -> Nullable<Text>,
+        max_account_age_days -> Nullable<Int4>,
+        max_creator_score -> Nullable<Int4>,
+        report_count -> Nullable<Int4>,
+        language_id -> Nullable<Int4>,
+        enabled -> Bool,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    automod_rule_trigger (id) {
+        id -> Int4,
+        automod_rule_id -> Int4,
+        post_id -> Int4,
+        comment_id -> Nullable<Int4>,
+        published_at -> Timestamptz,
+    }
 }
 
 diesel::table! {
@@ -100,6 +159,7 @@ diesel::table! {
         federation_pending -> Bool,
         locked -> Bool,
         community_id -> Int4,
+        approval_pending -> Bool,
     }
 }
 
@@ -110,6 +170,7 @@ diesel::table! {
         person_id -> Int4,
         comment_id -> Int4,
         vote_is_upvote -> Nullable<Bool>,
+        reacted_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -126,6 +187,26 @@ diesel::table! {
         updated_at -> Nullable<Timestamptz>,
         violates_instance_rules -> Bool,
         conclusion -> Nullable<Text>,
+        rule_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    comment_revision (id) {
+        id -> Int4,
+        comment_id -> Int4,
+        content -> Text,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
+    comment_search (comment_id) {
+        comment_id -> Int4,
+        search_vector -> Tsvector,
     }
 }
 
@@ -180,6 +261,13 @@ diesel::table! {
         report_count -> Int2,
         unresolved_report_count -> Int2,
         local_removed -> Bool,
+        posts_require_approval -> Bool,
+        comments_require_approval -> Bool,
+        approval_max_account_age_days -> Nullable<Int4>,
+        approval_remote_only -> Bool,
+        warning_ban_threshold -> Nullable<Int4>,
+        warning_ban_window_days -> Nullable<Int4>,
+        warning_ban_days -> Nullable<Int4>,
     }
 }
 
@@ -200,6 +288,7 @@ diesel::table! {
         follow_approver_id -> Nullable<Int4>,
         notifications -> Nullable<CommunityNotificationsModeEnum>,
         follow_activity_id -> Nullable<Text>,
+        flair_id -> Nullable<Int4>,
     }
 }
 
@@ -211,6 +300,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::TagColorEnum;
+
+    community_flair (id) {
+        id -> Int4,
+        ap_id -> Text,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 255]
+        display_name -> Nullable<Varchar>,
+        community_id -> Int4,
+        color -> TagColorEnum,
+        self_assignable -> Bool,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+        deleted -> Bool,
+    }
+}
+
 diesel::table! {
     community_language (community_id, language_id) {
         community_id -> Int4,
@@ -238,6 +347,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
+    community_search (community_id) {
+        community_id -> Int4,
+        search_vector -> Tsvector,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::TagColorEnum;
@@ -259,6 +378,42 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    conversation (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        local -> Bool,
+        published_at -> Timestamptz,
+        last_message_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    conversation_message (id) {
+        id -> Int4,
+        conversation_id -> Int4,
+        creator_id -> Int4,
+        content -> Text,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        local -> Bool,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+        deleted -> Bool,
+    }
+}
+
+diesel::table! {
+    conversation_participant (conversation_id, person_id) {
+        conversation_id -> Int4,
+        person_id -> Int4,
+        last_read_at -> Nullable<Timestamptz>,
+        published_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     custom_emoji (id) {
         id -> Int4,
@@ -280,6 +435,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    email_outbox (id) {
+        id -> Int4,
+        recipient_email -> Text,
+        recipient_name -> Text,
+        subject -> Text,
+        html -> Text,
+        fail_count -> Int4,
+        last_error -> Nullable<Text>,
+        next_attempt_at -> Timestamptz,
+        sent_at -> Nullable<Timestamptz>,
+        failed_at -> Nullable<Timestamptz>,
+        published_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     email_verification (id) {
         id -> Int4,
@@ -380,6 +551,7 @@ diesel::table! {
     use super::sql_types::CommentSortTypeEnum;
     use super::sql_types::FederationModeEnum;
     use super::sql_types::ImageModeEnum;
+    use super::sql_types::EditHistoryVisibilityEnum;
 
     local_site (id) {
         id -> Int4,
@@ -432,6 +604,10 @@ diesel::table! {
         image_allow_video_uploads -> Bool,
         image_upload_disabled -> Bool,
         max_invites_per_user_allowed -> Int4,
+        edit_history_visibility -> EditHistoryVisibilityEnum,
+        warning_ban_threshold -> Nullable<Int4>,
+        warning_ban_window_days -> Nullable<Int4>,
+        warning_ban_days -> Nullable<Int4>,
     }
 }
 
@@ -473,6 +649,7 @@ diesel::table! {
     use super::sql_types::PostListingModeEnum;
     use super::sql_types::CommentSortTypeEnum;
     use super::sql_types::VoteShowEnum;
+    use super::sql_types::EmailDigestCadenceEnum;
 
     local_user (id) {
         id -> Int4,
@@ -514,6 +691,9 @@ diesel::table! {
         default_items_per_page -> Int4,
         invited_by_local_user_id -> Nullable<Int4>,
         show_media -> Bool,
+        email_digest_cadence -> EmailDigestCadenceEnum,
+        email_digest_top_posts -> Bool,
+        last_email_digest_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -544,6 +724,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::NotificationTypeEnum;
+
+    local_user_notification_preference (local_user_id, kind) {
+        local_user_id -> Int4,
+        kind -> NotificationTypeEnum,
+        in_app -> Bool,
+        email -> Bool,
+        push -> Bool,
+    }
+}
+
 diesel::table! {
     login_token (token) {
         token -> Text,
@@ -554,6 +747,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mod_note (id) {
+        id -> Int4,
+        community_id -> Int4,
+        target_person_id -> Int4,
+        creator_id -> Int4,
+        content -> Text,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::ModlogKind;
@@ -573,6 +778,37 @@ diesel::table! {
         published_at -> Timestamptz,
         bulk_action_parent_id -> Nullable<Int4>,
         child_count -> Int4,
+        rule_id -> Nullable<Int4>,
+        warning_ban_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    modmail_message (id) {
+        id -> Int4,
+        modmail_thread_id -> Int4,
+        creator_id -> Nullable<Int4>,
+        from_moderator -> Bool,
+        internal -> Bool,
+        content -> Text,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        local -> Bool,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    modmail_thread (id) {
+        id -> Int4,
+        community_id -> Int4,
+        creator_id -> Int4,
+        assignee_id -> Nullable<Int4>,
+        resolved -> Bool,
+        resolver_id -> Nullable<Int4>,
+        published_at -> Timestamptz,
+        last_message_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -639,6 +875,7 @@ diesel::table! {
         creator_id -> Int4,
         instance_id -> Nullable<Int4>,
         community_id -> Nullable<Int4>,
+        score_milestone -> Nullable<Int4>,
     }
 }
 
@@ -710,6 +947,9 @@ diesel::table! {
         post_score -> Int4,
         comment_count -> Int4,
         comment_score -> Int4,
+        also_known_as -> Array<Text>,
+        moved_to_id -> Nullable<Int4>,
+        follower_count -> Int4,
     }
 }
 
@@ -752,6 +992,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    person_report (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        person_id -> Int4,
+        original_person_name -> Text,
+        original_person_display_name -> Nullable<Text>,
+        original_person_bio -> Nullable<Text>,
+        original_person_avatar -> Nullable<Text>,
+        original_person_banner -> Nullable<Text>,
+        reason -> Text,
+        resolved -> Bool,
+        resolver_id -> Nullable<Int4>,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+        conclusion -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     person_saved_combined (id) {
         saved_at -> Timestamptz,
@@ -765,6 +1024,49 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
+    person_search (person_id) {
+        person_id -> Int4,
+        search_vector -> Tsvector,
+    }
+}
+
+diesel::table! {
+    poll (post_id) {
+        post_id -> Int4,
+        multiple_choice -> Bool,
+        end_time_at -> Nullable<Timestamptz>,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+        votes_changed -> Bool,
+    }
+}
+
+diesel::table! {
+    poll_option (id) {
+        id -> Int4,
+        post_id -> Int4,
+        name -> Text,
+        display_order -> Int4,
+        vote_count -> Int4,
+    }
+}
+
+diesel::table! {
+    poll_vote (poll_option_id, person_id) {
+        poll_option_id -> Int4,
+        person_id -> Int4,
+        post_id -> Int4,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ScheduleRecurrenceEnum;
+
     post (id) {
         id -> Int4,
         #[max_length = 200]
@@ -808,6 +1110,8 @@ diesel::table! {
         federation_pending -> Bool,
         embed_video_width -> Nullable<Int4>,
         embed_video_height -> Nullable<Int4>,
+        approval_pending -> Bool,
+        scheduled_recurrence -> Nullable<ScheduleRecurrenceEnum>,
     }
 }
 
@@ -826,6 +1130,8 @@ diesel::table! {
         read_comments_amount -> Nullable<Int4>,
         vote_is_upvote -> Nullable<Bool>,
         notifications -> Nullable<PostNotificationsModeEnum>,
+        poll_voted_at -> Nullable<Timestamptz>,
+        reacted_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -853,6 +1159,31 @@ diesel::table! {
         updated_at -> Nullable<Timestamptz>,
         violates_instance_rules -> Bool,
         conclusion -> Nullable<Text>,
+        rule_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    post_revision (id) {
+        id -> Int4,
+        post_id -> Int4,
+        #[max_length = 200]
+        name -> Varchar,
+        #[max_length = 2000]
+        url -> Nullable<Varchar>,
+        body -> Nullable<Text>,
+        alt_text -> Nullable<Text>,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
+    post_search (post_id) {
+        post_id -> Int4,
+        search_vector -> Tsvector,
     }
 }
 
@@ -888,6 +1219,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    push_subscription (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        endpoint -> Text,
+        p256dh -> Text,
+        auth -> Text,
+        expires_at -> Nullable<Timestamptz>,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    reaction (id) {
+        id -> Int4,
+        person_id -> Int4,
+        post_id -> Nullable<Int4>,
+        comment_id -> Nullable<Int4>,
+        #[max_length = 128]
+        emoji -> Varchar,
+        custom_emoji_id -> Nullable<Int4>,
+        emoji_url -> Nullable<Text>,
+        published_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     received_activity (ap_id) {
         ap_id -> Text,
@@ -930,6 +1287,51 @@ diesel::table! {
         comment_id -> Nullable<Int4>,
         community_id -> Nullable<Int4>,
         private_message_id -> Nullable<Int4>,
+        person_report_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    rule (id) {
+        id -> Int4,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        local -> Bool,
+        community_id -> Nullable<Int4>,
+        position -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        description -> Nullable<Text>,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+        deleted -> Bool,
+    }
+}
+
+diesel::table! {
+    scheduled_comment (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        post_id -> Int4,
+        parent_id -> Nullable<Int4>,
+        content -> Text,
+        language_id -> Nullable<Int4>,
+        distinguished -> Bool,
+        scheduled_publish_time_at -> Timestamptz,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    scheduled_private_message (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        recipient_id -> Int4,
+        content -> Text,
+        scheduled_publish_time_at -> Timestamptz,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -937,6 +1339,7 @@ diesel::table! {
     secret (id) {
         id -> Int4,
         jwt_secret -> Varchar,
+        vapid_private_key -> Nullable<Text>,
     }
 }
 
@@ -998,6 +1401,47 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WebhookEventEnum;
+
+    webhook (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        community_id -> Nullable<Int4>,
+        #[max_length = 2000]
+        url -> Varchar,
+        secret -> Text,
+        events -> Array<WebhookEventEnum>,
+        enabled -> Bool,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WebhookEventEnum;
+
+    webhook_delivery (id) {
+        id -> Int4,
+        webhook_id -> Int4,
+        event -> WebhookEventEnum,
+        payload -> Jsonb,
+        fail_count -> Int4,
+        last_error -> Nullable<Text>,
+        next_attempt_at -> Timestamptz,
+        delivered_at -> Nullable<Timestamptz>,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::joinable!(automod_rule -> community (community_id));
+diesel::joinable!(automod_rule -> language (language_id));
+diesel::joinable!(automod_rule -> person (creator_id));
+diesel::joinable!(automod_rule_trigger -> automod_rule (automod_rule_id));
+diesel::joinable!(automod_rule_trigger -> comment (comment_id));
+diesel::joinable!(automod_rule_trigger -> post (post_id));
 diesel::joinable!(comment -> community (community_id));
 diesel::joinable!(comment -> language (language_id));
 diesel::joinable!(comment -> person (creator_id));
@@ -1005,12 +1449,22 @@ diesel::joinable!(comment -> post (post_id));
 diesel::joinable!(comment_actions -> comment (comment_id));
 diesel::joinable!(comment_actions -> person (person_id));
 diesel::joinable!(comment_report -> comment (comment_id));
+diesel::joinable!(comment_report -> rule (rule_id));
+diesel::joinable!(comment_revision -> comment (comment_id));
+diesel::joinable!(comment_search -> comment (comment_id));
 diesel::joinable!(community -> instance (instance_id));
 diesel::joinable!(community_actions -> community (community_id));
 diesel::joinable!(community_language -> community (community_id));
 diesel::joinable!(community_language -> language (language_id));
 diesel::joinable!(community_report -> community (community_id));
+diesel::joinable!(community_search -> community (community_id));
+diesel::joinable!(community_flair -> community (community_id));
 diesel::joinable!(community_tag -> community (community_id));
+diesel::joinable!(conversation -> person (creator_id));
+diesel::joinable!(conversation_message -> conversation (conversation_id));
+diesel::joinable!(conversation_message -> person (creator_id));
+diesel::joinable!(conversation_participant -> conversation (conversation_id));
+diesel::joinable!(conversation_participant -> person (person_id));
 diesel::joinable!(custom_emoji_keyword -> custom_emoji (custom_emoji_id));
 diesel::joinable!(email_verification -> local_user (local_user_id));
 diesel::joinable!(federation_allowlist -> instance (instance_id));
@@ -1029,11 +1483,16 @@ diesel::joinable!(local_user_invite -> local_user (local_user_id));
 diesel::joinable!(local_user_keyword_block -> local_user (local_user_id));
 diesel::joinable!(local_user_language -> language (language_id));
 diesel::joinable!(local_user_language -> local_user (local_user_id));
+diesel::joinable!(local_user_notification_preference -> local_user (local_user_id));
 diesel::joinable!(login_token -> local_user (user_id));
+diesel::joinable!(mod_note -> community (community_id));
 diesel::joinable!(modlog -> comment (target_comment_id));
 diesel::joinable!(modlog -> community (target_community_id));
 diesel::joinable!(modlog -> instance (target_instance_id));
 diesel::joinable!(modlog -> post (target_post_id));
+diesel::joinable!(modlog -> rule (rule_id));
+diesel::joinable!(modmail_message -> modmail_thread (modmail_thread_id));
+diesel::joinable!(modmail_thread -> community (community_id));
 diesel::joinable!(multi_community -> instance (instance_id));
 diesel::joinable!(multi_community -> person (creator_id));
 diesel::joinable!(multi_community_entry -> community (community_id));
@@ -1060,6 +1519,12 @@ diesel::joinable!(person_liked_combined -> post (post_id));
 diesel::joinable!(person_saved_combined -> comment (comment_id));
 diesel::joinable!(person_saved_combined -> community (community_id));
 diesel::joinable!(person_saved_combined -> post (post_id));
+diesel::joinable!(person_search -> person (person_id));
+diesel::joinable!(poll -> post (post_id));
+diesel::joinable!(poll_option -> poll (post_id));
+diesel::joinable!(poll_vote -> person (person_id));
+diesel::joinable!(poll_vote -> poll (post_id));
+diesel::joinable!(poll_vote -> poll_option (poll_option_id));
 diesel::joinable!(post -> community (community_id));
 diesel::joinable!(post -> language (language_id));
 diesel::joinable!(post -> person (creator_id));
@@ -1068,30 +1533,57 @@ diesel::joinable!(post_actions -> post (post_id));
 diesel::joinable!(post_community_tag -> community_tag (community_tag_id));
 diesel::joinable!(post_community_tag -> post (post_id));
 diesel::joinable!(post_report -> post (post_id));
+diesel::joinable!(post_report -> rule (rule_id));
+diesel::joinable!(post_revision -> post (post_id));
+diesel::joinable!(post_search -> post (post_id));
 diesel::joinable!(private_message_report -> private_message (private_message_id));
+diesel::joinable!(push_subscription -> local_user (local_user_id));
+diesel::joinable!(reaction -> comment (comment_id));
+diesel::joinable!(reaction -> custom_emoji (custom_emoji_id));
+diesel::joinable!(reaction -> person (person_id));
+diesel::joinable!(reaction -> post (post_id));
 diesel::joinable!(registration_application -> local_user (local_user_id));
 diesel::joinable!(registration_application -> person (admin_id));
 diesel::joinable!(report_combined -> comment (comment_id));
 diesel::joinable!(report_combined -> comment_report (comment_report_id));
 diesel::joinable!(report_combined -> community (community_id));
 diesel::joinable!(report_combined -> community_report (community_report_id));
+diesel::joinable!(report_combined -> person_report (person_report_id));
 diesel::joinable!(report_combined -> post (post_id));
 diesel::joinable!(report_combined -> post_report (post_report_id));
 diesel::joinable!(report_combined -> private_message (private_message_id));
 diesel::joinable!(report_combined -> private_message_report (private_message_report_id));
+diesel::joinable!(rule -> community (community_id));
+diesel::joinable!(scheduled_comment -> comment (parent_id));
+diesel::joinable!(scheduled_comment -> language (language_id));
+diesel::joinable!(scheduled_comment -> person (creator_id));
+diesel::joinable!(scheduled_comment -> post (post_id));
 diesel::joinable!(site -> instance (instance_id));
 diesel::joinable!(site_language -> language (language_id));
 diesel::joinable!(site_language -> site (site_id));
+diesel::joinable!(webhook -> community (community_id));
+diesel::joinable!(webhook -> person (creator_id));
+diesel::joinable!(webhook_delivery -> webhook (webhook_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+  automod_rule,
+  automod_rule_trigger,
   comment,
   comment_actions,
   comment_report,
+  comment_revision,
+  comment_search,
   community,
   community_actions,
+  community_flair,
   community_language,
   community_report,
+  community_search,
   community_tag,
+  conversation,
+  conversation_message,
+  conversation_participant,
+  email_outbox,
   email_verification,
   federation_allowlist,
   federation_blocklist,
@@ -1106,8 +1598,12 @@ diesel::allow_tables_to_appear_in_same_query!(
   local_user_invite,
   local_user_keyword_block,
   local_user_language,
+  local_user_notification_preference,
   login_token,
+  mod_note,
   modlog,
+  modmail_message,
+  modmail_thread,
   multi_community,
   multi_community_entry,
   multi_community_follow,
@@ -1118,18 +1614,32 @@ diesel::allow_tables_to_appear_in_same_query!(
   person,
   person_content_combined,
   person_liked_combined,
+  person_report,
   person_saved_combined,
+  person_search,
+  poll,
+  poll_option,
+  poll_vote,
   post,
   post_actions,
   post_community_tag,
   post_report,
+  post_revision,
+  post_search,
   private_message,
   private_message_report,
+  push_subscription,
+  reaction,
   registration_application,
   report_combined,
+  rule,
+  scheduled_comment,
+  scheduled_private_message,
   site,
   site_language,
   person_actions,
   image_details,
+  webhook,
+  webhook_delivery,
 );
 diesel::allow_tables_to_appear_in_same_query!(custom_emoji, custom_emoji_keyword,);
@@ -1,5 +1,8 @@
 use crate::CommentView;
-use lemmy_db_schema::newtypes::{CommentId, CommunityId, LanguageId, PostId};
+use lemmy_db_schema::{
+  newtypes::{CommentId, CommunityId, LanguageId, PostId, RuleId, ScheduledCommentId},
+  source::{comment_revision::CommentRevision, scheduled::ScheduledComment},
+};
 use lemmy_db_schema_file::{
   PersonId,
   enums::{CommentSortType, ListingType},
@@ -39,6 +42,18 @@ pub struct CreateCommentLike {
   pub is_upvote: Option<bool>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Add or remove an emoji reaction on a comment.
+pub struct CreateCommentReaction {
+  pub comment_id: CommentId,
+  /// A unicode emoji, or the shortcode of a custom emoji like `:lemmy:`.
+  pub emoji: String,
+  /// False removes the reaction.
+  pub add: bool,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -68,6 +83,25 @@ pub struct GetComment {
   pub id: CommentId,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Get previous versions of an edited comment.
+pub struct GetCommentHistory {
+  pub comment_id: CommentId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Previous versions of a comment, newest first. The current version is not included.
+///
+/// No diffs are computed on the server. Each revision was replaced by the one before it in
+/// the list (the first one by the current comment), so clients can diff consecutive pairs.
+pub struct GetCommentHistoryResponse {
+  pub revisions: Vec<CommentRevision>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -135,6 +169,8 @@ pub struct RemoveComment {
   /// Setting this will override whatever `removed` was set to,
   /// leave as null or unset to act just on the comment itself.
   pub remove_children: Option<bool>,
+  /// The community or site rule which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -165,4 +201,51 @@ pub struct EditComment {
 pub struct CreateCommentWarning {
   pub comment_id: CommentId,
   pub reason: String,
+  /// A time that the warning will expire, in unix epoch seconds. Expired warnings don't count
+  /// toward the warning policy.
+  pub expires_at: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Schedule a comment, which is created once the time is reached.
+pub struct CreateScheduledComment {
+  pub content: String,
+  pub post_id: PostId,
+  pub parent_id: Option<CommentId>,
+  pub language_id: Option<LanguageId>,
+  /// Distinguish the comment as moderator. Only for mods and admins.
+  pub distinguished: Option<bool>,
+  /// Unix timestamp when the comment should be created.
+  pub scheduled_publish_time_at: i64,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edit a scheduled comment before it is created.
+pub struct EditScheduledComment {
+  pub scheduled_comment_id: ScheduledCommentId,
+  pub content: Option<String>,
+  pub language_id: Option<LanguageId>,
+  pub distinguished: Option<bool>,
+  pub scheduled_publish_time_at: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Cancel a scheduled comment.
+pub struct DeleteScheduledComment {
+  pub scheduled_comment_id: ScheduledCommentId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ScheduledCommentResponse {
+  pub scheduled_comment: ScheduledComment,
 }
@@ -3,14 +3,15 @@ use diesel::{
   BoolExpressionMethods,
   ExpressionMethods,
   NullableExpressionMethods,
-  PgTextExpressionMethods,
   QueryDsl,
   SelectableHelper,
+  dsl::not,
 };
 use diesel_async::RunQueryDsl;
 use diesel_ltree::{Ltree, LtreeExtensions, nlevel};
 use i_love_jesus::asc_if;
 use lemmy_db_schema::{
+  SearchSortType,
   impls::local_user::LocalUserOptionHelper,
   newtypes::{CommentId, CommunityId, PostId},
   source::{
@@ -24,11 +25,16 @@ use lemmy_db_schema::{
   },
   utils::{
     limit_fetch,
-    queries::filters::{
-      filter_blocked,
-      filter_is_subscribed,
-      filter_private_or_followed,
-      filter_unlisted_or_followed,
+    queries::{
+      filters::{
+        filter_blocked,
+        filter_is_followed_person,
+        filter_is_subscribed,
+        filter_private_or_followed,
+        filter_unlisted_or_followed,
+      },
+      search::{TsvectorMatches, search_query, ts_rank},
+      selects::local_user_community_can_mod,
     },
   },
 };
@@ -53,7 +59,7 @@ use lemmy_db_schema_file::{
     my_local_user_admin_join,
     my_person_actions_join,
   },
-  schema::{comment, community, person, post},
+  schema::{comment, comment_search, community, community_actions, person, post},
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -62,10 +68,11 @@ use lemmy_diesel_utils::{
     PagedResponse,
     PaginationCursor,
     PaginationCursorConversion,
+    paginate_offset_response,
     paginate_response,
   },
   traits::Crud,
-  utils::{Subpath, fuzzy_search, now, seconds_to_pg_interval},
+  utils::{Subpath, now, seconds_to_pg_interval},
 };
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
@@ -139,12 +146,54 @@ impl CommentView {
       query = query.filter(community::visibility.ne(CommunityVisibility::LocalOnlyPrivate));
     }
 
+    // Comments in the mod queue are only visible to their creator and the mods
+    query = query.filter(
+      comment::approval_pending
+        .eq(false)
+        .or(comment::creator_id.nullable().eq(my_local_user.person_id()))
+        .or(local_user_community_can_mod()),
+    );
+
     query
       .first::<Self>(conn)
       .await
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
 
+  /// List the comments which are held in the mod queue, oldest first. Without a community, this
+  /// returns comments from all communities which the user moderates, or from all communities for
+  /// admins who set `all_communities`.
+  pub async fn list_approval_pending(
+    pool: &mut DbPool<'_>,
+    my_local_user: &LocalUser,
+    local_instance_id: InstanceId,
+    community_id: Option<CommunityId>,
+    all_communities: bool,
+    limit: Option<i64>,
+  ) -> LemmyResult<Vec<Self>> {
+    let limit = limit_fetch(limit, None)?;
+    let mut query = Self::joins(Some(my_local_user.person_id), local_instance_id)
+      .filter(comment::approval_pending)
+      .filter(not(comment::deleted.or(comment::removed)))
+      .select(Self::as_select())
+      .order_by(comment::published_at.asc())
+      .limit(limit)
+      .into_boxed();
+
+    if let Some(community_id) = community_id {
+      query = query.filter(comment::community_id.eq(community_id));
+    }
+    if !all_communities {
+      query = query.filter(community_actions::became_moderator_at.is_not_null());
+    }
+
+    let conn = &mut get_conn(pool).await?;
+    query
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   pub fn map_to_slim(self) -> CommentSlimView {
     CommentSlimView {
       comment: self.comment,
@@ -156,6 +205,8 @@ impl CommentView {
       creator_banned: self.creator_banned,
       creator_banned_from_community: self.creator_banned_from_community,
       creator_is_moderator: self.creator_is_moderator,
+      creator_flair: self.creator_flair,
+      reactions: self.reactions,
     }
   }
 }
@@ -172,6 +223,9 @@ pub struct CommentQuery<'a> {
   pub local_user: Option<&'a LocalUser>,
   pub max_depth: Option<i32>,
   pub search_term: Option<String>,
+  pub search_sort: Option<SearchSortType>,
+  /// Only show comments in communities from this instance.
+  pub instance_id: Option<InstanceId>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
 }
@@ -211,6 +265,10 @@ impl CommentQuery<'_> {
       query = query.filter(comment::creator_id.eq(creator_id));
     }
 
+    if let Some(instance_id) = self.instance_id {
+      query = query.filter(community::instance_id.eq(instance_id));
+    }
+
     // For posts, we only show hidden if its subscribed, but for comments,
     // we ignore hidden.
     let listing_type = self.listing_type.unwrap_or_default();
@@ -228,6 +286,9 @@ impl CommentQuery<'_> {
         }
       }
       ListingType::All => query.filter(filter_unlisted_or_followed()),
+      ListingType::FollowedPersons => query
+        .filter(filter_is_followed_person())
+        .filter(filter_unlisted_or_followed()),
       ListingType::ModeratorView => {
         // Pre-fetch the moderator view community ids, since the join is too costly
         let community_ids = if let Some(my_person_id) = my_person_id {
@@ -277,9 +338,14 @@ impl CommentQuery<'_> {
     }
 
     // The search term
-    if let Some(search_term) = self.search_term {
-      let searcher = fuzzy_search(&search_term);
-      query = query.filter(comment::content.ilike(searcher));
+    if let Some(search_term) = &self.search_term {
+      let matches = comment_search::table
+        .filter(comment_search::search_vector.matches(search_query(
+          search_term.clone(),
+          self.local_user.interface_language(),
+        )))
+        .select(comment_search::comment_id);
+      query = query.filter(comment::id.eq_any(matches));
     }
 
     if !self.local_user.show_nsfw(site) {
@@ -294,6 +360,13 @@ impl CommentQuery<'_> {
         .or(comment::creator_id.nullable().eq(my_person_id)),
     );
 
+    // Comments held in the mod queue are only shown to their creator. Mods see them in the queue.
+    query = query.filter(
+      comment::approval_pending
+        .eq(false)
+        .or(comment::creator_id.nullable().eq(my_person_id)),
+    );
+
     // Filter by the time range
     if let Some(time_range_seconds) = self.time_range_seconds {
       query =
@@ -328,6 +401,33 @@ impl CommentQuery<'_> {
     };
     query = query.limit(limit);
 
+    // Best search matches first. There is no unique key for the rank, so this uses offset
+    // pagination.
+    if let (Some(SearchSortType::Relevance), Some(search_term)) =
+      (self.search_sort, &self.search_term)
+    {
+      let offset = self
+        .page_cursor
+        .map(PaginationCursor::offset)
+        .transpose()?
+        .unwrap_or_default();
+      let rank = comment_search::table
+        .filter(comment_search::comment_id.eq(comment::id))
+        .select(ts_rank(
+          comment_search::search_vector,
+          search_query(search_term.clone(), self.local_user.interface_language()),
+        ))
+        .single_value();
+      let query = query
+        .order_by(rank.desc())
+        .then_order_by(comment::id.desc())
+        .offset(offset);
+
+      let conn = &mut get_conn(pool).await?;
+      let res = query.load::<CommentView>(conn).await?;
+      return paginate_offset_response(res, limit, offset);
+    }
+
     // Only sort by ascending for Old
     let sort = self.sort.unwrap_or(Hot);
     let sort_direction = asc_if(sort == Old);
@@ -1143,6 +1243,35 @@ mod tests {
     assert_length!(1, comment_search_by_name);
     assert_eq!(data.comment_2.id, comment_search_by_name[0].comment.id);
 
+    // Order by relevance, with offset based pagination
+    let comment_search_relevance = CommentQuery {
+      search_term: Some("comment -2".into()),
+      search_sort: Some(SearchSortType::Relevance),
+      limit: Some(2),
+      ..Default::default()
+    }
+    .list(pool, &data.site, &data.local_site)
+    .await?;
+    assert_length!(2, comment_search_relevance);
+    assert!(comment_search_relevance.prev_page.is_none());
+
+    let comment_search_relevance_2 = CommentQuery {
+      search_term: Some("comment -2".into()),
+      search_sort: Some(SearchSortType::Relevance),
+      limit: Some(2),
+      page_cursor: comment_search_relevance.next_page.clone(),
+      ..Default::default()
+    }
+    .list(pool, &data.site, &data.local_site)
+    .await?;
+    assert_length!(2, comment_search_relevance_2);
+    assert!(comment_search_relevance_2.prev_page.is_some());
+    assert!(
+      comment_search_relevance_2
+        .iter()
+        .all(|c| c.comment.id != data.comment_2.id && !comment_search_relevance.contains(c))
+    );
+
     cleanup(data, pool).await
   }
 }
@@ -2,9 +2,11 @@ use chrono::{DateTime, Utc};
 use lemmy_db_schema::source::{
   comment::{Comment, CommentActions},
   community::{Community, CommunityActions},
+  community_flair::PersonFlair,
   community_tag::CommunityTagsView,
   person::{Person, PersonActions},
   post::Post,
+  reaction::ReactionCountsView,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -13,9 +15,11 @@ use {
   diesel::{Queryable, Selectable},
   lemmy_db_schema::utils::queries::selects::{
     CreatorLocalHomeCommunityBanExpiresType,
+    comment_reactions_fragment,
     comment_select_remove_deletes,
     creator_ban_expires_from_community,
     creator_banned_from_community,
+    creator_community_flair_fragment,
     creator_is_admin,
     creator_is_moderator,
     creator_local_home_community_ban_expires,
@@ -67,6 +71,12 @@ pub struct CommentView {
     )
   )]
   pub tags: CommunityTagsView,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = comment_reactions_fragment()
+    )
+  )]
+  pub reactions: ReactionCountsView,
   #[cfg_attr(feature = "full",
     diesel(
       select_expression = local_user_can_mod()
@@ -104,6 +114,12 @@ pub struct CommentView {
     )
   )]
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = creator_community_flair_fragment()
+    )
+  )]
+  pub creator_flair: Option<PersonFlair>,
 }
 
 #[skip_serializing_none]
@@ -123,4 +139,6 @@ pub struct CommentSlimView {
   pub creator_banned: bool,
   pub creator_is_moderator: bool,
   pub creator_banned_from_community: bool,
+  pub creator_flair: Option<PersonFlair>,
+  pub reactions: ReactionCountsView,
 }
@@ -3,8 +3,8 @@ use lemmy_db_schema::{
   CommunitySortType,
   MultiCommunityListingType,
   MultiCommunitySortType,
-  newtypes::{CommunityId, CommunityTagId, LanguageId, MultiCommunityId},
-  source::site::Site,
+  newtypes::{CommunityFlairId, CommunityId, CommunityTagId, LanguageId, MultiCommunityId, RuleId},
+  source::{rule::Rule, site::Site},
 };
 use lemmy_db_schema_file::{
   PersonId,
@@ -59,6 +59,26 @@ pub struct BanFromCommunity {
   ///
   /// An i64 unix timestamp is used for a simpler API client implementation.
   pub expires_at: Option<i64>,
+  /// The community or site rule which was broken.
+  pub rule_id: Option<RuleId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Ban or unban many users from a community at once.
+pub struct BulkBanFromCommunity {
+  pub community_id: CommunityId,
+  pub person_ids: Vec<PersonId>,
+  pub ban: bool,
+  /// Optionally remove or restore all their data.
+  pub remove_or_restore_data: Option<bool>,
+  pub reason: String,
+  /// A time that the bans will expire, in unix epoch seconds.
+  pub expires_at: Option<i64>,
+  /// The community or site rule which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
@@ -108,6 +128,21 @@ pub struct CreateCommunity {
   pub posting_restricted_to_mods: Option<bool>,
   pub discussion_languages: Option<Vec<LanguageId>>,
   pub visibility: Option<CommunityVisibility>,
+  /// Hold new posts in the mod queue until they are approved.
+  pub posts_require_approval: Option<bool>,
+  /// Hold new comments in the mod queue until they are approved.
+  pub comments_require_approval: Option<bool>,
+  /// Only hold content from accounts younger than this many days. Zero means no limit.
+  pub approval_max_account_age_days: Option<i32>,
+  /// Only hold content from remote users.
+  pub approval_remote_only: Option<bool>,
+  /// Automatically ban users after this many warnings. Zero means the site's warning policy is
+  /// used.
+  pub warning_ban_threshold: Option<i32>,
+  /// Only count warnings from this many days toward the ban. Zero means no limit.
+  pub warning_ban_window_days: Option<i32>,
+  /// Length of the automatic ban in days. Zero means a permanent ban.
+  pub warning_ban_days: Option<i32>,
 }
 
 #[skip_serializing_none]
@@ -139,6 +174,21 @@ pub struct EditCommunity {
   pub posting_restricted_to_mods: Option<bool>,
   pub discussion_languages: Option<Vec<LanguageId>>,
   pub visibility: Option<CommunityVisibility>,
+  /// Hold new posts in the mod queue until they are approved.
+  pub posts_require_approval: Option<bool>,
+  /// Hold new comments in the mod queue until they are approved.
+  pub comments_require_approval: Option<bool>,
+  /// Only hold content from accounts younger than this many days. Zero means no limit.
+  pub approval_max_account_age_days: Option<i32>,
+  /// Only hold content from remote users.
+  pub approval_remote_only: Option<bool>,
+  /// Automatically ban users after this many warnings. Zero means the site's warning policy is
+  /// used.
+  pub warning_ban_threshold: Option<i32>,
+  /// Only count warnings from this many days toward the ban. Zero means no limit.
+  pub warning_ban_window_days: Option<i32>,
+  /// Length of the automatic ban in days. Zero means a permanent ban.
+  pub warning_ban_days: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
@@ -172,6 +222,7 @@ pub struct GetCommunityResponse {
   pub site: Option<Site>,
   pub moderators: Vec<CommunityModeratorView>,
   pub discussion_languages: Vec<LanguageId>,
+  pub rules: Vec<Rule>,
 }
 
 #[skip_serializing_none]
@@ -363,3 +414,53 @@ pub struct DeleteCommunityTag {
   pub tag_id: CommunityTagId,
   pub delete: bool,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Create a person flair for a community.
+pub struct CreateCommunityFlair {
+  pub community_id: CommunityId,
+  pub name: String,
+  pub display_name: Option<String>,
+  pub color: Option<TagColor>,
+  /// Allow community members to assign this flair to themselves.
+  pub self_assignable: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Make changes to a community flair.
+pub struct EditCommunityFlair {
+  pub flair_id: CommunityFlairId,
+  pub display_name: Option<String>,
+  pub color: Option<TagColor>,
+  pub self_assignable: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a community flair.
+pub struct DeleteCommunityFlair {
+  pub flair_id: CommunityFlairId,
+  pub delete: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Assign a flair to a community member, or remove it if `flair_id` is empty.
+///
+/// Mods can assign any flair to any member. Other users can only assign self assignable flairs
+/// to themselves.
+pub struct AssignCommunityFlair {
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  pub flair_id: Option<CommunityFlairId>,
+}
@@ -2,6 +2,7 @@ use crate::{CommunityView, MultiCommunityView};
 use diesel::{
   BoolExpressionMethods,
   ExpressionMethods,
+  PgSortExpressionMethods,
   PgTextExpressionMethods,
   QueryDsl,
   SelectableHelper,
@@ -12,6 +13,7 @@ use lemmy_db_schema::{
   CommunitySortType,
   MultiCommunityListingType,
   MultiCommunitySortType,
+  SearchSortType,
   impls::local_user::LocalUserOptionHelper,
   newtypes::{CommunityId, MultiCommunityId},
   source::{
@@ -21,9 +23,16 @@ use lemmy_db_schema::{
     multi_community::{MultiCommunity, MultiCommunityEntry, multi_community_keys as mkey},
     site::Site,
   },
-  utils::{limit_fetch, queries::filters::filter_is_subscribed},
+  utils::{
+    limit_fetch,
+    queries::{
+      filters::filter_is_subscribed,
+      search::{TsvectorMatches, search_query, ts_rank},
+    },
+  },
 };
 use lemmy_db_schema_file::{
+  InstanceId,
   PersonId,
   enums::{CommunityVisibility, ListingType},
   joins::{
@@ -35,6 +44,7 @@ use lemmy_db_schema_file::{
   schema::{
     community,
     community_actions,
+    community_search,
     instance_actions,
     multi_community,
     multi_community_entry,
@@ -49,6 +59,7 @@ use lemmy_diesel_utils::{
     PagedResponse,
     PaginationCursor,
     PaginationCursorConversion,
+    paginate_offset_response,
     paginate_response,
   },
   traits::Crud,
@@ -121,6 +132,8 @@ pub struct CommunityQuery<'a> {
   pub multi_community_id: Option<MultiCommunityId>,
   pub search_term: Option<String>,
   pub search_title_only: Option<bool>,
+  pub search_sort: Option<SearchSortType>,
+  pub instance_id: Option<InstanceId>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
 }
@@ -152,7 +165,8 @@ impl CommunityQuery<'_> {
 
     if let Some(listing_type) = self.listing_type {
       query = match listing_type {
-        ListingType::All => query,
+        // Communities have no creator, so there is nothing to filter by
+        ListingType::All | ListingType::FollowedPersons => query,
         ListingType::Subscribed => query.filter(filter_is_subscribed()),
         ListingType::Local => query.filter(community::local.eq(true)),
         ListingType::ModeratorView => {
@@ -187,10 +201,21 @@ impl CommunityQuery<'_> {
       query = query.filter(community::id.eq_any(communities))
     }
 
+    if let Some(instance_id) = self.instance_id {
+      query = query.filter(community::instance_id.eq(instance_id));
+    }
+
+    // Filter by the time range
+    if let Some(time_range_seconds) = self.time_range_seconds {
+      query = query
+        .filter(community::published_at.gt(now() - seconds_to_pg_interval(time_range_seconds)));
+    }
+
     // The search term
-    if let Some(search_term) = self.search_term {
-      let searcher = fuzzy_search(&search_term);
+    if let Some(search_term) = &self.search_term {
+      let searcher = fuzzy_search(search_term);
 
+      // Names are matched as substrings, title, summary and sidebar with full-text search
       let name_or_title_filter = community::name
         .ilike(searcher.clone())
         // Also include the ap_id to allow for instance searching
@@ -200,17 +225,45 @@ impl CommunityQuery<'_> {
       query = if self.search_title_only.unwrap_or_default() {
         query.filter(name_or_title_filter)
       } else {
-        let body_or_description_filter = community::summary
-          .ilike(searcher.clone())
-          .or(community::sidebar.ilike(searcher.clone()));
+        let body_or_description_filter = community::id.eq_any(
+          community_search::table
+            .filter(community_search::search_vector.matches(search_query(
+              search_term.clone(),
+              self.local_user.interface_language(),
+            )))
+            .select(community_search::community_id),
+        );
         query.filter(name_or_title_filter.or(body_or_description_filter))
-      }
-    }
+      };
 
-    // Filter by the time range
-    if let Some(time_range_seconds) = self.time_range_seconds {
-      query = query
-        .filter(community::published_at.gt(now() - seconds_to_pg_interval(time_range_seconds)));
+      // Name matches first, then the best full-text matches. There is no unique key for the rank,
+      // so this uses offset pagination.
+      if self.search_sort == Some(SearchSortType::Relevance) {
+        let offset = self
+          .page_cursor
+          .map(PaginationCursor::offset)
+          .transpose()?
+          .unwrap_or_default();
+        let rank = community_search::table
+          .filter(community_search::community_id.eq(community::id))
+          .select(ts_rank(
+            community_search::search_vector,
+            search_query(search_term.clone(), self.local_user.interface_language()),
+          ))
+          .single_value();
+        let query = query
+          .order_by(community::name.ilike(searcher).desc())
+          .then_order_by(rank.desc().nulls_last())
+          .then_order_by(community::id.desc())
+          .offset(offset);
+
+        let conn = &mut get_conn(pool).await?;
+        let res = query
+          .load::<CommunityView>(conn)
+          .await
+          .with_lemmy_type(LemmyErrorType::NotFound)?;
+        return paginate_offset_response(res, limit, offset);
+      }
     }
 
     // Only sort by ascending for Old or NameAsc sorts.
@@ -396,6 +449,7 @@ mod tests {
   };
   use lemmy_db_schema::{
     CommunitySortType,
+    SearchSortType,
     assert_length,
     source::{
       community::{
@@ -834,6 +888,44 @@ mod tests {
 
     assert!(community_search_title_only.is_empty());
 
+    // Quoted phrases need the words in the same order
+    let community_search_phrase = CommunityQuery {
+      search_term: Some("\"here sidebar\"".into()),
+      ..Default::default()
+    }
+    .list(pool, &data.site, &data.local_site)
+    .await?;
+
+    assert!(community_search_phrase.is_empty());
+
+    // Name matches come before matches in the sidebar
+    Community::update(
+      pool,
+      data.communities[0].id,
+      &CommunityUpdateForm {
+        sidebar: Some(Some("better than test_community_3".to_string())),
+        ..Default::default()
+      },
+    )
+    .await?;
+    let community_search_relevance = CommunityQuery {
+      search_term: Some("test_community_3".into()),
+      search_sort: Some(SearchSortType::Relevance),
+      ..Default::default()
+    }
+    .list(pool, &data.site, &data.local_site)
+    .await?;
+
+    assert_length!(2, community_search_relevance);
+    assert_eq!(
+      data.communities[2].id,
+      community_search_relevance[0].community.id
+    );
+    assert_eq!(
+      data.communities[0].id,
+      community_search_relevance[1].community.id
+    );
+
     cleanup(data, pool).await?;
 
     Ok(())
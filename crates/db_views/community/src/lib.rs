@@ -1,5 +1,6 @@
 use lemmy_db_schema::source::{
   community::{Community, CommunityActions},
+  community_flair::CommunityFlairsView,
   community_tag::CommunityTagsView,
   multi_community::MultiCommunity,
   person::Person,
@@ -11,6 +12,7 @@ use serde_with::skip_serializing_none;
 use {
   diesel::{NullableExpressionMethods, Queryable, Selectable},
   lemmy_db_schema::utils::queries::selects::{
+    community_flairs_fragment,
     community_tags_fragment,
     local_user_community_can_mod,
   },
@@ -45,6 +47,12 @@ pub struct CommunityView {
     )
   )]
   pub tags: CommunityTagsView,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = community_flairs_fragment()
+    )
+  )]
+  pub flairs: CommunityFlairsView,
 }
 
 #[skip_serializing_none]
@@ -0,0 +1,284 @@
+use crate::{ConversationMessageView, ConversationView};
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use i_love_jesus::SortDirection;
+use lemmy_db_schema::{
+  newtypes::{ConversationId, ConversationMessageId},
+  source::conversation::{
+    Conversation,
+    ConversationMessage,
+    conversation_keys,
+    conversation_message_keys,
+  },
+  utils::limit_fetch,
+};
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{conversation, conversation_message, conversation_participant, person},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  pagination::{
+    CursorData,
+    PagedResponse,
+    PaginationCursor,
+    PaginationCursorConversion,
+    paginate_response,
+  },
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl ConversationView {
+  #[diesel::dsl::auto_type(no_type_alias)]
+  fn joins(my_person_id: PersonId) -> _ {
+    conversation::table
+      .inner_join(conversation_participant::table)
+      .filter(conversation_participant::person_id.eq(my_person_id))
+  }
+
+  /// Fails with [LemmyErrorType::NotAConversationParticipant] if the user isn't part of the
+  /// conversation.
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    my_person_id: PersonId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    Self::joins(my_person_id)
+      .filter(conversation::id.eq(conversation_id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotAConversationParticipant)
+  }
+}
+
+impl PaginationCursorConversion for ConversationView {
+  type PaginatedType = Conversation;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.conversation.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    Conversation::read(pool, ConversationId(cursor.id()?)).await
+  }
+}
+
+#[derive(Default)]
+pub struct ConversationQuery {
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+impl ConversationQuery {
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    my_person_id: PersonId,
+  ) -> LemmyResult<PagedResponse<ConversationView>> {
+    let limit = limit_fetch(self.limit, None)?;
+    let query = ConversationView::joins(my_person_id)
+      .select(ConversationView::as_select())
+      .limit(limit)
+      .into_boxed();
+
+    let paginated_query =
+      ConversationView::paginate(query, &self.page_cursor, SortDirection::Desc, pool)
+        .await?
+        .then_order_by(conversation_keys::last_message_at)
+        .then_order_by(conversation_keys::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<ConversationView>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    paginate_response(res, limit, self.page_cursor)
+  }
+}
+
+impl ConversationMessageView {
+  #[diesel::dsl::auto_type(no_type_alias)]
+  fn joins() -> _ {
+    conversation_message::table.inner_join(person::table)
+  }
+
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    conversation_message_id: ConversationMessageId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    Self::joins()
+      .filter(conversation_message::id.eq(conversation_message_id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl PaginationCursorConversion for ConversationMessageView {
+  type PaginatedType = ConversationMessage;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.conversation_message.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    ConversationMessage::read(pool, ConversationMessageId(cursor.id()?)).await
+  }
+}
+
+pub struct ConversationMessageQuery {
+  pub conversation_id: ConversationId,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+impl ConversationMessageQuery {
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<PagedResponse<ConversationMessageView>> {
+    let limit = limit_fetch(self.limit, None)?;
+    let query = ConversationMessageView::joins()
+      .filter(conversation_message::conversation_id.eq(self.conversation_id))
+      .select(ConversationMessageView::as_select())
+      .limit(limit)
+      .into_boxed();
+
+    let paginated_query =
+      ConversationMessageView::paginate(query, &self.page_cursor, SortDirection::Desc, pool)
+        .await?
+        .then_order_by(conversation_message_keys::published_at)
+        .then_order_by(conversation_message_keys::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<ConversationMessageView>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?
+      .into_iter()
+      .map(|mut m| {
+        // Deleted messages stay in place, but without their content
+        if m.conversation_message.deleted {
+          m.conversation_message.content = String::new();
+        }
+        m
+      })
+      .collect();
+    paginate_response(res, limit, self.page_cursor)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::impls::{ConversationMessageQuery, ConversationQuery, ConversationView};
+  use lemmy_db_schema::source::{
+    conversation::{
+      Conversation,
+      ConversationInsertForm,
+      ConversationMessage,
+      ConversationMessageInsertForm,
+      ConversationParticipant,
+    },
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_conversation_views() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let alice = Person::create(pool, &PersonInsertForm::test_form(instance.id, "alice")).await?;
+    let bob = Person::create(pool, &PersonInsertForm::test_form(instance.id, "bob")).await?;
+    let carol = Person::create(pool, &PersonInsertForm::test_form(instance.id, "carol")).await?;
+
+    let first = Conversation::create(pool, &ConversationInsertForm::new(alice.id)).await?;
+    ConversationParticipant::add(pool, first.id, &[alice.id, bob.id, carol.id]).await?;
+    let second = Conversation::create(pool, &ConversationInsertForm::new(bob.id)).await?;
+    ConversationParticipant::add(pool, second.id, &[bob.id, carol.id]).await?;
+
+    for (conversation_id, creator_id, content) in [
+      (first.id, alice.id, "hello"),
+      (first.id, bob.id, "hi alice"),
+      (second.id, bob.id, "just us"),
+    ] {
+      let form = ConversationMessageInsertForm::new(conversation_id, creator_id, content.into());
+      ConversationMessage::create(pool, &form).await?;
+    }
+
+    // Own messages are never unread
+    let alice_view = ConversationView::read(pool, first.id, alice.id).await?;
+    assert_eq!(1, alice_view.unread_count);
+    let carol_view = ConversationView::read(pool, first.id, carol.id).await?;
+    assert_eq!(2, carol_view.unread_count);
+    assert!(
+      ConversationView::read(pool, second.id, alice.id)
+        .await
+        .is_err()
+    );
+
+    // Read state is per participant
+    ConversationParticipant::mark_as_read(pool, first.id, carol.id).await?;
+    let carol_view = ConversationView::read(pool, first.id, carol.id).await?;
+    assert_eq!(0, carol_view.unread_count);
+    let bob_view = ConversationView::read(pool, first.id, bob.id).await?;
+    assert_eq!(1, bob_view.unread_count);
+
+    // The conversation with the newest message comes first
+    let list = ConversationQuery::default().list(pool, carol.id).await?;
+    assert_eq!(
+      vec![second.id, first.id],
+      list
+        .items
+        .iter()
+        .map(|c| c.conversation.id)
+        .collect::<Vec<_>>()
+    );
+    let list = ConversationQuery::default().list(pool, alice.id).await?;
+    assert_eq!(1, list.items.len());
+
+    let messages = ConversationMessageQuery {
+      conversation_id: first.id,
+      page_cursor: None,
+      limit: None,
+    }
+    .list(pool)
+    .await?;
+    assert_eq!(
+      vec!["hi alice", "hello"],
+      messages
+        .items
+        .iter()
+        .map(|m| m.conversation_message.content.as_str())
+        .collect::<Vec<_>>()
+    );
+    assert_eq!(
+      bob.id,
+      messages
+        .items
+        .first()
+        .map(|m| m.creator.id)
+        .unwrap_or_default()
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,47 @@
+use lemmy_db_schema::source::{
+  conversation::{Conversation, ConversationMessage, ConversationParticipant},
+  person::Person,
+};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "full")]
+use {
+  diesel::{Queryable, Selectable},
+  lemmy_db_schema::utils::queries::selects::conversation_unread_count,
+};
+
+pub mod api;
+#[cfg(feature = "full")]
+pub mod impls;
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A conversation, as seen by one of its participants.
+pub struct ConversationView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub conversation: Conversation,
+  /// The read state of the current user.
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub participant: ConversationParticipant,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = conversation_unread_count()
+    )
+  )]
+  pub unread_count: i64,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A conversation message view.
+pub struct ConversationMessageView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub conversation_message: ConversationMessage,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub creator: Person,
+}
@@ -6,6 +6,8 @@ use diesel::{
   NullableExpressionMethods,
   QueryDsl,
   SelectableHelper,
+  data_types::PgInterval,
+  dsl::IntervalDsl,
 };
 use diesel_async::RunQueryDsl;
 use i_love_jesus::asc_if;
@@ -21,6 +23,7 @@ use lemmy_db_schema::{
 use lemmy_db_schema_file::{
   PersonId,
   aliases::creator_home_instance_actions,
+  enums::EmailDigestCadence,
   joins::creator_home_instance_actions_join,
   schema::{instance_actions, local_user, oauth_account, person},
 };
@@ -134,6 +137,32 @@ impl LocalUserView {
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
 
+  /// Users with a digest cadence whose last digest is older than that cadence. The scheduled task
+  /// runs hourly, so a few minutes of tolerance are subtracted to avoid skipping a whole period.
+  pub async fn list_due_for_email_digest(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let due = |cadence: EmailDigestCadence, period: PgInterval| {
+      local_user::email_digest_cadence.eq(cadence).and(
+        local_user::last_email_digest_at
+          .is_null()
+          .or(local_user::last_email_digest_at.lt((now() - period).nullable())),
+      )
+    };
+    Self::joins()
+      .filter(local_user::email.is_not_null())
+      .filter(local_user::send_notifications_to_email.eq(true))
+      .filter(person::deleted.eq(false))
+      .filter(
+        due(EmailDigestCadence::Hourly, 55.minutes())
+          .or(due(EmailDigestCadence::Daily, 23.hours()))
+          .or(due(EmailDigestCadence::Weekly, 167.hours())),
+      )
+      .select(Self::as_select())
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   pub async fn create_test_user(
     pool: &mut DbPool<'_>,
     name: &str,
@@ -244,11 +273,12 @@ impl PaginationCursorConversion for LocalUserView {
 mod tests {
 
   use super::*;
+  use chrono::{Duration, Utc};
   use lemmy_db_schema::{
     assert_length,
     source::{
       instance::{Instance, InstanceActions, InstanceBanForm},
-      local_user::{LocalUser, LocalUserInsertForm},
+      local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
       person::{Person, PersonInsertForm},
     },
     traits::Bannable,
@@ -308,4 +338,58 @@ mod tests {
 
     cleanup(data, pool).await
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn list_due_for_email_digest() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+    let alice = LocalUserView::read_person(pool, data.alice.id).await?;
+
+    // Immediate cadence is never part of a digest
+    let form = LocalUserUpdateForm {
+      email: Some(Some("alice@example.com".to_string())),
+      send_notifications_to_email: Some(true),
+      ..Default::default()
+    };
+    LocalUser::update(pool, alice.local_user.id, &form).await?;
+    assert!(
+      LocalUserView::list_due_for_email_digest(pool)
+        .await?
+        .is_empty()
+    );
+
+    // Daily digest without any previous digest is due
+    let form = LocalUserUpdateForm {
+      email_digest_cadence: Some(EmailDigestCadence::Daily),
+      ..Default::default()
+    };
+    LocalUser::update(pool, alice.local_user.id, &form).await?;
+    let due = LocalUserView::list_due_for_email_digest(pool).await?;
+    assert_length!(1, due);
+    assert_eq!(due[0].person.id, data.alice.id);
+
+    // After sending a digest it is not due again until the next day
+    let form = LocalUserUpdateForm {
+      last_email_digest_at: Some(Some(Utc::now() - Duration::hours(2))),
+      ..Default::default()
+    };
+    LocalUser::update(pool, alice.local_user.id, &form).await?;
+    assert!(
+      LocalUserView::list_due_for_email_digest(pool)
+        .await?
+        .is_empty()
+    );
+
+    // But an hourly digest is
+    let form = LocalUserUpdateForm {
+      email_digest_cadence: Some(EmailDigestCadence::Hourly),
+      ..Default::default()
+    };
+    LocalUser::update(pool, alice.local_user.id, &form).await?;
+    assert_length!(1, LocalUserView::list_due_for_email_digest(pool).await?);
+
+    cleanup(data, pool).await
+  }
 }
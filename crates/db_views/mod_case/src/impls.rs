@@ -0,0 +1,288 @@
+use crate::{ModCaseView, ModNoteView};
+use chrono::Utc;
+use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  ModlogKindFilter,
+  newtypes::{CommunityId, ModNoteId},
+  source::{
+    community::{Community, CommunityActions},
+    local_site::LocalSite,
+    modlog::Modlog,
+    person::Person,
+  },
+};
+use lemmy_db_schema_file::{
+  PersonId,
+  enums::ModlogKind,
+  schema::{mod_note, person},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modlog::impls::ModlogQuery;
+use lemmy_db_views_report_combined::impls::ReportCombinedQuery;
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+/// How many of the most recent modlog entries and reports are included in a mod case.
+const MOD_CASE_HISTORY_LIMIT: i64 = 50;
+
+impl ModNoteView {
+  pub async fn read(pool: &mut DbPool<'_>, mod_note_id: ModNoteId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    mod_note::table
+      .find(mod_note_id)
+      .inner_join(person::table.on(mod_note::creator_id.eq(person::id)))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// All notes about a user in a community, newest first.
+  pub async fn list_for_person(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    mod_note::table
+      .inner_join(person::table.on(mod_note::creator_id.eq(person::id)))
+      .filter(mod_note::community_id.eq(community_id))
+      .filter(mod_note::target_person_id.eq(person_id))
+      .select(Self::as_select())
+      .order_by(mod_note::published_at.desc())
+      .then_order_by(mod_note::id.desc())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl ModCaseView {
+  /// Permissions are not checked here, the caller needs to ensure that `my_local_user` moderates
+  /// the community.
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+    my_local_user: &LocalUserView,
+    local_site: &LocalSite,
+  ) -> LemmyResult<Self> {
+    let person = Person::read(pool, person_id).await?;
+    let community = Community::read(pool, community_id).await?;
+    let actions = CommunityActions::read(pool, community_id, person_id)
+      .await
+      .ok();
+    let banned_from_community = actions.as_ref().is_some_and(|a| {
+      a.received_ban_at.is_some() && a.ban_expires_at.is_none_or(|e| e > Utc::now())
+    });
+    let ban_expires_at = actions.and_then(|a| a.ban_expires_at);
+
+    let warning_count = Modlog::count_for_person_in_community(
+      pool,
+      community_id,
+      person_id,
+      &[ModlogKind::ModWarnPost, ModlogKind::ModWarnComment],
+    )
+    .await?;
+    let ban_count = Modlog::count_for_person_in_community(
+      pool,
+      community_id,
+      person_id,
+      &[ModlogKind::ModBanFromCommunity],
+    )
+    .await?;
+
+    let notes = ModNoteView::list_for_person(pool, community_id, person_id).await?;
+
+    let modlog = ModlogQuery {
+      type_: Some(ModlogKindFilter::All),
+      community_id: Some(community_id),
+      target_person_id: Some(person_id),
+      local_user: Some(&my_local_user.local_user),
+      limit: Some(MOD_CASE_HISTORY_LIMIT),
+      ..Default::default()
+    }
+    .list(pool, local_site)
+    .await?
+    .items;
+
+    let reports = ReportCombinedQuery {
+      community_id: Some(community_id),
+      item_creator_id: Some(person_id),
+      show_community_rule_violations: Some(true),
+      limit: Some(MOD_CASE_HISTORY_LIMIT),
+      ..Default::default()
+    }
+    .list(pool, my_local_user)
+    .await?
+    .items;
+
+    Ok(ModCaseView {
+      person,
+      community,
+      banned_from_community,
+      ban_expires_at,
+      warning_count,
+      ban_count,
+      notes,
+      modlog,
+      reports,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::ModCaseView;
+  use lemmy_db_schema::{
+    source::{
+      community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
+      instance::Instance,
+      local_site::{LocalSite, LocalSiteInsertForm},
+      local_user::{LocalUser, LocalUserInsertForm},
+      mod_note::{ModNote, ModNoteInsertForm},
+      modlog::{Modlog, ModlogInsertForm},
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      post_report::{PostReport, PostReportForm},
+      site::{Site, SiteInsertForm},
+    },
+    traits::Reportable,
+  };
+  use lemmy_db_views_local_user::LocalUserView;
+  use lemmy_db_views_report_combined::ReportCombinedView;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_mod_case() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let site = Site::create(pool, &SiteInsertForm::new("site".to_string(), instance.id)).await?;
+    let system_acct =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "system")).await?;
+    let local_site =
+      LocalSite::create(pool, &LocalSiteInsertForm::new(site.id, system_acct.id)).await?;
+
+    let moderator = Person::create(pool, &PersonInsertForm::test_form(instance.id, "mod")).await?;
+    let local_user =
+      LocalUser::create(pool, &LocalUserInsertForm::test_form(moderator.id), vec![]).await?;
+    let mod_view = LocalUserView {
+      local_user,
+      person: moderator.clone(),
+      banned: false,
+      ban_expires_at: None,
+    };
+    let user = Person::create(pool, &PersonInsertForm::test_form(instance.id, "user")).await?;
+    let reporter =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "reporter")).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "case".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+    let other_community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "other".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+    CommunityActions::join(
+      pool,
+      &CommunityModeratorForm::new(community.id, moderator.id),
+    )
+    .await?;
+
+    let post = Post::create(
+      pool,
+      &PostInsertForm::new("spam".to_string(), user.id, community.id),
+    )
+    .await?;
+    let other_post = Post::create(
+      pool,
+      &PostInsertForm::new("spam".to_string(), user.id, other_community.id),
+    )
+    .await?;
+
+    // Two warnings and a ban which was reverted, plus a warning in another community
+    let forms = vec![
+      ModlogInsertForm::mod_create_post_warning(moderator.id, &post, "first"),
+      ModlogInsertForm::mod_create_post_warning(moderator.id, &post, "second"),
+      ModlogInsertForm::mod_create_post_warning(moderator.id, &other_post, "elsewhere"),
+      ModlogInsertForm::mod_ban_from_community(
+        moderator.id,
+        community.id,
+        user.id,
+        true,
+        None,
+        "ban",
+      ),
+      ModlogInsertForm::mod_ban_from_community(
+        moderator.id,
+        community.id,
+        user.id,
+        false,
+        None,
+        "unban",
+      ),
+    ];
+    Modlog::create(pool, &forms).await?;
+
+    let report_form = PostReportForm {
+      creator_id: reporter.id,
+      post_id: post.id,
+      original_post_name: post.name.clone(),
+      original_post_url: None,
+      original_post_body: None,
+      reason: "spam".to_string(),
+      violates_instance_rules: false,
+      rule_id: None,
+    };
+    PostReport::report(pool, &report_form).await?;
+
+    for content in ["known spammer", "second account of someone"] {
+      let form = ModNoteInsertForm::new(community.id, user.id, moderator.id, content.to_string());
+      ModNote::create(pool, &form).await?;
+    }
+    let form = ModNoteInsertForm::new(
+      other_community.id,
+      user.id,
+      moderator.id,
+      "other".to_string(),
+    );
+    ModNote::create(pool, &form).await?;
+
+    let case = ModCaseView::read(pool, community.id, user.id, &mod_view, &local_site).await?;
+    assert_eq!(user.id, case.person.id);
+    assert!(!case.banned_from_community);
+    assert_eq!(2, case.warning_count);
+    assert_eq!(1, case.ban_count);
+    assert_eq!(4, case.modlog.len());
+    assert_eq!(
+      vec!["second account of someone", "known spammer"],
+      case
+        .notes
+        .iter()
+        .map(|n| n.mod_note.content.as_str())
+        .collect::<Vec<_>>()
+    );
+    assert!(case.notes.iter().all(|n| n.creator.id == moderator.id));
+    assert_eq!(1, case.reports.len());
+    assert!(matches!(
+      case.reports.first(),
+      Some(ReportCombinedView::Post(v)) if v.post.id == post.id
+    ));
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
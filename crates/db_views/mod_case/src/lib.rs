@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use diesel::{Queryable, Selectable};
+use lemmy_db_schema::source::{community::Community, mod_note::ModNote, person::Person};
+use lemmy_db_views_modlog::ModlogView;
+use lemmy_db_views_report_combined::ReportCombinedView;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+pub mod api;
+#[cfg(feature = "full")]
+pub mod impls;
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A mod note, with the moderator who wrote it.
+pub struct ModNoteView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub mod_note: ModNote,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub creator: Person,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Everything the moderators of a community know about a user: the notes they wrote, the
+/// user's modlog entries in the community including warnings and bans, and the reports against
+/// the user's content. Modlog entries and reports are limited to the most recent ones, older ones
+/// can be fetched with `GetModlog` and `ListReports`.
+pub struct ModCaseView {
+  pub person: Person,
+  pub community: Community,
+  pub banned_from_community: bool,
+  pub ban_expires_at: Option<DateTime<Utc>>,
+  /// How often the user was warned in this community.
+  pub warning_count: i64,
+  /// How often the user was banned from this community.
+  pub ban_count: i64,
+  pub notes: Vec<ModNoteView>,
+  pub modlog: Vec<ModlogView>,
+  pub reports: Vec<ReportCombinedView>,
+}
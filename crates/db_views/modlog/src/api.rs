@@ -1,6 +1,7 @@
 use lemmy_db_schema::{
   ModlogKindFilter,
-  newtypes::{CommentId, CommunityId, ModlogId, PostId},
+  newtypes::{CommentId, CommunityId, ModlogId, PostId, RuleId},
+  source::modlog::Modlog,
 };
 use lemmy_db_schema_file::{PersonId, enums::ListingType};
 use lemmy_diesel_utils::pagination::PaginationCursor;
@@ -32,6 +33,43 @@ pub struct GetModlog {
   pub show_bulk: Option<bool>,
   /// Return only child entries triggered by this parent modlog action.
   pub bulk_action_parent_id: Option<ModlogId>,
+  /// Filter by the cited rule.
+  pub rule_id: Option<RuleId>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Remove or restore many posts and comments of a community at once (only doable by mods).
+pub struct BulkRemove {
+  pub community_id: CommunityId,
+  pub post_ids: Option<Vec<PostId>>,
+  pub comment_ids: Option<Vec<CommentId>>,
+  pub removed: bool,
+  pub reason: String,
+  /// The community or site rule which was broken.
+  pub rule_id: Option<RuleId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Lock or unlock many posts of a community at once (only doable by mods).
+pub struct BulkLockPosts {
+  pub community_id: CommunityId,
+  pub post_ids: Vec<PostId>,
+  pub locked: bool,
+  pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The parent modlog entry of a bulk action. Its children can be listed with
+/// `GetModlog.bulk_action_parent_id`.
+pub struct BulkActionResponse {
+  pub bulk_action: Modlog,
+}
@@ -12,7 +12,7 @@ use i_love_jesus::SortDirection;
 use lemmy_db_schema::{
   ModlogKindFilter,
   impls::local_user::LocalUserOptionHelper,
-  newtypes::{CommentId, CommunityId, ModlogId, PostId},
+  newtypes::{CommentId, CommunityId, ModlogId, PostId, RuleId},
   source::{
     local_site::LocalSite,
     local_user::LocalUser,
@@ -100,6 +100,7 @@ pub struct ModlogQuery<'a> {
   pub target_person_id: Option<PersonId>,
   pub show_bulk: Option<bool>,
   pub bulk_action_parent_id: Option<ModlogId>,
+  pub rule_id: Option<RuleId>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
 }
@@ -140,6 +141,10 @@ impl ModlogQuery<'_> {
       query = query.filter(comment::id.eq(comment_id))
     }
 
+    if let Some(rule_id) = self.rule_id {
+      query = query.filter(modlog::rule_id.eq(rule_id))
+    }
+
     // `show_bulk`: true => show all entries; false/None => hide bulk child entries.
     // When bulk_action_parent_id is provided the caller is looking into a bulk
     // action, so skip null guard
@@ -167,7 +172,7 @@ impl ModlogQuery<'_> {
     }
 
     query = match self.listing_type.unwrap_or(ListingType::All) {
-      ListingType::All => query,
+      ListingType::All | ListingType::FollowedPersons => query,
       ListingType::Subscribed => query.filter(filter_is_subscribed()),
       ListingType::Local => query.filter(community::local.eq(true)),
       ListingType::ModeratorView => {
@@ -226,6 +231,7 @@ impl ModlogView {
 #[expect(clippy::indexing_slicing)]
 mod tests {
   use super::*;
+  use chrono::{Duration, Utc};
   use lemmy_db_schema::source::{
     comment::{Comment, CommentInsertForm},
     community::{Community, CommunityInsertForm},
@@ -1133,4 +1139,110 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn mod_bulk_action() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let parent_form =
+      ModlogInsertForm::mod_bulk_action(data.jessica.id, data.community.id, "spam wave");
+    let parent = Modlog::create(pool, &[parent_form]).await?;
+    let parent_id = parent[0].id;
+
+    let lock_forms = [&data.post, &data.post_2].map(|post| {
+      ModlogInsertForm::mod_lock_post(data.jessica.id, post, true, "spam wave")
+        .with_bulk_action_parent(parent_id)
+    });
+    Modlog::create(pool, &lock_forms).await?;
+
+    let parent = Modlog::read(pool, parent_id).await?;
+    assert_eq!(ModlogKind::ModBulkAction, parent.kind);
+    assert_eq!(Some(data.community.id), parent.target_community_id);
+    assert_eq!(2, parent.child_count);
+
+    let children = ModlogQuery {
+      bulk_action_parent_id: Some(parent_id),
+      ..Default::default()
+    }
+    .list(pool, &data.local_site)
+    .await?
+    .items;
+    assert_eq!(2, children.len());
+    assert!(
+      children
+        .iter()
+        .all(|e| e.modlog.kind == ModlogKind::ModLockPost)
+    );
+
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn active_warnings() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    // Timmy is warned three times, but one warning already expired
+    let expired = Utc::now() - Duration::days(1);
+    let forms = [
+      ModlogInsertForm::mod_create_post_warning(data.jessica.id, &data.post, "first"),
+      ModlogInsertForm::mod_create_comment_warning(
+        data.jessica.id,
+        &data.comment,
+        data.community.id,
+        "second",
+      ),
+      ModlogInsertForm::mod_create_post_warning(data.jessica.id, &data.post, "expired")
+        .with_expires_at(Some(expired)),
+    ];
+    Modlog::create(pool, &forms).await?;
+
+    let warnings =
+      Modlog::list_active_warnings(pool, data.community.id, data.timmy.id, None).await?;
+    assert_eq!(2, warnings.len());
+    let since = Some(Utc::now() + Duration::days(1));
+    let recent_warnings =
+      Modlog::list_active_warnings(pool, data.community.id, data.timmy.id, since).await?;
+    assert!(recent_warnings.is_empty());
+
+    // The warnings lead to a ban, after which they don't count anymore
+    let ban_form = ModlogInsertForm::mod_ban_from_community(
+      data.jessica.id,
+      data.community.id,
+      data.timmy.id,
+      true,
+      None,
+      "too many warnings",
+    );
+    let ban = Modlog::create(pool, &[ban_form]).await?;
+    let warning_ids: Vec<_> = warnings.iter().map(|w| w.id).collect();
+    Modlog::set_warning_ban(pool, &warning_ids, ban[0].id).await?;
+
+    let warning = Modlog::read(pool, warning_ids[0]).await?;
+    assert_eq!(Some(ban[0].id), warning.warning_ban_id);
+    assert_eq!(None, warning.bulk_action_parent_id);
+    let warnings =
+      Modlog::list_active_warnings(pool, data.community.id, data.timmy.id, None).await?;
+    assert!(warnings.is_empty());
+
+    // The warnings are still shown in the modlog
+    let modlog = ModlogQuery {
+      type_: Some(ModlogKindFilter::Other(ModlogKind::ModWarnPost)),
+      ..Default::default()
+    }
+    .list(pool, &data.local_site)
+    .await?;
+    assert_eq!(2, modlog.items.len());
+
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
 }
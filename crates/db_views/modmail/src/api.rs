@@ -0,0 +1,89 @@
+use crate::{ModmailMessageView, ModmailThreadView};
+use lemmy_db_schema::newtypes::{CommunityId, ModmailThreadId};
+use lemmy_db_schema_file::PersonId;
+use lemmy_diesel_utils::pagination::PaginationCursor;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Send a message to the moderators of a community. Continues your existing modmail thread with
+/// the community, and reopens it if it was resolved.
+pub struct CreateModmail {
+  pub community_id: CommunityId,
+  pub content: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Reply to a modmail thread as moderator. The reply is sent as the community.
+pub struct ReplyToModmail {
+  pub modmail_thread_id: ModmailThreadId,
+  pub content: String,
+  /// Write a note which only the other moderators can see.
+  pub internal: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Assign a modmail thread to a moderator of the community, or remove the assignment.
+pub struct AssignModmail {
+  pub modmail_thread_id: ModmailThreadId,
+  pub assignee_id: Option<PersonId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Resolve or reopen a modmail thread.
+pub struct ResolveModmail {
+  pub modmail_thread_id: ModmailThreadId,
+  pub resolved: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List modmail threads of the communities you moderate, newest first.
+pub struct ListModmailThreads {
+  pub community_id: Option<CommunityId>,
+  pub unresolved_only: Option<bool>,
+  /// Only show threads which are assigned to you.
+  pub assigned_to_me: Option<bool>,
+  /// Show the threads which you started with the moderators of other communities instead. The
+  /// assigned moderator and resolver are not shown for these.
+  pub mine: Option<bool>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the messages of a modmail thread, newest first.
+pub struct ListModmailMessages {
+  pub modmail_thread_id: ModmailThreadId,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ModmailThreadResponse {
+  pub modmail_thread_view: ModmailThreadView,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ModmailMessageResponse {
+  pub modmail_message_view: ModmailMessageView,
+}
@@ -0,0 +1,403 @@
+use crate::{ModmailMessageView, ModmailThreadView};
+use diesel::{
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+  SelectableHelper,
+  dsl::{count, not},
+};
+use diesel_async::RunQueryDsl;
+use i_love_jesus::SortDirection;
+use lemmy_db_schema::{
+  newtypes::{CommunityId, ModmailMessageId, ModmailThreadId},
+  source::modmail::{ModmailMessage, ModmailThread, modmail_message_keys, modmail_thread_keys},
+  utils::limit_fetch,
+};
+use lemmy_db_schema_file::{
+  PersonId,
+  aliases,
+  joins::my_community_actions_join,
+  schema::{community, community_actions, modmail_message, modmail_thread, person},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  pagination::{
+    CursorData,
+    PagedResponse,
+    PaginationCursor,
+    PaginationCursorConversion,
+    paginate_response,
+  },
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl ModmailThreadView {
+  #[diesel::dsl::auto_type(no_type_alias)]
+  fn joins(my_person_id: PersonId) -> _ {
+    let creator_join = person::table.on(modmail_thread::creator_id.eq(person::id));
+    let assignee = aliases::person1.field(person::id).nullable();
+    let assignee_join = aliases::person1.on(modmail_thread::assignee_id.eq(assignee));
+    let my_community_actions_join: my_community_actions_join =
+      my_community_actions_join(Some(my_person_id));
+
+    modmail_thread::table
+      .inner_join(community::table)
+      .inner_join(creator_join)
+      .left_join(assignee_join)
+      .left_join(my_community_actions_join)
+  }
+
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    modmail_thread_id: ModmailThreadId,
+    my_person_id: PersonId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    Self::joins(my_person_id)
+      .filter(modmail_thread::id.eq(modmail_thread_id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Replies are sent as the community, so the user who wrote the modmail doesn't see which
+  /// moderator handles it.
+  pub fn hide_moderators(self) -> Self {
+    Self {
+      modmail_thread: ModmailThread {
+        assignee_id: None,
+        resolver_id: None,
+        ..self.modmail_thread
+      },
+      assignee: None,
+      ..self
+    }
+  }
+
+  /// The number of unresolved threads in the communities which the user moderates.
+  pub async fn get_unresolved_count(
+    pool: &mut DbPool<'_>,
+    my_person_id: PersonId,
+    is_admin: bool,
+  ) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = Self::joins(my_person_id)
+      .filter(not(modmail_thread::resolved))
+      .select(count(modmail_thread::id))
+      .into_boxed();
+    if !is_admin {
+      query = query.filter(community_actions::became_moderator_at.is_not_null());
+    }
+    query
+      .first::<i64>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl PaginationCursorConversion for ModmailThreadView {
+  type PaginatedType = ModmailThread;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.modmail_thread.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    ModmailThread::read(pool, ModmailThreadId(cursor.id()?)).await
+  }
+}
+
+#[derive(Default)]
+pub struct ModmailThreadQuery {
+  pub community_id: Option<CommunityId>,
+  pub unresolved_only: Option<bool>,
+  pub assigned_to_me: Option<bool>,
+  /// Threads which the user started, instead of those of the communities they moderate.
+  pub mine: Option<bool>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+impl ModmailThreadQuery {
+  /// Moderators only see the threads of their own communities, admins see all of them.
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    my_person_id: PersonId,
+    is_admin: bool,
+  ) -> LemmyResult<PagedResponse<ModmailThreadView>> {
+    let limit = limit_fetch(self.limit, None)?;
+    let mut query = ModmailThreadView::joins(my_person_id)
+      .select(ModmailThreadView::as_select())
+      .limit(limit)
+      .into_boxed();
+
+    let mine = self.mine.unwrap_or_default();
+    if mine {
+      query = query.filter(modmail_thread::creator_id.eq(my_person_id));
+    } else if !is_admin {
+      query = query.filter(community_actions::became_moderator_at.is_not_null());
+    }
+    if let Some(community_id) = self.community_id {
+      query = query.filter(modmail_thread::community_id.eq(community_id));
+    }
+    if self.unresolved_only.unwrap_or_default() {
+      query = query.filter(not(modmail_thread::resolved));
+    }
+    if self.assigned_to_me.unwrap_or_default() {
+      query = query.filter(modmail_thread::assignee_id.eq(my_person_id));
+    }
+
+    let paginated_query =
+      ModmailThreadView::paginate(query, &self.page_cursor, SortDirection::Desc, pool)
+        .await?
+        .then_order_by(modmail_thread_keys::last_message_at)
+        .then_order_by(modmail_thread_keys::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<ModmailThreadView>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    let res = if mine {
+      res
+        .into_iter()
+        .map(ModmailThreadView::hide_moderators)
+        .collect()
+    } else {
+      res
+    };
+    paginate_response(res, limit, self.page_cursor)
+  }
+}
+
+impl ModmailMessageView {
+  #[diesel::dsl::auto_type(no_type_alias)]
+  fn joins() -> _ {
+    let creator_join = person::table.on(modmail_message::creator_id.eq(person::id.nullable()));
+    modmail_message::table.left_join(creator_join)
+  }
+
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    modmail_message_id: ModmailMessageId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    Self::joins()
+      .filter(modmail_message::id.eq(modmail_message_id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Replies of the moderators are sent as the community, so the user doesn't see who wrote them.
+  pub fn hide_moderator(self) -> Self {
+    if self.modmail_message.from_moderator {
+      Self {
+        creator: None,
+        ..self
+      }
+    } else {
+      self
+    }
+  }
+}
+
+impl PaginationCursorConversion for ModmailMessageView {
+  type PaginatedType = ModmailMessage;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.modmail_message.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    ModmailMessage::read(pool, ModmailMessageId(cursor.id()?)).await
+  }
+}
+
+pub struct ModmailMessageQuery {
+  pub modmail_thread_id: ModmailThreadId,
+  /// Moderators also see internal notes, and who wrote the replies.
+  pub as_moderator: bool,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+impl ModmailMessageQuery {
+  pub async fn list(self, pool: &mut DbPool<'_>) -> LemmyResult<PagedResponse<ModmailMessageView>> {
+    let limit = limit_fetch(self.limit, None)?;
+    let mut query = ModmailMessageView::joins()
+      .filter(modmail_message::modmail_thread_id.eq(self.modmail_thread_id))
+      .select(ModmailMessageView::as_select())
+      .limit(limit)
+      .into_boxed();
+    if !self.as_moderator {
+      query = query.filter(not(modmail_message::internal));
+    }
+
+    let paginated_query =
+      ModmailMessageView::paginate(query, &self.page_cursor, SortDirection::Desc, pool)
+        .await?
+        .then_order_by(modmail_message_keys::published_at)
+        .then_order_by(modmail_message_keys::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<ModmailMessageView>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    let res = if self.as_moderator {
+      res
+    } else {
+      res
+        .into_iter()
+        .map(ModmailMessageView::hide_moderator)
+        .collect()
+    };
+    paginate_response(res, limit, self.page_cursor)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    ModmailThreadView,
+    impls::{ModmailMessageQuery, ModmailThreadQuery},
+  };
+  use lemmy_db_schema::source::{
+    community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
+    instance::Instance,
+    modmail::{ModmailMessage, ModmailMessageInsertForm, ModmailThread, ModmailThreadUpdateForm},
+    person::{Person, PersonInsertForm},
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_modmail_views() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let user = Person::create(pool, &PersonInsertForm::test_form(instance.id, "user")).await?;
+    let moderator = Person::create(pool, &PersonInsertForm::test_form(instance.id, "mod")).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "mail".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+    let other_community = Community::create(
+      pool,
+      &CommunityInsertForm::new(instance.id, "other".to_string(), "pubkey".to_string()),
+    )
+    .await?;
+    CommunityActions::join(
+      pool,
+      &CommunityModeratorForm::new(community.id, moderator.id),
+    )
+    .await?;
+
+    let thread = ModmailThread::read_or_create(pool, community.id, user.id).await?;
+    ModmailThread::read_or_create(pool, other_community.id, user.id).await?;
+    for (creator_id, from_moderator, internal, content) in [
+      (user.id, false, false, "help"),
+      (moderator.id, true, true, "known spammer"),
+      (moderator.id, true, false, "no"),
+    ] {
+      let form = ModmailMessageInsertForm {
+        from_moderator: Some(from_moderator),
+        internal: Some(internal),
+        ..ModmailMessageInsertForm::new(thread.id, Some(creator_id), content.to_string())
+      };
+      ModmailMessage::create(pool, &form).await?;
+    }
+
+    let form = ModmailThreadUpdateForm {
+      assignee_id: Some(Some(moderator.id)),
+      ..Default::default()
+    };
+    ModmailThread::update(pool, thread.id, &form).await?;
+
+    // Moderators only see the threads of their own communities
+    let threads = ModmailThreadQuery::default()
+      .list(pool, moderator.id, false)
+      .await?;
+    assert_eq!(1, threads.items.len());
+    let threads = ModmailThreadQuery {
+      mine: Some(true),
+      ..Default::default()
+    }
+    .list(pool, user.id, false)
+    .await?;
+    assert_eq!(2, threads.items.len());
+    // The user doesn't see which moderator takes care of the thread
+    assert!(
+      threads
+        .items
+        .iter()
+        .all(|t| t.assignee.is_none() && t.modmail_thread.assignee_id.is_none())
+    );
+    assert_eq!(
+      1,
+      ModmailThreadView::get_unresolved_count(pool, moderator.id, false).await?
+    );
+
+    // The user doesn't see internal notes or who replied
+    let messages = ModmailMessageQuery {
+      modmail_thread_id: thread.id,
+      as_moderator: false,
+      page_cursor: None,
+      limit: None,
+    }
+    .list(pool)
+    .await?;
+    assert_eq!(
+      vec![None, Some(user.id)],
+      messages
+        .items
+        .iter()
+        .map(|m| m.creator.as_ref().map(|c| c.id))
+        .collect::<Vec<_>>()
+    );
+
+    let messages = ModmailMessageQuery {
+      modmail_thread_id: thread.id,
+      as_moderator: true,
+      page_cursor: None,
+      limit: None,
+    }
+    .list(pool)
+    .await?;
+    assert_eq!(
+      vec!["no", "known spammer", "help"],
+      messages
+        .items
+        .iter()
+        .map(|m| m.modmail_message.content.as_str())
+        .collect::<Vec<_>>()
+    );
+    assert_eq!(
+      Some(moderator.id),
+      messages
+        .items
+        .first()
+        .and_then(|m| m.creator.as_ref().map(|c| c.id))
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,54 @@
+use lemmy_db_schema::source::{
+  community::Community,
+  modmail::{ModmailMessage, ModmailThread},
+  person::Person,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use {
+  diesel::{NullableExpressionMethods, Queryable, Selectable, dsl::Nullable},
+  lemmy_db_schema::{Person1AliasAllColumnsTuple, utils::queries::selects::person1_select},
+};
+
+pub mod api;
+#[cfg(feature = "full")]
+pub mod impls;
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A modmail thread, with the user who wrote it and the assigned moderator.
+pub struct ModmailThreadView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub modmail_thread: ModmailThread,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub community: Community,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub creator: Person,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression_type = Nullable<Person1AliasAllColumnsTuple>,
+      select_expression = person1_select().nullable()
+    )
+  )]
+  pub assignee: Option<Person>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A modmail message view.
+pub struct ModmailMessageView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub modmail_message: ModmailMessage,
+  /// Only shown to moderators for replies of the moderators.
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub creator: Option<Person>,
+}
@@ -1,5 +1,11 @@
-use lemmy_db_schema::newtypes::NotificationId;
+use crate::NotificationView;
+use chrono::{DateTime, Utc};
+use lemmy_db_schema::newtypes::{CommunityId, NotificationId, PostId};
+use lemmy_db_schema_file::enums::NotificationType;
+use lemmy_db_views_comment::CommentView;
+use lemmy_db_views_post::PostView;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -9,3 +15,59 @@ pub struct MarkNotificationAsRead {
   pub notification_id: NotificationId,
   pub read: bool,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Mark all notifications of a group as read.
+pub struct MarkNotificationGroupAsRead {
+  pub kind: NotificationType,
+  pub post_id: PostId,
+  pub read: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Open a stream of server-sent events. New notifications and unread count changes are always
+/// sent. New and edited posts and comments are only sent for the given community or post.
+pub struct GetEvents {
+  pub community_id: Option<CommunityId>,
+  pub post_id: Option<PostId>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+#[serde(tag = "type_", rename_all = "snake_case")]
+/// A single message in the event stream.
+pub enum EventMessage {
+  Notification(NotificationView),
+  UnreadCount { notification_count: i64 },
+  Post(PostView),
+  Comment(CommentView),
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Register a Web Push subscription, as returned by `PushManager.subscribe()` in the browser.
+/// Notifications are then also sent to this subscription.
+pub struct CreatePushSubscription {
+  pub endpoint: String,
+  /// The base64url encoded `p256dh` key of the subscription.
+  pub p256dh: String,
+  /// The base64url encoded `auth` key of the subscription.
+  pub auth: String,
+  pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Remove a Web Push subscription, for example on logout.
+pub struct DeletePushSubscription {
+  pub endpoint: String,
+}
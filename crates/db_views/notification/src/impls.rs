@@ -1,16 +1,25 @@
-use crate::{CommentView, NotificationData, NotificationView, NotificationViewInternal};
+use crate::{
+  CommentView,
+  NotificationData,
+  NotificationGroupView,
+  NotificationView,
+  NotificationViewInternal,
+};
 use diesel::{
+  AggregateExpressionMethods,
   BoolExpressionMethods,
   ExpressionMethods,
   PgExpressionMethods,
   QueryDsl,
   SelectableHelper,
+  dsl::{count, max, sql},
+  sql_types::BigInt,
 };
 use diesel_async::RunQueryDsl;
 use i_love_jesus::SortDirection;
 use lemmy_db_schema::{
   NotificationTypeFilter,
-  newtypes::NotificationId,
+  newtypes::{NotificationId, PostId},
   source::{
     notification::{Notification, notification_keys},
     person::Person,
@@ -33,10 +42,12 @@ use lemmy_diesel_utils::{
     PagedResponse,
     PaginationCursor,
     PaginationCursorConversion,
+    paginate_offset_response,
     paginate_response,
   },
 };
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use std::collections::HashMap;
 
 impl NotificationView {
   /// Gets the number of unread mentions
@@ -45,7 +56,6 @@ impl NotificationView {
     my_person: &Person,
     show_bot_accounts: bool,
   ) -> LemmyResult<i64> {
-    use diesel::dsl::count;
     let conn = &mut get_conn(pool).await?;
 
     let unread_filter = notification::read.eq(false);
@@ -72,6 +82,37 @@ impl NotificationView {
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
 
+  /// Gets the number of notification groups with unread notifications. See
+  /// [NotificationGroupQuery] for how notifications are grouped.
+  pub async fn get_unread_group_count(
+    pool: &mut DbPool<'_>,
+    my_person: &Person,
+    show_bot_accounts: bool,
+  ) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+
+    let mut query = notification_joins(my_person.id, my_person.instance_id)
+      .filter(notification::recipient_id.eq(my_person.id))
+      .filter(notification::read.eq(false))
+      .filter(filter_deleted_and_removed(my_person.id))
+      .filter(filter_blocked())
+      .select(sql::<BigInt>(
+        "count(DISTINCT (notification.kind, notification.post_id, \
+         notification.private_message_id, notification.modlog_id))",
+      ))
+      .into_boxed();
+
+    // These filters need to be kept in sync with the filters in get_unread_count()
+    if !show_bot_accounts {
+      query = query.filter(person::bot_account.is_distinct_from(true));
+    }
+
+    query
+      .first::<i64>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   pub async fn read(
     pool: &mut DbPool<'_>,
     id: NotificationId,
@@ -119,6 +160,7 @@ pub struct NotificationQuery {
   pub show_bot_accounts: Option<bool>,
   pub hide_modlog_names: Option<bool>,
   pub creator_id: Option<PersonId>,
+  pub post_id: Option<PostId>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
   pub no_limit: Option<bool>,
@@ -198,6 +240,10 @@ impl NotificationQuery {
         }
       }
 
+      if let Some(post_id) = self.post_id {
+        query = query.filter(notification::post_id.eq(post_id));
+      }
+
       if !self.show_bot_accounts.unwrap_or_default() {
         query = query.filter(person::bot_account.is_distinct_from(true));
       };
@@ -230,6 +276,111 @@ impl NotificationQuery {
   }
 }
 
+/// Lists notifications grouped by type and post. Private messages and mod actions have their own
+/// id column set, so each of them ends up in a separate group.
+#[derive(Default)]
+pub struct NotificationGroupQuery {
+  pub type_: Option<NotificationTypeFilter>,
+  pub unread_only: Option<bool>,
+  pub show_bot_accounts: Option<bool>,
+  pub hide_modlog_names: Option<bool>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+impl NotificationGroupQuery {
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    my_person: &Person,
+  ) -> LemmyResult<PagedResponse<NotificationGroupView>> {
+    let limit = limit_fetch(self.limit, None)?;
+    // Groups have no unique key which could be used for cursor pagination.
+    let offset = self
+      .page_cursor
+      .map(PaginationCursor::offset)
+      .transpose()?
+      .unwrap_or_default();
+
+    let mut query = notification_joins(my_person.id, my_person.instance_id)
+      .filter(notification::recipient_id.eq(my_person.id))
+      .filter(filter_blocked())
+      .filter(filter_deleted_and_removed(my_person.id))
+      .group_by((
+        notification::kind,
+        notification::post_id,
+        notification::private_message_id,
+        notification::modlog_id,
+      ))
+      .select((
+        notification::kind,
+        notification::post_id,
+        max(notification::id),
+        count(notification::id),
+        count(notification::id).aggregate_filter(notification::read.eq(false)),
+        count(notification::creator_id).aggregate_distinct(),
+      ))
+      // Newest group first
+      .order_by(max(notification::id).desc())
+      .limit(limit)
+      .offset(offset)
+      .into_boxed();
+
+    // These filters need to be kept in sync with the filters in NotificationQuery::list()
+    if self.unread_only.unwrap_or_default() {
+      query = query.filter(notification::read.eq(false));
+    }
+    if let Some(NotificationTypeFilter::Other(kind)) = self.type_ {
+      query = query.filter(notification::kind.eq(kind));
+    }
+    if !self.show_bot_accounts.unwrap_or_default() {
+      query = query.filter(person::bot_account.is_distinct_from(true));
+    }
+
+    let conn = &mut get_conn(pool).await?;
+    let groups = query
+      .load::<(
+        NotificationType,
+        Option<PostId>,
+        Option<NotificationId>,
+        i64,
+        i64,
+        i64,
+      )>(conn)
+      .await?;
+
+    // Load the newest notification of each group
+    let latest_ids: Vec<_> = groups.iter().filter_map(|g| g.2).collect();
+    let hide_modlog_names = self.hide_modlog_names.unwrap_or_default();
+    let mut latest: HashMap<_, _> = notification_joins(my_person.id, my_person.instance_id)
+      .filter(notification::id.eq_any(latest_ids))
+      .select(NotificationViewInternal::as_select())
+      .load::<NotificationViewInternal>(conn)
+      .await?
+      .into_iter()
+      .filter_map(|r| map_to_enum(r, hide_modlog_names, my_person))
+      .map(|n| (n.notification.id, n))
+      .collect();
+
+    let res = groups
+      .into_iter()
+      .filter_map(
+        |(kind, post_id, latest_id, count, unread_count, creator_count)| {
+          Some(NotificationGroupView {
+            kind,
+            post_id,
+            latest: latest.remove(&latest_id?)?,
+            count,
+            unread_count,
+            creator_count,
+          })
+        },
+      )
+      .collect();
+    paginate_offset_response(res, limit, offset)
+  }
+}
+
 fn map_to_enum(
   v: NotificationViewInternal,
   hide_modlog_name: bool,
@@ -257,8 +408,10 @@ fn map_to_enum(
       person_actions: v.person_actions,
       comment_actions: v.comment_actions,
       tags: v.tags,
+      reactions: v.comment_reactions,
       creator_banned_from_community: v.creator_banned_from_community,
       creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+      creator_flair: v.creator_flair,
       creator_is_admin: v.creator_is_admin,
       can_mod: v.can_mod,
       creator_banned: v.creator_banned,
@@ -275,8 +428,11 @@ fn map_to_enum(
       post_actions: v.post_actions,
       person_actions: v.person_actions,
       tags: v.tags,
+      poll: v.poll,
+      reactions: v.post_reactions,
       creator_banned_from_community: v.creator_banned_from_community,
       creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+      creator_flair: v.creator_flair,
       creator_is_admin: v.creator_is_admin,
       can_mod: v.can_mod,
       creator_banned: v.creator_banned,
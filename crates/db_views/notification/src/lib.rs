@@ -3,16 +3,23 @@ use chrono::{DateTime, Utc};
 use lemmy_db_schema::source::{
   comment::{Comment, CommentActions},
   community::{Community, CommunityActions},
+  community_flair::PersonFlair,
   community_tag::CommunityTagsView,
   images::ImageDetails,
   instance::Instance,
   modlog::Modlog,
   person::{Person, PersonActions},
+  poll::PollView,
   post::{Post, PostActions},
   private_message::PrivateMessage,
+  reaction::ReactionCountsView,
 };
-use lemmy_db_schema::{NotificationTypeFilter, source::notification::Notification};
-use lemmy_db_schema_file::PersonId;
+use lemmy_db_schema::{
+  NotificationTypeFilter,
+  newtypes::PostId,
+  source::notification::Notification,
+};
+use lemmy_db_schema_file::{PersonId, enums::NotificationType};
 use lemmy_db_views_comment::CommentView;
 use lemmy_db_views_modlog::ModlogView;
 use lemmy_db_views_post::PostView;
@@ -34,10 +41,14 @@ use {
       local_user_can_mod,
     },
     utils::queries::selects::{
+      comment_reactions_fragment,
       creator_ban_expires_from_community,
       creator_banned_from_community,
+      creator_community_flair_fragment,
       person1_select,
       post_community_tags_fragment,
+      post_poll_fragment,
+      post_reactions_fragment,
     },
   },
 };
@@ -86,6 +97,12 @@ struct NotificationViewInternal {
   modlog: Option<Modlog>,
   #[diesel(select_expression = post_community_tags_fragment())]
   tags: CommunityTagsView,
+  #[diesel(select_expression = post_poll_fragment())]
+  poll: Option<PollView>,
+  #[diesel(select_expression = post_reactions_fragment())]
+  post_reactions: ReactionCountsView,
+  #[diesel(select_expression = comment_reactions_fragment())]
+  comment_reactions: ReactionCountsView,
   #[diesel(select_expression = creator_is_admin())]
   creator_is_admin: bool,
   #[diesel(select_expression = local_user_can_mod())]
@@ -103,6 +120,8 @@ struct NotificationViewInternal {
   creator_banned_from_community: bool,
   #[diesel(select_expression = creator_ban_expires_from_community())]
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  #[diesel(select_expression = creator_community_flair_fragment())]
+  creator_flair: Option<PersonFlair>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -124,6 +143,23 @@ pub enum NotificationData {
   ModAction(ModlogView),
 }
 
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Notifications of the same type about the same post, for example "12 new comments on your
+/// post". Private messages and mod actions are never grouped.
+pub struct NotificationGroupView {
+  pub kind: NotificationType,
+  pub post_id: Option<PostId>,
+  /// The newest notification in the group.
+  pub latest: NotificationView,
+  pub count: i64,
+  pub unread_count: i64,
+  /// The number of different people who caused these notifications.
+  pub creator_count: i64,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -133,6 +169,21 @@ pub struct ListNotifications {
   pub type_: Option<NotificationTypeFilter>,
   pub unread_only: Option<bool>,
   pub creator_id: Option<PersonId>,
+  /// Only show notifications about this post. Used together with `type_` to expand a
+  /// notification group.
+  pub post_id: Option<PostId>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Get your notifications grouped by type and post, newest group first.
+pub struct ListNotificationGroups {
+  pub type_: Option<NotificationTypeFilter>,
+  pub unread_only: Option<bool>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
 }
@@ -1,4 +1,8 @@
-use crate::{NotificationData, NotificationView, impls::NotificationQuery};
+use crate::{
+  NotificationData,
+  NotificationView,
+  impls::{NotificationGroupQuery, NotificationQuery},
+};
 use lemmy_db_schema::{
   NotificationTypeFilter,
   assert_length,
@@ -272,3 +276,82 @@ async fn test_modlog() -> LemmyResult<()> {
 
   cleanup(data, pool).await
 }
+
+#[tokio::test]
+#[serial]
+async fn test_grouping() -> LemmyResult<()> {
+  let pool = &build_db_pool_for_tests();
+  let pool = &mut pool.into();
+  let data = init_data(pool).await?;
+
+  let form = CommunityInsertForm::new(data.alice.instance_id, "group".to_string(), String::new());
+  let community = Community::create(pool, &form).await?;
+  let form = PostInsertForm::new("popular".to_string(), data.alice.id, community.id);
+  let post = Post::create(pool, &form).await?;
+
+  // Three replies to alice's post, from two different people
+  for creator in [&data.bob, &data.carmen, &data.bob] {
+    let form = CommentInsertForm::new(creator.id, post.id, community.id, "reply".to_string());
+    let comment = Comment::create(pool, &form, None).await?;
+    let form =
+      NotificationInsertForm::new_comment(&comment, data.alice.id, NotificationType::Reply);
+    Notification::create(pool, &[form]).await?;
+  }
+  let form = &PrivateMessageInsertForm::new(data.bob.id, data.alice.id, "hi".to_string());
+  let pm = PrivateMessage::create(pool, form).await?;
+  Notification::create(pool, &[NotificationInsertForm::new_private_message(&pm)]).await?;
+
+  let count = NotificationView::get_unread_count(pool, &data.alice, false).await?;
+  assert_eq!(4, count);
+  let count = NotificationView::get_unread_group_count(pool, &data.alice, false).await?;
+  assert_eq!(2, count);
+
+  // The newest group comes first
+  let groups = NotificationGroupQuery::default()
+    .list(pool, &data.alice)
+    .await?;
+  assert_length!(2, groups);
+  assert_eq!(NotificationType::PrivateMessage, groups[0].kind);
+  assert_eq!(1, groups[0].count);
+  assert_eq!(NotificationType::Reply, groups[1].kind);
+  assert_eq!(Some(post.id), groups[1].post_id);
+  assert_eq!(3, groups[1].count);
+  assert_eq!(3, groups[1].unread_count);
+  assert_eq!(2, groups[1].creator_count);
+  assert_eq!(data.bob.id, groups[1].latest.notification.creator_id);
+
+  // Expand the group
+  let notifs = NotificationQuery {
+    type_: Some(NotificationTypeFilter::Other(NotificationType::Reply)),
+    post_id: Some(post.id),
+    ..Default::default()
+  }
+  .list(pool, &data.alice)
+  .await?;
+  assert_length!(3, notifs);
+
+  Notification::mark_read_by_group_and_person(
+    pool,
+    NotificationType::Reply,
+    post.id,
+    data.alice.id,
+    true,
+  )
+  .await?;
+  let count = NotificationView::get_unread_group_count(pool, &data.alice, false).await?;
+  assert_eq!(1, count);
+  let groups = NotificationGroupQuery::default()
+    .list(pool, &data.alice)
+    .await?;
+  assert_eq!(0, groups[1].unread_count);
+
+  let groups = NotificationGroupQuery {
+    unread_only: Some(true),
+    ..Default::default()
+  }
+  .list(pool, &data.alice)
+  .await?;
+  assert_length!(1, groups);
+
+  cleanup(data, pool).await
+}
@@ -2,7 +2,7 @@ use crate::PersonView;
 use lemmy_db_schema::{
   PersonListingType,
   PersonSortType,
-  newtypes::CommunityId,
+  newtypes::{CommunityId, RuleId},
   source::site::Site,
 };
 use lemmy_db_schema_file::PersonId;
@@ -45,6 +45,8 @@ pub struct BanPerson {
   ///
   /// An i64 unix timestamp is used for a simpler API client implementation.
   pub expires_at: Option<i64>,
+  /// The site rule which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -56,6 +58,15 @@ pub struct BlockPerson {
   pub block: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Follow a person.
+pub struct FollowPerson {
+  pub person_id: PersonId,
+  pub follow: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -2,6 +2,7 @@ use crate::PersonView;
 use diesel::{
   BoolExpressionMethods,
   ExpressionMethods,
+  PgSortExpressionMethods,
   PgTextExpressionMethods,
   QueryDsl,
   SelectableHelper,
@@ -11,6 +12,7 @@ use i_love_jesus::asc_if;
 use lemmy_db_schema::{
   PersonListingType,
   PersonSortType,
+  SearchSortType,
   impls::local_user::LocalUserOptionHelper,
   newtypes::CommunityId,
   source::{
@@ -18,7 +20,10 @@ use lemmy_db_schema::{
     person::{Person, person_keys as key},
     site::Site,
   },
-  utils::limit_fetch,
+  utils::{
+    limit_fetch,
+    queries::search::{TsvectorMatches, search_query, ts_rank},
+  },
 };
 use lemmy_db_schema_file::{
   InstanceId,
@@ -30,7 +35,7 @@ use lemmy_db_schema_file::{
     my_person_actions_join,
     person_community_actions_join,
   },
-  schema::{community_actions, local_user, person},
+  schema::{community_actions, local_user, person, person_search},
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -39,6 +44,7 @@ use lemmy_diesel_utils::{
     PagedResponse,
     PaginationCursor,
     PaginationCursorConversion,
+    paginate_offset_response,
     paginate_response,
   },
   traits::Crud,
@@ -132,6 +138,8 @@ pub struct PersonQuery<'a> {
   pub listing_type: Option<PersonListingType>,
   pub search_term: Option<String>,
   pub search_title_only: Option<bool>,
+  pub search_sort: Option<SearchSortType>,
+  pub instance_id: Option<InstanceId>,
   pub community_id: Option<CommunityId>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
@@ -171,10 +179,15 @@ impl PersonQuery<'_> {
       };
     }
 
+    if let Some(instance_id) = self.instance_id {
+      query = query.filter(person::instance_id.eq(instance_id));
+    }
+
     // The search term
-    if let Some(search_term) = self.search_term {
-      let searcher = fuzzy_search(&search_term);
+    if let Some(search_term) = &self.search_term {
+      let searcher = fuzzy_search(search_term);
 
+      // Names are matched as substrings, the bio with full-text search
       let name_or_title_filter = person::name
         .ilike(searcher.clone())
         // Also include the ap_id to allow for instance searching
@@ -184,8 +197,44 @@ impl PersonQuery<'_> {
       query = if self.search_title_only.unwrap_or_default() {
         query.filter(name_or_title_filter)
       } else {
-        let body_or_description_filter = person::bio.ilike(searcher.clone());
+        let body_or_description_filter = person::id.eq_any(
+          person_search::table
+            .filter(person_search::search_vector.matches(search_query(
+              search_term.clone(),
+              self.local_user.interface_language(),
+            )))
+            .select(person_search::person_id),
+        );
         query.filter(name_or_title_filter.or(body_or_description_filter))
+      };
+
+      // Name matches first, then the best matches of the bio. There is no unique key for the
+      // rank, so this uses offset pagination.
+      if self.search_sort == Some(SearchSortType::Relevance) {
+        let offset = self
+          .page_cursor
+          .map(PaginationCursor::offset)
+          .transpose()?
+          .unwrap_or_default();
+        let rank = person_search::table
+          .filter(person_search::person_id.eq(person::id))
+          .select(ts_rank(
+            person_search::search_vector,
+            search_query(search_term.clone(), self.local_user.interface_language()),
+          ))
+          .single_value();
+        let query = query
+          .order_by(person::name.ilike(searcher).desc())
+          .then_order_by(rank.desc().nulls_last())
+          .then_order_by(person::id.desc())
+          .offset(offset);
+
+        let conn = &mut get_conn(pool).await?;
+        let res = query
+          .load::<PersonView>(conn)
+          .await
+          .with_lemmy_type(LemmyErrorType::NotFound)?;
+        return paginate_offset_response(res, limit, offset);
       }
     }
 
@@ -1,11 +1,21 @@
 use crate::PostView;
 use lemmy_db_schema::{
   PostFeatureType,
-  newtypes::{CommentId, CommunityId, CommunityTagId, LanguageId, MultiCommunityId, PostId},
+  newtypes::{
+    CommentId,
+    CommunityId,
+    CommunityTagId,
+    LanguageId,
+    MultiCommunityId,
+    PollOptionId,
+    PostId,
+    RuleId,
+  },
+  source::post_revision::PostRevision,
 };
 use lemmy_db_schema_file::{
   PersonId,
-  enums::{ListingType, PostNotificationsMode, PostSortType},
+  enums::{ListingType, PostNotificationsMode, PostSortType, ScheduleRecurrence},
 };
 use lemmy_db_views_community::CommunityView;
 use lemmy_db_views_community_moderator::CommunityModeratorView;
@@ -35,6 +45,34 @@ pub struct CreatePost {
   pub tags: Option<Vec<CommunityTagId>>,
   /// Time when this post should be scheduled. Null means publish immediately.
   pub scheduled_publish_time_at: Option<i64>,
+  /// Publish a copy of the post again with this interval. Only for scheduled posts.
+  pub scheduled_recurrence: Option<ScheduleRecurrence>,
+  /// Attach a poll to the post.
+  pub poll: Option<CreatePoll>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A poll which is attached to a post.
+pub struct CreatePoll {
+  /// The answers which can be chosen, in display order.
+  pub options: Vec<String>,
+  /// Allow voting for more than one option. Defaults to false.
+  pub multiple_choice: Option<bool>,
+  /// Time after which no more votes are accepted. Null means the poll never closes.
+  pub end_time_at: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Vote in the poll of a post.
+pub struct VotePoll {
+  pub post_id: PostId,
+  /// The chosen options. An empty list removes your vote.
+  pub option_ids: Vec<PollOptionId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -47,6 +85,18 @@ pub struct CreatePostLike {
   pub is_upvote: Option<bool>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Add or remove an emoji reaction on a post.
+pub struct CreatePostReaction {
+  pub post_id: PostId,
+  /// A unicode emoji, or the shortcode of a custom emoji like `:lemmy:`.
+  pub emoji: String,
+  /// False removes the reaction.
+  pub add: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -75,7 +125,14 @@ pub struct EditPost {
   pub custom_thumbnail: Option<String>,
   /// Time when this post should be scheduled. Null means publish immediately.
   pub scheduled_publish_time_at: Option<i64>,
+  /// Publish a copy of the post again with this interval. Null removes the recurrence. Only
+  /// applies while the post is still scheduled.
+  pub scheduled_recurrence: Option<ScheduleRecurrence>,
   pub tags: Option<Vec<CommunityTagId>>,
+  /// Add or change the poll of the post. Existing options keep their votes.
+  pub poll: Option<CreatePoll>,
+  /// Remove the poll of the post, together with its votes.
+  pub remove_poll: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -262,6 +319,8 @@ pub struct RemovePost {
   /// Setting this will override whatever `removed` was set to,
   /// leave as null or unset to act just on the post itself.
   pub remove_children: Option<bool>,
+  /// The community or site rule which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -283,6 +342,7 @@ pub struct MarkManyPostsAsRead {
   pub read: bool,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -290,6 +350,9 @@ pub struct MarkManyPostsAsRead {
 pub struct CreatePostWarning {
   pub post_id: PostId,
   pub reason: String,
+  /// A time that the warning will expire, in unix epoch seconds. Expired warnings don't count
+  /// toward the warning policy.
+  pub expires_at: Option<i64>,
 }
 
 #[skip_serializing_none]
@@ -302,6 +365,25 @@ pub struct GetPost {
   pub comment_id: Option<CommentId>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Get previous versions of an edited post.
+pub struct GetPostHistory {
+  pub post_id: PostId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Previous versions of a post, newest first. The current version is not included.
+///
+/// No diffs are computed on the server. Each revision was replaced by the one before it in
+/// the list (the first one by the current post), so clients can diff consecutive pairs.
+pub struct GetPostHistoryResponse {
+  pub revisions: Vec<PostRevision>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
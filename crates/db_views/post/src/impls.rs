@@ -17,6 +17,7 @@ use diesel::{
 use diesel_async::RunQueryDsl;
 use i_love_jesus::{SortDirection, asc_if};
 use lemmy_db_schema::{
+  SearchSortType,
   impls::local_user::LocalUserOptionHelper,
   newtypes::{CommunityId, CommunityTagId, MultiCommunityId, PostId},
   source::{
@@ -31,7 +32,15 @@ use lemmy_db_schema::{
   },
   utils::{
     limit_fetch,
-    queries::filters::{filter_blocked, filter_private_or_followed, filter_unlisted_or_followed},
+    queries::{
+      filters::{
+        filter_blocked,
+        filter_is_followed_person,
+        filter_private_or_followed,
+        filter_unlisted_or_followed,
+      },
+      search::{TsvectorMatches, search_query, search_vector_title, ts_rank},
+    },
   },
 };
 use lemmy_db_schema_file::{
@@ -52,7 +61,15 @@ use lemmy_db_schema_file::{
     my_person_actions_join,
     my_post_actions_join,
   },
-  schema::{community, person, post, post_actions, post_community_tag},
+  schema::{
+    community,
+    community_actions,
+    person,
+    post,
+    post_actions,
+    post_community_tag,
+    post_search,
+  },
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -61,10 +78,11 @@ use lemmy_diesel_utils::{
     PagedResponse,
     PaginationCursor,
     PaginationCursorConversion,
+    paginate_offset_response,
     paginate_response,
   },
   traits::Crud,
-  utils::{CoalesceKey, Commented, fuzzy_search, now, seconds_to_pg_interval},
+  utils::{CoalesceKey, Commented, now, seconds_to_pg_interval},
 };
 use lemmy_utils::{
   error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
@@ -217,6 +235,12 @@ impl PostView {
             .or(post::creator_id.nullable().eq(my_person_id))
             .or(post::comments.gt(0)),
         )
+        // Posts in the mod queue are only visible to their creator
+        .filter(
+          post::approval_pending
+            .eq(false)
+            .or(post::creator_id.nullable().eq(my_person_id)),
+        )
         // private communities can only by browsed by accepted followers
         .filter(filter_private_or_followed());
     }
@@ -293,6 +317,41 @@ impl PostView {
       .with_lemmy_type(LemmyErrorType::NotFound)?;
     paginate_response(res, limit, page_cursor)
   }
+
+  /// List the posts which are held in the mod queue, oldest first. Without a community, this
+  /// returns posts from all communities which the user moderates, or from all communities for
+  /// admins who set `all_communities`.
+  pub async fn list_approval_pending(
+    pool: &mut DbPool<'_>,
+    my_local_user: &LocalUser,
+    local_instance_id: InstanceId,
+    community_id: Option<CommunityId>,
+    all_communities: bool,
+    limit: Option<i64>,
+  ) -> LemmyResult<Vec<PostView>> {
+    let limit = limit_fetch(limit, None)?;
+    let mut query = PostView::joins(Some(my_local_user.person_id), local_instance_id)
+      .filter(post::approval_pending)
+      .filter(not(post::deleted.or(post::removed)))
+      .select(PostView::as_select())
+      .order_by(post::published_at.asc())
+      .limit(limit)
+      .into_boxed();
+
+    if let Some(community_id) = community_id {
+      query = query.filter(post::community_id.eq(community_id));
+    }
+    if !all_communities {
+      query = query.filter(community_actions::became_moderator_at.is_not_null());
+    }
+
+    let conn = &mut get_conn(pool).await?;
+    Commented::new(query)
+      .text("PostView::list_approval_pending")
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
 }
 
 #[derive(Clone, Default)]
@@ -313,6 +372,9 @@ pub struct PostQuery<'a> {
   pub search_term: Option<String>,
   pub search_title_only: Option<bool>,
   pub search_url_only: Option<bool>,
+  pub search_sort: Option<SearchSortType>,
+  /// Only show posts in communities from this instance.
+  pub instance_id: Option<InstanceId>,
   pub page_cursor: Option<PaginationCursor>,
   pub tag_id: Option<CommunityTagId>,
   /// For backwards compat with API v3 (not available on API v4).
@@ -348,7 +410,7 @@ impl PostQuery<'_> {
       (None, None) => {
         // If no community or multi_community is given, then parse the listing_types
         match self.listing_type.unwrap_or_default() {
-          ListingType::Local | ListingType::All => None,
+          ListingType::Local | ListingType::All | ListingType::FollowedPersons => None,
           ListingType::Subscribed => {
             if let Some(my_person_id) = self.local_user.person_id() {
               Some(CommunityActions::list_subscribed_community_ids(pool, my_person_id).await?)
@@ -428,6 +490,10 @@ impl PostQuery<'_> {
       query = query.filter(post::creator_id.eq(creator_id));
     }
 
+    if let Some(instance_id) = self.instance_id {
+      query = query.filter(community::instance_id.eq(instance_id));
+    }
+
     // Although the other listing types pre-fetched the communities, you still need to filter by
     // local if necessary.
     let listing_type = self.listing_type.unwrap_or_default();
@@ -435,10 +501,17 @@ impl PostQuery<'_> {
       query = query.filter(community::local.eq(true));
     }
 
+    if listing_type == ListingType::FollowedPersons {
+      query = query.filter(filter_is_followed_person());
+    }
+
     // Posts from unlisted communities should not be visible in Local/All feeds
     if self.community_id.is_none()
       && self.multi_community_id.is_none()
-      && (listing_type == ListingType::All || listing_type == ListingType::Local)
+      && matches!(
+        listing_type,
+        ListingType::All | ListingType::Local | ListingType::FollowedPersons
+      )
     {
       query = query.filter(filter_unlisted_or_followed());
     }
@@ -455,23 +528,29 @@ impl PostQuery<'_> {
     }
 
     // The search term
-    if let Some(search_term) = self.search_term {
-      let searcher = fuzzy_search(&search_term);
-
-      let name_or_title_filter = post::name.ilike(searcher.clone());
-
+    let search_url_only = self.search_url_only.unwrap_or_default();
+    if let Some(search_term) = &self.search_term {
       // A url / cross-post search
-      query = if self.search_url_only.unwrap_or_default() {
+      if search_url_only {
         // Parse and normalize the url, removing tracking parameters (same logic which is used
         // when creating a new post).
-        let normalized_url = Url::parse(&search_term).map(|u| clean_url(&u).to_string())?;
+        let normalized_url = Url::parse(search_term).map(|u| clean_url(&u).to_string())?;
 
-        query.filter(post::url.eq(normalized_url))
-      } else if self.search_title_only.unwrap_or_default() {
-        query.filter(name_or_title_filter)
+        query = query.filter(post::url.eq(normalized_url));
       } else {
-        let body_or_description_filter = post::body.ilike(searcher.clone());
-        query.filter(name_or_title_filter.or(body_or_description_filter))
+        let mut matches = post_search::table
+          .filter(post_search::search_vector.matches(search_query(
+            search_term.clone(),
+            self.local_user.interface_language(),
+          )))
+          .select(post_search::post_id)
+          .into_boxed();
+        if self.search_title_only.unwrap_or_default() {
+          matches = matches.filter(search_vector_title(post_search::search_vector).matches(
+            search_query(search_term.clone(), self.local_user.interface_language()),
+          ));
+        }
+        query = query.filter(post::id.eq_any(matches));
       }
     }
 
@@ -518,6 +597,13 @@ impl PostQuery<'_> {
         .or(post::creator_id.nullable().eq(my_person_id)),
     );
 
+    // Posts held in the mod queue are only shown to their creator. Mods see them in the queue.
+    query = query.filter(
+      post::approval_pending
+        .eq(false)
+        .or(post::creator_id.nullable().eq(my_person_id)),
+    );
+
     // Dont filter blocks or missing languages for moderator view type
     if self.listing_type.unwrap_or_default() != ListingType::ModeratorView {
       // Filter out the rows with missing languages if user is logged in
@@ -561,6 +647,38 @@ impl PostQuery<'_> {
       query = query.filter(tags.eq(tag_id));
     }
 
+    // Best search matches first. There is no unique key for the rank, so this uses offset
+    // pagination.
+    if let (Some(SearchSortType::Relevance), Some(search_term), false) =
+      (self.search_sort, &self.search_term, search_url_only)
+    {
+      let offset = self
+        .page_cursor
+        .map(PaginationCursor::offset)
+        .transpose()?
+        .unwrap_or_default();
+      let rank = post_search::table
+        .filter(post_search::post_id.eq(post::id))
+        .select(ts_rank(
+          post_search::search_vector,
+          search_query(search_term.clone(), self.local_user.interface_language()),
+        ))
+        .single_value();
+      let query = query
+        .order_by(rank.desc())
+        .then_order_by(post::id.desc())
+        .offset(offset);
+
+      debug!("Post View Query: {:?}", debug_query::<Pg, _>(&query));
+      let conn = &mut get_conn(pool).await?;
+      let res = Commented::new(query)
+        .text("PostQuery::list")
+        .load::<PostView>(conn)
+        .await
+        .with_lemmy_type(LemmyErrorType::NotFound)?;
+      return paginate_offset_response(res, limit, offset);
+    }
+
     // Only sort by ascending for Old
     let sort = self.sort.unwrap_or(PostSortType::Hot);
     let sort_direction = asc_if(sort == PostSortType::Old);
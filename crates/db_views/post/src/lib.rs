@@ -1,10 +1,13 @@
 use chrono::{DateTime, Utc};
 use lemmy_db_schema::source::{
   community::{Community, CommunityActions},
+  community_flair::PersonFlair,
   community_tag::CommunityTagsView,
   images::ImageDetails,
   person::{Person, PersonActions},
+  poll::PollView,
   post::{Post, PostActions},
+  reaction::ReactionCountsView,
 };
 use serde::{Deserialize, Serialize};
 #[cfg(test)]
@@ -20,12 +23,15 @@ use {
     CreatorLocalHomeBanExpiresType,
     creator_ban_expires_from_community,
     creator_banned_from_community,
+    creator_community_flair_fragment,
     creator_is_admin,
     creator_is_moderator,
     creator_local_home_ban_expires,
     creator_local_home_community_banned,
     local_user_can_mod,
     post_community_tags_fragment,
+    post_poll_fragment,
+    post_reactions_fragment,
   },
 };
 
@@ -70,6 +76,18 @@ pub struct PostView {
     )
   )]
   pub tags: CommunityTagsView,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = post_poll_fragment()
+    )
+  )]
+  pub poll: Option<PollView>,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = post_reactions_fragment()
+    )
+  )]
+  pub reactions: ReactionCountsView,
   #[cfg_attr(feature = "full",
     diesel(
       select_expression = local_user_can_mod()
@@ -107,4 +125,10 @@ pub struct PostView {
     )
   )]
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = creator_community_flair_fragment()
+    )
+  )]
+  pub creator_flair: Option<PersonFlair>,
 }
@@ -5,9 +5,10 @@ use chrono::{DateTime, Days, Utc};
 use diesel_async::SimpleAsyncConnection;
 use diesel_uplete::UpleteCount;
 use lemmy_db_schema::{
+  SearchSortType,
   assert_length,
   impls::actor_language::UNDETERMINED_ID,
-  newtypes::{LanguageId, PostId},
+  newtypes::{LanguageId, PostId, PostOrCommentId},
   source::{
     actor_language::LocalUserLanguage,
     comment::{Comment, CommentInsertForm},
@@ -19,8 +20,10 @@ use lemmy_db_schema::{
       CommunityInsertForm,
       CommunityModeratorForm,
       CommunityPersonBanForm,
+      CommunityPersonFlairForm,
       CommunityUpdateForm,
     },
+    community_flair::{CommunityFlair, CommunityFlairInsertForm, CommunityFlairUpdateForm},
     community_tag::{CommunityTag, CommunityTagInsertForm, PostCommunityTag},
     instance::{
       Instance,
@@ -34,8 +37,16 @@ use lemmy_db_schema::{
     local_site::{LocalSite, LocalSiteUpdateForm},
     local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
     multi_community::{MultiCommunity, MultiCommunityInsertForm},
-    person::{Person, PersonActions, PersonBlockForm, PersonInsertForm, PersonNoteForm},
+    person::{
+      Person,
+      PersonActions,
+      PersonBlockForm,
+      PersonFollowerForm,
+      PersonInsertForm,
+      PersonNoteForm,
+    },
     post::{Post, PostActions, PostHideForm, PostInsertForm, PostLikeForm, PostUpdateForm},
+    reaction::{Reaction, ReactionInsertForm},
     site::Site,
   },
   test_data::TestData,
@@ -992,6 +1003,88 @@ async fn post_listings_deleted(data: &mut Data) -> LemmyResult<()> {
   Ok(())
 }
 
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_listings_approval_pending(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  // Hold the bot post in the mod queue
+  Post::update(
+    pool,
+    data.bot_post.id,
+    &PostUpdateForm {
+      approval_pending: Some(true),
+      ..Default::default()
+    },
+  )
+  .await?;
+
+  // Pending post is only listed for its creator
+  for (local_user, expect_contains_pending) in [
+    (None, false),
+    (Some(&data.john.local_user), false),
+    (Some(&data.tegan.local_user), false),
+    (Some(&data.bot.local_user), true),
+  ] {
+    let contains_pending = PostQuery {
+      local_user,
+      ..data.default_post_query()
+    }
+    .list(pool, &data.site, &data.local_site)
+    .await?
+    .iter()
+    .any(|p| p.post.id == data.bot_post.id);
+
+    assert_eq!(expect_contains_pending, contains_pending);
+  }
+
+  // Admins can read it and see it in the mod queue
+  let post_view = PostView::read(
+    pool,
+    data.bot_post.id,
+    Some(&data.tegan.local_user),
+    data.instance.id,
+    true,
+  )
+  .await?;
+  assert!(post_view.post.approval_pending);
+  let queue = PostView::list_approval_pending(
+    pool,
+    &data.tegan.local_user,
+    data.instance.id,
+    None,
+    true,
+    None,
+  )
+  .await?;
+  assert_eq!(vec![POST_BY_BOT], names(&queue));
+
+  // Other users can't read it, and john doesn't moderate any communities
+  let read = PostView::read(
+    pool,
+    data.bot_post.id,
+    Some(&data.john.local_user),
+    data.instance.id,
+    false,
+  )
+  .await;
+  assert!(read.is_err());
+  let queue = PostView::list_approval_pending(
+    pool,
+    &data.john.local_user,
+    data.instance.id,
+    Some(data.community.id),
+    false,
+    None,
+  )
+  .await?;
+  assert!(queue.is_empty());
+
+  Ok(())
+}
+
 #[test_context(Data)]
 #[tokio::test]
 #[serial]
@@ -1039,6 +1132,40 @@ async fn post_listings_hidden_community(data: &mut Data) -> LemmyResult<()> {
   Ok(())
 }
 
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_listing_followed_persons(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  let query = PostQuery {
+    listing_type: Some(ListingType::FollowedPersons),
+    local_user: Some(&data.john.local_user),
+    ..data.default_post_query()
+  };
+  let posts = query
+    .clone()
+    .list(pool, &data.site, &data.local_site)
+    .await?;
+  assert!(posts.is_empty());
+
+  // Pending follows are not included
+  let form = PersonFollowerForm::new(data.bot.person.id, data.john.person.id, true);
+  PersonActions::follow(pool, &form).await?;
+  let posts = query
+    .clone()
+    .list(pool, &data.site, &data.local_site)
+    .await?;
+  assert!(posts.is_empty());
+
+  PersonActions::follow_accepted(pool, data.bot.person.id, data.john.person.id).await?;
+  let posts = query.list(pool, &data.site, &data.local_site).await?;
+  assert_eq!(vec![POST_BY_BOT], names(&posts));
+
+  Ok(())
+}
+
 #[test_context(Data)]
 #[tokio::test]
 #[serial]
@@ -2315,6 +2442,116 @@ async fn post_tags_present(data: &mut Data) -> LemmyResult<()> {
   Ok(())
 }
 
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_creator_flair(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  let flair_form = CommunityFlairInsertForm {
+    ap_id: Url::parse(&format!("{}/flair/crew", data.community.ap_id))?.into(),
+    name: "crew".to_string(),
+    display_name: Some("Crew".to_string()),
+    community_id: data.community.id,
+    color: None,
+    self_assignable: None,
+    deleted: None,
+  };
+  let flair = CommunityFlair::create(pool, &flair_form).await?;
+  let form = CommunityPersonFlairForm::new(data.community.id, data.tegan.person.id, flair.id);
+  CommunityFlair::assign(pool, &form).await?;
+
+  let all_posts = data
+    .default_post_query()
+    .list(pool, &data.site, &data.local_site)
+    .await?;
+  for post_view in all_posts.iter() {
+    let flair_name = post_view.creator_flair.as_ref().map(|f| f.0.name.as_str());
+    if post_view.creator.id == data.tegan.person.id {
+      assert_eq!(Some("crew"), flair_name);
+    } else {
+      assert_eq!(None, flair_name);
+    }
+  }
+
+  // Deleted flairs are not shown
+  let update_form = CommunityFlairUpdateForm {
+    deleted: Some(true),
+    ..Default::default()
+  };
+  CommunityFlair::update(pool, flair.id, &update_form).await?;
+  let post_view = PostView::read(
+    pool,
+    data.post.id,
+    Some(&data.john.local_user),
+    data.instance.id,
+    false,
+  )
+  .await?;
+  assert!(post_view.creator_flair.is_none());
+
+  Ok(())
+}
+
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_reactions(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  let post_id = data.post.id;
+  let mut form = ReactionInsertForm::new(data.john.person.id, "👍".to_string());
+  form.post_id = Some(post_id);
+  Reaction::add(pool, &form).await?;
+  // Adding the same reaction twice is ignored
+  Reaction::add(pool, &form).await?;
+  form.person_id = data.tegan.person.id;
+  Reaction::add(pool, &form).await?;
+  form.emoji = "🎉".to_string();
+  Reaction::add(pool, &form).await?;
+
+  let read_reactions = async |pool: &mut DbPool<'_>| -> LemmyResult<Vec<(String, i32, bool)>> {
+    let post_view = PostView::read(
+      pool,
+      post_id,
+      Some(&data.john.local_user),
+      data.instance.id,
+      false,
+    )
+    .await?;
+    Ok(
+      post_view
+        .reactions
+        .0
+        .into_iter()
+        .map(|r| (r.emoji, r.count, r.reacted))
+        .collect(),
+    )
+  };
+  assert_eq!(
+    vec![("👍".to_string(), 2, true), ("🎉".to_string(), 1, false)],
+    read_reactions(pool).await?
+  );
+
+  Reaction::remove(
+    pool,
+    data.john.person.id,
+    PostOrCommentId::Post(post_id),
+    "👍",
+  )
+  .await?;
+  let mut reactions = read_reactions(pool).await?;
+  reactions.sort();
+  assert_eq!(
+    vec![("🎉".to_string(), 1, false), ("👍".to_string(), 1, false)],
+    reactions
+  );
+
+  Ok(())
+}
+
 #[test_context(Data)]
 #[tokio::test]
 #[serial]
@@ -2439,5 +2676,46 @@ async fn search(data: &mut Data) -> LemmyResult<()> {
   assert_length!(1, search_url_only);
   assert_eq!(POST_WITH_TAGS, search_url_only[0].post.name);
 
+  // The post is in French, so its words are stemmed and the singular also matches
+  let search_stemmed = PostQuery {
+    search_term: Some("tag".into()),
+    ..Default::default()
+  }
+  .list(pool, &data.site, &data.local_site)
+  .await?;
+  assert_length!(1, search_stemmed);
+  assert_eq!(POST_WITH_TAGS, search_stemmed[0].post.name);
+
+  // Exclude words with a minus
+  let search_exclude = PostQuery {
+    search_term: Some("post -tags".into()),
+    ..Default::default()
+  }
+  .list(pool, &data.site, &data.local_site)
+  .await?;
+  assert!(!search_exclude.is_empty());
+  assert!(!names(&search_exclude).contains(&POST_WITH_TAGS));
+
+  // Quoted phrases need the words in the same order
+  let search_phrase = PostQuery {
+    search_term: Some("\"tags with post\"".into()),
+    ..Default::default()
+  }
+  .list(pool, &data.site, &data.local_site)
+  .await?;
+  assert!(search_phrase.is_empty());
+
+  // Title matches rank higher than body matches
+  let search_relevance = PostQuery {
+    search_term: Some("body or bot".into()),
+    search_sort: Some(SearchSortType::Relevance),
+    local_user: Some(&data.tegan.local_user),
+    ..Default::default()
+  }
+  .list(pool, &data.site, &data.local_site)
+  .await?;
+  assert_eq!(vec![POST_BY_BOT, POST_WITH_TAGS], names(&search_relevance));
+  assert!(search_relevance.next_page.is_none());
+
   Ok(())
 }
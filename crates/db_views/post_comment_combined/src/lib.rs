@@ -2,10 +2,13 @@ use chrono::{DateTime, Utc};
 use lemmy_db_schema::source::{
   comment::{Comment, CommentActions},
   community::{Community, CommunityActions},
+  community_flair::PersonFlair,
   community_tag::CommunityTagsView,
   images::ImageDetails,
   person::{Person, PersonActions},
+  poll::PollView,
   post::{Post, PostActions},
+  reaction::ReactionCountsView,
 };
 use lemmy_db_views_comment::CommentView;
 use lemmy_db_views_post::PostView;
@@ -16,15 +19,19 @@ use {
   lemmy_db_schema::traits::InternalToCombinedView,
   lemmy_db_schema::utils::queries::selects::{
     CreatorLocalHomeCommunityBanExpiresType,
+    comment_reactions_fragment,
     comment_select_remove_deletes,
     creator_ban_expires_from_community,
     creator_banned_from_community,
+    creator_community_flair_fragment,
     creator_is_admin,
     creator_is_moderator,
     creator_local_home_community_ban_expires,
     creator_local_home_community_banned,
     local_user_can_mod,
     post_community_tags_fragment,
+    post_poll_fragment,
+    post_reactions_fragment,
     post_select_remove_deletes,
   },
 };
@@ -56,6 +63,12 @@ pub struct PostCommentCombinedViewInternal {
   pub item_creator_is_admin: bool,
   #[diesel(select_expression = post_community_tags_fragment())]
   pub tags: CommunityTagsView,
+  #[diesel(select_expression = post_poll_fragment())]
+  pub poll: Option<PollView>,
+  #[diesel(select_expression = post_reactions_fragment())]
+  pub post_reactions: ReactionCountsView,
+  #[diesel(select_expression = comment_reactions_fragment())]
+  pub comment_reactions: ReactionCountsView,
   #[diesel(select_expression = local_user_can_mod())]
   pub can_mod: bool,
   #[diesel(select_expression = creator_local_home_community_banned())]
@@ -71,6 +84,8 @@ pub struct PostCommentCombinedViewInternal {
   pub creator_banned_from_community: bool,
   #[diesel(select_expression = creator_ban_expires_from_community())]
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  #[diesel(select_expression = creator_community_flair_fragment())]
+  pub creator_flair: Option<PersonFlair>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -101,12 +116,14 @@ impl InternalToCombinedView for PostCommentCombinedViewInternal {
         person_actions: v.person_actions,
         creator_is_admin: v.item_creator_is_admin,
         tags: v.tags,
+        reactions: v.comment_reactions,
         can_mod: v.can_mod,
         creator_banned: v.creator_banned,
         creator_ban_expires_at: v.creator_ban_expires_at,
         creator_is_moderator: v.creator_is_moderator,
         creator_banned_from_community: v.creator_banned_from_community,
         creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+        creator_flair: v.creator_flair,
       }))
     } else {
       Some(PostCommentCombinedView::Post(PostView {
@@ -119,12 +136,15 @@ impl InternalToCombinedView for PostCommentCombinedViewInternal {
         person_actions: v.person_actions,
         creator_is_admin: v.item_creator_is_admin,
         tags: v.tags,
+        poll: v.poll,
+        reactions: v.post_reactions,
         can_mod: v.can_mod,
         creator_banned: v.creator_banned,
         creator_ban_expires_at: v.creator_ban_expires_at,
         creator_is_moderator: v.creator_is_moderator,
         creator_banned_from_community: v.creator_banned_from_community,
         creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+        creator_flair: v.creator_flair,
       }))
     }
   }
@@ -1,5 +1,8 @@
 use crate::PrivateMessageView;
-use lemmy_db_schema::newtypes::PrivateMessageId;
+use lemmy_db_schema::{
+  newtypes::{PrivateMessageId, ScheduledPrivateMessageId},
+  source::scheduled::ScheduledPrivateMessage,
+};
 use lemmy_db_schema_file::PersonId;
 use serde::{Deserialize, Serialize};
 
@@ -37,3 +40,39 @@ pub struct EditPrivateMessage {
 pub struct PrivateMessageResponse {
   pub private_message_view: PrivateMessageView,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Schedule a private message, which is sent once the time is reached.
+pub struct CreateScheduledPrivateMessage {
+  pub content: String,
+  pub recipient_id: PersonId,
+  /// Unix timestamp when the message should be sent.
+  pub scheduled_publish_time_at: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edit a scheduled private message before it is sent.
+pub struct EditScheduledPrivateMessage {
+  pub scheduled_private_message_id: ScheduledPrivateMessageId,
+  pub content: Option<String>,
+  pub scheduled_publish_time_at: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Cancel a scheduled private message.
+pub struct DeleteScheduledPrivateMessage {
+  pub scheduled_private_message_id: ScheduledPrivateMessageId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ScheduledPrivateMessageResponse {
+  pub scheduled_private_message: ScheduledPrivateMessage,
+}
@@ -265,6 +265,9 @@ mod tests {
         show_upvote_percentage: sara_local_user.show_upvote_percentage,
         show_person_votes: sara_local_user.show_person_votes,
         invited_by_local_user_id: sara_local_user.invited_by_local_user_id,
+        email_digest_cadence: sara_local_user.email_digest_cadence,
+        email_digest_top_posts: sara_local_user.email_digest_top_posts,
+        last_email_digest_at: sara_local_user.last_email_digest_at,
       },
       creator: Person {
         id: sara_person.id,
@@ -289,6 +292,9 @@ mod tests {
         post_score: 0,
         comment_count: 0,
         comment_score: 0,
+        also_known_as: vec![],
+        moved_to_id: None,
+        follower_count: 0,
       },
       admin: None,
     };
@@ -363,6 +369,9 @@ mod tests {
       post_score: 0,
       comment_count: 0,
       comment_score: 0,
+      also_known_as: vec![],
+      moved_to_id: None,
+      follower_count: 0,
     });
     assert_eq!(read_sara_app_view_after_approve, expected_sara_app_view);
 
@@ -1,4 +1,10 @@
-use crate::{CommentReportView, CommunityReportView, PostReportView, PrivateMessageReportView};
+use crate::{
+  CommentReportView,
+  CommunityReportView,
+  PersonReportView,
+  PostReportView,
+  PrivateMessageReportView,
+};
 use lemmy_db_schema::{
   ReportSortType,
   ReportType,
@@ -7,12 +13,15 @@ use lemmy_db_schema::{
     CommentReportId,
     CommunityId,
     CommunityReportId,
+    PersonReportId,
     PostId,
     PostReportId,
     PrivateMessageId,
     PrivateMessageReportId,
+    RuleId,
   },
 };
+use lemmy_db_schema_file::PersonId;
 use lemmy_diesel_utils::pagination::PaginationCursor;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -40,6 +49,10 @@ pub struct ListReports {
   pub show_community_rule_violations: Option<bool>,
   /// If true, view all your created reports. Works for non-admins/mods also.
   pub my_reports_only: Option<bool>,
+  /// Only show post and comment reports which cite this rule.
+  pub rule_id: Option<RuleId>,
+  /// Only show reports about the content of this user, or about the user themselves.
+  pub item_creator_id: Option<PersonId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -68,6 +81,9 @@ pub struct CreateCommentReport {
   /// The comment violates rules of the local instance. This report will only be shown to local
   /// admins, not to community mods and will not be federated.
   pub violates_instance_rules: Option<bool>,
+  /// The community or site rule which was broken. Citing a site rule also sets
+  /// `violates_instance_rules`.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
@@ -79,6 +95,16 @@ pub struct CreateCommunityReport {
   pub reason: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Report a user profile, for example because of a spam bio or an offensive username. The report
+/// is shown to the admins of the local instance and of the user's home instance.
+pub struct CreatePersonReport {
+  pub person_id: PersonId,
+  pub reason: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -89,6 +115,9 @@ pub struct CreatePostReport {
   /// The post violates rules of the local instance. This report will only be shown to local
   /// admins, not to community mods and will not be federated.
   pub violates_instance_rules: Option<bool>,
+  /// The community or site rule which was broken. Citing a site rule also sets
+  /// `violates_instance_rules`.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
@@ -111,6 +140,16 @@ pub struct ResolveCommunityReport {
   pub conclusion: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Resolve a person report (admins only).
+pub struct ResolvePersonReport {
+  pub report_id: PersonReportId,
+  pub resolved: bool,
+  pub conclusion: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -121,6 +160,19 @@ pub struct ResolvePostReport {
   pub conclusion: Option<String>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Resolve many post and comment reports of a community at once (mods only).
+pub struct BulkResolveReports {
+  pub community_id: CommunityId,
+  pub post_report_ids: Option<Vec<PostReportId>>,
+  pub comment_report_ids: Option<Vec<CommentReportId>>,
+  pub resolved: bool,
+  pub conclusion: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -148,6 +200,14 @@ pub struct PrivateMessageReportResponse {
   pub private_message_report_view: PrivateMessageReportView,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A person report response.
+pub struct PersonReportResponse {
+  pub person_report_view: PersonReportView,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -2,6 +2,7 @@ use crate::{
   CommentReportView,
   CommunityReportView,
   LocalUserView,
+  PersonReportView,
   PostReportView,
   PrivateMessageReportView,
   ReportCombinedView,
@@ -25,9 +26,11 @@ use lemmy_db_schema::{
     CommentReportId,
     CommunityId,
     CommunityReportId,
+    PersonReportId,
     PostId,
     PostReportId,
     PrivateMessageReportId,
+    RuleId,
   },
   source::{
     combined::report::{ReportCombined, report_combined_keys as key},
@@ -36,11 +39,9 @@ use lemmy_db_schema::{
   traits::InternalToCombinedView,
   utils::limit_fetch,
 };
-use lemmy_db_schema_file::schema::{
-  comment_report,
-  community_actions,
-  post_report,
-  report_combined,
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{comment_report, community_actions, post_report, report_combined},
 };
 use lemmy_db_views_report_combined_sql::report_combined_joins;
 use lemmy_diesel_utils::{
@@ -113,6 +114,25 @@ impl ReportCombinedViewInternal {
     Ok(c)
   }
 
+  pub async fn read_person_report(
+    pool: &mut DbPool<'_>,
+    report_id: PersonReportId,
+    my_person: &Person,
+  ) -> LemmyResult<PersonReportView> {
+    let conn = &mut get_conn(pool).await?;
+    let res = report_combined_joins(my_person.id, my_person.instance_id)
+      .filter(report_combined::person_report_id.eq(report_id))
+      .select(ReportCombinedViewInternal::as_select())
+      .first(conn)
+      .await?;
+
+    let res = InternalToCombinedView::map_to_enum(res);
+    let Some(ReportCombinedView::Person(p)) = res else {
+      return Err(LemmyErrorType::NotFound.into());
+    };
+    Ok(p)
+  }
+
   pub async fn read_private_message_report(
     pool: &mut DbPool<'_>,
     report_id: PrivateMessageReportId,
@@ -165,6 +185,7 @@ impl PaginationCursorConversion for ReportCombinedView {
       ReportCombinedView::Post(v) => ('P', v.post_report.id.0),
       ReportCombinedView::PrivateMessage(v) => ('M', v.private_message_report.id.0),
       ReportCombinedView::Community(v) => ('Y', v.community_report.id.0),
+      ReportCombinedView::Person(v) => ('U', v.person_report.id.0),
     };
     CursorData::new_with_prefix(prefix, id)
   }
@@ -185,6 +206,7 @@ impl PaginationCursorConversion for ReportCombinedView {
       'P' => query.filter(report_combined::post_report_id.eq(id)),
       'M' => query.filter(report_combined::private_message_report_id.eq(id)),
       'Y' => query.filter(report_combined::community_report_id.eq(id)),
+      'U' => query.filter(report_combined::person_report_id.eq(id)),
       _ => return Err(LemmyErrorType::CouldntParsePaginationToken.into()),
     };
     let token = query.first(conn).await?;
@@ -202,6 +224,9 @@ pub struct ReportCombinedQuery {
   /// For admins, also show reports with `violates_instance_rules=false`
   pub show_community_rule_violations: Option<bool>,
   pub my_reports_only: Option<bool>,
+  pub rule_id: Option<RuleId>,
+  /// Only show reports about the content of this user, or about the user themselves.
+  pub item_creator_id: Option<PersonId>,
   pub sort: Option<ReportSortType>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
@@ -237,6 +262,18 @@ impl ReportCombinedQuery {
       query = query.filter(report_combined::post_id.eq(post_id));
     }
 
+    if let Some(rule_id) = self.rule_id {
+      query = query.filter(
+        post_report::rule_id
+          .eq(rule_id)
+          .or(comment_report::rule_id.eq(rule_id)),
+      );
+    }
+
+    if let Some(item_creator_id) = self.item_creator_id {
+      query = query.filter(report_combined::item_creator_id.eq(item_creator_id));
+    }
+
     if self.my_reports_only.unwrap_or_default() {
       query = query.filter(report_combined::report_creator_id.eq(user.person.id));
     }
@@ -250,6 +287,7 @@ impl ReportCombinedQuery {
           query.filter(report_combined::private_message_report_id.is_not_null())
         }
         ReportType::Communities => query.filter(report_combined::community_report_id.is_not_null()),
+        ReportType::Persons => query.filter(report_combined::person_report_id.is_not_null()),
       }
     }
 
@@ -295,9 +333,10 @@ impl ReportCombinedQuery {
 fn filter_mod_reports() -> _ {
   community_actions::became_moderator_at
     .is_not_null()
-    // Reporting a community or private message must go to admins
+    // Reporting a community, person or private message must go to admins
     .and(report_combined::community_report_id.is_null())
     .and(report_combined::private_message_report_id.is_null())
+    .and(report_combined::person_report_id.is_null())
     .and(filter_violates_instance_rules().is_distinct_from(true))
 }
 
@@ -319,6 +358,7 @@ fn filter_violates_instance_rules() -> _ {
     .or(comment_report::violates_instance_rules)
     .or(report_combined::community_report_id.is_not_null())
     .or(report_combined::private_message_report_id.is_not_null())
+    .or(report_combined::person_report_id.is_not_null())
 }
 
 impl InternalToCombinedView for ReportCombinedViewInternal {
@@ -384,6 +424,17 @@ impl InternalToCombinedView for ReportCombinedViewInternal {
         creator_community_ban_expires_at: v.creator_community_ban_expires_at,
         tags: v.tags,
       }))
+    } else if let (Some(person_report), Some(person)) = (v.person_report, v.creator.clone()) {
+      Some(ReportCombinedView::Person(PersonReportView {
+        person_report,
+        person,
+        creator: v.report_creator,
+        resolver: v.resolver,
+        person_actions: v.person_actions,
+        creator_is_admin: v.creator_is_admin,
+        creator_banned: v.creator_banned,
+        creator_ban_expires_at: v.creator_ban_expires_at,
+      }))
     } else if let (
       Some(private_message_report),
       Some(private_message),
@@ -445,6 +496,7 @@ mod tests {
       instance::{Instance, InstanceActions, InstanceBanForm},
       local_user::{LocalUser, LocalUserInsertForm},
       person::{Person, PersonInsertForm},
+      person_report::{PersonReport, PersonReportForm, UpdatePersonReportForm},
       post::{Post, PostInsertForm},
       post_report::{PostReport, PostReportForm, UpdatePostReportForm},
       private_message::{PrivateMessage, PrivateMessageInsertForm},
@@ -453,6 +505,7 @@ mod tests {
         PrivateMessageReportForm,
         UpdatePrivateMessageReportForm,
       },
+      rule::{Rule, RuleInsertForm},
     },
     traits::{Bannable, Reportable},
   };
@@ -595,16 +648,20 @@ mod tests {
       original_post_body: None,
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     let inserted_post_report = PostReport::report(pool, &sara_report_post_form).await?;
 
-    // Sara reports the comment
+    // Sara reports the comment, citing a community rule
+    let rule_form = RuleInsertForm::new(Some(data.community.id), 1, "Be nice".into());
+    let rule = Rule::create(pool, &rule_form).await?;
     let sara_report_comment_form = CommentReportForm {
       creator_id: data.sara.id,
       comment_id: data.comment.id,
       original_comment_text: "A test comment rv".into(),
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: Some(rule.id),
     };
     CommentReport::report(pool, &sara_report_comment_form).await?;
 
@@ -686,6 +743,19 @@ mod tests {
     .await?;
     assert_length!(2, reports_by_post_id);
 
+    // Filter by the cited rule
+    let reports_by_rule = ReportCombinedQuery {
+      rule_id: Some(rule.id),
+      ..Default::default()
+    }
+    .list(pool, &data.timmy_view)
+    .await?;
+    assert_length!(1, reports_by_rule);
+    assert!(matches!(
+      &reports_by_rule[0],
+      ReportCombinedView::Comment(v) if v.comment_report.rule_id == Some(rule.id)
+    ));
+
     // Timmy should only see 2 reports, since they're not an admin,
     // but they do mod the community
     let timmys_reports = ReportCombinedQuery::default()
@@ -830,6 +900,7 @@ mod tests {
       original_post_body: None,
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
 
     PostReport::report(pool, &sara_report_form).await?;
@@ -843,6 +914,7 @@ mod tests {
       original_post_body: None,
       reason: "from jessica".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
 
     let inserted_jessica_report = PostReport::report(pool, &jessica_report_form).await?;
@@ -961,6 +1033,7 @@ mod tests {
       original_comment_text: "this was it at time of creation".into(),
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
 
     CommentReport::report(pool, &sara_report_form).await?;
@@ -972,6 +1045,7 @@ mod tests {
       original_comment_text: "this was it at time of creation".into(),
       reason: "from jessica".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
 
     let inserted_jessica_report = CommentReport::report(pool, &jessica_report_form).await?;
@@ -1156,6 +1230,115 @@ mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn person_reports() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    // jessica reports sara
+    let person_report_form = PersonReportForm {
+      creator_id: data.jessica.id,
+      person_id: data.sara.id,
+      original_person_name: data.sara.name.clone(),
+      original_person_display_name: None,
+      original_person_bio: Some("spam profile".into()),
+      original_person_avatar: None,
+      original_person_banner: None,
+      reason: "spam account".into(),
+    };
+    let person_report = PersonReport::report(pool, &person_report_form).await?;
+
+    // Mods cant see person reports
+    let reports = ReportCombinedQuery::default()
+      .list(pool, &data.timmy_view)
+      .await?;
+    assert_length!(0, reports);
+
+    let reports = ReportCombinedQuery {
+      type_: Some(ReportType::Persons),
+      ..Default::default()
+    }
+    .list(pool, &data.admin_view)
+    .await?;
+    assert_length!(1, reports);
+    if let ReportCombinedView::Person(v) = &reports[0] {
+      assert!(!v.person_report.resolved);
+      assert_eq!(data.jessica.name, v.creator.name);
+      assert_eq!(data.sara.name, v.person.name);
+      assert_eq!(person_report.reason, v.person_report.reason);
+      assert_eq!(
+        Some("spam profile".to_string()),
+        v.person_report.original_person_bio
+      );
+      let read_report = ReportCombinedViewInternal::read_person_report(
+        pool,
+        person_report.id,
+        &data.admin_view.person,
+      )
+      .await?;
+      assert_eq!(&read_report, v);
+    } else {
+      panic!("wrong type");
+    }
+
+    let update_form = UpdatePersonReportForm {
+      resolver_id: Some(data.admin_view.person.id),
+      resolved: Some(true),
+      conclusion: Some(Some("Account was banned".to_string())),
+      ..Default::default()
+    };
+
+    // admin resolves the report (after taking appropriate action)
+    PersonReport::update_resolved(pool, person_report.id, &update_form).await?;
+
+    let reports = ReportCombinedQuery {
+      type_: Some(ReportType::Persons),
+      unresolved_only: Some(true),
+      ..Default::default()
+    }
+    .list(pool, &data.admin_view)
+    .await?;
+    assert_length!(0, reports);
+
+    let reports = ReportCombinedQuery {
+      type_: Some(ReportType::Persons),
+      ..Default::default()
+    }
+    .list(pool, &data.admin_view)
+    .await?;
+    assert_length!(1, reports);
+    if let ReportCombinedView::Person(v) = &reports[0] {
+      assert!(v.person_report.resolved);
+      assert_eq!(
+        Some(&data.admin_view.person.name),
+        v.resolver.as_ref().map(|r| &r.name)
+      );
+    } else {
+      panic!("wrong type");
+    }
+
+    // A second report is resolved when the reported person gets banned
+    let timmy_report_form = PersonReportForm {
+      creator_id: data.timmy.id,
+      ..person_report_form
+    };
+    PersonReport::report(pool, &timmy_report_form).await?;
+    PersonReport::resolve_all_for_object(pool, data.sara.id, data.admin_view.person.id).await?;
+    let reports = ReportCombinedQuery {
+      unresolved_only: Some(true),
+      ..Default::default()
+    }
+    .list(pool, &data.admin_view)
+    .await?;
+    assert_length!(0, reports);
+
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn violates_instance_rules() -> LemmyResult<()> {
@@ -1172,6 +1355,7 @@ mod tests {
       original_post_body: None,
       reason: "from sara".into(),
       violates_instance_rules: true,
+      rule_id: None,
     };
     PostReport::report(pool, &report_form).await?;
 
@@ -1201,6 +1385,7 @@ mod tests {
       original_comment_text: "this was it at time of creation".into(),
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     let comment_report = CommentReport::report(pool, &report_form).await?;
 
@@ -1260,6 +1445,7 @@ mod tests {
       original_comment_text: "this was it at time of creation".into(),
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     CommentReport::report(pool, &sara_report_form).await?;
 
@@ -1270,6 +1456,7 @@ mod tests {
       original_comment_text: "this was it at time of creation".into(),
       reason: "from timmy".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     CommentReport::report(pool, &timmy_report_form).await?;
 
@@ -1316,6 +1503,7 @@ mod tests {
       original_post_body: None,
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     let inserted_sara_report = PostReport::report(pool, &sara_report_form).await?;
 
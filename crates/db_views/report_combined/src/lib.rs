@@ -7,6 +7,7 @@ use lemmy_db_schema::source::{
   community_report::CommunityReport,
   community_tag::CommunityTagsView,
   person::{Person, PersonActions},
+  person_report::PersonReport,
   post::{Post, PostActions},
   post_report::PostReport,
   private_message::PrivateMessage,
@@ -52,6 +53,8 @@ pub struct ReportCombinedViewInternal {
   pub private_message_report: Option<PrivateMessageReport>,
   #[diesel(embed)]
   pub community_report: Option<CommunityReport>,
+  #[diesel(embed)]
+  pub person_report: Option<PersonReport>,
   #[diesel(
     select_expression_type = Person1AliasAllColumnsTuple,
     select_expression = person1_select()
@@ -108,6 +111,7 @@ pub enum ReportCombinedView {
   Comment(CommentReportView),
   PrivateMessage(PrivateMessageReportView),
   Community(CommunityReportView),
+  Person(PersonReportView),
 }
 
 #[skip_serializing_none]
@@ -169,6 +173,23 @@ pub struct CommunityReportView {
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A person report view.
+pub struct PersonReportView {
+  pub person_report: PersonReport,
+  /// The reported person.
+  pub person: Person,
+  pub creator: Person,
+  pub resolver: Option<Person>,
+  pub person_actions: Option<PersonActions>,
+  pub creator_is_admin: bool,
+  pub creator_banned: bool,
+  pub creator_ban_expires_at: Option<DateTime<Utc>>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
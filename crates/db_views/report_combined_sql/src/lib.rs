@@ -25,6 +25,7 @@ use lemmy_db_schema_file::{
     local_user,
     person,
     person_actions,
+    person_report,
     post,
     post_actions,
     post_report,
@@ -103,6 +104,7 @@ pub fn report_combined_joins(my_person_id: PersonId, local_instance_id: Instance
     .left_join(comment_report::table)
     .left_join(private_message_report::table)
     .left_join(community_report::table)
+    .left_join(person_report::table)
     .inner_join(report_creator_join)
     .left_join(comment::table)
     .left_join(private_message::table)
@@ -2,28 +2,50 @@ use crate::{ResolveObjectView, SiteView};
 #[cfg(feature = "full")]
 use activitypub_federation::protocol::helpers::deserialize_skip_error;
 use lemmy_db_schema::{
+  SearchSortType,
   SearchType,
-  newtypes::{CommunityId, LanguageId, MultiCommunityId, OAuthProviderId, TaglineId},
+  newtypes::{
+    AutomodRuleId,
+    CommentId,
+    CommunityId,
+    EmailOutboxId,
+    LanguageId,
+    MultiCommunityId,
+    OAuthProviderId,
+    PostId,
+    RuleId,
+    TaglineId,
+    WebhookId,
+  },
   source::{
+    automod_rule::AutomodRule,
     comment::Comment,
     community::Community,
+    email_outbox::EmailOutbox,
     instance::Instance,
     language::Language,
     local_site_url_blocklist::LocalSiteUrlBlocklist,
     local_user::LocalUser,
     login_token::LoginToken,
+    notification_preference::NotificationPreference,
     oauth_provider::{AdminOAuthProvider, PublicOAuthProvider},
     person::Person,
     post::Post,
     private_message::PrivateMessage,
+    rule::{Rule, RuleStats},
+    scheduled::{ScheduledComment, ScheduledPrivateMessage},
     tagline::Tagline,
+    webhook::Webhook,
   },
 };
 use lemmy_db_schema_file::{
   InstanceId,
   PersonId,
   enums::{
+    AutomodAction,
     CommentSortType,
+    EditHistoryVisibility,
+    EmailDigestCadence,
     FederationMode,
     ImageMode,
     ListingType,
@@ -31,6 +53,7 @@ use lemmy_db_schema_file::{
     PostSortType,
     RegistrationMode,
     VoteShow,
+    WebhookEventType,
   },
 };
 use lemmy_db_views_comment::CommentView;
@@ -170,6 +193,15 @@ pub struct CreateSite {
   pub image_allow_video_uploads: Option<bool>,
   pub image_upload_disabled: Option<bool>,
   pub max_invites_per_user_allowed: Option<i32>,
+  /// Who can see previous versions of edited posts and comments.
+  pub edit_history_visibility: Option<EditHistoryVisibility>,
+  /// Automatically ban users from a community after this many warnings, unless the community has
+  /// its own warning policy. Zero disables it.
+  pub warning_ban_threshold: Option<i32>,
+  /// Only count warnings from this many days toward the ban. Zero means no limit.
+  pub warning_ban_window_days: Option<i32>,
+  /// Length of the automatic ban in days. Zero means a permanent ban.
+  pub warning_ban_days: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -307,6 +339,15 @@ pub struct EditSite {
   pub image_allow_video_uploads: Option<bool>,
   pub image_upload_disabled: Option<bool>,
   pub max_invites_per_user_allowed: Option<i32>,
+  /// Who can see previous versions of edited posts and comments.
+  pub edit_history_visibility: Option<EditHistoryVisibility>,
+  /// Automatically ban users from a community after this many warnings, unless the community has
+  /// its own warning policy. Zero disables it.
+  pub warning_ban_threshold: Option<i32>,
+  /// Only count warnings from this many days toward the ban. Zero means no limit.
+  pub warning_ban_window_days: Option<i32>,
+  /// Length of the automatic ban in days. Zero means a permanent ban.
+  pub warning_ban_days: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -355,6 +396,10 @@ pub struct GetSiteResponse {
   /// Useful for estimating when your application will be approved.
   pub last_application_duration_seconds: Option<i64>,
   pub captcha_enabled: bool,
+  /// The base64url encoded key for Web Push, which clients need to pass as
+  /// `applicationServerKey` when subscribing.
+  pub vapid_public_key: Option<String>,
+  pub rules: Vec<Rule>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -401,6 +446,27 @@ pub struct DeleteAccount {
   pub delete_content: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Set your other accounts, which are allowed to move to this account. Replaces the existing
+/// aliases.
+pub struct SetAccountAliases {
+  /// Accounts in the form `name@example.com`, or their ActivityPub ids.
+  pub also_known_as: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Move your account to another one, which needs to list this account as an alias. Your
+/// community follows are transferred to the new account.
+pub struct MoveAccount {
+  /// The new account in the form `name@example.com`, or its ActivityPub id.
+  pub target: String,
+  pub password: SensitiveString,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -472,6 +538,7 @@ pub struct MyUserInfo {
   pub person_blocks: Vec<Person>,
   pub keyword_blocks: Vec<String>,
   pub discussion_languages: Vec<LanguageId>,
+  pub notification_preferences: Vec<NotificationPreference>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
@@ -572,6 +639,13 @@ pub struct SaveUserSettings {
   pub hide_posts_with_media: Option<bool>,
   /// Whether to show vote totals given to others.
   pub show_person_votes: Option<bool>,
+  /// Whether notification emails are sent immediately, or bundled into a periodic digest.
+  pub email_digest_cadence: Option<EmailDigestCadence>,
+  /// Whether to include the top posts of subscribed communities in digest emails.
+  pub email_digest_top_posts: Option<bool>,
+  /// Through which channels to receive each type of notification. Types which aren't listed are
+  /// left unchanged.
+  pub notification_preferences: Option<Vec<NotificationPreference>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
@@ -657,6 +731,274 @@ pub struct EditTagline {
   pub content: String,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Create a webhook. Without a community it is a site webhook which can only be created by admins,
+/// otherwise it only receives events of that community and can be created by its mods.
+pub struct CreateWebhook {
+  pub url: String,
+  pub events: Vec<WebhookEventType>,
+  pub community_id: Option<CommunityId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edit a webhook.
+pub struct EditWebhook {
+  pub webhook_id: WebhookId,
+  pub url: Option<String>,
+  pub events: Option<Vec<WebhookEventType>>,
+  pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a webhook.
+pub struct DeleteWebhook {
+  pub webhook_id: WebhookId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Lists the webhooks of a community, or the site webhooks if no community is given.
+pub struct ListWebhooks {
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct WebhookResponse {
+  pub webhook: Webhook,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListWebhooksResponse {
+  pub webhooks: Vec<Webhook>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Lists outgoing emails which gave up after too many failed attempts. Only for admins.
+pub struct ListFailedEmails {
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Queues a failed email to be sent again. Only for admins.
+pub struct ResendEmail {
+  pub email_outbox_id: EmailOutboxId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct EmailOutboxResponse {
+  pub email: EmailOutbox,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Create an automod rule. Without a community it is a site rule which can only be created by
+/// admins, otherwise it only applies to that community and can be created by its mods.
+///
+/// At least one condition needs to be set. All conditions which are set need to match for the
+/// rule to trigger.
+pub struct CreateAutomodRule {
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+  pub action: AutomodAction,
+  /// Required for the reply action.
+  pub reply_text: Option<String>,
+  pub check_posts: Option<bool>,
+  pub check_comments: Option<bool>,
+  pub content_regex: Option<String>,
+  pub link_domain: Option<String>,
+  pub max_account_age_days: Option<i32>,
+  pub max_creator_score: Option<i32>,
+  pub report_count: Option<i32>,
+  pub language_id: Option<LanguageId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edit an automod rule. The conditions are replaced with the given ones.
+pub struct EditAutomodRule {
+  pub automod_rule_id: AutomodRuleId,
+  pub reason: Option<String>,
+  pub action: Option<AutomodAction>,
+  pub reply_text: Option<String>,
+  pub check_posts: Option<bool>,
+  pub check_comments: Option<bool>,
+  pub content_regex: Option<String>,
+  pub link_domain: Option<String>,
+  pub max_account_age_days: Option<i32>,
+  pub max_creator_score: Option<i32>,
+  pub report_count: Option<i32>,
+  pub language_id: Option<LanguageId>,
+  pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete an automod rule.
+pub struct DeleteAutomodRule {
+  pub automod_rule_id: AutomodRuleId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Lists the automod rules of a community, or the site rules if no community is given.
+pub struct ListAutomodRules {
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct AutomodRuleResponse {
+  pub automod_rule: AutomodRule,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListAutomodRulesResponse {
+  pub automod_rules: Vec<AutomodRule>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Create a rule. Without a community it is a site rule which can only be created by admins,
+/// otherwise it is a rule of that community and can be created by its mods.
+pub struct CreateRule {
+  pub community_id: Option<CommunityId>,
+  pub title: String,
+  pub description: Option<String>,
+  /// Defaults to after the last rule.
+  pub position: Option<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edit a rule.
+pub struct EditRule {
+  pub rule_id: RuleId,
+  pub title: Option<String>,
+  /// An empty description removes it.
+  pub description: Option<String>,
+  pub position: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a rule. Reports and modlog entries which cite it still reference the rule.
+pub struct DeleteRule {
+  pub rule_id: RuleId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Lists the rules of a community, or the site rules if no community is given.
+pub struct ListRules {
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RuleResponse {
+  pub rule: Rule,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListRulesResponse {
+  pub rules: Vec<Rule>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Counts how often each rule of a community, or each site rule, was cited by reports, removals
+/// and bans. Only for mods of the community, or admins for site rules.
+pub struct GetRuleStats {
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct GetRuleStatsResponse {
+  pub stats: Vec<RuleStats>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Lists the posts and comments which are held for approval, oldest first. Without a community
+/// it lists the items of all communities you moderate.
+pub struct ListModQueue {
+  pub community_id: Option<CommunityId>,
+  /// Only for admins, show items of communities which you dont moderate
+  pub all_communities: Option<bool>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListModQueueResponse {
+  pub posts: Vec<PostView>,
+  pub comments: Vec<CommentView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Approve or reject a post or comment from the mod queue. Approved items become visible and are
+/// federated, rejected items are removed.
+pub struct ApproveModQueueItem {
+  /// Either a post or a comment needs to be given.
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  pub approve: bool,
+  /// The removal reason, if rejected.
+  pub reason: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[cfg_attr(feature = "plugins", derive(FromBytes))]
 #[cfg_attr(feature = "plugins", encoding(Json))]
@@ -695,12 +1037,16 @@ pub struct ResolveObject {
 pub struct Search {
   /// The search query. Can be a plain text, or an object ID which will be resolved
   /// (eg `https://lemmy.world/comment/1` or `!fediverse@lemmy.ml`).
+  ///
+  /// Plain text supports `"quoted phrases"`, `-excluded` words and the operators
+  /// `author:name@example.com`, `community:name@example.com` and `instance:example.com`.
   pub search_term: String,
   pub community_id: Option<CommunityId>,
   pub community_name: Option<String>,
   pub creator_id: Option<PersonId>,
   pub creator_username: Option<String>,
   pub type_: Option<SearchType>,
+  pub sort: Option<SearchSortType>,
   /// Filter to within a given time range, in seconds.
   /// IE 60 would give results for the past minute.
   pub time_range_seconds: Option<i32>,
@@ -803,6 +1149,17 @@ pub struct ExportDataResponse {
   pub settings: UserSettingsBackup,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Your posts, comments and private messages which are not published yet. Scheduled posts are
+/// changed with `EditPost` and cancelled with `DeletePost`.
+pub struct ListScheduledResponse {
+  pub posts: Vec<Post>,
+  pub comments: Vec<ScheduledComment>,
+  pub private_messages: Vec<ScheduledPrivateMessage>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -818,15 +1175,18 @@ impl Default for SuccessResponse {
 }
 
 /// Contains the amount of unread items of various types. For normal users this means the number of
-/// unread notifications, mods and admins get additional unread counts for reports, registration
-/// applications and pending follows to private communities.
+/// unread notifications, mods and admins get additional unread counts for reports, unresolved
+/// modmail, registration applications and pending follows to private communities.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
 pub struct UnreadCountsResponse {
   pub notification_count: i64,
+  /// The number of notification groups which contain unread notifications.
+  pub notification_group_count: i64,
   pub report_count: Option<i64>,
+  pub modmail_count: Option<i64>,
   pub pending_follow_count: Option<i64>,
   pub registration_application_count: Option<i64>,
 }
@@ -277,6 +277,23 @@ WHERE
 RETURN NULL;
 END;
 $$);
+CALL r.create_triggers ('person_actions', $$
+BEGIN
+    UPDATE
+        person AS a
+    SET
+        follower_count = a.follower_count + diff.follower_count
+    FROM (
+        SELECT
+            (person_actions).target_id, coalesce(sum(count_diff), 0) AS follower_count
+        FROM select_old_and_new_rows AS old_and_new_rows
+        WHERE (person_actions).followed_at IS NOT NULL GROUP BY (person_actions).target_id) AS diff
+WHERE
+    a.id = diff.target_id
+        AND diff.follower_count != 0;
+RETURN NULL;
+END;
+$$);
 CALL r.create_triggers ('post_report', $$
 BEGIN
     UPDATE
@@ -324,6 +341,22 @@ WHERE (diff.report_count, diff.unresolved_report_count) != (0, 0)
 RETURN NULL;
 END;
 $$);
+CALL r.create_triggers ('poll_vote', $$
+BEGIN
+    UPDATE
+        poll_option AS a
+    SET
+        vote_count = a.vote_count + diff.vote_count
+    FROM (
+        SELECT
+            (poll_vote).poll_option_id, coalesce(sum(count_diff), 0) AS vote_count
+        FROM select_old_and_new_rows AS old_and_new_rows GROUP BY (poll_vote).poll_option_id) AS diff
+WHERE
+    diff.vote_count != 0
+    AND a.id = diff.poll_option_id;
+RETURN NULL;
+END;
+$$);
 -- Change the order of some cascading deletions to make deletion triggers run before the deletion of rows that the triggers need to read
 CREATE FUNCTION r.delete_follow_before_person ()
     RETURNS TRIGGER
@@ -394,6 +427,111 @@ CREATE TRIGGER change_values
     BEFORE INSERT ON private_message
     FOR EACH ROW
     EXECUTE FUNCTION r.private_message_change_values ();
+CREATE FUNCTION r.conversation_change_values ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    -- Set local ap_id
+    IF NEW.local THEN
+        NEW.ap_id = coalesce(NEW.ap_id, r.local_url ('/conversation/' || NEW.id::text));
+    END IF;
+    RETURN NEW;
+END
+$$;
+CREATE TRIGGER change_values
+    BEFORE INSERT ON conversation
+    FOR EACH ROW
+    EXECUTE FUNCTION r.conversation_change_values ();
+CREATE FUNCTION r.conversation_message_change_values ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    -- Set local ap_id
+    IF NEW.local THEN
+        NEW.ap_id = coalesce(NEW.ap_id, r.local_url ('/conversation_message/' || NEW.id::text));
+    END IF;
+    RETURN NEW;
+END
+$$;
+CREATE TRIGGER change_values
+    BEFORE INSERT ON conversation_message
+    FOR EACH ROW
+    EXECUTE FUNCTION r.conversation_message_change_values ();
+-- Move the conversation to the top when a new message arrives
+CREATE FUNCTION r.conversation_message_update_last_message ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    UPDATE
+        conversation
+    SET
+        last_message_at = NEW.published_at
+    WHERE
+        id = NEW.conversation_id
+        AND last_message_at < NEW.published_at;
+    RETURN NULL;
+END
+$$;
+CREATE TRIGGER update_last_message
+    AFTER INSERT ON conversation_message
+    FOR EACH ROW
+    EXECUTE FUNCTION r.conversation_message_update_last_message ();
+CREATE FUNCTION r.modmail_message_change_values ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    -- Set local ap_id
+    IF NEW.local THEN
+        NEW.ap_id = coalesce(NEW.ap_id, r.local_url ('/modmail_message/' || NEW.id::text));
+    END IF;
+    RETURN NEW;
+END
+$$;
+CREATE TRIGGER change_values
+    BEFORE INSERT ON modmail_message
+    FOR EACH ROW
+    EXECUTE FUNCTION r.modmail_message_change_values ();
+-- Move the modmail thread to the top when a new message arrives. Internal notes don't count.
+CREATE FUNCTION r.modmail_message_update_last_message ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    UPDATE
+        modmail_thread
+    SET
+        last_message_at = NEW.published_at
+    WHERE
+        id = NEW.modmail_thread_id
+        AND last_message_at < NEW.published_at;
+    RETURN NULL;
+END
+$$;
+CREATE TRIGGER update_last_message
+    AFTER INSERT ON modmail_message
+    FOR EACH ROW
+    WHEN (NOT NEW.internal)
+    EXECUTE FUNCTION r.modmail_message_update_last_message ();
+CREATE FUNCTION r.rule_change_values ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    -- Set local ap_id
+    IF NEW.local THEN
+        NEW.ap_id = coalesce(NEW.ap_id, r.local_url ('/rule/' || NEW.id::text));
+    END IF;
+    RETURN NEW;
+END
+$$;
+CREATE TRIGGER change_values
+    BEFORE INSERT ON rule
+    FOR EACH ROW
+    EXECUTE FUNCTION r.rule_change_values ();
 -- Combined tables triggers
 -- These insert (published_at, item_id) into X_combined tables
 -- Reports (comment_report, post_report, private_message_report)
@@ -571,6 +709,42 @@ CREATE TRIGGER report_combined_private_message_update
     resolver_id ON private_message_report
     FOR EACH ROW
     EXECUTE FUNCTION r.report_combined_private_message_update ();
+-- report_combined_person_insert
+CREATE FUNCTION r.report_combined_person_insert ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO report_combined (published_at, person_report_id, report_creator_id, item_creator_id)
+        VALUES (NEW.published_at, NEW.id, NEW.creator_id, NEW.person_id);
+    RETURN NEW;
+END
+$$;
+CREATE TRIGGER report_combined_person_insert
+    AFTER INSERT ON person_report
+    FOR EACH ROW
+    EXECUTE FUNCTION r.report_combined_person_insert ();
+-- report_combined_person_update
+CREATE FUNCTION r.report_combined_person_update ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    UPDATE
+        report_combined
+    SET
+        resolved = NEW.resolved,
+        resolver_id = NEW.resolver_id
+    WHERE
+        person_report_id = NEW.id;
+    RETURN NULL;
+END
+$$;
+CREATE TRIGGER report_combined_person_update
+    AFTER UPDATE OF resolved,
+    resolver_id ON person_report
+    FOR EACH ROW
+    EXECUTE FUNCTION r.report_combined_person_update ();
 -- person_content_combined_post
 CREATE FUNCTION r.person_content_combined_post_insert ()
     RETURNS TRIGGER
@@ -1121,3 +1295,75 @@ CREATE TRIGGER modlog_bulk_action_parent_delete
     FOR EACH ROW
     WHEN (OLD.bulk_action_parent_id IS NOT NULL)
     EXECUTE FUNCTION r.modlog_child_count_decrement ();
+-- Keep the full-text search vectors up to date. Vote and other aggregate updates don't change the
+-- searched columns, so they don't fire these triggers.
+CREATE FUNCTION r.post_search_vector ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+DECLARE
+    config regconfig = r.search_config (NEW.language_id);
+BEGIN
+    INSERT INTO post_search (post_id, search_vector)
+        VALUES (NEW.id, r.search_vector (NEW.name, config, 'A') || r.search_vector (NEW.body, config, 'B'))
+    ON CONFLICT (post_id)
+        DO UPDATE SET
+            search_vector = excluded.search_vector;
+    RETURN NULL;
+END
+$$;
+CREATE TRIGGER search_vector
+    AFTER INSERT OR UPDATE OF name, body, language_id ON post
+    FOR EACH ROW
+    EXECUTE FUNCTION r.post_search_vector ();
+CREATE FUNCTION r.comment_search_vector ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO comment_search (comment_id, search_vector)
+        VALUES (NEW.id, r.search_vector (NEW.content, r.search_config (NEW.language_id), 'A'))
+    ON CONFLICT (comment_id)
+        DO UPDATE SET
+            search_vector = excluded.search_vector;
+    RETURN NULL;
+END
+$$;
+CREATE TRIGGER search_vector
+    AFTER INSERT OR UPDATE OF content, language_id ON comment
+    FOR EACH ROW
+    EXECUTE FUNCTION r.comment_search_vector ();
+CREATE FUNCTION r.community_search_vector ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO community_search (community_id, search_vector)
+        VALUES (NEW.id, r.search_vector (NEW.title, 'simple', 'A') || r.search_vector (NEW.summary, 'simple', 'B') || r.search_vector (NEW.sidebar, 'simple', 'C'))
+    ON CONFLICT (community_id)
+        DO UPDATE SET
+            search_vector = excluded.search_vector;
+    RETURN NULL;
+END
+$$;
+CREATE TRIGGER search_vector
+    AFTER INSERT OR UPDATE OF title, summary, sidebar ON community
+    FOR EACH ROW
+    EXECUTE FUNCTION r.community_search_vector ();
+CREATE FUNCTION r.person_search_vector ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO person_search (person_id, search_vector)
+        VALUES (NEW.id, r.search_vector (NEW.bio, 'simple', 'A'))
+    ON CONFLICT (person_id)
+        DO UPDATE SET
+            search_vector = excluded.search_vector;
+    RETURN NULL;
+END
+$$;
+CREATE TRIGGER search_vector
+    AFTER INSERT OR UPDATE OF bio ON person
+    FOR EACH ROW
+    EXECUTE FUNCTION r.person_search_vector ();
@@ -287,3 +287,97 @@ BEGIN
 END;
 $$;
 
+-- The text search configuration used for stemming content in the given language. Languages without
+-- a builtin Postgres configuration use `simple`, which doesn't do any stemming.
+CREATE FUNCTION r.search_config (language_code text)
+    RETURNS regconfig
+    LANGUAGE sql
+    STABLE PARALLEL SAFE RETURN (
+        CASE left (language_code, 2)
+        WHEN 'ar' THEN
+            'arabic'
+        WHEN 'da' THEN
+            'danish'
+        WHEN 'de' THEN
+            'german'
+        WHEN 'el' THEN
+            'greek'
+        WHEN 'en' THEN
+            'english'
+        WHEN 'es' THEN
+            'spanish'
+        WHEN 'fi' THEN
+            'finnish'
+        WHEN 'fr' THEN
+            'french'
+        WHEN 'ga' THEN
+            'irish'
+        WHEN 'hu' THEN
+            'hungarian'
+        WHEN 'id' THEN
+            'indonesian'
+        WHEN 'it' THEN
+            'italian'
+        WHEN 'lt' THEN
+            'lithuanian'
+        WHEN 'nb' THEN
+            'norwegian'
+        WHEN 'ne' THEN
+            'nepali'
+        WHEN 'nl' THEN
+            'dutch'
+        WHEN 'nn' THEN
+            'norwegian'
+        WHEN 'no' THEN
+            'norwegian'
+        WHEN 'pt' THEN
+            'portuguese'
+        WHEN 'ro' THEN
+            'romanian'
+        WHEN 'ru' THEN
+            'russian'
+        WHEN 'sv' THEN
+            'swedish'
+        WHEN 'ta' THEN
+            'tamil'
+        WHEN 'tr' THEN
+            'turkish'
+        ELSE
+            'simple'
+        END)::regconfig;
+
+CREATE FUNCTION r.search_config (language_id integer)
+    RETURNS regconfig
+    LANGUAGE sql
+    STABLE PARALLEL SAFE RETURN r.search_config ((
+        SELECT
+            code
+        FROM LANGUAGE
+        WHERE
+            id = language_id));
+
+-- A search vector which contains both the stemmed and the unstemmed words of `content`, so that it
+-- can be matched by queries in any language.
+CREATE FUNCTION r.search_vector (content text, config regconfig, weight "char")
+    RETURNS tsvector
+    LANGUAGE sql
+    IMMUTABLE PARALLEL SAFE RETURN setweight(
+        CASE WHEN config = 'simple'::regconfig THEN
+            to_tsvector('simple', coalesce(content, ''))
+        ELSE
+            to_tsvector(config, coalesce(content, '')) || to_tsvector('simple', coalesce(content, ''))
+        END, weight);
+
+-- Converts a search term in websearch syntax (quoted phrases, `-` exclusions, `or`) to a tsquery.
+-- Matches either the words stemmed for the language of the searching user, or the unstemmed words
+-- for content in other languages.
+CREATE FUNCTION r.search_query (search_term text, language_code text)
+    RETURNS tsquery
+    LANGUAGE sql
+    STABLE PARALLEL SAFE RETURN websearch_to_tsquery(r.search_config (language_code), search_term) || websearch_to_tsquery('simple', search_term);
+
+-- Only keeps the lexemes from the title (weight A) of a search vector
+CREATE FUNCTION r.search_vector_title (search_vector tsvector)
+    RETURNS tsvector
+    LANGUAGE sql
+    IMMUTABLE PARALLEL SAFE RETURN ts_filter(search_vector, '{a}');
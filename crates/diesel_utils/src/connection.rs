@@ -1,11 +1,16 @@
 use deadpool::Runtime;
-use diesel::result::{
-  ConnectionError,
-  ConnectionResult,
-  Error::{self as DieselError, QueryBuilderError},
+use diesel::{
+  result::{
+    ConnectionError,
+    ConnectionResult,
+    Error::{self as DieselError, QueryBuilderError},
+  },
+  sql_query,
+  sql_types::{Array, Text},
 };
 use diesel_async::{
   AsyncConnection,
+  RunQueryDsl,
   pg::AsyncPgConnection,
   pooled_connection::{
     AsyncDieselConnectionManager,
@@ -14,7 +19,7 @@ use diesel_async::{
   },
   scoped_futures::ScopedBoxFuture,
 };
-use futures_util::{FutureExt, future::BoxFuture};
+use futures_util::{FutureExt, StreamExt, future::BoxFuture};
 use lemmy_utils::{
   error::{LemmyError, LemmyResult},
   settings::SETTINGS,
@@ -34,6 +39,7 @@ use rustls::{
 };
 use std::{
   ops::{Deref, DerefMut},
+  pin::pin,
   sync::Arc,
   time::Duration,
 };
@@ -198,18 +204,45 @@ pub fn build_db_pool_for_tests() -> ActualDbPool {
   build_db_pool().expect("db pool missing")
 }
 
+/// Sends the payloads as Postgres notifications on the given channel, so that they are received by
+/// all Lemmy processes which call [listen_notifications].
+pub async fn send_notifications(
+  pool: &mut DbPool<'_>,
+  channel: &str,
+  payloads: Vec<String>,
+) -> LemmyResult<()> {
+  let conn = &mut get_conn(pool).await?;
+  sql_query("SELECT pg_notify($1, payload) FROM unnest($2::text[]) AS payload")
+    .bind::<Text, _>(channel)
+    .bind::<Array<Text>, _>(payloads)
+    .execute(conn)
+    .await?;
+  Ok(())
+}
+
+/// Listens for Postgres notifications on the given channel, and calls the handler with the payload
+/// of each one. This uses a dedicated connection outside of the pool, and only returns once the
+/// connection is lost.
+pub async fn listen_notifications(
+  channel: &str,
+  mut handler: impl FnMut(String),
+) -> LemmyResult<()> {
+  let mut conn = establish_listen_connection(&SETTINGS.get_database_url_with_options()?).await?;
+  sql_query(format!("LISTEN \"{channel}\""))
+    .execute(&mut conn)
+    .await?;
+  let mut notifications = pin!(conn.notifications_stream());
+  while let Some(notification) = notifications.next().await {
+    handler(notification?.payload);
+  }
+  Ok(())
+}
+
 fn establish_connection(config: &str) -> BoxFuture<'_, ConnectionResult<AsyncPgConnection>> {
   let fut = async {
     // We only support TLS with sslmode=require currently
     let conn = if config.contains("sslmode=require") {
-      let rustls_config = DangerousClientConfigBuilder {
-        cfg: ClientConfig::builder(),
-      }
-      .with_custom_certificate_verifier(Arc::new(NoCertVerifier {}))
-      .with_no_client_auth();
-
-      let tls = tokio_postgres_rustls::MakeRustlsConnect::new(rustls_config);
-      let (client, conn) = tokio_postgres::connect(config, tls)
+      let (client, conn) = tokio_postgres::connect(config, tls_connector())
         .await
         .map_err(|e| ConnectionError::BadConnection(e.to_string()))?;
       tokio::spawn(async move {
@@ -227,6 +260,30 @@ fn establish_connection(config: &str) -> BoxFuture<'_, ConnectionResult<AsyncPgC
   fut.boxed()
 }
 
+/// Like [establish_connection], but lets diesel-async drive the TLS connection so that
+/// notifications from LISTEN are received. Connection errors are returned by the notifications
+/// stream.
+async fn establish_listen_connection(config: &str) -> ConnectionResult<AsyncPgConnection> {
+  if config.contains("sslmode=require") {
+    let (client, conn) = tokio_postgres::connect(config, tls_connector())
+      .await
+      .map_err(|e| ConnectionError::BadConnection(e.to_string()))?;
+    AsyncPgConnection::try_from_client_and_connection(client, conn).await
+  } else {
+    AsyncPgConnection::establish(config).await
+  }
+}
+
+fn tls_connector() -> tokio_postgres_rustls::MakeRustlsConnect {
+  let rustls_config = DangerousClientConfigBuilder {
+    cfg: ClientConfig::builder(),
+  }
+  .with_custom_certificate_verifier(Arc::new(NoCertVerifier {}))
+  .with_no_client_auth();
+
+  tokio_postgres_rustls::MakeRustlsConnect::new(rustls_config)
+}
+
 #[derive(Debug)]
 struct NoCertVerifier {}
 
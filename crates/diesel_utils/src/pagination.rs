@@ -153,6 +153,19 @@ impl PaginationCursor {
   pub fn is_back(self) -> LemmyResult<bool> {
     Ok(self.into_internal()?.back)
   }
+
+  /// Cursor for offset based pagination. Only used for orderings without a unique key, like search
+  /// relevance.
+  pub fn new_offset(offset: i64) -> LemmyResult<Self> {
+    Self::from_internal(PaginationCursorInternal {
+      back: false,
+      data: CursorData::new_plain(offset.to_string()),
+      recovery: false,
+    })
+  }
+  pub fn offset(self) -> LemmyResult<i64> {
+    Ok(self.into_internal()?.data.plain().parse()?)
+  }
 }
 
 impl From<String> for PaginationCursor {
@@ -318,6 +331,30 @@ where
   })
 }
 
+/// Add prev/next cursors to the result of an offset based query.
+#[cfg(feature = "full")]
+pub fn paginate_offset_response<
+  #[cfg(feature = "ts-rs")] T: ts_rs::TS,
+  #[cfg(not(feature = "ts-rs"))] T,
+>(
+  data: Vec<T>,
+  limit: i64,
+  offset: i64,
+) -> LemmyResult<PagedResponse<T>> {
+  let len: i64 = data.len().try_into()?;
+  let prev_page = (offset > 0)
+    .then(|| PaginationCursor::new_offset(offset.saturating_sub(limit).max(0)))
+    .transpose()?;
+  let next_page = (len >= limit)
+    .then(|| PaginationCursor::new_offset(offset.saturating_add(limit)))
+    .transpose()?;
+  Ok(PagedResponse {
+    items: data,
+    next_page,
+    prev_page,
+  })
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
@@ -333,6 +370,27 @@ mod test {
     Ok(())
   }
 
+  #[test]
+  fn test_offset_cursor() -> LemmyResult<()> {
+    let cursor = PaginationCursor::new_offset(40)?;
+    assert_eq!(40, cursor.offset()?);
+
+    let res = paginate_offset_response(vec![1, 2], 2, 2)?;
+    assert_eq!(
+      Some(0),
+      res.prev_page.map(PaginationCursor::offset).transpose()?
+    );
+    assert_eq!(
+      Some(4),
+      res.next_page.map(PaginationCursor::offset).transpose()?
+    );
+
+    let res = paginate_offset_response(vec![1], 2, 0)?;
+    assert!(res.prev_page.is_none());
+    assert!(res.next_page.is_none());
+    Ok(())
+  }
+
   fn do_test_cursor(data: CursorData) -> LemmyResult<()> {
     let cursor = PaginationCursorInternal {
       back: true,
@@ -13,6 +13,8 @@ pub mod admin;
 pub mod notifications;
 mod send;
 
+pub use send::deliver_emails;
+
 /// Avoid warnings for unused 0.19 translations
 #[expect(mismatched_lifetime_syntaxes)]
 pub mod translations {
@@ -1,9 +1,21 @@
 use crate::{inbox_link, send::send_email, user_language};
-use lemmy_db_schema::source::{comment::Comment, community::Community, person::Person, post::Post};
-use lemmy_db_schema_file::enums::ModlogKind;
+use lemmy_db_schema::source::{
+  comment::Comment,
+  community::Community,
+  notification_preference::NotificationPreference,
+  person::Person,
+  post::Post,
+};
+use lemmy_db_schema_file::enums::{EmailDigestCadence, ModlogKind, NotificationType};
 use lemmy_db_views_local_user::LocalUserView;
-use lemmy_diesel_utils::dburl::DbUrl;
-use lemmy_utils::{settings::structs::Settings, utils::markdown::markdown_to_html};
+use lemmy_db_views_notification::{NotificationData, NotificationView};
+use lemmy_db_views_post::PostView;
+use lemmy_diesel_utils::{connection::DbPool, dburl::DbUrl};
+use lemmy_utils::{
+  error::LemmyResult,
+  settings::structs::Settings,
+  utils::markdown::markdown_to_html,
+};
 
 pub enum NotificationEmailData<'a> {
   Mention {
@@ -35,14 +47,23 @@ pub enum NotificationEmailData<'a> {
   },
 }
 
-pub fn send_notification_email(
+pub async fn send_notification_email(
   local_user_view: LocalUserView,
   link: DbUrl,
-  data: NotificationEmailData,
+  data: NotificationEmailData<'_>,
+  preference: &NotificationPreference,
+  pool: &mut DbPool<'_>,
   settings: &'static Settings,
-) {
+) -> LemmyResult<()> {
   if local_user_view.banned || !local_user_view.local_user.send_notifications_to_email {
-    return;
+    return Ok(());
+  }
+  // Users with a digest cadence get these bundled by the scheduled digest task instead.
+  if local_user_view.local_user.email_digest_cadence != EmailDigestCadence::Immediate {
+    return Ok(());
+  }
+  if !preference.email {
+    return Ok(());
   }
 
   let inbox_link = inbox_link(settings);
@@ -142,7 +163,110 @@ pub fn send_notification_email(
       user_email,
       local_user_view.person.name,
       body,
+      pool,
+      settings,
+    )
+    .await?;
+  }
+  Ok(())
+}
+
+/// Send a single email which bundles all of the given notifications, optionally followed by the
+/// top posts from subscribed communities.
+pub async fn send_digest_email(
+  local_user_view: LocalUserView,
+  notifications: &[NotificationView],
+  top_posts: &[PostView],
+  pool: &mut DbPool<'_>,
+  settings: &'static Settings,
+) -> LemmyResult<()> {
+  if local_user_view.banned || notifications.is_empty() {
+    return Ok(());
+  }
+
+  let inbox_link = inbox_link(settings);
+  let lang = user_language(&local_user_view.local_user);
+
+  let mut items = String::new();
+  for n in notifications {
+    let (creator_name, url, content): (&str, String, &str) = match &n.data {
+      NotificationData::Comment(c) => match c.comment.local_url(settings) {
+        Ok(url) => (&c.creator.name, url.to_string(), &c.comment.content),
+        Err(_) => continue,
+      },
+      NotificationData::Post(p) => match p.post.local_url(settings) {
+        Ok(url) => (
+          &p.creator.name,
+          url.to_string(),
+          p.post.body.as_deref().unwrap_or_default(),
+        ),
+        Err(_) => continue,
+      },
+      NotificationData::PrivateMessage(pm) => (
+        &pm.creator.name,
+        inbox_link.clone(),
+        &pm.private_message.content,
+      ),
+      NotificationData::ModAction(m) => (
+        "",
+        inbox_link.clone(),
+        m.modlog.reason.as_deref().unwrap_or_default(),
+      ),
+    };
+    let title = match n.notification.kind {
+      NotificationType::Mention => lang.mention_from_x(creator_name),
+      NotificationType::Reply => lang.reply_from_x(creator_name),
+      NotificationType::Subscribed => lang.subscribed().to_string(),
+      NotificationType::PrivateMessage => lang.private_message_from_x(creator_name),
+      NotificationType::ModAction => lang.mod_action().to_string(),
+      // There are no translations for these yet, featuring and distinguishing are mod actions
+      // and the score is shown as number.
+      NotificationType::ScoreMilestone => {
+        format!("+{}", n.notification.score_milestone.unwrap_or_default())
+      }
+      NotificationType::PostFeatured | NotificationType::CommentDistinguished => {
+        lang.mod_action().to_string()
+      }
+    };
+    items.push_str(&format!(
+      "<li><a href=\"{url}\">{title}</a>{}</li>",
+      markdown_to_html(content)
+    ));
+  }
+
+  let mut body = format!(
+    "<ul>{items}</ul><p><a href=\"{inbox_link}\">{}</a></p>",
+    lang.notifications()
+  );
+  if !top_posts.is_empty() {
+    let mut posts = String::new();
+    for p in top_posts {
+      if let Ok(url) = p.post.local_url(settings) {
+        posts.push_str(&format!(
+          "<li><a href=\"{url}\">{}</a> ({})</li>",
+          p.post.name, p.community.name
+        ));
+      }
+    }
+    body.push_str(&format!("<h3>{}</h3><ul>{posts}</ul>", lang.subscribed()));
+  }
+  let subject = format!(
+    "{} - {} ({})",
+    settings.hostname,
+    lang.notifications(),
+    notifications.len()
+  );
+
+  if let Some(user_email) = local_user_view.local_user.email {
+    send_email(
+      subject,
+      user_email,
+      local_user_view.person.name,
+      body,
+      pool,
       settings,
-    );
+    )
+    .await?;
   }
+  Ok(())
 }
@@ -1,8 +1,18 @@
-use lemmy_diesel_utils::sensitive::SensitiveString;
+use chrono::Utc;
+use futures::future::join_all;
+use lemmy_db_schema::source::email_outbox::{
+  EmailOutbox,
+  EmailOutboxInsertForm,
+  EmailOutboxUpdateForm,
+};
+use lemmy_diesel_utils::{
+  connection::{ActualDbPool, DbPool},
+  sensitive::SensitiveString,
+  traits::Crud,
+};
 use lemmy_utils::{
-  error::{LemmyErrorExt, LemmyErrorType},
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
   settings::structs::Settings,
-  spawn_try_task,
 };
 use lettre::{
   Address,
@@ -11,58 +21,147 @@ use lettre::{
   message::{Mailbox, MultiPart},
   transport::smtp::extension::ClientId,
 };
-use std::{str::FromStr, sync::OnceLock};
+use std::{str::FromStr, sync::OnceLock, time::Duration};
+use tokio::time::sleep;
+use tracing::warn;
 use uuid::Uuid;
 
 type AsyncSmtpTransport = lettre::AsyncSmtpTransport<lettre::Tokio1Executor>;
 
-pub(crate) fn send_email(
+/// Emails are given up after this many failed attempts. With the retry delays of
+/// [retry_delay] this corresponds to about a day and a half.
+const MAX_FAIL_COUNT: i32 = 12;
+
+/// Maximum number of emails which are sent at the same time.
+const DELIVERY_BATCH_SIZE: i64 = 20;
+
+/// How long to wait before checking for new emails, if the outbox is empty.
+const RECHECK_DELAY: Duration = Duration::from_secs(5);
+
+/// How long a claimed email is reserved for the worker which sends it. If the worker dies in the
+/// meantime, another one picks up the email after this time.
+const CLAIM_LEASE: Duration = Duration::from_secs(5 * 60);
+
+/// Queues the email in the outbox, from where it is sent by [deliver_emails].
+pub(crate) async fn send_email(
   subject: String,
   to_email: SensitiveString,
   to_username: String,
   html: String,
+  pool: &mut DbPool<'_>,
   settings: &'static Settings,
-) {
-  spawn_try_task(async move {
-    static MAILER: OnceLock<AsyncSmtpTransport> = OnceLock::new();
-    let email_config = settings.email.clone().ok_or(LemmyErrorType::NoEmailSetup)?;
-
-    #[expect(clippy::expect_used)]
-    let mailer = MAILER.get_or_init(|| {
-      AsyncSmtpTransport::from_url(&email_config.connection)
-        .expect("init email transport")
-        .hello_name(ClientId::Domain(settings.hostname.clone()))
-        .build()
-    });
-
-    // use usize::MAX as the line wrap length, since lettre handles the wrapping for us
-    let plain_text = html2text::from_read(html.as_bytes(), usize::MAX)?;
-
-    let smtp_from_address = &email_config.smtp_from_address;
-
-    let email = Message::builder()
-      .from(
-        smtp_from_address
-          .parse()
-          .with_lemmy_type(LemmyErrorType::InvalidEmailAddress(
-            smtp_from_address.into(),
-          ))?,
-      )
-      .to(Mailbox::new(
-        Some(to_username.clone()),
-        Address::from_str(&to_email)
-          .with_lemmy_type(LemmyErrorType::InvalidEmailAddress(to_email.into_inner()))?,
-      ))
-      .message_id(Some(format!("<{}@{}>", Uuid::new_v4(), settings.hostname)))
-      .subject(subject)
-      .multipart(MultiPart::alternative_plain_html(plain_text, html.clone()))
-      .with_lemmy_type(LemmyErrorType::EmailSendFailed)?;
-
-    mailer
-      .send(email)
+) -> LemmyResult<()> {
+  if settings.email.is_none() {
+    warn!("Not sending email \"{subject}\" because no email server is configured");
+    return Ok(());
+  }
+  let form = EmailOutboxInsertForm::new(to_email.into_inner(), to_username, subject, html);
+  EmailOutbox::create(pool, &form).await?;
+  Ok(())
+}
+
+/// Sends queued emails in a loop. Emails are claimed with row locks, so this can run in any number
+/// of Lemmy processes at the same time. Failed emails are retried with increasing delay, and are
+/// marked as failed after [MAX_FAIL_COUNT] attempts so that admins can resend them.
+pub async fn deliver_emails(pool: ActualDbPool, settings: &'static Settings) {
+  // Emails are only queued if an email server is configured, so there is nothing to send
+  if settings.email.is_none() {
+    return;
+  }
+  loop {
+    let lease_until = Utc::now() + CLAIM_LEASE;
+    let pending = EmailOutbox::claim_pending(&mut (&pool).into(), lease_until, DELIVERY_BATCH_SIZE)
       .await
-      .with_lemmy_type(LemmyErrorType::EmailSendFailed)?;
+      .inspect_err(|e| warn!("Failed to load queued emails: {e}"))
+      .unwrap_or_default();
+    if pending.is_empty() {
+      sleep(RECHECK_DELAY).await;
+      continue;
+    }
+    join_all(
+      pending
+        .into_iter()
+        .map(|email| deliver(email, &pool, settings)),
+    )
+    .await;
+  }
+}
+
+async fn deliver(email: EmailOutbox, pool: &ActualDbPool, settings: &'static Settings) {
+  let form = match send(&email, settings).await {
+    Ok(()) => EmailOutboxUpdateForm {
+      sent_at: Some(Some(Utc::now())),
+      ..Default::default()
+    },
+    Err(e) => {
+      let fail_count = email.fail_count + 1;
+      let mut form = EmailOutboxUpdateForm {
+        fail_count: Some(fail_count),
+        last_error: Some(Some(e.to_string())),
+        ..Default::default()
+      };
+      if fail_count >= MAX_FAIL_COUNT {
+        form.failed_at = Some(Some(Utc::now()));
+      } else {
+        form.next_attempt_at = Some(Utc::now() + retry_delay(fail_count));
+      }
+      form
+    }
+  };
+  if let Err(e) = EmailOutbox::update(&mut pool.into(), email.id, &form).await {
+    warn!("Failed to update queued email {}: {e}", email.id.0);
+  }
+}
+
+/// Doubles the delay after each failed attempt, starting with one minute and up to about 17 hours.
+fn retry_delay(fail_count: i32) -> Duration {
+  let exponent = fail_count.saturating_sub(1).clamp(0, 10).unsigned_abs();
+  Duration::from_secs(60 * 2_u64.pow(exponent))
+}
+
+async fn send(email: &EmailOutbox, settings: &'static Settings) -> LemmyResult<()> {
+  static MAILER: OnceLock<AsyncSmtpTransport> = OnceLock::new();
+  let email_config = settings.email.clone().ok_or(LemmyErrorType::NoEmailSetup)?;
+
+  #[expect(clippy::expect_used)]
+  let mailer = MAILER.get_or_init(|| {
+    AsyncSmtpTransport::from_url(&email_config.connection)
+      .expect("init email transport")
+      .hello_name(ClientId::Domain(settings.hostname.clone()))
+      .build()
+  });
+
+  // use usize::MAX as the line wrap length, since lettre handles the wrapping for us
+  let plain_text = html2text::from_read(email.html.as_bytes(), usize::MAX)?;
+
+  let smtp_from_address = &email_config.smtp_from_address;
+
+  let message = Message::builder()
+    .from(
+      smtp_from_address
+        .parse()
+        .with_lemmy_type(LemmyErrorType::InvalidEmailAddress(
+          smtp_from_address.into(),
+        ))?,
+    )
+    .to(Mailbox::new(
+      Some(email.recipient_name.clone()),
+      Address::from_str(&email.recipient_email).with_lemmy_type(
+        LemmyErrorType::InvalidEmailAddress(email.recipient_email.clone()),
+      )?,
+    ))
+    .message_id(Some(format!("<{}@{}>", Uuid::new_v4(), settings.hostname)))
+    .subject(email.subject.clone())
+    .multipart(MultiPart::alternative_plain_html(
+      plain_text,
+      email.html.clone(),
+    ))
+    .with_lemmy_type(LemmyErrorType::EmailSendFailed)?;
+
+  mailer
+    .send(message)
+    .await
+    .with_lemmy_type(LemmyErrorType::EmailSendFailed)?;
 
-    Ok(())
-  })
+  Ok(())
 }
@@ -0,0 +1,182 @@
+use actix_web::{
+  HttpResponse,
+  http::header::{CacheControl, CacheDirective, ContentType},
+  mime,
+  web::{Bytes, Data, Query},
+};
+use futures::stream;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  realtime::RealtimeEvent,
+  utils::check_local_user_banned_or_deleted,
+};
+use lemmy_db_views_comment::CommentView;
+use lemmy_db_views_community::CommunityView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_notification::{
+  NotificationView,
+  api::{EventMessage, GetEvents},
+};
+use lemmy_db_views_post::PostView;
+use lemmy_utils::error::LemmyResult;
+use std::{convert::Infallible, time::Duration};
+use tokio::{
+  sync::broadcast::{Receiver, error::RecvError},
+  time::{Instant, Interval, interval, interval_at},
+};
+
+/// Send a comment in this interval, so that proxies don't close idle streams.
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Read the user again in this interval, so that changed settings and permissions apply to open
+/// streams.
+const USER_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Streams new notifications and unread count changes of the user, and optionally new posts and
+/// comments, as server-sent events.
+pub async fn get_events(
+  Query(data): Query<GetEvents>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<HttpResponse> {
+  // Make sure that the user can read the subscribed community or post
+  let local_user = Some(&local_user_view.local_user);
+  if let Some(community_id) = data.community_id {
+    CommunityView::read(&mut context.pool(), community_id, local_user, false).await?;
+  }
+  if let Some(post_id) = data.post_id {
+    let local_instance_id = local_user_view.person.instance_id;
+    PostView::read(
+      &mut context.pool(),
+      post_id,
+      local_user,
+      local_instance_id,
+      false,
+    )
+    .await?;
+  }
+
+  let events = EventStream {
+    data,
+    context,
+    local_user_view,
+    receiver: RealtimeEvent::subscribe(),
+    keepalive: interval(KEEPALIVE_INTERVAL),
+    user_refresh: interval_at(
+      Instant::now() + USER_REFRESH_INTERVAL,
+      USER_REFRESH_INTERVAL,
+    ),
+  };
+  let stream = stream::unfold(events, |mut events| async move {
+    let bytes = events.next_chunk().await?;
+    Some((Ok::<_, Infallible>(bytes), events))
+  });
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header(ContentType(mime::TEXT_EVENT_STREAM))
+      .insert_header(CacheControl(vec![CacheDirective::NoCache]))
+      .streaming(stream),
+  )
+}
+
+struct EventStream {
+  data: GetEvents,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+  receiver: Receiver<RealtimeEvent>,
+  keepalive: Interval,
+  user_refresh: Interval,
+}
+
+impl EventStream {
+  /// Waits for the next event which is relevant for this user, and formats it as server-sent
+  /// events. Returns `None` once the stream should be closed.
+  async fn next_chunk(&mut self) -> Option<Bytes> {
+    loop {
+      let event = tokio::select! {
+        _ = self.keepalive.tick() => return Some(Bytes::from_static(b": keepalive\n\n")),
+        _ = self.user_refresh.tick() => {
+          // Close the stream if the account was deleted or banned in the meantime
+          self.refresh_user().await.ok()?;
+          continue;
+        }
+        event = self.receiver.recv() => event,
+      };
+      let event = match event {
+        Ok(event) => event,
+        // This stream was too slow and missed some events
+        Err(RecvError::Lagged(_)) => continue,
+        Err(RecvError::Closed) => return None,
+      };
+      // Errors mean that the item doesn't exist anymore or can't be viewed by this user
+      let Ok(messages) = self.messages(event).await else {
+        continue;
+      };
+      let chunk = messages
+        .iter()
+        .filter_map(|m| serde_json::to_string(m).ok())
+        .map(|json| format!("data: {json}\n\n"))
+        .collect::<String>();
+      if !chunk.is_empty() {
+        return Some(chunk.into());
+      }
+    }
+  }
+
+  async fn refresh_user(&mut self) -> LemmyResult<()> {
+    let local_user_id = self.local_user_view.local_user.id;
+    let local_user_view = LocalUserView::read(&mut self.context.pool(), local_user_id).await?;
+    check_local_user_banned_or_deleted(&local_user_view)?;
+    self.local_user_view = local_user_view;
+    Ok(())
+  }
+
+  async fn messages(&self, event: RealtimeEvent) -> LemmyResult<Vec<EventMessage>> {
+    let pool = &mut self.context.pool();
+    let person = &self.local_user_view.person;
+    let local_user = Some(&self.local_user_view.local_user);
+    let messages = match event {
+      RealtimeEvent::Notification {
+        recipient_id,
+        notification_id,
+      } if recipient_id == person.id => {
+        let notification = NotificationView::read(pool, notification_id, person).await?;
+        vec![
+          EventMessage::Notification(notification),
+          self.unread_count().await?,
+        ]
+      }
+      RealtimeEvent::NotificationsRead { person_id } if person_id == person.id => {
+        vec![self.unread_count().await?]
+      }
+      RealtimeEvent::Post {
+        post_id,
+        community_id,
+      } if self.data.community_id == Some(community_id) => {
+        let post = PostView::read(pool, post_id, local_user, person.instance_id, false).await?;
+        vec![EventMessage::Post(post)]
+      }
+      RealtimeEvent::Comment {
+        comment_id,
+        post_id,
+        community_id,
+      } if self.data.community_id == Some(community_id) || self.data.post_id == Some(post_id) => {
+        let comment = CommentView::read(pool, comment_id, local_user, person.instance_id).await?;
+        vec![EventMessage::Comment(comment)]
+      }
+      _ => vec![],
+    };
+    Ok(messages)
+  }
+
+  async fn unread_count(&self) -> LemmyResult<EventMessage> {
+    let notification_count = NotificationView::get_unread_count(
+      &mut self.context.pool(),
+      &self.local_user_view.person,
+      self.local_user_view.local_user.show_bot_accounts,
+    )
+    .await?;
+    Ok(EventMessage::UnreadCount { notification_count })
+  }
+}
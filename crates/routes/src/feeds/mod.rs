@@ -656,6 +656,59 @@ fn create_modlog_items(
           ),
           settings,
         ),
+        ModlogKind::AutomodReport => build_modlog_item(
+          r,
+          &modlog_url,
+          if r.target_comment.is_some() {
+            format!("Automod reported comment {}", &target_comment_content)
+          } else {
+            format!("Automod reported post {}", &target_post_name)
+          },
+          settings,
+        ),
+        ModlogKind::AutomodReply => build_modlog_item(
+          r,
+          &modlog_url,
+          if r.target_comment.is_some() {
+            format!("Automod replied to comment {}", &target_comment_content)
+          } else {
+            format!("Automod replied to post {}", &target_post_name)
+          },
+          settings,
+        ),
+        ModlogKind::AutomodHold => build_modlog_item(
+          r,
+          &modlog_url,
+          if r.target_comment.is_some() {
+            format!(
+              "Automod held comment {} for approval",
+              &target_comment_content
+            )
+          } else {
+            format!("Automod held post {} for approval", &target_post_name)
+          },
+          settings,
+        ),
+        ModlogKind::ModBulkAction => build_modlog_item(
+          r,
+          &modlog_url,
+          format!(
+            "Bulk action with {} items in community {}",
+            r.modlog.child_count, &target_community_name
+          ),
+          settings,
+        ),
+        ModlogKind::ModResolveReport => build_modlog_item(
+          r,
+          &modlog_url,
+          match (r.target_comment.is_some(), r.modlog.is_revert) {
+            (true, false) => format!("Resolved report about comment {}", &target_comment_content),
+            (true, true) => format!("Reopened report about comment {}", &target_comment_content),
+            (false, false) => format!("Resolved report about post {}", &target_post_name),
+            (false, true) => format!("Reopened report about post {}", &target_post_name),
+          },
+          settings,
+        ),
       }
     })
     .collect::<LemmyResult<Vec<Item>>>()?;
@@ -715,6 +768,14 @@ fn build_item(
     NotificationType::Subscribed => lang.subscribed().to_string(),
     NotificationType::PrivateMessage => lang.private_message_from_x(creator.name.clone()),
     NotificationType::ModAction => lang.mod_action().to_string(),
+    // There are no translations for these yet, featuring and distinguishing are mod actions and
+    // the score is shown as number.
+    NotificationType::ScoreMilestone => {
+      format!("+{}", notification.score_milestone.unwrap_or_default())
+    }
+    NotificationType::PostFeatured | NotificationType::CommentDistinguished => {
+      lang.mod_action().to_string()
+    }
   };
   Ok(Item {
     title: Some(title),
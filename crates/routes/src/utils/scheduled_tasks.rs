@@ -1,6 +1,6 @@
 use crate::nodeinfo::{NodeInfo, NodeInfoWellKnown};
 use activitypub_federation::config::Data;
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Days, Months, TimeZone, Utc};
 use clokwerk::{AsyncScheduler, TimeUnits as CTimeUnits};
 use diesel::{
   BoolExpressionMethods,
@@ -16,43 +16,66 @@ use diesel::{
 };
 use diesel_async::{AsyncPgConnection, RunQueryDsl};
 use diesel_uplete::uplete;
+use lemmy_api_crud::{
+  comment::schedule::publish_scheduled_comments,
+  private_message::schedule::publish_scheduled_private_messages,
+};
 use lemmy_api_utils::{
+  automod::{AutomodEvent, automod_post},
   context::LemmyContext,
   plugins::plugin_hook_after,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::send_webmention,
+  utils::{requires_approval, send_webmention},
+  webhooks::send_webhook,
 };
 use lemmy_db_schema::{
   source::{
     community::Community,
+    community_tag::{CommunityTag, PostCommunityTag},
     instance::{Instance, InstanceForm},
-    local_user::LocalUser,
-    post::{Post, PostUpdateForm},
+    local_user::{LocalUser, LocalUserUpdateForm},
+    notification_preference::NotificationPreference,
+    person::Person,
+    poll::{Poll, PollInsertForm, PollOption, PollOptionInsertForm},
+    post::{Post, PostActions, PostInsertForm, PostLikeForm, PostUpdateForm},
   },
+  traits::Likeable,
   utils::DELETED_REPLACEMENT_TEXT,
 };
-use lemmy_db_schema_file::schema::{
-  comment,
-  community,
-  community_actions,
-  federation_blocklist,
-  instance,
-  instance_actions,
-  local_site,
-  local_user,
-  local_user_invite,
-  person,
-  post,
-  received_activity,
-  sent_activity,
-  site,
+use lemmy_db_schema_file::{
+  enums::{EmailDigestCadence, ListingType, PostSortType, ScheduleRecurrence, WebhookEventType},
+  schema::{
+    comment,
+    comment_revision,
+    community,
+    community_actions,
+    email_outbox,
+    federation_blocklist,
+    instance,
+    instance_actions,
+    local_site,
+    local_user,
+    local_user_invite,
+    person,
+    post,
+    post_revision,
+    push_subscription,
+    received_activity,
+    sent_activity,
+    site,
+    webhook_delivery,
+  },
 };
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_notification::impls::NotificationQuery;
+use lemmy_db_views_post::impls::PostQuery;
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
   traits::Crud,
   utils::{functions::coalesce, now},
 };
+use lemmy_email::notifications::send_digest_email;
 use lemmy_utils::{
   DB_BATCH_SIZE,
   error::{LemmyErrorType, LemmyResult},
@@ -66,13 +89,23 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   // https://github.com/mdsherry/clokwerk/issues/38
   let mut scheduler = AsyncScheduler::with_tz(Utc);
 
-  // Every 1 minute run plugin hooks
-  scheduler.every(CTimeUnits::minutes(1)).run(async move || {
-    plugin_hook_after("scheduled_task_1_min", &());
+  let context_1 = context.clone();
+  // Every 1 minute federate changed poll votes and run plugin hooks
+  scheduler.every(CTimeUnits::minutes(1)).run(move || {
+    let context = context_1.clone();
+
+    async move {
+      federate_poll_votes(&context)
+        .await
+        .inspect_err(|e| warn!("Failed to federate poll votes: {e}"))
+        .ok();
+      plugin_hook_after("scheduled_task_1_min", &());
+    }
   });
 
   let context_1 = context.clone();
-  // Every 10 minutes update hot ranks, delete expired captchas and publish scheduled posts
+  // Every 10 minutes update hot ranks, delete expired captchas and publish scheduled posts,
+  // comments and private messages
   scheduler.every(CTimeUnits::minutes(10)).run(move || {
     let context = context_1.clone();
 
@@ -85,6 +118,14 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
         .await
         .inspect_err(|e| warn!("Failed to publish scheduled posts: {e}"))
         .ok();
+      publish_scheduled_comments(&context)
+        .await
+        .inspect_err(|e| warn!("Failed to publish scheduled comments: {e}"))
+        .ok();
+      publish_scheduled_private_messages(&context)
+        .await
+        .inspect_err(|e| warn!("Failed to publish scheduled private messages: {e}"))
+        .ok();
       plugin_hook_after("scheduled_task_10_mins", &());
     }
   });
@@ -95,6 +136,8 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   // - Expired bans
   // - Expired instance blocks
   // - Expired invitations
+  // - Expired push subscriptions
+  // - Email digests
   scheduler.every(CTimeUnits::hour(1)).run(move || {
     let context = context_1.clone();
 
@@ -115,6 +158,14 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
         .await
         .inspect_err(|e| warn!("Failed to delete expired invitations: {e}"))
         .ok();
+      delete_push_subscriptions_when_expired(&mut context.pool())
+        .await
+        .inspect_err(|e| warn!("Failed to delete expired push subscriptions: {e}"))
+        .ok();
+      send_email_digests(&context)
+        .await
+        .inspect_err(|e| warn!("Failed to send email digests: {e}"))
+        .ok();
       plugin_hook_after("scheduled_task_1_hour", &());
     }
   });
@@ -126,7 +177,7 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   // - Overwrite deleted & removed posts and comments every day
   // - Delete old denied users
   // - Update instance software
-  // - Delete old outgoing activities
+  // - Delete old outgoing activities, webhook deliveries and sent or failed emails
   scheduler.every(CTimeUnits::days(1)).run(move || {
     let context = context_1.reset_request_count();
 
@@ -308,6 +359,26 @@ async fn clear_old_activities(pool: &mut DbPool<'_>) -> LemmyResult<()> {
   )
   .execute(conn)
   .await?;
+
+  diesel::delete(
+    webhook_delivery::table.filter(webhook_delivery::published_at.lt(now() - IntervalDsl::days(7))),
+  )
+  .execute(conn)
+  .await?;
+
+  diesel::delete(
+    email_outbox::table.filter(email_outbox::sent_at.lt((now() - IntervalDsl::days(7)).nullable())),
+  )
+  .execute(conn)
+  .await?;
+
+  // Failed emails contain tokens, so they are only kept long enough for admins to resend them
+  diesel::delete(
+    email_outbox::table
+      .filter(email_outbox::failed_at.lt((now() - IntervalDsl::days(30)).nullable())),
+  )
+  .execute(conn)
+  .await?;
   info!("Done.");
   Ok(())
 }
@@ -336,6 +407,15 @@ async fn overwrite_deleted_posts_and_comments(pool: &mut DbPool<'_>) -> LemmyRes
   .execute(conn)
   .await?;
 
+  // Also remove previous versions, so that the content isn't available in the edit history
+  let deleted_posts = post::table
+    .filter(post::deleted.eq(true))
+    .filter(post::updated_at.lt(now().nullable() - 1.months()))
+    .select(post::id);
+  diesel::delete(post_revision::table.filter(post_revision::post_id.eq_any(deleted_posts)))
+    .execute(conn)
+    .await?;
+
   info!("Overwriting deleted comments...");
   diesel::update(
     comment::table
@@ -346,6 +426,16 @@ async fn overwrite_deleted_posts_and_comments(pool: &mut DbPool<'_>) -> LemmyRes
   .set(comment::content.eq(DELETED_REPLACEMENT_TEXT))
   .execute(conn)
   .await?;
+
+  let deleted_comments = comment::table
+    .filter(comment::deleted.eq(true))
+    .filter(comment::updated_at.lt(now().nullable() - 1.months()))
+    .select(comment::id);
+  diesel::delete(
+    comment_revision::table.filter(comment_revision::comment_id.eq_any(deleted_comments)),
+  )
+  .execute(conn)
+  .await?;
   info!("Done.");
   Ok(())
 }
@@ -587,6 +677,121 @@ async fn delete_invitations_when_expired(pool: &mut DbPool<'_>) -> LemmyResult<(
   Ok(())
 }
 
+/// Delete push subscriptions which the browser marked as expiring. Subscriptions which are
+/// cancelled without expiration date are deleted when the push service rejects a message.
+async fn delete_push_subscriptions_when_expired(pool: &mut DbPool<'_>) -> LemmyResult<()> {
+  let conn = &mut get_conn(pool).await?;
+  diesel::delete(
+    push_subscription::table.filter(push_subscription::expires_at.lt(now().nullable())),
+  )
+  .execute(conn)
+  .await?;
+  Ok(())
+}
+
+const DIGEST_NOTIFICATIONS_LIMIT: i64 = 50;
+const DIGEST_TOP_POSTS_LIMIT: i64 = 5;
+
+/// Send a digest email with unread notifications to all users whose digest cadence has elapsed.
+async fn send_email_digests(context: &Data<LemmyContext>) -> LemmyResult<()> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  if site_view.local_site.email_notifications_disabled {
+    return Ok(());
+  }
+
+  let users = LocalUserView::list_due_for_email_digest(&mut context.pool()).await?;
+  for user in users {
+    let name = user.person.name.clone();
+    if let Err(e) = send_email_digest(user, &site_view, context).await {
+      warn!("Failed to send email digest to {name}: {e}");
+    }
+  }
+  Ok(())
+}
+
+async fn send_email_digest(
+  user: LocalUserView,
+  site_view: &SiteView,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let local_user = &user.local_user;
+  let since = local_user.last_email_digest_at;
+  let period_seconds = match local_user.email_digest_cadence {
+    EmailDigestCadence::Immediate | EmailDigestCadence::Hourly => 60 * 60,
+    EmailDigestCadence::Daily => 24 * 60 * 60,
+    EmailDigestCadence::Weekly => 7 * 24 * 60 * 60,
+  };
+
+  // Only include notifications which werent part of a previous digest, and whose type the user
+  // wants to receive by email
+  let preferences = NotificationPreference::read(&mut context.pool(), local_user.id).await?;
+  let notifications: Vec<_> = NotificationQuery {
+    unread_only: Some(true),
+    show_bot_accounts: Some(local_user.show_bot_accounts),
+    limit: Some(DIGEST_NOTIFICATIONS_LIMIT),
+    ..Default::default()
+  }
+  .list(&mut context.pool(), &user.person)
+  .await?
+  .items
+  .into_iter()
+  .filter(|n| since.is_none_or(|since| n.notification.published_at > since))
+  .filter(|n| {
+    preferences
+      .iter()
+      .any(|p| p.kind == n.notification.kind && p.email)
+  })
+  .collect();
+
+  let top_posts = if local_user.email_digest_top_posts && !notifications.is_empty() {
+    let query = PostQuery {
+      listing_type: Some(ListingType::Subscribed),
+      sort: Some(PostSortType::Top),
+      time_range_seconds: Some(period_seconds),
+      local_user: Some(local_user),
+      limit: Some(DIGEST_TOP_POSTS_LIMIT),
+      ..Default::default()
+    };
+    Box::pin(query.list(&mut context.pool(), &site_view.site, &site_view.local_site))
+      .await?
+      .items
+  } else {
+    vec![]
+  };
+
+  let form = LocalUserUpdateForm {
+    last_email_digest_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  LocalUser::update(&mut context.pool(), local_user.id, &form).await?;
+
+  send_digest_email(
+    user,
+    &notifications,
+    &top_posts,
+    &mut context.pool(),
+    context.settings(),
+  )
+  .await
+}
+
+/// Sends the new vote counts of local polls which received votes since the last run. This way
+/// remote instances get a single update per poll, instead of one for every vote.
+async fn federate_poll_votes(context: &Data<LemmyContext>) -> LemmyResult<()> {
+  let post_ids = Poll::take_votes_changed(&mut context.pool()).await?;
+  for post_id in post_ids {
+    let res = Post::read(&mut context.pool(), post_id)
+      .await
+      .and_then(|post| {
+        ActivityChannel::submit_activity(SendActivityData::UpdatePost(post), context)
+      });
+    if let Err(e) = res {
+      warn!("Failed to federate poll votes of post {post_id}: {e}");
+    }
+  }
+  Ok(())
+}
+
 /// Find all unpublished posts with scheduled date in the future, and publish them.
 async fn publish_scheduled_posts(context: &Data<LemmyContext>) -> LemmyResult<()> {
   let pool = &mut context.pool();
@@ -623,14 +828,122 @@ async fn publish_scheduled_posts(context: &Data<LemmyContext>) -> LemmyResult<()
     // mark post as published in db
     let form = PostUpdateForm {
       scheduled_publish_time_at: Some(None),
+      scheduled_recurrence: Some(None),
       ..Default::default()
     };
     Post::update(&mut context.pool(), post.id, &form).await?;
+    if let Err(e) = schedule_next_recurrence(&post, &community, context).await {
+      warn!(
+        "Failed to schedule next recurrence of post {}: {e}",
+        post.id
+      );
+    }
+
+    // Posts in the mod queue are published once they are approved
+    if post.approval_pending || automod_post(post.id, AutomodEvent::BeforeCreate, context).await {
+      continue;
+    }
 
-    // send out post via federation and webmention
+    // send out post via federation, webhooks and webmention
     let send_activity = SendActivityData::CreatePost(post.clone());
     ActivityChannel::submit_activity(send_activity, context)?;
+    send_webhook(
+      WebhookEventType::NewPost,
+      Some(community.id),
+      &post,
+      context,
+    );
+    let post_id = post.id;
     send_webmention(post, &community, context.clone());
+    automod_post(post_id, AutomodEvent::Create, context).await;
+  }
+  Ok(())
+}
+
+/// Returns the first occurrence of a recurring schedule which is after `now`.
+fn next_recurrence(
+  recurrence: ScheduleRecurrence,
+  mut time: DateTime<Utc>,
+  now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+  while time <= now {
+    time = match recurrence {
+      ScheduleRecurrence::Daily => time.checked_add_days(Days::new(1)),
+      ScheduleRecurrence::Weekly => time.checked_add_days(Days::new(7)),
+      ScheduleRecurrence::Monthly => time.checked_add_months(Months::new(1)),
+    }?;
+  }
+  Some(time)
+}
+
+/// For recurring posts, schedule a copy of the post for the next occurrence.
+async fn schedule_next_recurrence(
+  post: &Post,
+  community: &Community,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let (Some(recurrence), Some(published)) =
+    (post.scheduled_recurrence, post.scheduled_publish_time_at)
+  else {
+    return Ok(());
+  };
+  let next =
+    next_recurrence(recurrence, published, Utc::now()).ok_or(LemmyErrorType::InvalidUnixTime)?;
+
+  // Each copy goes through the mod queue like a newly created post
+  let creator = Person::read(&mut context.pool(), post.creator_id).await?;
+  let approval_pending = requires_approval(&creator, community, true, &mut context.pool()).await?;
+  let form = PostInsertForm {
+    nsfw: Some(post.nsfw),
+    url: post.url.clone(),
+    body: post.body.clone(),
+    embed_title: post.embed_title.clone(),
+    embed_description: post.embed_description.clone(),
+    embed_video_url: post.embed_video_url.clone(),
+    embed_video_width: post.embed_video_width,
+    embed_video_height: post.embed_video_height,
+    thumbnail_url: post.thumbnail_url.clone(),
+    language_id: Some(post.language_id),
+    url_content_type: post.url_content_type.clone(),
+    alt_text: post.alt_text.clone(),
+    federation_pending: Some(post.federation_pending),
+    scheduled_publish_time_at: Some(next),
+    scheduled_recurrence: Some(recurrence),
+    approval_pending: Some(approval_pending),
+    ..PostInsertForm::new(post.name.clone(), post.creator_id, post.community_id)
+  };
+  let inserted = Post::create(&mut context.pool(), &form).await?;
+  let like_form = PostLikeForm::new(inserted.id, inserted.creator_id, Some(true));
+  PostActions::like(&mut context.pool(), &like_form).await?;
+
+  let tag_ids = CommunityTag::read_for_post(&mut context.pool(), post.id)
+    .await?
+    .into_iter()
+    .map(|t| t.id)
+    .collect::<Vec<_>>();
+  if !tag_ids.is_empty() {
+    PostCommunityTag::update(&mut context.pool(), &inserted, &tag_ids).await?;
+  }
+
+  // The poll starts over without votes, and stays open for the same duration
+  if let Some(poll) = Poll::read(&mut context.pool(), post.id).await? {
+    let poll_form = PollInsertForm {
+      post_id: inserted.id,
+      multiple_choice: poll.multiple_choice,
+      end_time_at: poll.end_time_at.map(|end| end + (next - published)),
+      updated_at: None,
+    };
+    let options = PollOption::list_for_post(&mut context.pool(), post.id)
+      .await?
+      .into_iter()
+      .map(|o| PollOptionInsertForm {
+        post_id: inserted.id,
+        name: o.name,
+        display_order: o.display_order,
+        vote_count: None,
+      })
+      .collect::<Vec<_>>();
+    Poll::upsert(&mut context.pool(), &poll_form, &options).await?;
   }
   Ok(())
 }
@@ -764,6 +1077,32 @@ mod tests {
     Ok(())
   }
 
+  #[test]
+  fn test_next_recurrence() -> LemmyResult<()> {
+    let time = Utc
+      .with_ymd_and_hms(2026, 1, 31, 12, 0, 0)
+      .single()
+      .ok_or(LemmyErrorType::NotFound)?;
+    let now = time + Duration::from_secs(60);
+
+    let daily = next_recurrence(ScheduleRecurrence::Daily, time, now);
+    assert_eq!(Utc.with_ymd_and_hms(2026, 2, 1, 12, 0, 0).single(), daily);
+    let weekly = next_recurrence(ScheduleRecurrence::Weekly, time, now);
+    assert_eq!(Utc.with_ymd_and_hms(2026, 2, 7, 12, 0, 0).single(), weekly);
+    // Clamped to the last day of the month
+    let monthly = next_recurrence(ScheduleRecurrence::Monthly, time, now);
+    assert_eq!(
+      Utc.with_ymd_and_hms(2026, 2, 28, 12, 0, 0).single(),
+      monthly
+    );
+
+    // Occurrences which were missed are skipped
+    let later = time + Duration::from_secs(3 * 24 * 60 * 60);
+    let daily = next_recurrence(ScheduleRecurrence::Daily, time, later);
+    assert_eq!(Utc.with_ymd_and_hms(2026, 2, 4, 12, 0, 0).single(), daily);
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_scheduled_tasks() -> LemmyResult<()> {
@@ -799,6 +1138,7 @@ mod tests {
     delete_old_denied_users(pool).await?;
     update_instance_software(pool, &context).await?;
     publish_scheduled_posts(&context).await?;
+    send_email_digests(&context).await?;
 
     let community_after = Community::read(pool, community.id).await?;
     assert_eq!(
@@ -12,9 +12,11 @@ use lemmy_api::sitemap::get_sitemap;
 use lemmy_api_utils::{
   context::LemmyContext,
   plugins::LemmyPlugins,
+  realtime::listen_realtime_events,
   request::client_builder,
   send_activity::ActivityChannel,
   utils::local_site_rate_limit_to_rate_limit_config,
+  webhooks::deliver_webhooks,
 };
 use lemmy_apub::{
   FEDERATION_HTTP_FETCH_LIMIT,
@@ -27,6 +29,7 @@ use lemmy_apub_send::{Opts, SendManager};
 use lemmy_db_schema::source::secret::Secret;
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::connection::build_db_pool;
+use lemmy_email::deliver_emails;
 use lemmy_routes::{
   feeds,
   middleware::{
@@ -76,7 +79,7 @@ const ACTIVITY_SENDING_TIMEOUT: Duration = Duration::from_secs(125);
 // TODO: Instead of defining individual env vars, only specify prefix once supported by clap.
 //       https://github.com/clap-rs/clap/issues/3221
 pub struct CmdArgs {
-  /// Don't run scheduled tasks.
+  /// Don't run scheduled tasks and don't send webhooks.
   ///
   /// If you are running multiple Lemmy server processes, you probably want to disable scheduled
   /// tasks on all but one of the processes, to avoid running the tasks more often than intended.
@@ -247,13 +250,21 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
   if !args.disable_scheduled_tasks {
     // Schedules various cleanup tasks for the DB
     let _scheduled_tasks = tokio::task::spawn(scheduled_tasks::setup(request_data.clone()));
+    // Sends queued webhooks in the background
+    let _webhook_deliveries = tokio::task::spawn(deliver_webhooks(request_data.clone()));
   }
+  // Sends queued emails in the background. Emails are claimed with row locks, so this runs in all
+  // processes without sending any email twice.
+  let _email_deliveries = tokio::task::spawn(deliver_emails(pool.clone(), &SETTINGS));
 
   let server = if !args.disable_http_server {
     if let Some(startup_server_handle) = startup_server_handle {
       startup_server_handle.stop(true).await;
     }
 
+    // Forwards realtime events from all Lemmy processes to the event streams of this one
+    let _realtime_events = tokio::task::spawn(listen_realtime_events());
+
     Some(create_http_server(
       federation_config.clone(),
       SETTINGS.clone(),
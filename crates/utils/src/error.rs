@@ -19,6 +19,8 @@ pub enum LemmyErrorType {
   NotAnAdmin,
   CantBlockYourself,
   CantNoteYourself,
+  CantReportYourself,
+  CantFollowYourself,
   CantBlockAdmin,
   PasswordsDoNotMatch,
   EmailNotVerified,
@@ -95,7 +97,11 @@ pub enum LemmyErrorType {
   SiteNameLengthOverflow,
   PermissiveRegex,
   InvalidRegex,
+  /// Automod rules need at least one condition, and a reply text for the reply action.
+  InvalidAutomodRule,
   InvalidUrlScheme,
+  /// Webhook and push urls must not point to loopback, private or link-local addresses.
+  UrlResolvesToPrivateAddress,
   ContradictingFilters,
   /// Thrown when an API call is submitted with more than 1000 array elements, see
   /// [[MAX_API_PARAM_ELEMENTS]]
@@ -124,6 +130,43 @@ pub enum LemmyErrorType {
   MultiCommunityEntryLimitReached,
   TooManyRequests,
   ResolveObjectFailed(String),
+  InvalidPoll,
+  PollClosed,
+  CantMoveToYourself,
+  /// The account which should be moved to doesn't list the old account in `also_known_as`.
+  MissingAccountAlias,
+  AccountAlreadyMoved,
+  /// The flair can only be assigned by community moderators.
+  FlairNotSelfAssignable,
+  /// Reactions must be a single unicode emoji or a custom emoji shortcode.
+  InvalidReaction,
+  /// The push subscription needs an https endpoint and valid encryption keys.
+  InvalidPushSubscription,
+  /// A user can only register a limited number of push subscriptions.
+  TooManyPushSubscriptions,
+  NotAConversationParticipant,
+  /// A conversation needs at least two other participants, and not too many.
+  InvalidConversationParticipants,
+  OnlyConversationCreatorCanRemoveParticipants,
+  OnlyConversationCreatorCanAddParticipants,
+  /// Only moderators can be assigned to modmail.
+  ModmailAssigneeNotAModerator,
+  /// The cited rule doesn't exist, or belongs to a different community.
+  InvalidRule,
+  /// Mod notes can only be edited by the moderator who wrote them.
+  EditModNoteNotAllowed,
+  /// The values of a warning policy can't be negative.
+  InvalidWarningPolicy,
+  TooManyScheduledComments,
+  TooManyScheduledPrivateMessages,
+  /// Scheduled comments can only be edited or deleted by their creator.
+  EditScheduledCommentNotAllowed,
+  /// Rules of remote communities are managed on the community's instance.
+  CantManageRulesOfRemoteCommunity,
+  /// Conversation messages can only be edited or deleted by their creator.
+  EditConversationMessageNotAllowed,
+  /// Moderators can only reply to modmail on the home instance of the community.
+  CantReplyToModmailOfRemoteCommunity,
   #[serde(untagged)]
   #[cfg_attr(feature = "ts-rs", ts(skip))]
   UntranslatedError(Option<UntranslatedError>),
@@ -160,6 +203,8 @@ pub enum UntranslatedError {
   /// A remote community sent an activity to us, but actually no local user follows the community
   /// so the activity was rejected.
   CommunityHasNoFollowers(String),
+  /// Modmail must be sent between a person and a community.
+  InvalidModmail,
 }
 
 cfg_select! {
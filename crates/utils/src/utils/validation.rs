@@ -27,6 +27,7 @@ const MIN_LENGTH_BLOCKING_KEYWORD: usize = 3;
 const MAX_LENGTH_BLOCKING_KEYWORD: usize = 50;
 const ACTOR_NAME_MAX_LENGTH: usize = 20;
 pub const DISPLAY_NAME_MAX_LENGTH: usize = 50;
+const REACTION_MAX_LENGTH: usize = 128;
 
 fn has_newline(name: &str) -> bool {
   name.contains('\n')
@@ -322,12 +323,50 @@ pub fn truncate_for_db(text: &str, len: usize) -> String {
   }
 }
 
+/// Checks that an emoji reaction is either a single unicode emoji, or a custom emoji shortcode
+/// like `:lemmy:`. Shortcodes of remote emojis may include the domain, like `:lemmy@example.com:`.
+pub fn is_valid_reaction(emoji: &str) -> LemmyResult<()> {
+  #[expect(clippy::expect_used)]
+  static VALID_SHORTCODE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^:[a-zA-Z0-9_+-]+(@[a-zA-Z0-9.-]+)?:$").expect("compile regex")
+  });
+
+  max_length_check(emoji, REACTION_MAX_LENGTH, LemmyErrorType::InvalidReaction)?;
+  let is_single_emoji = emoji.graphemes(true).count() == 1
+    && !emoji.is_ascii()
+    && !emoji.chars().any(char::is_whitespace)
+    && emoji.chars().any(|c| !INVISIBLE_CHARS.contains(&c));
+  if is_single_emoji || VALID_SHORTCODE_REGEX.is_match(emoji) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidReaction.into())
+  }
+}
+
 pub fn check_api_elements_count(len: usize) -> LemmyResult<()> {
   if len >= MAX_API_PARAM_ELEMENTS {
     return Err(LemmyErrorType::TooManyItems.into());
   }
   Ok(())
 }
+
+/// Checks the values of a warning policy, which are counts of warnings or days. Zero unsets a
+/// value.
+pub fn check_warning_policy(
+  threshold: Option<i32>,
+  window_days: Option<i32>,
+  ban_days: Option<i32>,
+) -> LemmyResult<()> {
+  if [threshold, window_days, ban_days]
+    .into_iter()
+    .flatten()
+    .any(|v| v < 0)
+  {
+    Err(LemmyErrorType::InvalidWarningPolicy.into())
+  } else {
+    Ok(())
+  }
+}
 #[cfg(test)]
 mod tests {
 
@@ -340,12 +379,14 @@ mod tests {
       URL_MAX_LENGTH,
       build_and_check_regex,
       check_urls_are_valid,
+      check_warning_policy,
       is_url_blocked,
       is_valid_actor_name,
       is_valid_bio_field,
       is_valid_display_name,
       is_valid_matrix_id,
       is_valid_post_title,
+      is_valid_reaction,
       is_valid_url,
       site_name_length_check,
       summary_length_check,
@@ -424,6 +465,19 @@ Line3",
     assert!(is_valid_post_title("\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}").is_ok());
   }
 
+  #[test]
+  fn test_valid_reaction() {
+    assert!(is_valid_reaction("👍").is_ok());
+    assert!(is_valid_reaction("\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}").is_ok());
+    assert!(is_valid_reaction(":lemmy:").is_ok());
+    assert!(is_valid_reaction(":blobcat_heart@misskey.example:").is_ok());
+    assert!(is_valid_reaction("👍👍").is_err());
+    assert!(is_valid_reaction("a").is_err());
+    assert!(is_valid_reaction("").is_err());
+    assert!(is_valid_reaction(": lemmy:").is_err());
+    assert!(is_valid_reaction("\u{200b}").is_err());
+  }
+
   #[test]
   fn test_valid_matrix_id() {
     assert!(is_valid_matrix_id("@dess:matrix.org").is_ok());
@@ -633,4 +687,11 @@ Line3",
 
     Ok(())
   }
+
+  #[test]
+  fn test_check_warning_policy() {
+    assert!(check_warning_policy(Some(3), Some(30), Some(7)).is_ok());
+    assert!(check_warning_policy(Some(0), None, None).is_ok());
+    assert!(check_warning_policy(Some(3), Some(-1), Some(7)).is_err());
+  }
 }
@@ -0,0 +1,2 @@
+DROP TABLE post_search, comment_search, community_search, person_search;
+
@@ -0,0 +1,136 @@
+-- Full-text search vectors for posts, comments, communities and persons. These are kept in
+-- separate tables, so that the frequently updated content tables don't need to carry the (large)
+-- tsvector values. The tables are filled by triggers in replaceable_schema/triggers.sql.
+CREATE TABLE post_search (
+    post_id int PRIMARY KEY REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    search_vector tsvector NOT NULL
+);
+
+CREATE TABLE comment_search (
+    comment_id int PRIMARY KEY REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    search_vector tsvector NOT NULL
+);
+
+CREATE TABLE community_search (
+    community_id int PRIMARY KEY REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    search_vector tsvector NOT NULL
+);
+
+CREATE TABLE person_search (
+    person_id int PRIMARY KEY REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    search_vector tsvector NOT NULL
+);
+
+-- Fill the tables for existing content. These are the same calculations as r.search_config and
+-- r.search_vector, which can't be used here because the `r` schema doesn't exist during migrations.
+CREATE FUNCTION pg_temp.search_config (language_id integer)
+    RETURNS regconfig
+    LANGUAGE sql
+    STABLE PARALLEL SAFE RETURN (
+        SELECT
+            (
+                CASE left (code, 2)
+                WHEN 'ar' THEN
+                    'arabic'
+                WHEN 'da' THEN
+                    'danish'
+                WHEN 'de' THEN
+                    'german'
+                WHEN 'el' THEN
+                    'greek'
+                WHEN 'en' THEN
+                    'english'
+                WHEN 'es' THEN
+                    'spanish'
+                WHEN 'fi' THEN
+                    'finnish'
+                WHEN 'fr' THEN
+                    'french'
+                WHEN 'ga' THEN
+                    'irish'
+                WHEN 'hu' THEN
+                    'hungarian'
+                WHEN 'id' THEN
+                    'indonesian'
+                WHEN 'it' THEN
+                    'italian'
+                WHEN 'lt' THEN
+                    'lithuanian'
+                WHEN 'nb' THEN
+                    'norwegian'
+                WHEN 'ne' THEN
+                    'nepali'
+                WHEN 'nl' THEN
+                    'dutch'
+                WHEN 'nn' THEN
+                    'norwegian'
+                WHEN 'no' THEN
+                    'norwegian'
+                WHEN 'pt' THEN
+                    'portuguese'
+                WHEN 'ro' THEN
+                    'romanian'
+                WHEN 'ru' THEN
+                    'russian'
+                WHEN 'sv' THEN
+                    'swedish'
+                WHEN 'ta' THEN
+                    'tamil'
+                WHEN 'tr' THEN
+                    'turkish'
+                ELSE
+                    'simple'
+                END)::regconfig
+        FROM LANGUAGE
+        WHERE
+            id = language_id);
+
+CREATE FUNCTION pg_temp.search_vector (content text, config regconfig, weight "char")
+    RETURNS tsvector
+    LANGUAGE sql
+    IMMUTABLE PARALLEL SAFE RETURN setweight(
+        CASE WHEN config = 'simple'::regconfig THEN
+            to_tsvector('simple', coalesce(content, ''))
+        ELSE
+            to_tsvector(config, coalesce(content, '')) || to_tsvector('simple', coalesce(content, ''))
+        END, weight);
+
+INSERT INTO post_search (post_id, search_vector)
+SELECT
+    id,
+    pg_temp.search_vector (name, pg_temp.search_config (language_id), 'A') || pg_temp.search_vector (body, pg_temp.search_config (language_id), 'B')
+FROM
+    post;
+
+INSERT INTO comment_search (comment_id, search_vector)
+SELECT
+    id,
+    pg_temp.search_vector (content, pg_temp.search_config (language_id), 'A')
+FROM
+    comment;
+
+INSERT INTO community_search (community_id, search_vector)
+SELECT
+    id,
+    pg_temp.search_vector (title, 'simple', 'A') || pg_temp.search_vector (summary, 'simple', 'B') || pg_temp.search_vector (sidebar, 'simple', 'C')
+FROM
+    community;
+
+INSERT INTO person_search (person_id, search_vector)
+SELECT
+    id,
+    pg_temp.search_vector (bio, 'simple', 'A')
+FROM
+    person;
+
+-- Temporary functions would otherwise stay around until the connection is closed
+DROP FUNCTION pg_temp.search_config, pg_temp.search_vector;
+
+CREATE INDEX idx_post_search_vector ON post_search USING gin (search_vector);
+
+CREATE INDEX idx_comment_search_vector ON comment_search USING gin (search_vector);
+
+CREATE INDEX idx_community_search_vector ON community_search USING gin (search_vector);
+
+CREATE INDEX idx_person_search_vector ON person_search USING gin (search_vector);
+
@@ -0,0 +1,4 @@
+ALTER TABLE post_actions
+    DROP COLUMN poll_voted_at;
+
+DROP TABLE poll_vote, poll_option, poll;
@@ -0,0 +1,42 @@
+-- A post can have at most one poll attached. For remote polls the vote counts are taken from the
+-- Question object of the origin instance, for local polls they are counted from poll_vote.
+CREATE TABLE poll (
+    post_id int PRIMARY KEY REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    multiple_choice boolean NOT NULL DEFAULT FALSE,
+    end_time_at timestamptz,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    -- Vote counts of local polls are federated by a scheduled task, instead of sending an update
+    -- of the post after every vote.
+    votes_changed boolean NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX idx_poll_votes_changed ON poll (post_id)
+WHERE
+    votes_changed;
+
+CREATE TABLE poll_option (
+    id serial PRIMARY KEY,
+    post_id int NOT NULL REFERENCES poll ON UPDATE CASCADE ON DELETE CASCADE,
+    name text NOT NULL,
+    display_order int NOT NULL,
+    vote_count int NOT NULL DEFAULT 0,
+    UNIQUE (post_id, name)
+);
+
+CREATE TABLE poll_vote (
+    poll_option_id int NOT NULL REFERENCES poll_option ON UPDATE CASCADE ON DELETE CASCADE,
+    person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int NOT NULL REFERENCES poll ON UPDATE CASCADE ON DELETE CASCADE,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (poll_option_id, person_id)
+);
+
+CREATE INDEX idx_poll_vote_post_person ON poll_vote (post_id, person_id);
+
+CREATE INDEX idx_poll_vote_person ON poll_vote (person_id);
+
+-- Keeps the post_actions row around while the user has voted, so that PostView can read the
+-- user's votes through it.
+ALTER TABLE post_actions
+    ADD COLUMN poll_voted_at timestamptz;
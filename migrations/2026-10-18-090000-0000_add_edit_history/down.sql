@@ -0,0 +1,6 @@
+ALTER TABLE local_site
+    DROP COLUMN edit_history_visibility;
+
+DROP TYPE edit_history_visibility_enum;
+
+DROP TABLE post_revision, comment_revision;
@@ -0,0 +1,30 @@
+-- Previous versions of posts and comments, which are stored when the content is edited. The
+-- current version is always in the post or comment itself.
+CREATE TABLE post_revision (
+    id serial PRIMARY KEY,
+    post_id int NOT NULL REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    name varchar(200) NOT NULL,
+    url varchar(2000),
+    body text,
+    alt_text text,
+    published_at timestamptz NOT NULL
+);
+
+CREATE INDEX idx_post_revision_post ON post_revision (post_id, published_at DESC);
+
+CREATE TABLE comment_revision (
+    id serial PRIMARY KEY,
+    comment_id int NOT NULL REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    content text NOT NULL,
+    published_at timestamptz NOT NULL
+);
+
+CREATE INDEX idx_comment_revision_comment ON comment_revision (comment_id, published_at DESC);
+
+CREATE TYPE edit_history_visibility_enum AS enum (
+    'ModsOnly',
+    'Everyone'
+);
+
+ALTER TABLE local_site
+    ADD COLUMN edit_history_visibility edit_history_visibility_enum NOT NULL DEFAULT 'ModsOnly';
@@ -0,0 +1,42 @@
+-- Outgoing webhooks which are called for site or community events. Webhooks without a community
+-- are managed by admins and receive events for the whole site, while community webhooks are
+-- managed by the community mods and only receive events for that community.
+CREATE TYPE webhook_event_enum AS enum (
+    'NewReport',
+    'NewRegistrationApplication',
+    'NewPost',
+    'ModlogEntry',
+    'Ban'
+);
+
+CREATE TABLE webhook (
+    id serial PRIMARY KEY,
+    creator_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    url varchar(2000) NOT NULL,
+    secret text NOT NULL,
+    events webhook_event_enum[] NOT NULL,
+    enabled boolean NOT NULL DEFAULT TRUE,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_webhook_community ON webhook (community_id);
+
+-- Queue of webhook calls. Failed deliveries are retried with increasing delay.
+CREATE TABLE webhook_delivery (
+    id serial PRIMARY KEY,
+    webhook_id int NOT NULL REFERENCES webhook ON UPDATE CASCADE ON DELETE CASCADE,
+    event webhook_event_enum NOT NULL,
+    payload jsonb NOT NULL,
+    fail_count int NOT NULL DEFAULT 0,
+    last_error text,
+    next_attempt_at timestamptz NOT NULL DEFAULT now(),
+    delivered_at timestamptz,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_webhook_delivery_pending ON webhook_delivery (next_attempt_at)
+WHERE
+    delivered_at IS NULL;
+
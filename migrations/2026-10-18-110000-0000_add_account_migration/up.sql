@@ -0,0 +1,5 @@
+-- Aliases of the account which are allowed to move to it, and the account it was moved to
+ALTER TABLE person
+    ADD COLUMN also_known_as text[] NOT NULL DEFAULT '{}',
+    ADD COLUMN moved_to_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL;
+
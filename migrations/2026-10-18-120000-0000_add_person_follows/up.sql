@@ -0,0 +1,23 @@
+ALTER TYPE listing_type_enum
+    ADD VALUE 'FollowedPersons';
+
+ALTER TABLE person
+    ADD COLUMN follower_count int NOT NULL DEFAULT 0;
+
+UPDATE
+    person AS p
+SET
+    follower_count = f.count
+FROM (
+    SELECT
+        target_id,
+        count(*) AS count
+    FROM
+        person_actions
+    WHERE
+        followed_at IS NOT NULL
+    GROUP BY
+        target_id) AS f
+WHERE
+    p.id = f.target_id;
+
@@ -0,0 +1,57 @@
+-- Automod rules, which are evaluated for new posts and comments as well as for new reports. Rules
+-- without a community are managed by admins and apply to the whole site, while community rules
+-- are managed by the community mods. All conditions which are set must match for the rule to
+-- trigger.
+CREATE TYPE automod_action_enum AS enum (
+    'Remove',
+    'Lock',
+    'HoldForApproval',
+    'Report',
+    'Reply'
+);
+
+CREATE TABLE automod_rule (
+    id serial PRIMARY KEY,
+    creator_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    reason text NOT NULL,
+    action automod_action_enum NOT NULL,
+    reply_text text,
+    check_posts boolean NOT NULL DEFAULT TRUE,
+    check_comments boolean NOT NULL DEFAULT TRUE,
+    content_regex text,
+    link_domain text,
+    max_account_age_days int,
+    max_creator_score int,
+    report_count int,
+    language_id int REFERENCES
+    LANGUAGE ON UPDATE CASCADE ON DELETE CASCADE,
+    enabled boolean NOT NULL DEFAULT TRUE,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    CHECK ((action = 'Reply') = (reply_text IS NOT NULL)),
+    CHECK (num_nonnulls (content_regex, link_domain, max_account_age_days, max_creator_score, report_count, language_id) > 0)
+);
+
+CREATE INDEX idx_automod_rule_community ON automod_rule (community_id);
+
+-- Remembers which automod rules with a report count condition have already been applied to a post
+-- or comment, so that they only trigger once when the count reaches the threshold.
+CREATE TABLE automod_rule_trigger (
+    id serial PRIMARY KEY,
+    automod_rule_id int NOT NULL REFERENCES automod_rule ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int NOT NULL REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX idx_automod_rule_trigger_unique ON automod_rule_trigger (automod_rule_id, post_id, coalesce(comment_id, 0));
+
+ALTER TYPE modlog_kind
+    ADD VALUE 'AutomodReport';
+
+ALTER TYPE modlog_kind
+    ADD VALUE 'AutomodReply';
+
+ALTER TYPE modlog_kind
+    ADD VALUE 'AutomodHold';
@@ -0,0 +1,12 @@
+ALTER TABLE community
+    DROP COLUMN posts_require_approval,
+    DROP COLUMN comments_require_approval,
+    DROP COLUMN approval_max_account_age_days,
+    DROP COLUMN approval_remote_only;
+
+ALTER TABLE post
+    DROP COLUMN approval_pending;
+
+ALTER TABLE comment
+    DROP COLUMN approval_pending;
+
@@ -0,0 +1,23 @@
+-- Pre-moderation for communities. When enabled, new posts and/or comments are held in a pending
+-- state until a moderator approves them. Optionally only content from new accounts (younger than
+-- the given number of days) or from remote instances is held.
+ALTER TABLE community
+    ADD COLUMN posts_require_approval boolean NOT NULL DEFAULT FALSE,
+    ADD COLUMN comments_require_approval boolean NOT NULL DEFAULT FALSE,
+    ADD COLUMN approval_max_account_age_days int,
+    ADD COLUMN approval_remote_only boolean NOT NULL DEFAULT FALSE;
+
+ALTER TABLE post
+    ADD COLUMN approval_pending boolean NOT NULL DEFAULT FALSE;
+
+ALTER TABLE comment
+    ADD COLUMN approval_pending boolean NOT NULL DEFAULT FALSE;
+
+CREATE INDEX idx_post_approval_pending ON post (community_id, published_at)
+WHERE
+    approval_pending;
+
+CREATE INDEX idx_comment_approval_pending ON comment (community_id, published_at)
+WHERE
+    approval_pending;
+
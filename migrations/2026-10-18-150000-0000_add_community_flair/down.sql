@@ -0,0 +1,5 @@
+ALTER TABLE community_actions
+    DROP COLUMN flair_id;
+
+DROP TABLE community_flair;
+
@@ -0,0 +1,24 @@
+-- Person flair which is defined by community mods. Each community member can have a single flair,
+-- which is assigned by mods, or by the member themself if the flair is self assignable.
+CREATE TABLE community_flair (
+    id serial PRIMARY KEY,
+    ap_id text NOT NULL UNIQUE,
+    name varchar(255) NOT NULL,
+    display_name varchar(255),
+    community_id int NOT NULL REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    color tag_color_enum NOT NULL DEFAULT 'color01',
+    self_assignable boolean NOT NULL DEFAULT FALSE,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    deleted boolean NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX idx_community_flair_community ON community_flair (community_id);
+
+ALTER TABLE community_actions
+    ADD COLUMN flair_id int REFERENCES community_flair ON UPDATE CASCADE ON DELETE SET NULL;
+
+CREATE INDEX idx_community_actions_flair ON community_actions (flair_id)
+WHERE
+    flair_id IS NOT NULL;
+
@@ -0,0 +1,8 @@
+ALTER TABLE post_actions
+    DROP COLUMN reacted_at;
+
+ALTER TABLE comment_actions
+    DROP COLUMN reacted_at;
+
+DROP TABLE reaction;
+
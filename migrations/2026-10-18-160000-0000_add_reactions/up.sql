@@ -0,0 +1,30 @@
+-- Emoji reactions on posts and comments. The emoji is either a unicode emoji, or a custom emoji
+-- shortcode like `:lemmy:`. For local custom emojis the id is stored, for remote custom emojis
+-- the image url from the federated activity.
+CREATE TABLE reaction (
+    id serial PRIMARY KEY,
+    person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    comment_id int REFERENCES COMMENT ON UPDATE CASCADE ON DELETE CASCADE,
+    emoji varchar(128) NOT NULL,
+    custom_emoji_id int REFERENCES custom_emoji ON UPDATE CASCADE ON DELETE CASCADE,
+    emoji_url text,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    CHECK (num_nonnulls (post_id, comment_id) = 1)
+);
+
+CREATE UNIQUE INDEX idx_reaction_post ON reaction (post_id, person_id, emoji)
+WHERE
+    post_id IS NOT NULL;
+
+CREATE UNIQUE INDEX idx_reaction_comment ON reaction (comment_id, person_id, emoji)
+WHERE
+    comment_id IS NOT NULL;
+
+-- Set while the person has any reactions on the item, so that views can find their own reactions.
+ALTER TABLE post_actions
+    ADD COLUMN reacted_at timestamptz;
+
+ALTER TABLE comment_actions
+    ADD COLUMN reacted_at timestamptz;
+
@@ -0,0 +1,5 @@
+DROP TABLE push_subscription;
+
+ALTER TABLE secret
+    DROP COLUMN vapid_private_key;
+
@@ -0,0 +1,18 @@
+-- The VAPID keypair which signs Web Push requests, as base64url encoded PKCS#8 document. It is
+-- generated by Lemmy on startup, as Postgres can't generate P-256 keys.
+ALTER TABLE secret
+    ADD COLUMN vapid_private_key text;
+
+-- Web Push subscriptions of local users. The keys are base64url encoded, as sent by the browser.
+CREATE TABLE push_subscription (
+    id serial PRIMARY KEY,
+    local_user_id int NOT NULL REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE,
+    endpoint text NOT NULL UNIQUE,
+    p256dh text NOT NULL,
+    auth text NOT NULL,
+    expires_at timestamptz,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_push_subscription_local_user ON push_subscription (local_user_id);
+
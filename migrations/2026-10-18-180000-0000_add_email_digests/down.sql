@@ -0,0 +1,7 @@
+ALTER TABLE local_user
+    DROP COLUMN email_digest_cadence,
+    DROP COLUMN email_digest_top_posts,
+    DROP COLUMN last_email_digest_at;
+
+DROP TYPE email_digest_cadence_enum;
+
@@ -0,0 +1,13 @@
+-- Users can receive notification emails immediately, or batched into a periodic digest.
+CREATE TYPE email_digest_cadence_enum AS enum (
+    'Immediate',
+    'Hourly',
+    'Daily',
+    'Weekly'
+);
+
+ALTER TABLE local_user
+    ADD COLUMN email_digest_cadence email_digest_cadence_enum NOT NULL DEFAULT 'Immediate',
+    ADD COLUMN email_digest_top_posts boolean NOT NULL DEFAULT FALSE,
+    ADD COLUMN last_email_digest_at timestamptz;
+
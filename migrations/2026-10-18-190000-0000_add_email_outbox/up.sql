@@ -0,0 +1,25 @@
+-- Queue of outgoing emails, which are sent by a background worker. Failed emails are retried with
+-- increasing delay, and after too many attempts they are marked as failed so that admins can
+-- resend them.
+CREATE TABLE email_outbox (
+    id serial PRIMARY KEY,
+    recipient_email text NOT NULL,
+    recipient_name text NOT NULL,
+    subject text NOT NULL,
+    html text NOT NULL,
+    fail_count int NOT NULL DEFAULT 0,
+    last_error text,
+    next_attempt_at timestamptz NOT NULL DEFAULT now(),
+    sent_at timestamptz,
+    failed_at timestamptz,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_email_outbox_pending ON email_outbox (next_attempt_at)
+WHERE
+    sent_at IS NULL AND failed_at IS NULL;
+
+CREATE INDEX idx_email_outbox_failed ON email_outbox (failed_at)
+WHERE
+    failed_at IS NOT NULL;
+
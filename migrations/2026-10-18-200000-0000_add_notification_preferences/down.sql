@@ -0,0 +1,2 @@
+DROP TABLE local_user_notification_preference;
+
@@ -0,0 +1,11 @@
+-- Per notification type preferences for each delivery channel. If there is no row for a type,
+-- notifications of that type are delivered through all channels.
+CREATE TABLE local_user_notification_preference (
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    kind notification_type_enum NOT NULL,
+    in_app boolean NOT NULL DEFAULT TRUE,
+    email boolean NOT NULL DEFAULT TRUE,
+    push boolean NOT NULL DEFAULT TRUE,
+    PRIMARY KEY (local_user_id, kind)
+);
+
@@ -0,0 +1,2 @@
+DROP TABLE conversation_message, conversation_participant, conversation;
+
@@ -0,0 +1,34 @@
+-- Private conversations with several participants. Each participant has their own read state.
+CREATE TABLE conversation (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    ap_id varchar(255) UNIQUE NOT NULL,
+    local boolean NOT NULL DEFAULT TRUE,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    last_message_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE TABLE conversation_participant (
+    conversation_id int REFERENCES conversation ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    last_read_at timestamptz,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (conversation_id, person_id)
+);
+
+CREATE INDEX idx_conversation_participant_person ON conversation_participant (person_id);
+
+CREATE TABLE conversation_message (
+    id serial PRIMARY KEY,
+    conversation_id int REFERENCES conversation ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    content text NOT NULL,
+    ap_id varchar(255) UNIQUE NOT NULL,
+    local boolean NOT NULL DEFAULT TRUE,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    deleted boolean NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX idx_conversation_message_conversation ON conversation_message (conversation_id, published_at);
+
@@ -0,0 +1,2 @@
+DROP TABLE modmail_message, modmail_thread;
+
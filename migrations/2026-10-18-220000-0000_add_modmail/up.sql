@@ -0,0 +1,34 @@
+-- Modmail lets users contact the moderators of a community. There is one thread per user and
+-- community, which all moderators share. Writing again reopens a resolved thread.
+CREATE TABLE modmail_thread (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    assignee_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    resolved boolean NOT NULL DEFAULT FALSE,
+    resolver_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    last_message_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    UNIQUE (community_id, creator_id)
+);
+
+CREATE INDEX idx_modmail_thread_creator ON modmail_thread (creator_id);
+
+-- Replies of moderators are sent as the community. For replies received from a remote community
+-- the moderator isn't known, so creator_id is null. Internal notes are only visible to
+-- moderators and never federated.
+CREATE TABLE modmail_message (
+    id serial PRIMARY KEY,
+    modmail_thread_id int REFERENCES modmail_thread ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    from_moderator boolean NOT NULL DEFAULT FALSE,
+    internal boolean NOT NULL DEFAULT FALSE,
+    content text NOT NULL,
+    ap_id varchar(255) UNIQUE NOT NULL,
+    local boolean NOT NULL DEFAULT TRUE,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_modmail_message_thread ON modmail_message (modmail_thread_id, published_at);
+
@@ -0,0 +1,40 @@
+ALTER TABLE notification
+    DROP CONSTRAINT notification_check;
+
+ALTER TYPE notification_type_enum RENAME TO notification_type_enum__;
+
+DELETE FROM notification
+WHERE kind IN ('ScoreMilestone', 'PostFeatured', 'CommentDistinguished');
+
+DELETE FROM local_user_notification_preference
+WHERE kind IN ('ScoreMilestone', 'PostFeatured', 'CommentDistinguished');
+
+CREATE TYPE notification_type_enum AS ENUM (
+    'Mention',
+    'Reply',
+    'Subscribed',
+    'PrivateMessage',
+    'ModAction'
+);
+
+ALTER TABLE notification
+    ALTER COLUMN kind TYPE notification_type_enum
+    USING kind::text::notification_type_enum;
+
+ALTER TABLE local_user_notification_preference
+    ALTER COLUMN kind TYPE notification_type_enum
+    USING kind::text::notification_type_enum;
+
+DROP TYPE notification_type_enum__;
+
+ALTER TABLE notification
+    ADD CHECK (((kind = 'Mention'
+        OR kind = 'Reply')
+        AND num_nonnulls (post_id, comment_id, community_id) >= 2)
+        OR (kind = 'PrivateMessage'
+        AND num_nonnulls (private_message_id) = 1)
+        OR (kind = 'Subscribed'
+        AND num_nonnulls (post_id) = 1)
+        OR (kind = 'ModAction'
+        AND num_nonnulls (modlog_id, post_id, comment_id, community_id, instance_id) >= 2));
+
@@ -0,0 +1,12 @@
+-- Opt-in notifications about the user's own content: a post reaching a score milestone, a post
+-- being featured, and a moderator distinguishing a reply to the user. The new values can only be
+-- used after this migration is committed, so the columns are added in the next migration.
+ALTER TYPE notification_type_enum
+    ADD value 'ScoreMilestone';
+
+ALTER TYPE notification_type_enum
+    ADD value 'PostFeatured';
+
+ALTER TYPE notification_type_enum
+    ADD value 'CommentDistinguished';
+
@@ -0,0 +1,26 @@
+ALTER TABLE notification
+    ADD COLUMN score_milestone int,
+    DROP CONSTRAINT notification_check;
+
+ALTER TABLE notification
+    ADD CHECK (((kind = 'Mention'
+        OR kind = 'Reply')
+        AND num_nonnulls (post_id, comment_id, community_id) >= 2)
+        OR (kind = 'PrivateMessage'
+        AND num_nonnulls (private_message_id) = 1)
+        OR (kind = 'Subscribed'
+        AND num_nonnulls (post_id) = 1)
+        OR (kind = 'ModAction'
+        AND num_nonnulls (modlog_id, post_id, comment_id, community_id, instance_id) >= 2)
+        OR (kind = 'ScoreMilestone'
+        AND num_nonnulls (post_id, score_milestone) = 2)
+        OR (kind = 'PostFeatured'
+        AND num_nonnulls (post_id) = 1)
+        OR (kind = 'CommentDistinguished'
+        AND num_nonnulls (post_id, comment_id) = 2));
+
+-- Each milestone is only notified once, even if the score drops and rises again.
+CREATE UNIQUE INDEX idx_notification_score_milestone ON notification (recipient_id, post_id, score_milestone)
+WHERE
+    score_milestone IS NOT NULL;
+
@@ -0,0 +1,7 @@
+DROP TABLE scheduled_comment, scheduled_private_message;
+
+ALTER TABLE post
+    DROP COLUMN scheduled_recurrence;
+
+DROP TYPE schedule_recurrence_enum;
+
@@ -0,0 +1,44 @@
+-- Scheduled posts can repeat, eg for a weekly discussion thread. When a recurring post is
+-- published, a copy of it gets scheduled for the next date.
+CREATE TYPE schedule_recurrence_enum AS enum (
+    'Daily',
+    'Weekly',
+    'Monthly'
+);
+
+ALTER TABLE post
+    ADD COLUMN scheduled_recurrence schedule_recurrence_enum;
+
+-- Comments and private messages which are created by the scheduled task once the time is
+-- reached. The row is deleted after publishing.
+CREATE TABLE scheduled_comment (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    parent_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    content text NOT NULL,
+    language_id int REFERENCES LANGUAGE ON UPDATE CASCADE ON DELETE CASCADE,
+    distinguished boolean NOT NULL DEFAULT FALSE,
+    scheduled_publish_time_at timestamptz NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_scheduled_comment_creator ON scheduled_comment (creator_id);
+
+CREATE INDEX idx_scheduled_comment_time ON scheduled_comment (scheduled_publish_time_at);
+
+CREATE TABLE scheduled_private_message (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    recipient_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    content text NOT NULL,
+    scheduled_publish_time_at timestamptz NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_scheduled_private_message_creator ON scheduled_private_message (creator_id);
+
+CREATE INDEX idx_scheduled_private_message_time ON scheduled_private_message (scheduled_publish_time_at);
+
@@ -0,0 +1,39 @@
+-- Numbered rules of a community, or of the whole site if there is no community. Reports, removals
+-- and bans can cite the rule which was broken. Rules are only marked as deleted, so that reports
+-- and modlog entries which cite them can still show them.
+CREATE TABLE rule (
+    id serial PRIMARY KEY,
+    ap_id varchar(255) UNIQUE NOT NULL,
+    local boolean NOT NULL DEFAULT TRUE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    position int NOT NULL DEFAULT 0,
+    title varchar(255) NOT NULL,
+    description text,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    deleted boolean NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX idx_rule_community ON rule (community_id, position);
+
+ALTER TABLE post_report
+    ADD COLUMN rule_id int REFERENCES rule ON UPDATE CASCADE ON DELETE SET NULL;
+
+ALTER TABLE comment_report
+    ADD COLUMN rule_id int REFERENCES rule ON UPDATE CASCADE ON DELETE SET NULL;
+
+ALTER TABLE modlog
+    ADD COLUMN rule_id int REFERENCES rule ON UPDATE CASCADE ON DELETE SET NULL;
+
+CREATE INDEX idx_post_report_rule ON post_report (rule_id)
+WHERE
+    rule_id IS NOT NULL;
+
+CREATE INDEX idx_comment_report_rule ON comment_report (rule_id)
+WHERE
+    rule_id IS NOT NULL;
+
+CREATE INDEX idx_modlog_rule ON modlog (rule_id)
+WHERE
+    rule_id IS NOT NULL;
+
@@ -0,0 +1,10 @@
+DELETE FROM report_combined
+WHERE person_report_id IS NOT NULL;
+
+ALTER TABLE report_combined
+    DROP CONSTRAINT report_combined_check,
+    ADD CONSTRAINT report_combined_check CHECK (num_nonnulls (post_report_id, comment_report_id, private_message_report_id, community_report_id) = 1),
+    DROP COLUMN person_report_id;
+
+DROP TABLE person_report;
+
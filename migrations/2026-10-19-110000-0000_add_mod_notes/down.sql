@@ -0,0 +1,2 @@
+DROP TABLE mod_note;
+
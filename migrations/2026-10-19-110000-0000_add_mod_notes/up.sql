@@ -0,0 +1,16 @@
+-- Notes which the moderators of a community keep about a user. Unlike person_actions.note they
+-- are shared between all moderators of the community, and never federated.
+CREATE TABLE mod_note (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    target_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    content text NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_mod_note_community_target ON mod_note (community_id, target_person_id, published_at DESC);
+
+CREATE INDEX idx_mod_note_creator ON mod_note (creator_id);
+
@@ -0,0 +1,9 @@
+-- Parent entry for bulk moderation actions, the individual removals, locks and bans are logged as
+-- its children
+ALTER TYPE modlog_kind
+    ADD VALUE 'ModBulkAction';
+
+
+-- Logged as children of the bulk action when many reports are resolved at once
+ALTER TYPE modlog_kind
+    ADD VALUE 'ModResolveReport';
@@ -0,0 +1,13 @@
+ALTER TABLE community
+    DROP COLUMN warning_ban_threshold,
+    DROP COLUMN warning_ban_window_days,
+    DROP COLUMN warning_ban_days;
+
+ALTER TABLE local_site
+    DROP COLUMN warning_ban_threshold,
+    DROP COLUMN warning_ban_window_days,
+    DROP COLUMN warning_ban_days;
+
+ALTER TABLE modlog
+    DROP COLUMN warning_ban_id;
+
@@ -0,0 +1,17 @@
+-- Escalating warnings. When a user collects the given number of warnings in a community within the
+-- window, they are automatically banned from it. Communities without their own policy use the
+-- policy of the site.
+ALTER TABLE community
+    ADD COLUMN warning_ban_threshold int,
+    ADD COLUMN warning_ban_window_days int,
+    ADD COLUMN warning_ban_days int;
+
+ALTER TABLE local_site
+    ADD COLUMN warning_ban_threshold int,
+    ADD COLUMN warning_ban_window_days int,
+    ADD COLUMN warning_ban_days int;
+
+-- Links warnings to the automatic ban which they led to
+ALTER TABLE modlog
+    ADD COLUMN warning_ban_id int REFERENCES modlog ON UPDATE CASCADE ON DELETE SET NULL;
+